@@ -41,7 +41,7 @@ from laud_files import (
     anonymize_existing_index, migrate_shares_to_per_file,
 )
 from laud_crypto import (
-    ShamirScheme, key_fingerprint, generate_fek,
+    ShamirScheme, key_fingerprint, generate_fek, presence_nullifier,
     DOMAIN_VAULT_FEK,
 )
 
@@ -2057,6 +2057,11 @@ class LaudCLI:
     # Custom handlers: Presence
     # ------------------------------------------------------------------
 
+    def _presence_nullifier(self, sec, epoch):
+        genesis = bytes.fromhex(self.substrate.get_block_hash(0)[2:])
+        n = presence_nullifier(bytes.fromhex(sec), int(epoch), genesis)
+        return "0x" + n.hex()
+
     def _presence_commit(self):
         e = self._prompt_epoch()
         a = self._prompt_account("Signer")
@@ -2068,7 +2073,8 @@ class LaudCLI:
         print(f"  {C.DIM}Randomness: {rnd[:32]}...{C.R}")
         print(f"  {C.DIM}Commitment: 0x{h[:32]}...{C.R}")
         self._submit("Presence", "declare_presence_with_commitment",
-                     {"epoch": e, "commitment": "0x" + h}, a)
+                     {"epoch": e, "commitment": "0x" + h,
+                      "nullifier": self._presence_nullifier(sec, e)}, a)
 
     def _presence_commitment_count(self):
         e = self._prompt_epoch()
@@ -2285,6 +2291,14 @@ class LaudCLI:
     # Custom handlers: Triangulation
     # ------------------------------------------------------------------
 
+    def _triangulation_register_reporter(self):
+        pos = self._prompt_position("Reporter position")
+        region = self._prompt("Region (blank = none)", "")
+        a = self._prompt_account()
+        self._submit("Triangulation", "register_reporter",
+                     {"position": pos,
+                      "region": int(region) if region.isdigit() else None}, a)
+
     def _triangulation_report_signal(self):
         rid = self._prompt_int("Reporter ID", 0)
         mac = self._prompt_h256("MAC hash")
@@ -2397,6 +2411,14 @@ class LaudCLI:
                       "trust_level": trust, "expires_at": None,
                       "bidirectional": bidir}, a)
 
+    def _semantic_accept(self):
+        rid = self._prompt_int("Relationship ID", 0)
+        ceiling = self._prompt("Max accepted trust (blank = any)", "")
+        a = self._prompt_account()
+        self._submit("Semantic", "accept_relationship",
+                     {"relationship_id": rid,
+                      "max_accepted_trust": int(ceiling) if ceiling.isdigit() else None}, a)
+
     # ------------------------------------------------------------------
     # Custom handlers: ZK
     # ------------------------------------------------------------------
@@ -2464,7 +2486,7 @@ class LaudCLI:
         cid = self._prompt_int("Cluster ID", 0)
         op = self._prompt_actor("Operator")
         self._submit("Octopus", "register_subnode",
-                     {"cluster_id": cid, "operator": op}, a)
+                     {"cluster_id": cid, "operator": op, "priority": None}, a)
 
     def _octopus_activate_subnode(self):
         a = self._prompt_account()
@@ -2499,8 +2521,10 @@ class LaudCLI:
 
     def _octopus_record_heartbeat(self):
         a = self._prompt_account()
+        sid = self._prompt_int("Subnode ID", 0)
+        seq = self._prompt_int("Sequence", 1)
         self._submit("Octopus", "record_heartbeat",
-                     {"subnode_id": self._prompt_int("Subnode ID", 0)}, a)
+                     {"subnode_id": sid, "sequence": seq}, a)
 
     def _octopus_device_observation(self):
         a = self._prompt_account()
@@ -2524,11 +2548,12 @@ class LaudCLI:
     def _octopus_heartbeat_device_proof(self):
         a = self._prompt_account()
         sid = self._prompt_int("Subnode ID", 0)
+        seq = self._prompt_int("Sequence", 1)
         dc = self._prompt_int("Device count", 5)
         cm = self._prompt_h256("Commitment")
         self._submit("Octopus", "heartbeat_with_device_proof",
-                     {"subnode_id": sid, "device_count": dc,
-                      "commitment": cm}, a)
+                     {"subnode_id": sid, "sequence": seq,
+                      "device_count": dc, "commitment": cm}, a)
 
     def _octopus_set_fusion_weights(self):
         a = self._prompt_account()
@@ -5687,7 +5712,9 @@ class LaudCLI:
 
         self._info(f"Committing (hash: 0x{h[:16]}...)")
         self._submit("Presence", "declare_presence_with_commitment",
-                     {"epoch": epoch, "commitment": "0x" + h}, "ferdie")
+                     {"epoch": epoch, "commitment": "0x" + h,
+                      "nullifier": self._presence_nullifier(sec, epoch)},
+                     "ferdie")
 
         self._val("Commitments",
                   self._query("Presence", "CommitmentCount", [epoch]))
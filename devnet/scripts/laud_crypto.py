@@ -12,6 +12,7 @@ DOMAIN_VSS = b"7ay:vss:v1"
 DOMAIN_VAULT_FEK = b"7ay:vault:fek:v1"
 DOMAIN_VAULT_FILE = b"7ay:vault:file:v1"
 DOMAIN_UNLOCK = b"7ay:unlock:v1"
+DOMAIN_NULLIFIER = b"7ay:nullifier:v1"
 
 RIJNDAEL_POLY = 0x11B
 
@@ -62,6 +63,17 @@ def hash_with_domain(domain, data):
     return hashlib.blake2b(domain + data, digest_size=32).digest()
 
 
+def presence_nullifier(secret, epoch_id, genesis_hash):
+    """Per-epoch presence nullifier (32 bytes).
+
+    Matches Rust Nullifier::derive, including the length-prefixed domain.
+    """
+    data = secret + epoch_id.to_bytes(8, "little") + genesis_hash
+    prefix = len(DOMAIN_NULLIFIER).to_bytes(4, "little")
+    return hashlib.blake2b(
+        prefix + DOMAIN_NULLIFIER + data, digest_size=32).digest()
+
+
 def key_fingerprint(fek):
     """Compute fingerprint of a File Encryption Key (32 bytes).
 
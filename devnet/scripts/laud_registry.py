@@ -528,9 +528,8 @@ DOMAINS = [
     4. Submit fraud proofs if you detect anomalies (option 5)
 """,
         commands=[
-            Command("1", "Register Reporter", "submit",
-                    pallet="Triangulation", function="register_reporter",
-                    params=[Param("position", "Reporter position", "position")],
+            Command("1", "Register Reporter", "custom",
+                    custom_handler="_triangulation_register_reporter",
                     help_text="Register a new signal reporter at a position"),
             Command("2", "Deregister Reporter", "submit",
                     pallet="Triangulation", function="deregister_reporter",
@@ -1127,9 +1126,8 @@ DOMAINS = [
             Command("1", "Create Relationship", "custom",
                     custom_handler="_semantic_create",
                     help_text="Start a new trust relationship with another identity"),
-            Command("2", "Accept Relationship", "submit",
-                    pallet="Semantic", function="accept_relationship",
-                    params=[Param("relationship_id", "Relationship ID", "int", 0)],
+            Command("2", "Accept Relationship", "custom",
+                    custom_handler="_semantic_accept",
                     help_text="Accept an incoming relationship request"),
             Command("3", "Revoke Relationship", "submit",
                     pallet="Semantic", function="revoke_relationship",
@@ -15,6 +15,7 @@ use parity_scale_codec::{Decode, Encode, MaxEncodedLen};
 use scale_info::TypeInfo;
 use seveny_primitives::types::ActorId;
 use sp_core::H256;
+use sp_runtime::Saturating;
 
 #[derive(
     Clone,
@@ -126,6 +127,8 @@ pub enum AutonomousStatus {
     Confirmed,
     UnderReview,
     Flagged,
+    /// Terminal: flagged `MaxFlagsBeforeBan` times. Only `lift_ban` can reverse it.
+    Banned,
 }
 
 #[derive(
@@ -226,6 +229,15 @@ pub mod pallet {
         /// Maximum number of distinct actors per pattern (M08).
         #[pallet::constant]
         type MaxActorsPerPattern: Get<u32>;
+
+        /// Flag count at which an actor is permanently banned.
+        #[pallet::constant]
+        type MaxFlagsBeforeBan: Get<u32>;
+
+        /// Percent of each new raw score folded into `automation_score`; 100
+        /// applies raw scores unsmoothed.
+        #[pallet::constant]
+        type ScoreSmoothingAlpha: Get<u8>;
     }
 
     #[pallet::storage]
@@ -273,6 +285,11 @@ pub mod pallet {
     #[pallet::getter(fn active_pattern_count)]
     pub type ActivePatternCount<T> = StorageValue<_, u32, ValueQuery>;
 
+    /// Unsmoothed automation score that `automation_score` follows.
+    #[pallet::storage]
+    #[pallet::getter(fn raw_automation_score)]
+    pub type RawAutomationScore<T: Config> = StorageMap<_, Blake2_128Concat, ActorId, u8>;
+
     #[pallet::genesis_config]
     #[derive(frame_support::DefaultNoBound)]
     pub struct GenesisConfig<T: Config> {
@@ -327,6 +344,21 @@ pub mod pallet {
             pattern_id: PatternId,
             actor: ActorId,
         },
+        /// Actor reached `MaxFlagsBeforeBan` and is now banned
+        ActorBanned {
+            actor: ActorId,
+            flag_count: u32,
+        },
+        /// Root lifted an actor's ban
+        BanLifted {
+            actor: ActorId,
+            new_status: AutonomousStatus,
+        },
+        /// A behavior outlived `BehaviorExpiryBlocks` and was removed
+        BehaviorExpired {
+            behavior_id: BehaviorId,
+            actor: ActorId,
+        },
     }
 
     #[pallet::error]
@@ -344,6 +376,12 @@ pub mod pallet {
         InvalidConfidenceScore,
         CannotFlagActor,
         BehaviorExpired,
+        /// Actor is banned; only `lift_ban` can change its status
+        ActorBanned,
+        /// Actor is not banned
+        ActorNotBanned,
+        /// Behavior is younger than `BehaviorExpiryBlocks`
+        BehaviorNotExpired,
     }
 
     #[pallet::call]
@@ -490,6 +528,11 @@ pub mod pallet {
             ActorProfiles::<T>::try_mutate(actor, |profile| -> DispatchResult {
                 let p = profile.as_mut().ok_or(Error::<T>::ProfileNotFound)?;
                 let old_status = p.status;
+                ensure!(
+                    old_status != AutonomousStatus::Banned
+                        && new_status != AutonomousStatus::Banned,
+                    Error::<T>::ActorBanned
+                );
                 p.status = new_status;
                 p.updated_at = block_number;
 
@@ -510,22 +553,33 @@ pub mod pallet {
 
             let block_number = frame_system::Pallet::<T>::block_number();
 
-            ActorProfiles::<T>::try_mutate(actor, |profile| -> DispatchResult {
-                let p = profile.as_mut().ok_or(Error::<T>::ProfileNotFound)?;
-
-                ensure!(
-                    p.status != AutonomousStatus::Flagged,
-                    Error::<T>::CannotFlagActor
-                );
-
-                p.status = AutonomousStatus::Flagged;
-                p.flag_count = p.flag_count.saturating_add(1);
-                p.updated_at = block_number;
+            let flag_count =
+                ActorProfiles::<T>::try_mutate(actor, |profile| -> Result<u32, DispatchError> {
+                    let p = profile.as_mut().ok_or(Error::<T>::ProfileNotFound)?;
+
+                    ensure!(
+                        !matches!(
+                            p.status,
+                            AutonomousStatus::Flagged | AutonomousStatus::Banned
+                        ),
+                        Error::<T>::CannotFlagActor
+                    );
+
+                    p.flag_count = p.flag_count.saturating_add(1);
+                    p.status = if p.flag_count >= T::MaxFlagsBeforeBan::get() {
+                        AutonomousStatus::Banned
+                    } else {
+                        AutonomousStatus::Flagged
+                    };
+                    p.updated_at = block_number;
 
-                Ok(())
-            })?;
+                    Ok(p.flag_count)
+                })?;
 
             Self::deposit_event(Event::ActorFlagged { actor, reason });
+            if flag_count >= T::MaxFlagsBeforeBan::get() {
+                Self::deposit_event(Event::ActorBanned { actor, flag_count });
+            }
 
             Ok(())
         }
@@ -595,6 +649,80 @@ pub mod pallet {
 
             Ok(())
         }
+
+        /// Root override for a ban: restores the actor to `new_status` and clears
+        /// its flag count.
+        #[pallet::call_index(7)]
+        #[pallet::weight(T::WeightInfo::lift_ban())]
+        pub fn lift_ban(
+            origin: OriginFor<T>,
+            actor: ActorId,
+            new_status: AutonomousStatus,
+        ) -> DispatchResult {
+            ensure_root(origin)?;
+            ensure!(
+                new_status != AutonomousStatus::Banned,
+                Error::<T>::ActorBanned
+            );
+
+            let block_number = frame_system::Pallet::<T>::block_number();
+
+            ActorProfiles::<T>::try_mutate(actor, |profile| -> DispatchResult {
+                let p = profile.as_mut().ok_or(Error::<T>::ProfileNotFound)?;
+                ensure!(
+                    p.status == AutonomousStatus::Banned,
+                    Error::<T>::ActorNotBanned
+                );
+                p.status = new_status;
+                p.flag_count = 0;
+                p.updated_at = block_number;
+                Ok(())
+            })?;
+
+            Self::deposit_event(Event::BanLifted { actor, new_status });
+
+            Ok(())
+        }
+
+        /// Remove a behavior older than `BehaviorExpiryBlocks`. An expired match
+        /// takes its `ScoreIncreasePerMatch` back off the actor's raw score.
+        #[pallet::call_index(8)]
+        #[pallet::weight(T::WeightInfo::expire_behavior())]
+        pub fn expire_behavior(
+            origin: OriginFor<T>,
+            actor: ActorId,
+            behavior_id: BehaviorId,
+        ) -> DispatchResult {
+            ensure_signed(origin)?;
+
+            let behavior =
+                ActorBehaviors::<T>::get(actor, behavior_id).ok_or(Error::<T>::BehaviorNotFound)?;
+            let block_number = frame_system::Pallet::<T>::block_number();
+            let expires_at = behavior
+                .recorded_at
+                .saturating_add(T::BehaviorExpiryBlocks::get());
+            ensure!(block_number >= expires_at, Error::<T>::BehaviorNotExpired);
+
+            ActorBehaviors::<T>::remove(actor, behavior_id);
+            BehaviorCountPerActor::<T>::mutate(actor, |count| *count = count.saturating_sub(1));
+
+            if behavior.matched_pattern.is_some() {
+                ActorProfiles::<T>::mutate(actor, |profile| {
+                    if let Some(ref mut p) = profile {
+                        let raw =
+                            Self::raw_score(p).saturating_sub(T::ScoreIncreasePerMatch::get());
+                        Self::apply_raw_score(p, raw);
+                        p.updated_at = block_number;
+
+                        Self::evaluate_status_decline(p);
+                    }
+                });
+            }
+
+            Self::deposit_event(Event::BehaviorExpired { behavior_id, actor });
+
+            Ok(())
+        }
     }
 
     impl<T: Config> Pallet<T> {
@@ -699,8 +827,10 @@ pub mod pallet {
                     if actor_count == 0 {
                         p.pattern_count = p.pattern_count.saturating_add(1);
                     }
-                    let score_increase = T::ScoreIncreasePerMatch::get();
-                    p.automation_score = p.automation_score.saturating_add(score_increase).min(100);
+                    let raw = Self::raw_score(p)
+                        .saturating_add(T::ScoreIncreasePerMatch::get())
+                        .min(100);
+                    Self::apply_raw_score(p, raw);
                     p.updated_at = block_number;
 
                     Self::evaluate_status_change(p);
@@ -708,13 +838,40 @@ pub mod pallet {
             });
         }
 
-        fn evaluate_status_change(profile: &mut ActorProfile<T>) {
-            let new_status = match profile.automation_score {
+        /// Raw score for the profile. Profiles scored before smoothing existed
+        /// start from their `automation_score`.
+        fn raw_score(profile: &ActorProfile<T>) -> u8 {
+            RawAutomationScore::<T>::get(profile.actor).unwrap_or(profile.automation_score)
+        }
+
+        /// Store the new raw score and move `automation_score` towards it by
+        /// `ScoreSmoothingAlpha` percent, always by at least one point.
+        fn apply_raw_score(profile: &mut ActorProfile<T>, raw: u8) {
+            RawAutomationScore::<T>::insert(profile.actor, raw);
+
+            let alpha = T::ScoreSmoothingAlpha::get().clamp(1, 100) as u16;
+            let current = profile.automation_score;
+            let blended = ((raw as u16 * alpha + current as u16 * (100 - alpha) + 50) / 100) as u8;
+            profile.automation_score = if blended != current || raw == current {
+                blended
+            } else if raw > current {
+                current.saturating_add(1)
+            } else {
+                current.saturating_sub(1)
+            };
+        }
+
+        fn score_band(score: u8) -> AutonomousStatus {
+            match score {
                 0..=20 => AutonomousStatus::Human,
                 21..=50 => AutonomousStatus::Suspected,
                 51..=100 => AutonomousStatus::Confirmed,
                 _ => AutonomousStatus::Unknown,
-            };
+            }
+        }
+
+        fn evaluate_status_change(profile: &mut ActorProfile<T>) {
+            let new_status = Self::score_band(profile.automation_score);
 
             if profile.status == AutonomousStatus::Unknown
                 || (profile.status == AutonomousStatus::Human
@@ -732,6 +889,28 @@ pub mod pallet {
             }
         }
 
+        /// Drop a Suspected or Confirmed actor to the band its score has fallen into.
+        fn evaluate_status_decline(profile: &mut ActorProfile<T>) {
+            let new_status = Self::score_band(profile.automation_score);
+            let declined = matches!(
+                (profile.status, new_status),
+                (
+                    AutonomousStatus::Confirmed,
+                    AutonomousStatus::Suspected | AutonomousStatus::Human
+                ) | (AutonomousStatus::Suspected, AutonomousStatus::Human)
+            );
+
+            if declined {
+                let old_status = profile.status;
+                profile.status = new_status;
+                Self::deposit_event(Event::StatusUpdated {
+                    actor: profile.actor,
+                    old_status,
+                    new_status,
+                });
+            }
+        }
+
         fn compute_pattern_signature(behavior_type: BehaviorType, data_hash: H256) -> H256 {
             const DOMAIN_AUTONOMOUS: &[u8] = b"7ay:autonomous:v1";
             let mut data = Vec::new();
@@ -744,6 +923,10 @@ pub mod pallet {
             ActorProfiles::<T>::get(actor).is_some_and(|p| p.status == AutonomousStatus::Confirmed)
         }
 
+        pub fn is_banned(actor: ActorId) -> bool {
+            ActorProfiles::<T>::get(actor).is_some_and(|p| p.status == AutonomousStatus::Banned)
+        }
+
         pub fn get_automation_score(actor: ActorId) -> u8 {
             ActorProfiles::<T>::get(actor)
                 .map(|p| p.automation_score)
@@ -766,6 +949,41 @@ pub mod pallet {
                 .collect()
         }
 
+        /// Behaviors `actor` recorded in blocks `from..=to`, ordered by behavior id.
+        ///
+        /// Reads every stored behavior of the actor, so the cost is bounded by
+        /// `MaxBehaviorsPerActor` regardless of the window.
+        pub fn behaviors_in_range(
+            actor: ActorId,
+            from: BlockNumberFor<T>,
+            to: BlockNumberFor<T>,
+        ) -> Vec<Behavior<T>> {
+            let mut behaviors: Vec<Behavior<T>> = ActorBehaviors::<T>::iter_prefix_values(actor)
+                .filter(|behavior| behavior.recorded_at >= from && behavior.recorded_at <= to)
+                .collect();
+            behaviors.sort_by_key(|behavior| behavior.id.0);
+            behaviors
+        }
+
+        /// Number of stored behaviors of `actor` per type, in `BehaviorType`
+        /// declaration order; types with no behaviors are omitted.
+        ///
+        /// Like `behaviors_in_range`, bounded by `MaxBehaviorsPerActor` reads.
+        pub fn behavior_type_histogram(actor: ActorId) -> Vec<(BehaviorType, u32)> {
+            let mut histogram: Vec<(BehaviorType, u32)> = Vec::new();
+            for behavior in ActorBehaviors::<T>::iter_prefix_values(actor) {
+                match histogram
+                    .iter_mut()
+                    .find(|(behavior_type, _)| *behavior_type == behavior.behavior_type)
+                {
+                    Some((_, count)) => *count = count.saturating_add(1),
+                    None => histogram.push((behavior.behavior_type, 1)),
+                }
+            }
+            histogram.sort_by_key(|(behavior_type, _)| *behavior_type as u8);
+            histogram
+        }
+
         pub fn get_active_patterns() -> u32 {
             ActivePatternCount::<T>::get()
         }
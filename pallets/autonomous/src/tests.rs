@@ -55,6 +55,8 @@ parameter_types! {
     pub const BehaviorExpiryBlocks: u64 = 1000;
     pub const ScoreIncreasePerMatch: u8 = 10;
     pub const MaxActorsPerPattern: u32 = 100;
+    pub const MaxFlagsBeforeBan: u32 = 3;
+    pub static ScoreSmoothingAlpha: u8 = 100;
 }
 
 impl pallet_autonomous::Config for Test {
@@ -65,6 +67,8 @@ impl pallet_autonomous::Config for Test {
     type BehaviorExpiryBlocks = BehaviorExpiryBlocks;
     type ScoreIncreasePerMatch = ScoreIncreasePerMatch;
     type MaxActorsPerPattern = MaxActorsPerPattern;
+    type MaxFlagsBeforeBan = MaxFlagsBeforeBan;
+    type ScoreSmoothingAlpha = ScoreSmoothingAlpha;
 }
 
 fn new_test_ext() -> sp_io::TestExternalities {
@@ -560,3 +564,287 @@ fn match_behavior_nonexistent_behavior() {
         );
     });
 }
+
+// ============================================================================
+// Flag-Count Ban Tests
+// ============================================================================
+
+/// Flag `actor` `times` times, clearing any prior flag through `update_status` first.
+fn flag_repeatedly(actor: ActorId, times: u8) {
+    for i in 0..times {
+        let profile = Autonomous::actor_profiles(actor).expect("profile should exist");
+        if profile.status == AutonomousStatus::Flagged {
+            assert_ok!(Autonomous::update_status(
+                RuntimeOrigin::root(),
+                actor,
+                AutonomousStatus::UnderReview
+            ));
+        }
+        assert_ok!(Autonomous::flag_actor(
+            RuntimeOrigin::root(),
+            actor,
+            H256([i; 32])
+        ));
+    }
+}
+
+#[test]
+fn reaching_flag_threshold_bans_actor() {
+    new_test_ext().execute_with(|| {
+        let actor = account_to_actor(1);
+        assert_ok!(Autonomous::create_profile(RuntimeOrigin::signed(1), actor));
+
+        flag_repeatedly(actor, 2);
+        assert!(!Autonomous::is_banned(actor));
+
+        flag_repeatedly(actor, 1);
+        let profile = Autonomous::actor_profiles(actor).expect("profile should exist");
+        assert_eq!(profile.status, AutonomousStatus::Banned);
+        assert_eq!(profile.flag_count, 3);
+        assert!(Autonomous::is_banned(actor));
+        System::assert_has_event(RuntimeEvent::Autonomous(Event::ActorBanned {
+            actor,
+            flag_count: 3,
+        }));
+    });
+}
+
+#[test]
+fn ban_is_terminal_except_for_root_override() {
+    new_test_ext().execute_with(|| {
+        let actor = account_to_actor(1);
+        assert_ok!(Autonomous::create_profile(RuntimeOrigin::signed(1), actor));
+        flag_repeatedly(actor, 3);
+
+        assert_noop!(
+            Autonomous::update_status(RuntimeOrigin::root(), actor, AutonomousStatus::Human),
+            Error::<Test>::ActorBanned
+        );
+        assert_noop!(
+            Autonomous::flag_actor(RuntimeOrigin::root(), actor, H256([9u8; 32])),
+            Error::<Test>::CannotFlagActor
+        );
+        assert!(
+            Autonomous::lift_ban(RuntimeOrigin::signed(1), actor, AutonomousStatus::Human).is_err()
+        );
+
+        assert_ok!(Autonomous::lift_ban(
+            RuntimeOrigin::root(),
+            actor,
+            AutonomousStatus::UnderReview
+        ));
+        let profile = Autonomous::actor_profiles(actor).expect("profile should exist");
+        assert_eq!(profile.status, AutonomousStatus::UnderReview);
+        assert_eq!(profile.flag_count, 0);
+        assert!(!Autonomous::is_banned(actor));
+        assert_noop!(
+            Autonomous::lift_ban(RuntimeOrigin::root(), actor, AutonomousStatus::Human),
+            Error::<Test>::ActorNotBanned
+        );
+    });
+}
+
+// ============================================================================
+// Behavior Window Query Tests
+// ============================================================================
+
+#[test]
+fn behaviors_in_range_filters_by_recorded_block() {
+    new_test_ext().execute_with(|| {
+        let actor = account_to_actor(1);
+        let types = [
+            BehaviorType::PresencePattern,
+            BehaviorType::NetworkPattern,
+            BehaviorType::PresencePattern,
+            BehaviorType::TemporalPattern,
+            BehaviorType::PresencePattern,
+        ];
+
+        // One behavior per block, at blocks 1..=5.
+        for (i, behavior_type) in types.iter().enumerate() {
+            System::set_block_number(i as u64 + 1);
+            assert_ok!(Autonomous::record_behavior(
+                RuntimeOrigin::signed(1),
+                actor,
+                *behavior_type,
+                H256([i as u8; 32])
+            ));
+        }
+
+        let window = Autonomous::behaviors_in_range(actor, 2, 4);
+        let blocks: Vec<u64> = window.iter().map(|b| b.recorded_at).collect();
+        assert_eq!(blocks, vec![2, 3, 4]);
+        assert_eq!(
+            window.iter().map(|b| b.id).collect::<Vec<_>>(),
+            vec![BehaviorId::new(1), BehaviorId::new(2), BehaviorId::new(3)]
+        );
+
+        assert_eq!(Autonomous::behaviors_in_range(actor, 1, 5).len(), 5);
+        assert!(Autonomous::behaviors_in_range(actor, 6, 10).is_empty());
+        assert!(Autonomous::behaviors_in_range(account_to_actor(2), 1, 5).is_empty());
+    });
+}
+
+#[test]
+fn behavior_type_histogram_counts_per_type() {
+    new_test_ext().execute_with(|| {
+        let actor = account_to_actor(1);
+        let types = [
+            BehaviorType::NetworkPattern,
+            BehaviorType::PresencePattern,
+            BehaviorType::NetworkPattern,
+            BehaviorType::PresencePattern,
+            BehaviorType::PresencePattern,
+            BehaviorType::Custom,
+        ];
+
+        for (i, behavior_type) in types.iter().enumerate() {
+            System::set_block_number(i as u64 + 1);
+            assert_ok!(Autonomous::record_behavior(
+                RuntimeOrigin::signed(1),
+                actor,
+                *behavior_type,
+                H256([i as u8; 32])
+            ));
+        }
+
+        assert_eq!(
+            Autonomous::behavior_type_histogram(actor),
+            vec![
+                (BehaviorType::PresencePattern, 3),
+                (BehaviorType::NetworkPattern, 2),
+                (BehaviorType::Custom, 1),
+            ]
+        );
+        assert!(Autonomous::behavior_type_histogram(account_to_actor(2)).is_empty());
+    });
+}
+
+// ============================================================================
+// Score Smoothing Tests
+// ============================================================================
+
+/// Record a behavior for account 1 and match it against pattern 0.
+fn record_matched_behavior(seed: u8) -> BehaviorId {
+    let actor = account_to_actor(1);
+    let behavior_id = BehaviorId::new(Autonomous::behavior_count());
+    assert_ok!(Autonomous::record_behavior(
+        RuntimeOrigin::signed(1),
+        actor,
+        BehaviorType::PresencePattern,
+        H256([seed; 32])
+    ));
+    assert_ok!(Autonomous::match_behavior(
+        RuntimeOrigin::root(),
+        behavior_id,
+        actor,
+        PatternId::new(0)
+    ));
+    behavior_id
+}
+
+/// Six matches at block 1, then three rounds of one expiry and one fresh match
+/// at block 2000. Returns the statuses seen after each round step.
+fn alternate_matches_and_expiries() -> Vec<AutonomousStatus> {
+    let actor = account_to_actor(1);
+    assert_ok!(Autonomous::register_pattern(
+        RuntimeOrigin::root(),
+        BehaviorType::PresencePattern,
+        H256([200u8; 32]),
+        PatternClassification::Automated
+    ));
+    let old: Vec<BehaviorId> = (0..6).map(record_matched_behavior).collect();
+    assert_eq!(Autonomous::raw_automation_score(actor), Some(60));
+
+    System::set_block_number(2000);
+    System::reset_events();
+    let mut statuses = Vec::new();
+    for (round, behavior_id) in old.into_iter().take(3).enumerate() {
+        assert_ok!(Autonomous::expire_behavior(
+            RuntimeOrigin::signed(2),
+            actor,
+            behavior_id
+        ));
+        statuses.push(profile_status(actor));
+        record_matched_behavior(100 + round as u8);
+        statuses.push(profile_status(actor));
+    }
+    statuses
+}
+
+fn profile_status(actor: ActorId) -> AutonomousStatus {
+    Autonomous::actor_profiles(actor)
+        .expect("profile should exist")
+        .status
+}
+
+fn status_update_count() -> usize {
+    System::events()
+        .iter()
+        .filter(|record| {
+            matches!(
+                record.event,
+                RuntimeEvent::Autonomous(Event::StatusUpdated { .. })
+            )
+        })
+        .count()
+}
+
+#[test]
+fn unsmoothed_score_flaps_on_alternating_events() {
+    new_test_ext().execute_with(|| {
+        let statuses = alternate_matches_and_expiries();
+
+        let (suspected, confirmed) = (AutonomousStatus::Suspected, AutonomousStatus::Confirmed);
+        assert_eq!(
+            statuses,
+            vec![suspected, confirmed, suspected, confirmed, suspected, confirmed]
+        );
+        assert_eq!(status_update_count(), 6);
+    });
+}
+
+#[test]
+fn smoothed_score_holds_status_on_alternating_events() {
+    ScoreSmoothingAlpha::set(20);
+    new_test_ext().execute_with(|| {
+        let actor = account_to_actor(1);
+        let statuses = alternate_matches_and_expiries();
+
+        assert!(statuses.iter().all(|s| *s == AutonomousStatus::Suspected));
+        assert_eq!(status_update_count(), 0);
+        assert_eq!(Autonomous::raw_automation_score(actor), Some(60));
+        assert_eq!(Autonomous::get_automation_score(actor), 50);
+    });
+    ScoreSmoothingAlpha::set(100);
+}
+
+#[test]
+fn expire_behavior_requires_expiry_window() {
+    new_test_ext().execute_with(|| {
+        let actor = account_to_actor(1);
+        assert_ok!(Autonomous::register_pattern(
+            RuntimeOrigin::root(),
+            BehaviorType::PresencePattern,
+            H256([200u8; 32]),
+            PatternClassification::Automated
+        ));
+        let behavior_id = record_matched_behavior(1);
+
+        System::set_block_number(BehaviorExpiryBlocks::get());
+        assert_noop!(
+            Autonomous::expire_behavior(RuntimeOrigin::signed(2), actor, behavior_id),
+            Error::<Test>::BehaviorNotExpired
+        );
+
+        System::set_block_number(1 + BehaviorExpiryBlocks::get());
+        assert_ok!(Autonomous::expire_behavior(
+            RuntimeOrigin::signed(2),
+            actor,
+            behavior_id
+        ));
+        assert!(Autonomous::actor_behaviors(actor, behavior_id).is_none());
+        assert_eq!(Autonomous::behavior_count_per_actor(actor), 0);
+        assert_eq!(Autonomous::get_automation_score(actor), 0);
+    });
+}
@@ -15,7 +15,10 @@ use parity_scale_codec::{Decode, Encode, MaxEncodedLen};
 use scale_info::TypeInfo;
 use seveny_primitives::types::ActorId;
 use sp_core::H256;
-use sp_runtime::Saturating;
+use sp_runtime::{traits::Zero, Saturating};
+
+/// Learned heartbeat intervals a device may go silent for before it counts as a miss.
+const ADAPTIVE_TIMEOUT_MULTIPLIER: u32 = 3;
 
 #[derive(
     Clone,
@@ -85,6 +88,13 @@ pub enum DeviceStatus {
     Offline,
 }
 
+impl DeviceStatus {
+    /// Revoked and compromised devices can never return to service.
+    pub fn is_terminal(&self) -> bool {
+        matches!(self, Self::Revoked | Self::Compromised)
+    }
+}
+
 #[derive(
     Clone,
     Debug,
@@ -102,6 +112,50 @@ pub struct HeartbeatInfo<BlockNumber> {
     pub sequence: u64,
     pub consecutive_misses: u32,
     pub health_score: u8,
+    /// Block of the last accepted heartbeat. Unlike `last_heartbeat`, the offline
+    /// check never advances it.
+    pub last_received: BlockNumber,
+    /// Smoothed interval between heartbeats; zero until learned.
+    pub average_interval: BlockNumber,
+}
+
+/// Shape of the health gain applied on each heartbeat.
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Default,
+    PartialEq,
+    Eq,
+    Encode,
+    Decode,
+    parity_scale_codec::DecodeWithMemTracking,
+    TypeInfo,
+    MaxEncodedLen,
+)]
+pub enum HealthRecoveryCurve {
+    /// Every heartbeat recovers the full step.
+    #[default]
+    Linear,
+    /// The step shrinks with the remaining gap to 100, so health approaches
+    /// full slowly and a flapping device cannot bounce straight back.
+    Asymptotic,
+}
+
+impl HealthRecoveryCurve {
+    /// Health after one heartbeat, given the configured per-heartbeat `step`.
+    pub fn recover(&self, health: u8, step: u8) -> u8 {
+        let health = health.min(100);
+        let gain = match self {
+            Self::Linear => step,
+            Self::Asymptotic => {
+                let gap = 100u16.saturating_sub(health as u16);
+                let scaled = (step as u16).saturating_mul(gap).div_ceil(100);
+                scaled as u8
+            }
+        };
+        health.saturating_add(gain).min(100)
+    }
 }
 
 #[derive(
@@ -126,6 +180,46 @@ pub enum AttestationType {
     SecureEnclave,
 }
 
+impl AttestationType {
+    /// Whether the attestation is rooted in device hardware.
+    pub fn is_hardware_backed(&self) -> bool {
+        matches!(self, Self::HardwareBacked | Self::Tpm | Self::SecureEnclave)
+    }
+
+    /// Rank used to tell an upgrade from a downgrade. Hardware-rooted types
+    /// share the top rank.
+    pub fn strength(&self) -> u8 {
+        match self {
+            Self::SelfSigned => 0,
+            Self::TrustedParty => 1,
+            Self::HardwareBacked | Self::Tpm | Self::SecureEnclave => 2,
+        }
+    }
+}
+
+/// Checks attestation evidence for a claimed attestation type before a device
+/// is upgraded to it.
+pub trait AttestationVerifier {
+    fn verify(
+        device_id: DeviceId,
+        public_key_hash: H256,
+        attestation_type: AttestationType,
+        attestation_hash: H256,
+    ) -> bool;
+}
+
+/// Never verifies -- use where no attestation verifier is configured.
+impl AttestationVerifier for () {
+    fn verify(
+        _device_id: DeviceId,
+        _public_key_hash: H256,
+        _attestation_type: AttestationType,
+        _attestation_hash: H256,
+    ) -> bool {
+        false
+    }
+}
+
 #[derive(
     Clone,
     Debug,
@@ -202,6 +296,41 @@ pub mod pallet {
 
         #[pallet::constant]
         type HealthScoreRecovery: Get<u8>;
+
+        /// How `HealthScoreRecovery` is applied as health nears 100.
+        #[pallet::constant]
+        type HealthRecoveryCurve: Get<HealthRecoveryCurve>;
+
+        /// Trust score at which an active device qualifies its owner as an attester
+        /// without hardware-backed attestation.
+        #[pallet::constant]
+        type MinAttesterTrustScore: Get<u8>;
+
+        /// Maximum number of devices accepted by a single batch registration.
+        #[pallet::constant]
+        type MaxBatchRegister: Get<u32>;
+
+        /// Upper bound on a device's learned heartbeat timeout. `HeartbeatTimeoutBlocks`
+        /// remains the lower bound.
+        #[pallet::constant]
+        type MaxHeartbeatTimeoutBlocks: Get<BlockNumberFor<Self>>;
+
+        /// Health below which a missed heartbeat takes a device offline before it
+        /// reaches `MaxConsecutiveMisses`. 0 disables the rule.
+        #[pallet::constant]
+        type MinHealthScore: Get<u8>;
+
+        /// Health an offline device must recover to before a heartbeat reactivates it.
+        /// Should not be below `MinHealthScore`.
+        #[pallet::constant]
+        type RecoveryHealthScore: Get<u8>;
+
+        /// Verifies the evidence behind `upgrade_attestation_type`.
+        type AttestationVerifier: AttestationVerifier;
+
+        /// Trust score added when a device upgrades its attestation type.
+        #[pallet::constant]
+        type AttestationUpgradeTrustBonus: Get<u8>;
     }
 
     #[pallet::storage]
@@ -222,6 +351,12 @@ pub mod pallet {
     pub type DeviceCountPerActor<T: Config> =
         StorageMap<_, Blake2_128Concat, ActorId, u32, ValueQuery>;
 
+    /// Devices per actor that are not yet revoked or compromised; enforces the device cap.
+    #[pallet::storage]
+    #[pallet::getter(fn active_device_count_per_actor)]
+    pub type ActiveDeviceCountPerActor<T: Config> =
+        StorageMap<_, Blake2_128Concat, ActorId, u32, ValueQuery>;
+
     #[pallet::storage]
     #[pallet::getter(fn attestations)]
     pub type Attestations<T: Config> =
@@ -297,6 +432,11 @@ pub mod pallet {
             device_id: DeviceId,
             attestation_hash: H256,
         },
+        /// Attestation invalidated because the device was revoked or compromised
+        AttestationRevoked {
+            device_id: DeviceId,
+            attestation_hash: H256,
+        },
         TrustScoreUpdated {
             device_id: DeviceId,
             old_score: u8,
@@ -318,6 +458,18 @@ pub mod pallet {
             device_id: DeviceId,
             health_score: u8,
         },
+        DevicesBatchRegistered {
+            owner: ActorId,
+            registered: u32,
+            skipped: u32,
+        },
+        /// Device moved to a stronger attestation type
+        AttestationTypeUpgraded {
+            device_id: DeviceId,
+            old_type: AttestationType,
+            new_type: AttestationType,
+            attestation_hash: H256,
+        },
     }
 
     #[pallet::error]
@@ -337,6 +489,13 @@ pub mod pallet {
         InvalidTrustScore,
         InvalidHeartbeatSequence,
         DeviceOffline,
+        /// Attester has no active device with valid hardware-backed attestation
+        /// or sufficient trust
+        UntrustedAttester,
+        /// New attestation type is not stronger than the current one
+        AttestationDowngrade,
+        /// `AttestationVerifier` rejected the attestation
+        AttestationVerificationFailed,
     }
 
     #[pallet::call]
@@ -357,37 +516,13 @@ pub mod pallet {
                 Error::<T>::PublicKeyAlreadyUsed
             );
 
-            let device_count = DeviceCountPerActor::<T>::get(owner);
+            let device_count = ActiveDeviceCountPerActor::<T>::get(owner);
             ensure!(
                 device_count < T::MaxDevicesPerActor::get(),
                 Error::<T>::MaxDevicesReached
             );
 
-            let block_number = frame_system::Pallet::<T>::block_number();
-            let device_id = Self::next_device_id();
-
-            let device = Device {
-                id: device_id,
-                owner,
-                device_type,
-                public_key_hash,
-                attestation_type,
-                status: DeviceStatus::Pending,
-                registered_at: block_number,
-                last_active: block_number,
-                trust_score: T::InitialTrustScore::get(),
-            };
-
-            Devices::<T>::insert(device_id, device);
-            ActorDevices::<T>::insert(owner, device_id, ());
-            DeviceCountPerActor::<T>::mutate(owner, |count| *count = count.saturating_add(1));
-            PublicKeyDevice::<T>::insert(public_key_hash, device_id);
-
-            Self::deposit_event(Event::DeviceRegistered {
-                device_id,
-                owner,
-                device_type,
-            });
+            Self::do_register_device(owner, device_type, public_key_hash, attestation_type);
 
             Ok(())
         }
@@ -459,8 +594,12 @@ pub mod pallet {
                 if d.status == DeviceStatus::Active {
                     ActiveDeviceCount::<T>::mutate(|count| *count = count.saturating_sub(1));
                 }
+                if !d.status.is_terminal() {
+                    Self::release_device_slot(d.owner);
+                }
 
                 d.status = DeviceStatus::Revoked;
+                Self::revoke_attestation(device_id);
 
                 Self::deposit_event(Event::DeviceRevoked { device_id });
 
@@ -479,8 +618,12 @@ pub mod pallet {
                 if d.status == DeviceStatus::Active {
                     ActiveDeviceCount::<T>::mutate(|count| *count = count.saturating_sub(1));
                 }
+                if !d.status.is_terminal() {
+                    Self::release_device_slot(d.owner);
+                }
 
                 d.status = DeviceStatus::Compromised;
+                Self::revoke_attestation(device_id);
 
                 Self::deposit_event(Event::DeviceMarkedCompromised { device_id });
 
@@ -501,8 +644,16 @@ pub mod pallet {
 
             let device = Devices::<T>::get(device_id).ok_or(Error::<T>::DeviceNotFound)?;
             ensure!(device.owner == caller_actor, Error::<T>::NotDeviceOwner);
+            ensure!(!device.status.is_terminal(), Error::<T>::DeviceNotActive);
 
             let block_number = frame_system::Pallet::<T>::block_number();
+            if let Some(attester) = attester {
+                ensure!(
+                    Self::is_trusted_attester(attester, block_number),
+                    Error::<T>::UntrustedAttester
+                );
+            }
+
             let valid_until =
                 Some(block_number.saturating_add(T::AttestationValidityBlocks::get()));
 
@@ -631,6 +782,8 @@ pub mod pallet {
                     sequence: 0,
                     consecutive_misses: 0,
                     health_score: 100,
+                    last_received: block_number,
+                    average_interval: Zero::zero(),
                 });
 
                 ensure!(
@@ -640,17 +793,20 @@ pub mod pallet {
 
                 let was_offline = d.status == DeviceStatus::Offline;
 
+                let interval = block_number.saturating_sub(heartbeat.last_received);
+                heartbeat.average_interval =
+                    Self::smooth_interval(heartbeat.average_interval, interval);
+
                 heartbeat.last_heartbeat = block_number;
+                heartbeat.last_received = block_number;
                 heartbeat.sequence = sequence;
                 heartbeat.consecutive_misses = 0;
-                heartbeat.health_score = heartbeat
-                    .health_score
-                    .saturating_add(T::HealthScoreRecovery::get())
-                    .min(100);
+                heartbeat.health_score = T::HealthRecoveryCurve::get()
+                    .recover(heartbeat.health_score, T::HealthScoreRecovery::get());
 
                 d.last_active = block_number;
 
-                if was_offline {
+                if was_offline && heartbeat.health_score >= T::RecoveryHealthScore::get() {
                     d.status = DeviceStatus::Active;
                     OfflineDeviceCount::<T>::mutate(|c| *c = c.saturating_sub(1));
                     ActiveDeviceCount::<T>::mutate(|c| *c = c.saturating_add(1));
@@ -672,6 +828,131 @@ pub mod pallet {
                 Ok(())
             })
         }
+
+        /// Register several devices for one owner in a single call.
+        ///
+        /// Public keys already bound to a device, or repeated within the batch, are
+        /// skipped. The whole batch is rejected if the remaining devices would take
+        /// the owner past `MaxDevicesPerActor`.
+        #[pallet::call_index(10)]
+        #[pallet::weight(T::WeightInfo::register_devices_batch(devices.len() as u32))]
+        pub fn register_devices_batch(
+            origin: OriginFor<T>,
+            owner: ActorId,
+            devices: BoundedVec<(DeviceType, H256, AttestationType), T::MaxBatchRegister>,
+        ) -> DispatchResult {
+            if let Some(caller) = ensure_signed_or_root(origin)? {
+                ensure!(
+                    Self::account_to_actor(&caller) == owner,
+                    Error::<T>::NotDeviceOwner
+                );
+            }
+
+            let total = devices.len() as u32;
+            let mut seen: Vec<H256> = Vec::with_capacity(devices.len());
+            let mut to_register = Vec::with_capacity(devices.len());
+            for (device_type, public_key_hash, attestation_type) in devices {
+                if seen.contains(&public_key_hash)
+                    || PublicKeyDevice::<T>::contains_key(public_key_hash)
+                {
+                    continue;
+                }
+                seen.push(public_key_hash);
+                to_register.push((device_type, public_key_hash, attestation_type));
+            }
+
+            let registered = to_register.len() as u32;
+            let skipped = total.saturating_sub(registered);
+            let device_count = ActiveDeviceCountPerActor::<T>::get(owner);
+            ensure!(
+                device_count.saturating_add(registered) <= T::MaxDevicesPerActor::get(),
+                Error::<T>::MaxDevicesReached
+            );
+
+            for (device_type, public_key_hash, attestation_type) in to_register {
+                Self::do_register_device(owner, device_type, public_key_hash, attestation_type);
+            }
+
+            Self::deposit_event(Event::DevicesBatchRegistered {
+                owner,
+                registered,
+                skipped,
+            });
+
+            Ok(())
+        }
+
+        /// Move a device to a stronger attestation type once `AttestationVerifier`
+        /// accepts the evidence. Records a fresh attestation and adds
+        /// `AttestationUpgradeTrustBonus` to the trust score.
+        #[pallet::call_index(11)]
+        #[pallet::weight(T::WeightInfo::upgrade_attestation_type())]
+        pub fn upgrade_attestation_type(
+            origin: OriginFor<T>,
+            device_id: DeviceId,
+            new_type: AttestationType,
+            attestation_hash: H256,
+        ) -> DispatchResult {
+            let caller = ensure_signed(origin)?;
+            let caller_actor = Self::account_to_actor(&caller);
+
+            let mut device = Devices::<T>::get(device_id).ok_or(Error::<T>::DeviceNotFound)?;
+            ensure!(device.owner == caller_actor, Error::<T>::NotDeviceOwner);
+            ensure!(!device.status.is_terminal(), Error::<T>::DeviceNotActive);
+
+            let old_type = device.attestation_type;
+            ensure!(
+                new_type.strength() > old_type.strength(),
+                Error::<T>::AttestationDowngrade
+            );
+            ensure!(
+                T::AttestationVerifier::verify(
+                    device_id,
+                    device.public_key_hash,
+                    new_type,
+                    attestation_hash
+                ),
+                Error::<T>::AttestationVerificationFailed
+            );
+
+            let block_number = frame_system::Pallet::<T>::block_number();
+            Attestations::<T>::insert(
+                device_id,
+                DeviceAttestation {
+                    device: device_id,
+                    attestation_hash,
+                    attester: None,
+                    attested_at: block_number,
+                    valid_until: Some(
+                        block_number.saturating_add(T::AttestationValidityBlocks::get()),
+                    ),
+                },
+            );
+
+            let old_score = device.trust_score;
+            device.attestation_type = new_type;
+            device.trust_score = old_score
+                .saturating_add(T::AttestationUpgradeTrustBonus::get())
+                .min(100);
+            let new_score = device.trust_score;
+            Devices::<T>::insert(device_id, device);
+
+            Self::deposit_event(Event::AttestationTypeUpgraded {
+                device_id,
+                old_type,
+                new_type,
+                attestation_hash,
+            });
+            if new_score != old_score {
+                Self::deposit_event(Event::TrustScoreUpdated {
+                    device_id,
+                    old_score,
+                    new_score,
+                });
+            }
+
+            Ok(())
+        }
     }
 
     impl<T: Config> Pallet<T> {
@@ -679,6 +960,56 @@ pub mod pallet {
             seveny_primitives::crypto::derive_actor_id(&account.encode())
         }
 
+        /// Drop a device's attestation so `is_attestation_valid` fails immediately.
+        fn revoke_attestation(device_id: DeviceId) {
+            if let Some(attestation) = Attestations::<T>::take(device_id) {
+                Self::deposit_event(Event::AttestationRevoked {
+                    device_id,
+                    attestation_hash: attestation.attestation_hash,
+                });
+            }
+        }
+
+        fn release_device_slot(owner: ActorId) {
+            ActiveDeviceCountPerActor::<T>::mutate(owner, |count| *count = count.saturating_sub(1));
+        }
+
+        fn do_register_device(
+            owner: ActorId,
+            device_type: DeviceType,
+            public_key_hash: H256,
+            attestation_type: AttestationType,
+        ) -> DeviceId {
+            let block_number = frame_system::Pallet::<T>::block_number();
+            let device_id = Self::next_device_id();
+
+            let device = Device {
+                id: device_id,
+                owner,
+                device_type,
+                public_key_hash,
+                attestation_type,
+                status: DeviceStatus::Pending,
+                registered_at: block_number,
+                last_active: block_number,
+                trust_score: T::InitialTrustScore::get(),
+            };
+
+            Devices::<T>::insert(device_id, device);
+            ActorDevices::<T>::insert(owner, device_id, ());
+            DeviceCountPerActor::<T>::mutate(owner, |count| *count = count.saturating_add(1));
+            ActiveDeviceCountPerActor::<T>::mutate(owner, |count| *count = count.saturating_add(1));
+            PublicKeyDevice::<T>::insert(public_key_hash, device_id);
+
+            Self::deposit_event(Event::DeviceRegistered {
+                device_id,
+                owner,
+                device_type,
+            });
+
+            device_id
+        }
+
         fn next_device_id() -> DeviceId {
             let id = DeviceCount::<T>::get();
             DeviceCount::<T>::put(id.saturating_add(1));
@@ -716,6 +1047,27 @@ pub mod pallet {
                 .is_some_and(|a| a.valid_until.is_none_or(|until| block_number <= until))
         }
 
+        /// An actor may vouch for other devices if it owns an active device that
+        /// either holds a valid hardware-backed attestation or has reached
+        /// `MinAttesterTrustScore`.
+        pub fn is_trusted_attester(actor: ActorId, block_number: BlockNumberFor<T>) -> bool {
+            Self::owned_devices(actor)
+                .filter(|d| d.status == DeviceStatus::Active)
+                .any(|d| {
+                    d.trust_score >= T::MinAttesterTrustScore::get()
+                        || (d.attestation_type.is_hardware_backed()
+                            && Self::is_attestation_valid(d.id, block_number))
+                })
+        }
+
+        /// Every device `actor` has registered, revoked ones included, bounded by
+        /// its `DeviceCountPerActor` rather than the active-device cap.
+        fn owned_devices(actor: ActorId) -> impl Iterator<Item = Device<T>> {
+            ActorDevices::<T>::iter_key_prefix(actor)
+                .take(DeviceCountPerActor::<T>::get(actor) as usize)
+                .filter_map(Devices::<T>::get)
+        }
+
         pub fn get_total_active_devices() -> u32 {
             ActiveDeviceCount::<T>::get()
         }
@@ -727,9 +1079,9 @@ pub mod pallet {
         /// Check heartbeats for offline devices. Bounded to 50 entries per block.
         /// Returns the number of heartbeats processed (for weight accounting).
         fn detect_offline_devices(current_block: BlockNumberFor<T>) -> u32 {
-            let timeout = T::HeartbeatTimeoutBlocks::get();
             let max_misses = T::MaxConsecutiveMisses::get();
             let decay = T::HealthScoreDecay::get();
+            let min_health = T::MinHealthScore::get();
             let max_per_block: u32 = 50;
             let mut processed: u32 = 0;
 
@@ -747,7 +1099,7 @@ pub mod pallet {
                 }
 
                 let blocks_since = current_block.saturating_sub(heartbeat.last_heartbeat);
-                if blocks_since < timeout {
+                if blocks_since < Self::heartbeat_timeout(&heartbeat) {
                     continue;
                 }
                 // M18: only penalize once per timeout period by advancing
@@ -756,7 +1108,8 @@ pub mod pallet {
                 heartbeat.consecutive_misses = heartbeat.consecutive_misses.saturating_add(1);
                 heartbeat.health_score = heartbeat.health_score.saturating_sub(decay);
 
-                if heartbeat.consecutive_misses >= max_misses {
+                let unhealthy = heartbeat.health_score < min_health;
+                if heartbeat.consecutive_misses >= max_misses || unhealthy {
                     Self::set_device_offline(device_id, heartbeat.consecutive_misses);
                 }
 
@@ -765,6 +1118,36 @@ pub mod pallet {
             processed
         }
 
+        /// Timeout for a device: `ADAPTIVE_TIMEOUT_MULTIPLIER` times its learned
+        /// interval, clamped between `HeartbeatTimeoutBlocks` and
+        /// `MaxHeartbeatTimeoutBlocks`.
+        pub fn heartbeat_timeout(
+            heartbeat: &HeartbeatInfo<BlockNumberFor<T>>,
+        ) -> BlockNumberFor<T> {
+            let floor = T::HeartbeatTimeoutBlocks::get();
+            let ceiling = T::MaxHeartbeatTimeoutBlocks::get().max(floor);
+            heartbeat
+                .average_interval
+                .saturating_mul(ADAPTIVE_TIMEOUT_MULTIPLIER.into())
+                .clamp(floor, ceiling)
+        }
+
+        /// Exponential moving average (weight 1/4) of heartbeat intervals. Zero
+        /// intervals (several beats in one block) carry no cadence information, and
+        /// gaps beyond `MaxHeartbeatTimeoutBlocks` are outages rather than cadence.
+        fn smooth_interval(
+            average: BlockNumberFor<T>,
+            interval: BlockNumberFor<T>,
+        ) -> BlockNumberFor<T> {
+            if interval.is_zero() || interval > T::MaxHeartbeatTimeoutBlocks::get() {
+                return average;
+            }
+            if average.is_zero() {
+                return interval;
+            }
+            average.saturating_mul(3u32.into()).saturating_add(interval) / 4u32.into()
+        }
+
         /// C12: counter mutations inside if-let to prevent orphaned updates
         fn set_device_offline(device_id: DeviceId, consecutive_misses: u32) {
             if let Some(mut dev) = Devices::<T>::get(device_id) {
@@ -781,4 +1164,22 @@ pub mod pallet {
             }
         }
     }
+
+    impl<T: Config> seveny_primitives::traits::DeviceRegistry for Pallet<T> {
+        type DeviceId = DeviceId;
+
+        fn device_owner(device_id: DeviceId) -> Option<ActorId> {
+            Devices::<T>::get(device_id).map(|d| d.owner)
+        }
+
+        fn is_device_active(device_id: DeviceId) -> bool {
+            Devices::<T>::get(device_id)
+                .map(|d| d.status == DeviceStatus::Active)
+                .unwrap_or(false)
+        }
+
+        fn has_active_device(actor: ActorId) -> bool {
+            Self::owned_devices(actor).any(|d| d.status == DeviceStatus::Active)
+        }
+    }
 }
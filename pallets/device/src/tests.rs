@@ -1,9 +1,12 @@
 #![allow(clippy::disallowed_macros)]
 
 use crate::{
-    self as pallet_device, AttestationType, DeviceId, DeviceStatus, DeviceType, Error, Event,
+    self as pallet_device, AttestationType, AttestationVerifier, DeviceId, DeviceStatus,
+    DeviceType, Error, Event, HealthRecoveryCurve,
+};
+use frame_support::{
+    assert_noop, assert_ok, derive_impl, parameter_types, traits::ConstU32, BoundedVec,
 };
-use frame_support::{assert_noop, assert_ok, derive_impl, parameter_types, traits::ConstU32};
 use frame_system as system;
 use seveny_primitives::types::ActorId;
 use sp_core::H256;
@@ -55,6 +58,13 @@ parameter_types! {
     pub const MaxConsecutiveMisses: u32 = 3;
     pub const HealthScoreDecay: u8 = 10;
     pub const HealthScoreRecovery: u8 = 5;
+    pub static RecoveryCurve: HealthRecoveryCurve = HealthRecoveryCurve::Linear;
+    pub const MinAttesterTrustScore: u8 = 80;
+    pub const MaxBatchRegister: u32 = 10;
+    pub const MaxHeartbeatTimeoutBlocks: u64 = 40;
+    pub static MinHealthScore: u8 = 0;
+    pub static RecoveryHealthScore: u8 = 0;
+    pub const AttestationUpgradeTrustBonus: u8 = 10;
 }
 
 impl pallet_device::Config for Test {
@@ -66,6 +76,27 @@ impl pallet_device::Config for Test {
     type MaxConsecutiveMisses = MaxConsecutiveMisses;
     type HealthScoreDecay = HealthScoreDecay;
     type HealthScoreRecovery = HealthScoreRecovery;
+    type HealthRecoveryCurve = RecoveryCurve;
+    type MinAttesterTrustScore = MinAttesterTrustScore;
+    type MaxBatchRegister = MaxBatchRegister;
+    type MaxHeartbeatTimeoutBlocks = MaxHeartbeatTimeoutBlocks;
+    type MinHealthScore = MinHealthScore;
+    type RecoveryHealthScore = RecoveryHealthScore;
+    type AttestationVerifier = MockAttestationVerifier;
+    type AttestationUpgradeTrustBonus = AttestationUpgradeTrustBonus;
+}
+
+/// Accepts any attestation except the zero hash.
+pub struct MockAttestationVerifier;
+impl AttestationVerifier for MockAttestationVerifier {
+    fn verify(
+        _device_id: DeviceId,
+        _public_key_hash: H256,
+        _attestation_type: AttestationType,
+        attestation_hash: H256,
+    ) -> bool {
+        attestation_hash != H256::zero()
+    }
 }
 
 fn new_test_ext() -> sp_io::TestExternalities {
@@ -163,6 +194,85 @@ fn max_devices_enforced() {
     });
 }
 
+#[test]
+fn revoking_device_frees_slot_for_replacement() {
+    new_test_ext().execute_with(|| {
+        let owner = account_to_actor(1);
+
+        for i in 0..10 {
+            assert_ok!(Device::register_device(
+                RuntimeOrigin::signed(1),
+                DeviceType::Mobile,
+                H256([i as u8; 32]),
+                AttestationType::SelfSigned
+            ));
+        }
+        assert_eq!(Device::active_device_count_per_actor(owner), 10);
+
+        assert_ok!(Device::revoke_device(
+            RuntimeOrigin::signed(1),
+            DeviceId::new(0)
+        ));
+        assert_eq!(Device::active_device_count_per_actor(owner), 9);
+
+        assert_ok!(Device::register_device(
+            RuntimeOrigin::signed(1),
+            DeviceType::Mobile,
+            H256([100u8; 32]),
+            AttestationType::SelfSigned
+        ));
+        assert_eq!(Device::active_device_count_per_actor(owner), 10);
+        assert_eq!(Device::device_count_per_actor(owner), 11);
+
+        assert_noop!(
+            Device::register_device(
+                RuntimeOrigin::signed(1),
+                DeviceType::Mobile,
+                H256([101u8; 32]),
+                AttestationType::SelfSigned
+            ),
+            Error::<Test>::MaxDevicesReached
+        );
+    });
+}
+
+#[test]
+fn compromised_device_frees_slot_once() {
+    new_test_ext().execute_with(|| {
+        let owner = account_to_actor(1);
+
+        assert_ok!(Device::register_device(
+            RuntimeOrigin::signed(1),
+            DeviceType::Mobile,
+            H256([1u8; 32]),
+            AttestationType::SelfSigned
+        ));
+        assert_ok!(Device::register_device(
+            RuntimeOrigin::signed(1),
+            DeviceType::Mobile,
+            H256([2u8; 32]),
+            AttestationType::SelfSigned
+        ));
+        assert_ok!(Device::activate_device(
+            RuntimeOrigin::signed(1),
+            DeviceId::new(0)
+        ));
+
+        assert_ok!(Device::mark_compromised(
+            RuntimeOrigin::root(),
+            DeviceId::new(0)
+        ));
+        assert_eq!(Device::active_device_count_per_actor(owner), 1);
+
+        // Moving between terminal states must not release the slot again.
+        assert_ok!(Device::revoke_device(
+            RuntimeOrigin::signed(1),
+            DeviceId::new(0)
+        ));
+        assert_eq!(Device::active_device_count_per_actor(owner), 1);
+    });
+}
+
 #[test]
 fn activate_device_success() {
     new_test_ext().execute_with(|| {
@@ -737,6 +847,7 @@ fn attestation_with_attester() {
             H256([1u8; 32]),
             AttestationType::TrustedParty
         ));
+        setup_hardware_attester(2, 10);
 
         let device_id = DeviceId::new(0);
 
@@ -751,3 +862,739 @@ fn attestation_with_attester() {
         assert_eq!(attestation.attester, Some(attester));
     });
 }
+
+// ============================================================================
+// Attester Chain-of-Trust Tests
+// ============================================================================
+
+/// Register, activate and attest a TPM-backed device for `account`.
+fn setup_hardware_attester(account: u64, key: u8) -> DeviceId {
+    let device_id = DeviceId::new(Device::device_count());
+    assert_ok!(Device::register_device(
+        RuntimeOrigin::signed(account),
+        DeviceType::Hardware,
+        H256([key; 32]),
+        AttestationType::Tpm
+    ));
+    assert_ok!(Device::activate_device(
+        RuntimeOrigin::signed(account),
+        device_id
+    ));
+    assert_ok!(Device::submit_attestation(
+        RuntimeOrigin::signed(account),
+        device_id,
+        H256([key.wrapping_add(1); 32]),
+        None
+    ));
+    device_id
+}
+
+fn register_party_attested_device(account: u64) -> DeviceId {
+    let device_id = DeviceId::new(Device::device_count());
+    assert_ok!(Device::register_device(
+        RuntimeOrigin::signed(account),
+        DeviceType::Mobile,
+        H256([account as u8; 32]),
+        AttestationType::TrustedParty
+    ));
+    device_id
+}
+
+#[test]
+fn hardware_attested_attester_accepted() {
+    new_test_ext().execute_with(|| {
+        let attester = account_to_actor(2);
+        setup_hardware_attester(2, 50);
+        let device_id = register_party_attested_device(1);
+
+        assert!(Device::is_trusted_attester(attester, 1));
+        assert_ok!(Device::submit_attestation(
+            RuntimeOrigin::signed(1),
+            device_id,
+            H256([9u8; 32]),
+            Some(attester)
+        ));
+    });
+}
+
+#[test]
+fn unverified_attester_rejected() {
+    new_test_ext().execute_with(|| {
+        let attester = account_to_actor(2);
+        let device_id = register_party_attested_device(1);
+
+        // No devices at all.
+        assert_noop!(
+            Device::submit_attestation(
+                RuntimeOrigin::signed(1),
+                device_id,
+                H256([9u8; 32]),
+                Some(attester)
+            ),
+            Error::<Test>::UntrustedAttester
+        );
+
+        // An active self-signed device below the trust bar is not enough.
+        assert_ok!(Device::register_device(
+            RuntimeOrigin::signed(2),
+            DeviceType::Mobile,
+            H256([50u8; 32]),
+            AttestationType::SelfSigned
+        ));
+        let attester_device = DeviceId::new(1);
+        assert_ok!(Device::activate_device(
+            RuntimeOrigin::signed(2),
+            attester_device
+        ));
+        assert_noop!(
+            Device::submit_attestation(
+                RuntimeOrigin::signed(1),
+                device_id,
+                H256([9u8; 32]),
+                Some(attester)
+            ),
+            Error::<Test>::UntrustedAttester
+        );
+
+        // Reaching the trust bar qualifies the attester.
+        assert_ok!(Device::update_trust_score(
+            RuntimeOrigin::root(),
+            attester_device,
+            80
+        ));
+        assert_ok!(Device::submit_attestation(
+            RuntimeOrigin::signed(1),
+            device_id,
+            H256([9u8; 32]),
+            Some(attester)
+        ));
+    });
+}
+
+#[test]
+fn attester_with_expired_or_inactive_device_rejected() {
+    new_test_ext().execute_with(|| {
+        let attester = account_to_actor(2);
+        let attester_device = setup_hardware_attester(2, 50);
+        let device_id = register_party_attested_device(1);
+
+        System::set_block_number(1002);
+        assert!(!Device::is_trusted_attester(attester, 1002));
+        assert_noop!(
+            Device::submit_attestation(
+                RuntimeOrigin::signed(1),
+                device_id,
+                H256([9u8; 32]),
+                Some(attester)
+            ),
+            Error::<Test>::UntrustedAttester
+        );
+
+        System::set_block_number(1);
+        assert_ok!(Device::revoke_device(
+            RuntimeOrigin::signed(2),
+            attester_device
+        ));
+        assert!(!Device::is_trusted_attester(attester, 1));
+    });
+}
+
+// ============================================================================
+// Batch Registration Tests
+// ============================================================================
+
+fn batch(keys: &[u8]) -> BoundedVec<(DeviceType, H256, AttestationType), MaxBatchRegister> {
+    keys.iter()
+        .map(|k| {
+            (
+                DeviceType::Mobile,
+                H256([*k; 32]),
+                AttestationType::SelfSigned,
+            )
+        })
+        .collect::<Vec<_>>()
+        .try_into()
+        .expect("batch should fit bound")
+}
+
+#[test]
+fn register_devices_batch_that_fits() {
+    new_test_ext().execute_with(|| {
+        let owner = account_to_actor(1);
+
+        assert_ok!(Device::register_devices_batch(
+            RuntimeOrigin::signed(1),
+            owner,
+            batch(&[1, 2, 3])
+        ));
+
+        assert_eq!(Device::active_device_count_per_actor(owner), 3);
+        assert_eq!(Device::get_actor_devices(owner).len(), 3);
+        for key in [1u8, 2, 3] {
+            let device_id =
+                Device::public_key_device(H256([key; 32])).expect("device should exist");
+            System::assert_has_event(
+                Event::DeviceRegistered {
+                    device_id,
+                    owner,
+                    device_type: DeviceType::Mobile,
+                }
+                .into(),
+            );
+        }
+        System::assert_last_event(
+            Event::DevicesBatchRegistered {
+                owner,
+                registered: 3,
+                skipped: 0,
+            }
+            .into(),
+        );
+    });
+}
+
+#[test]
+fn register_devices_batch_rejects_cap_overflow() {
+    new_test_ext().execute_with(|| {
+        let owner = account_to_actor(1);
+
+        for i in 0..8u8 {
+            assert_ok!(Device::register_device(
+                RuntimeOrigin::signed(1),
+                DeviceType::Mobile,
+                H256([100 + i; 32]),
+                AttestationType::SelfSigned
+            ));
+        }
+
+        assert_noop!(
+            Device::register_devices_batch(RuntimeOrigin::signed(1), owner, batch(&[1, 2, 3])),
+            Error::<Test>::MaxDevicesReached
+        );
+        assert!(Device::public_key_device(H256([1u8; 32])).is_none());
+
+        assert_ok!(Device::register_devices_batch(
+            RuntimeOrigin::signed(1),
+            owner,
+            batch(&[1, 2])
+        ));
+        assert_eq!(Device::active_device_count_per_actor(owner), 10);
+    });
+}
+
+#[test]
+fn register_devices_batch_skips_duplicate_keys() {
+    new_test_ext().execute_with(|| {
+        let owner = account_to_actor(1);
+
+        assert_ok!(Device::register_device(
+            RuntimeOrigin::signed(2),
+            DeviceType::Mobile,
+            H256([1u8; 32]),
+            AttestationType::SelfSigned
+        ));
+
+        assert_ok!(Device::register_devices_batch(
+            RuntimeOrigin::signed(1),
+            owner,
+            batch(&[1, 2, 2, 3])
+        ));
+
+        assert_eq!(Device::active_device_count_per_actor(owner), 2);
+        let existing = Device::public_key_device(H256([1u8; 32])).expect("device should exist");
+        assert_eq!(
+            Device::devices(existing)
+                .expect("device should exist")
+                .owner,
+            account_to_actor(2)
+        );
+        System::assert_last_event(
+            Event::DevicesBatchRegistered {
+                owner,
+                registered: 2,
+                skipped: 2,
+            }
+            .into(),
+        );
+    });
+}
+
+#[test]
+fn register_devices_batch_requires_owner_or_root() {
+    new_test_ext().execute_with(|| {
+        let owner = account_to_actor(1);
+
+        assert_noop!(
+            Device::register_devices_batch(RuntimeOrigin::signed(2), owner, batch(&[1])),
+            Error::<Test>::NotDeviceOwner
+        );
+        assert_ok!(Device::register_devices_batch(
+            RuntimeOrigin::root(),
+            owner,
+            batch(&[1])
+        ));
+        assert_eq!(Device::active_device_count_per_actor(owner), 1);
+    });
+}
+
+// ============================================================================
+// Heartbeat Health Recovery
+// ============================================================================
+
+/// Registers and activates a device for account 1, then records its first heartbeat.
+fn setup_heartbeat_device() -> DeviceId {
+    assert_ok!(Device::register_device(
+        RuntimeOrigin::signed(1),
+        DeviceType::IoT,
+        H256([7u8; 32]),
+        AttestationType::SelfSigned
+    ));
+    let device_id = DeviceId::new(0);
+    assert_ok!(Device::activate_device(RuntimeOrigin::signed(1), device_id));
+    assert_ok!(Device::record_heartbeat(
+        RuntimeOrigin::signed(1),
+        device_id,
+        1
+    ));
+    device_id
+}
+
+/// Runs the device offline through `MaxConsecutiveMisses` timeouts, then brings it
+/// back with `heartbeats` consecutive heartbeats. Returns the resulting health.
+fn flap_device(device_id: DeviceId, sequence: &mut u64, heartbeats: u32) -> u8 {
+    use frame_support::traits::Hooks;
+
+    for _ in 0..MaxConsecutiveMisses::get() {
+        let heartbeat = Device::heartbeats(device_id).expect("heartbeat should exist");
+        let next = System::block_number() + Device::heartbeat_timeout(&heartbeat);
+        System::set_block_number(next);
+        Device::on_initialize(next);
+    }
+    assert_eq!(
+        Device::devices(device_id)
+            .expect("device should exist")
+            .status,
+        DeviceStatus::Offline
+    );
+
+    for _ in 0..heartbeats {
+        *sequence += 1;
+        assert_ok!(Device::record_heartbeat(
+            RuntimeOrigin::signed(1),
+            device_id,
+            *sequence
+        ));
+    }
+    Device::heartbeats(device_id)
+        .expect("heartbeat should exist")
+        .health_score
+}
+
+#[test]
+fn health_recovery_curve_shapes() {
+    assert_eq!(HealthRecoveryCurve::Linear.recover(90, 5), 95);
+    assert_eq!(HealthRecoveryCurve::Linear.recover(98, 5), 100);
+    assert_eq!(HealthRecoveryCurve::Asymptotic.recover(0, 5), 5);
+    assert_eq!(HealthRecoveryCurve::Asymptotic.recover(70, 5), 72);
+    assert_eq!(HealthRecoveryCurve::Asymptotic.recover(99, 5), 100);
+    assert_eq!(HealthRecoveryCurve::Asymptotic.recover(100, 5), 100);
+}
+
+#[test]
+fn linear_recovery_lets_flapping_device_return_to_full_health() {
+    new_test_ext().execute_with(|| {
+        let device_id = setup_heartbeat_device();
+        let mut sequence = 1;
+
+        for _ in 0..4 {
+            assert_eq!(flap_device(device_id, &mut sequence, 6), 100);
+        }
+    });
+}
+
+#[test]
+fn asymptotic_recovery_trends_flapping_device_downward() {
+    new_test_ext().execute_with(|| {
+        RecoveryCurve::set(HealthRecoveryCurve::Asymptotic);
+        let device_id = setup_heartbeat_device();
+        let mut sequence = 1;
+
+        let mut previous = 100;
+        for _ in 0..4 {
+            let health = flap_device(device_id, &mut sequence, 6);
+            assert!(
+                health < previous,
+                "health {health} should fall below {previous}"
+            );
+            previous = health;
+        }
+        assert!(previous < 70);
+    });
+}
+
+/// Lets the device's current heartbeat timeout elapse and runs offline detection.
+fn miss_heartbeat(device_id: DeviceId) {
+    use frame_support::traits::Hooks;
+
+    let heartbeat = Device::heartbeats(device_id).expect("heartbeat should exist");
+    let next = System::block_number() + Device::heartbeat_timeout(&heartbeat);
+    System::set_block_number(next);
+    Device::on_initialize(next);
+}
+
+fn device_status(device_id: DeviceId) -> DeviceStatus {
+    Device::devices(device_id)
+        .expect("device should exist")
+        .status
+}
+
+#[test]
+fn low_health_takes_device_offline_before_miss_cap() {
+    new_test_ext().execute_with(|| {
+        MinHealthScore::set(85);
+        let device_id = setup_heartbeat_device();
+
+        miss_heartbeat(device_id);
+        assert_eq!(device_status(device_id), DeviceStatus::Active);
+
+        miss_heartbeat(device_id);
+        let heartbeat = Device::heartbeats(device_id).expect("heartbeat should exist");
+        assert_eq!(heartbeat.health_score, 80);
+        assert!(heartbeat.consecutive_misses < MaxConsecutiveMisses::get());
+        assert_eq!(device_status(device_id), DeviceStatus::Offline);
+        System::assert_has_event(RuntimeEvent::Device(Event::DeviceWentOffline {
+            device_id,
+            consecutive_misses: 2,
+        }));
+    });
+}
+
+#[test]
+fn offline_device_reactivates_once_health_recovers() {
+    new_test_ext().execute_with(|| {
+        RecoveryHealthScore::set(90);
+        let device_id = setup_heartbeat_device();
+        let mut sequence = 1;
+
+        // Three misses leave health at 70; each heartbeat recovers 5.
+        assert_eq!(flap_device(device_id, &mut sequence, 3), 85);
+        assert_eq!(device_status(device_id), DeviceStatus::Offline);
+        assert_eq!(Device::get_total_offline_devices(), 1);
+
+        sequence += 1;
+        assert_ok!(Device::record_heartbeat(
+            RuntimeOrigin::signed(1),
+            device_id,
+            sequence
+        ));
+        assert_eq!(device_status(device_id), DeviceStatus::Active);
+        assert_eq!(Device::get_total_offline_devices(), 0);
+        System::assert_has_event(RuntimeEvent::Device(Event::DeviceRecovered {
+            device_id,
+            health_score: 90,
+        }));
+    });
+}
+
+// ============================================================================
+// Adaptive Heartbeat Timeout Tests
+// ============================================================================
+
+/// Advances block by block to `until`, running the offline check each block.
+fn run_offline_checks(until: u64) {
+    use frame_support::traits::Hooks;
+
+    while System::block_number() < until {
+        let next = System::block_number() + 1;
+        System::set_block_number(next);
+        Device::on_initialize(next);
+    }
+}
+
+#[test]
+fn slow_regular_device_learns_cadence_and_stays_online() {
+    new_test_ext().execute_with(|| {
+        let device_id = setup_heartbeat_device();
+        let mut sequence = 1;
+
+        // A 35-block cadence outlasts three global timeouts, so the first gap
+        // takes the device offline before anything has been learned.
+        run_offline_checks(36);
+        assert_eq!(device_status(device_id), DeviceStatus::Offline);
+        sequence += 1;
+        assert_ok!(Device::record_heartbeat(
+            RuntimeOrigin::signed(1),
+            device_id,
+            sequence
+        ));
+
+        let heartbeat = Device::heartbeats(device_id).expect("heartbeat should exist");
+        assert_eq!(heartbeat.average_interval, 35);
+        assert_eq!(
+            Device::heartbeat_timeout(&heartbeat),
+            MaxHeartbeatTimeoutBlocks::get()
+        );
+
+        for _ in 0..5 {
+            run_offline_checks(System::block_number() + 35);
+            assert_eq!(device_status(device_id), DeviceStatus::Active);
+            assert_eq!(
+                Device::heartbeats(device_id)
+                    .expect("heartbeat should exist")
+                    .consecutive_misses,
+                0
+            );
+            sequence += 1;
+            assert_ok!(Device::record_heartbeat(
+                RuntimeOrigin::signed(1),
+                device_id,
+                sequence
+            ));
+        }
+        assert_eq!(
+            Device::heartbeats(device_id)
+                .expect("heartbeat should exist")
+                .average_interval,
+            35
+        );
+    });
+}
+
+#[test]
+fn stopped_device_goes_offline_under_adaptive_timeout() {
+    new_test_ext().execute_with(|| {
+        let device_id = setup_heartbeat_device();
+        let mut sequence = 1;
+
+        for _ in 0..3 {
+            run_offline_checks(System::block_number() + 20);
+            sequence += 1;
+            assert_ok!(Device::record_heartbeat(
+                RuntimeOrigin::signed(1),
+                device_id,
+                sequence
+            ));
+        }
+        let heartbeat = Device::heartbeats(device_id).expect("heartbeat should exist");
+        assert_eq!(heartbeat.average_interval, 20);
+        let timeout = Device::heartbeat_timeout(&heartbeat);
+        assert_eq!(timeout, 40);
+
+        // Silence shorter than the learned timeout is tolerated.
+        let last = System::block_number();
+        run_offline_checks(last + timeout - 1);
+        assert_eq!(device_status(device_id), DeviceStatus::Active);
+
+        // Once it stops for good it is still detected.
+        let misses = u64::from(MaxConsecutiveMisses::get());
+        run_offline_checks(last + timeout * misses);
+        assert_eq!(device_status(device_id), DeviceStatus::Offline);
+    });
+}
+
+#[test]
+fn fast_device_keeps_global_timeout_floor() {
+    new_test_ext().execute_with(|| {
+        let device_id = setup_heartbeat_device();
+        let mut sequence = 1;
+
+        for _ in 0..4 {
+            run_offline_checks(System::block_number() + 2);
+            sequence += 1;
+            assert_ok!(Device::record_heartbeat(
+                RuntimeOrigin::signed(1),
+                device_id,
+                sequence
+            ));
+        }
+        let heartbeat = Device::heartbeats(device_id).expect("heartbeat should exist");
+        assert_eq!(heartbeat.average_interval, 2);
+        assert_eq!(
+            Device::heartbeat_timeout(&heartbeat),
+            HeartbeatTimeoutBlocks::get()
+        );
+    });
+}
+
+// ============================================================================
+// Attestation Revocation Tests
+// ============================================================================
+
+#[test]
+fn compromised_device_attestation_invalid_immediately() {
+    new_test_ext().execute_with(|| {
+        let device_id = setup_hardware_attester(1, 10);
+        let block = System::block_number();
+        assert!(Device::is_attestation_valid(device_id, block));
+        assert!(Device::is_trusted_attester(account_to_actor(1), block));
+
+        assert_ok!(Device::mark_compromised(RuntimeOrigin::root(), device_id));
+        System::assert_has_event(RuntimeEvent::Device(Event::AttestationRevoked {
+            device_id,
+            attestation_hash: H256([11u8; 32]),
+        }));
+        assert!(!Device::is_attestation_valid(device_id, block));
+        assert!(Device::attestations(device_id).is_none());
+
+        // The device cannot be re-attested, and no longer vouches for others.
+        assert_noop!(
+            Device::submit_attestation(RuntimeOrigin::signed(1), device_id, H256([12u8; 32]), None),
+            Error::<Test>::DeviceNotActive
+        );
+        let other = register_party_attested_device(3);
+        assert_noop!(
+            Device::submit_attestation(
+                RuntimeOrigin::signed(3),
+                other,
+                H256([4u8; 32]),
+                Some(account_to_actor(1))
+            ),
+            Error::<Test>::UntrustedAttester
+        );
+    });
+}
+
+#[test]
+fn revoked_device_attestation_invalid_immediately() {
+    new_test_ext().execute_with(|| {
+        let device_id = setup_hardware_attester(1, 10);
+        let block = System::block_number();
+
+        assert_ok!(Device::revoke_device(RuntimeOrigin::signed(1), device_id));
+        System::assert_has_event(RuntimeEvent::Device(Event::AttestationRevoked {
+            device_id,
+            attestation_hash: H256([11u8; 32]),
+        }));
+        assert!(!Device::is_attestation_valid(device_id, block));
+    });
+}
+
+// ============================================================================
+// Attestation Upgrade Tests
+// ============================================================================
+
+fn register_self_signed_device(account: u64) -> DeviceId {
+    let device_id = DeviceId::new(Device::device_count());
+    assert_ok!(Device::register_device(
+        RuntimeOrigin::signed(account),
+        DeviceType::Mobile,
+        H256([account as u8; 32]),
+        AttestationType::SelfSigned
+    ));
+    device_id
+}
+
+#[test]
+fn upgrade_self_signed_to_hardware_backed() {
+    new_test_ext().execute_with(|| {
+        let device_id = register_self_signed_device(1);
+        let attestation_hash = H256([7u8; 32]);
+
+        assert_ok!(Device::upgrade_attestation_type(
+            RuntimeOrigin::signed(1),
+            device_id,
+            AttestationType::HardwareBacked,
+            attestation_hash
+        ));
+
+        let device = Device::devices(device_id).expect("device should exist");
+        assert_eq!(device.attestation_type, AttestationType::HardwareBacked);
+        assert_eq!(
+            device.trust_score,
+            InitialTrustScore::get() + AttestationUpgradeTrustBonus::get()
+        );
+        let attestation = Device::attestations(device_id).expect("attestation should exist");
+        assert_eq!(attestation.attestation_hash, attestation_hash);
+        assert_eq!(attestation.attester, None);
+        assert!(Device::is_attestation_valid(device_id, 1));
+        System::assert_has_event(RuntimeEvent::Device(Event::AttestationTypeUpgraded {
+            device_id,
+            old_type: AttestationType::SelfSigned,
+            new_type: AttestationType::HardwareBacked,
+            attestation_hash,
+        }));
+    });
+}
+
+#[test]
+fn attestation_downgrade_rejected() {
+    new_test_ext().execute_with(|| {
+        let device_id = register_party_attested_device(1);
+
+        assert_noop!(
+            Device::upgrade_attestation_type(
+                RuntimeOrigin::signed(1),
+                device_id,
+                AttestationType::SelfSigned,
+                H256([7u8; 32])
+            ),
+            Error::<Test>::AttestationDowngrade
+        );
+        assert_noop!(
+            Device::upgrade_attestation_type(
+                RuntimeOrigin::signed(1),
+                device_id,
+                AttestationType::TrustedParty,
+                H256([7u8; 32])
+            ),
+            Error::<Test>::AttestationDowngrade
+        );
+    });
+}
+
+#[test]
+fn attestation_upgrade_requires_verification() {
+    new_test_ext().execute_with(|| {
+        let device_id = register_self_signed_device(1);
+
+        assert_noop!(
+            Device::upgrade_attestation_type(
+                RuntimeOrigin::signed(1),
+                device_id,
+                AttestationType::Tpm,
+                H256::zero()
+            ),
+            Error::<Test>::AttestationVerificationFailed
+        );
+        assert_noop!(
+            Device::upgrade_attestation_type(
+                RuntimeOrigin::signed(2),
+                device_id,
+                AttestationType::Tpm,
+                H256([7u8; 32])
+            ),
+            Error::<Test>::NotDeviceOwner
+        );
+    });
+}
+
+// ============================================================================
+// Trusted Attester Lookup Tests
+// ============================================================================
+
+#[test]
+fn trusted_attester_found_among_many_revoked_devices() {
+    new_test_ext().execute_with(|| {
+        let attester = account_to_actor(2);
+        for key in 0..(3 * MaxDevicesPerActor::get()) as u8 {
+            let device_id = DeviceId::new(Device::device_count());
+            assert_ok!(Device::register_device(
+                RuntimeOrigin::signed(2),
+                DeviceType::Mobile,
+                H256([100u8.wrapping_add(key); 32]),
+                AttestationType::TrustedParty
+            ));
+            assert_ok!(Device::revoke_device(RuntimeOrigin::signed(2), device_id));
+        }
+        setup_hardware_attester(2, 50);
+
+        assert_eq!(
+            Device::device_count_per_actor(attester),
+            3 * MaxDevicesPerActor::get() + 1
+        );
+        assert!(Device::is_trusted_attester(
+            attester,
+            System::block_number()
+        ));
+    });
+}
@@ -19,6 +19,8 @@ pub trait WeightInfo {
     fn record_activity() -> Weight;
     fn reactivate_device() -> Weight;
     fn record_heartbeat() -> Weight;
+    fn register_devices_batch(n: u32) -> Weight;
+    fn upgrade_attestation_type() -> Weight;
 }
 
 pub struct SubstrateWeight<T>(PhantomData<T>);
@@ -27,7 +29,7 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
     fn register_device() -> Weight {
         Weight::from_parts(35_000_000, 0)
             .saturating_add(T::DbWeight::get().reads(3))
-            .saturating_add(T::DbWeight::get().writes(4))
+            .saturating_add(T::DbWeight::get().writes(5))
     }
 
     fn activate_device() -> Weight {
@@ -44,19 +46,19 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 
     fn revoke_device() -> Weight {
         Weight::from_parts(25_000_000, 0)
-            .saturating_add(T::DbWeight::get().reads(1))
-            .saturating_add(T::DbWeight::get().writes(2))
+            .saturating_add(T::DbWeight::get().reads(3))
+            .saturating_add(T::DbWeight::get().writes(4))
     }
 
     fn mark_compromised() -> Weight {
         Weight::from_parts(25_000_000, 0)
-            .saturating_add(T::DbWeight::get().reads(1))
-            .saturating_add(T::DbWeight::get().writes(2))
+            .saturating_add(T::DbWeight::get().reads(3))
+            .saturating_add(T::DbWeight::get().writes(4))
     }
 
     fn submit_attestation() -> Weight {
         Weight::from_parts(25_000_000, 0)
-            .saturating_add(T::DbWeight::get().reads(1))
+            .saturating_add(T::DbWeight::get().reads(4))
             .saturating_add(T::DbWeight::get().writes(1))
     }
 
@@ -83,13 +85,27 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
             .saturating_add(T::DbWeight::get().reads(2))
             .saturating_add(T::DbWeight::get().writes(3))
     }
+
+    fn register_devices_batch(n: u32) -> Weight {
+        Weight::from_parts(15_000_000, 0)
+            .saturating_add(Weight::from_parts(30_000_000, 0).saturating_mul(n as u64))
+            .saturating_add(T::DbWeight::get().reads(1))
+            .saturating_add(T::DbWeight::get().reads(n as u64 * 2))
+            .saturating_add(T::DbWeight::get().writes(n as u64 * 5))
+    }
+
+    fn upgrade_attestation_type() -> Weight {
+        Weight::from_parts(30_000_000, 0)
+            .saturating_add(T::DbWeight::get().reads(1))
+            .saturating_add(T::DbWeight::get().writes(2))
+    }
 }
 
 impl WeightInfo for () {
     fn register_device() -> Weight {
         Weight::from_parts(35_000_000, 0)
             .saturating_add(RocksDbWeight::get().reads(3))
-            .saturating_add(RocksDbWeight::get().writes(4))
+            .saturating_add(RocksDbWeight::get().writes(5))
     }
 
     fn activate_device() -> Weight {
@@ -106,19 +122,19 @@ impl WeightInfo for () {
 
     fn revoke_device() -> Weight {
         Weight::from_parts(25_000_000, 0)
-            .saturating_add(RocksDbWeight::get().reads(1))
-            .saturating_add(RocksDbWeight::get().writes(2))
+            .saturating_add(RocksDbWeight::get().reads(3))
+            .saturating_add(RocksDbWeight::get().writes(4))
     }
 
     fn mark_compromised() -> Weight {
         Weight::from_parts(25_000_000, 0)
-            .saturating_add(RocksDbWeight::get().reads(1))
-            .saturating_add(RocksDbWeight::get().writes(2))
+            .saturating_add(RocksDbWeight::get().reads(3))
+            .saturating_add(RocksDbWeight::get().writes(4))
     }
 
     fn submit_attestation() -> Weight {
         Weight::from_parts(25_000_000, 0)
-            .saturating_add(RocksDbWeight::get().reads(1))
+            .saturating_add(RocksDbWeight::get().reads(4))
             .saturating_add(RocksDbWeight::get().writes(1))
     }
 
@@ -145,4 +161,18 @@ impl WeightInfo for () {
             .saturating_add(RocksDbWeight::get().reads(2))
             .saturating_add(RocksDbWeight::get().writes(3))
     }
+
+    fn register_devices_batch(n: u32) -> Weight {
+        Weight::from_parts(15_000_000, 0)
+            .saturating_add(Weight::from_parts(30_000_000, 0).saturating_mul(n as u64))
+            .saturating_add(RocksDbWeight::get().reads(1))
+            .saturating_add(RocksDbWeight::get().reads(n as u64 * 2))
+            .saturating_add(RocksDbWeight::get().writes(n as u64 * 5))
+    }
+
+    fn upgrade_attestation_type() -> Weight {
+        Weight::from_parts(30_000_000, 0)
+            .saturating_add(RocksDbWeight::get().reads(1))
+            .saturating_add(RocksDbWeight::get().writes(2))
+    }
 }
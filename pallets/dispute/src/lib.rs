@@ -19,8 +19,8 @@ pub mod pallet {
         BoundedVec,
     };
     use frame_system::pallet_prelude::*;
-    use seveny_primitives::traits::ValidatorChecker as _;
-    use seveny_primitives::types::{ValidatorId, ViolationType};
+    use seveny_primitives::traits::{PresenceDisputes, ValidatorChecker as _};
+    use seveny_primitives::types::{ActorId, EpochId, ValidatorId, ViolationType};
     use sp_runtime::traits::Saturating;
 
     use crate::WeightInfo;
@@ -100,6 +100,8 @@ pub mod pallet {
         ValidatorSlashed,
         DisputeRejected,
         InsufficientEvidence,
+        /// A challenged presence was slashed
+        PresenceSlashed,
     }
 
     #[derive(
@@ -146,6 +148,32 @@ pub mod pallet {
         pub submitted_at: BlockNumberFor<T>,
     }
 
+    /// Dispute over a finalized presence, opened by the presence pallet when a
+    /// validator challenges it and closed when the challenge is resolved there.
+    #[derive(
+        Clone,
+        PartialEq,
+        Eq,
+        Encode,
+        Decode,
+        parity_scale_codec::DecodeWithMemTracking,
+        MaxEncodedLen,
+        TypeInfo,
+        RuntimeDebug,
+    )]
+    #[scale_info(skip_type_params(T))]
+    pub struct PresenceChallenge<T: Config> {
+        pub id: DisputeId,
+        pub challenger: T::AccountId,
+        pub actor: ActorId,
+        pub epoch: EpochId,
+        pub evidence_hash: sp_core::H256,
+        pub status: DisputeStatus,
+        pub created_at: BlockNumberFor<T>,
+        pub resolved_at: Option<BlockNumberFor<T>>,
+        pub outcome: Option<DisputeOutcome>,
+    }
+
     #[pallet::pallet]
     #[pallet::storage_version(STORAGE_VERSION)]
     pub struct Pallet<T>(_);
@@ -213,6 +241,12 @@ pub mod pallet {
     pub type OpenDisputes<T: Config> =
         StorageValue<_, BoundedVec<DisputeId, T::MaxOpenDisputes>, ValueQuery>;
 
+    /// Presence disputes, numbered from the same sequence as `Disputes`.
+    #[pallet::storage]
+    #[pallet::getter(fn presence_challenges)]
+    pub type PresenceChallenges<T: Config> =
+        StorageMap<_, Blake2_128Concat, DisputeId, PresenceChallenge<T>, OptionQuery>;
+
     #[pallet::event]
     #[pallet::generate_deposit(pub(super) fn deposit_event)]
     pub enum Event<T: Config> {
@@ -238,6 +272,16 @@ pub mod pallet {
             dispute_id: DisputeId,
             reason: DisputeRejectionReason,
         },
+        PresenceDisputeOpened {
+            dispute_id: DisputeId,
+            challenger: T::AccountId,
+            actor: ActorId,
+            epoch: EpochId,
+        },
+        PresenceDisputeClosed {
+            dispute_id: DisputeId,
+            outcome: DisputeOutcome,
+        },
     }
 
     #[derive(
@@ -516,4 +560,73 @@ pub mod pallet {
             OpenDisputes::<T>::get().len() as u32
         }
     }
+
+    impl<T: Config> PresenceDisputes<T::AccountId> for Pallet<T> {
+        fn open_presence_dispute(
+            challenger: &T::AccountId,
+            actor: ActorId,
+            epoch: EpochId,
+            evidence: sp_core::H256,
+        ) -> Option<u64> {
+            let dispute_id = DisputeId::new(DisputeCount::<T>::get());
+            OpenDisputes::<T>::try_mutate(|disputes| disputes.try_push(dispute_id)).ok()?;
+            DisputeCount::<T>::put(dispute_id.inner().saturating_add(1));
+
+            let challenge = PresenceChallenge {
+                id: dispute_id,
+                challenger: challenger.clone(),
+                actor,
+                epoch,
+                evidence_hash: evidence,
+                status: DisputeStatus::Open,
+                created_at: frame_system::Pallet::<T>::block_number(),
+                resolved_at: None,
+                outcome: None,
+            };
+            PresenceChallenges::<T>::insert(dispute_id, challenge);
+
+            Self::deposit_event(Event::PresenceDisputeOpened {
+                dispute_id,
+                challenger: challenger.clone(),
+                actor,
+                epoch,
+            });
+
+            Some(dispute_id.inner())
+        }
+
+        fn close_presence_dispute(dispute_id: u64, upheld: bool) {
+            let dispute_id = DisputeId::new(dispute_id);
+            let (status, outcome) = if upheld {
+                (DisputeStatus::Resolved, DisputeOutcome::PresenceSlashed)
+            } else {
+                (DisputeStatus::Rejected, DisputeOutcome::DisputeRejected)
+            };
+
+            let closed = PresenceChallenges::<T>::mutate(dispute_id, |challenge| {
+                let Some(c) = challenge
+                    .as_mut()
+                    .filter(|c| c.status == DisputeStatus::Open)
+                else {
+                    return false;
+                };
+                c.status = status;
+                c.resolved_at = Some(frame_system::Pallet::<T>::block_number());
+                c.outcome = Some(outcome);
+                true
+            });
+            if !closed {
+                return;
+            }
+
+            OpenDisputes::<T>::mutate(|disputes| {
+                disputes.retain(|id| *id != dispute_id);
+            });
+
+            Self::deposit_event(Event::PresenceDisputeClosed {
+                dispute_id,
+                outcome,
+            });
+        }
+    }
 }
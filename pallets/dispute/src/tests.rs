@@ -6,7 +6,10 @@ use crate::{
 };
 use frame_support::{assert_noop, assert_ok, derive_impl, parameter_types, traits::ConstU32};
 use frame_system as system;
-use seveny_primitives::types::{ValidatorId, ViolationType};
+use seveny_primitives::{
+    traits::PresenceDisputes,
+    types::{ActorId, EpochId, ValidatorId, ViolationType},
+};
 use sp_core::H256;
 use sp_runtime::{
     traits::{BlakeTwo256, Hash, IdentityLookup},
@@ -447,3 +450,53 @@ fn events_emitted_correctly() {
         }));
     });
 }
+
+// ============================================================================
+// Presence Challenge Tests
+// ============================================================================
+
+#[test]
+fn presence_dispute_opens_and_closes() {
+    new_test_ext().execute_with(|| {
+        let actor = ActorId::from_raw([7u8; 32]);
+        let epoch = EpochId::new(1);
+
+        let first = Dispute::open_presence_dispute(&2, actor, epoch, H256([1u8; 32]))
+            .expect("dispute should open");
+        let second = Dispute::open_presence_dispute(&3, actor, EpochId::new(2), H256([2u8; 32]))
+            .expect("dispute should open");
+        assert_eq!((first, second), (0, 1));
+        assert_eq!(Dispute::get_open_dispute_count(), 2);
+
+        let challenge =
+            Dispute::presence_challenges(DisputeId::new(first)).expect("challenge should exist");
+        assert_eq!(challenge.challenger, 2);
+        assert_eq!(challenge.actor, actor);
+        assert_eq!(challenge.status, DisputeStatus::Open);
+        System::assert_has_event(RuntimeEvent::Dispute(Event::PresenceDisputeOpened {
+            dispute_id: DisputeId::new(first),
+            challenger: 2,
+            actor,
+            epoch,
+        }));
+
+        Dispute::close_presence_dispute(first, true);
+        Dispute::close_presence_dispute(second, false);
+        assert_eq!(Dispute::get_open_dispute_count(), 0);
+
+        let upheld =
+            Dispute::presence_challenges(DisputeId::new(first)).expect("challenge should exist");
+        assert_eq!(upheld.status, DisputeStatus::Resolved);
+        assert_eq!(upheld.outcome, Some(DisputeOutcome::PresenceSlashed));
+        let rejected =
+            Dispute::presence_challenges(DisputeId::new(second)).expect("challenge should exist");
+        assert_eq!(rejected.status, DisputeStatus::Rejected);
+        assert_eq!(rejected.outcome, Some(DisputeOutcome::DisputeRejected));
+
+        // Closing twice changes nothing.
+        Dispute::close_presence_dispute(first, false);
+        let upheld =
+            Dispute::presence_challenges(DisputeId::new(first)).expect("challenge should exist");
+        assert_eq!(upheld.outcome, Some(DisputeOutcome::PresenceSlashed));
+    });
+}
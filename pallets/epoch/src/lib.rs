@@ -604,6 +604,23 @@ pub mod pallet {
                 .unwrap_or(false)
         }
 
+        /// True if the epoch is Closed and the schedule's grace period
+        /// after its end block has not yet elapsed.
+        pub fn is_within_grace_period(epoch_id: EpochId) -> bool {
+            let Some(metadata) = EpochInfo::<T>::get(epoch_id) else {
+                return false;
+            };
+            if metadata.state != EpochState::Closed {
+                return false;
+            }
+
+            let schedule = EpochSchedule::<T>::get();
+            let block_number = frame_system::Pallet::<T>::block_number();
+            let grace_end = metadata.end_block.saturating_add(schedule.grace_period);
+
+            block_number < grace_end
+        }
+
         pub fn is_participant(epoch_id: EpochId, account: &T::AccountId) -> bool {
             EpochParticipants::<T>::get(epoch_id, account)
         }
@@ -622,5 +639,9 @@ pub mod pallet {
         fn current_epoch() -> EpochId {
             CurrentEpoch::<T>::get()
         }
+
+        fn is_within_grace_period(epoch_id: EpochId) -> bool {
+            Self::is_within_grace_period(epoch_id)
+        }
     }
 }
@@ -289,6 +289,26 @@ fn cannot_finalize_before_grace_period() {
     });
 }
 
+#[test]
+fn grace_period_window_after_close() {
+    new_test_ext().execute_with(|| {
+        let epoch_id = EpochId::new(1);
+
+        // Active epochs are not in grace
+        assert!(!Epoch::is_within_grace_period(epoch_id));
+
+        assert_ok!(Epoch::close_epoch(RuntimeOrigin::root(), epoch_id));
+        assert!(Epoch::is_within_grace_period(epoch_id));
+
+        // end_block (101) + grace_period (10)
+        System::set_block_number(110);
+        assert!(Epoch::is_within_grace_period(epoch_id));
+
+        System::set_block_number(111);
+        assert!(!Epoch::is_within_grace_period(epoch_id));
+    });
+}
+
 #[test]
 fn update_schedule_success() {
     new_test_ext().execute_with(|| {
@@ -20,12 +20,14 @@ pub mod pallet {
         BoundedVec,
     };
     use frame_system::pallet_prelude::*;
-    use seveny_primitives::types::ActorId;
+    use seveny_primitives::types::{ActorId, NamedCapability};
 
     use crate::WeightInfo;
 
     const STORAGE_VERSION: StorageVersion = StorageVersion::new(1);
 
+    const DOMAIN_NAMED_CAPABILITY: &[u8] = b"7ay:capability:named:v1";
+
     #[derive(
         Clone,
         Copy,
@@ -674,5 +676,22 @@ pub mod pallet {
             let hash = sp_core::blake2_256(&encoded);
             ActorId::from_raw(hash)
         }
+
+        /// Resource a named capability is granted on; holders need `EXECUTE` on it.
+        pub fn named_capability_resource(capability: NamedCapability) -> ResourceId {
+            ResourceId::from_bytes(sp_core::blake2_256(
+                &(DOMAIN_NAMED_CAPABILITY, capability).encode(),
+            ))
+        }
+    }
+
+    impl<T: Config> seveny_primitives::traits::CapabilityCheck for Pallet<T> {
+        fn has_capability(actor: ActorId, capability: NamedCapability) -> bool {
+            Self::has_permission(
+                actor,
+                Self::named_capability_resource(capability),
+                Permissions::EXECUTE,
+            )
+        }
     }
 }
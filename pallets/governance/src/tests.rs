@@ -670,3 +670,41 @@ fn genesis_initializes_capability_count() {
         assert_eq!(Governance::capability_count(), 0);
     });
 }
+
+#[test]
+fn named_capability_requires_execute_on_its_resource() {
+    use seveny_primitives::traits::CapabilityCheck;
+    use seveny_primitives::types::NamedCapability;
+
+    new_test_ext().execute_with(|| {
+        let grantee = account_to_actor(2);
+        let resource = Governance::named_capability_resource(NamedCapability::CreateCluster);
+        assert!(!Governance::has_capability(
+            grantee,
+            NamedCapability::CreateCluster
+        ));
+
+        assert_ok!(Governance::grant_capability(
+            RuntimeOrigin::signed(1),
+            grantee,
+            resource,
+            Permissions::READ,
+            None,
+            false
+        ));
+        assert!(!Governance::has_capability(
+            grantee,
+            NamedCapability::CreateCluster
+        ));
+
+        assert_ok!(Governance::update_capability(
+            RuntimeOrigin::signed(1),
+            CapabilityId::new(0),
+            Permissions::EXECUTE
+        ));
+        assert!(Governance::has_capability(
+            grantee,
+            NamedCapability::CreateCluster
+        ));
+    });
+}
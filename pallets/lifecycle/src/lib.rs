@@ -630,6 +630,19 @@ pub mod pallet {
 
             ensure!(!rotation.completed, Error::<T>::KeyRotationPending);
 
+            Self::finish_rotation(actor, rotation);
+
+            Ok(())
+        }
+    }
+
+    impl<T: Config> Pallet<T> {
+        fn account_to_actor(account: T::AccountId) -> ActorId {
+            seveny_primitives::crypto::derive_actor_id(&account.encode())
+        }
+
+        /// Adopt the rotation's new key and start the rotation cooldown.
+        fn finish_rotation(actor: ActorId, rotation: KeyRotation<T>) {
             let block_number = frame_system::Pallet::<T>::block_number();
 
             // H15: remove entry entirely instead of marking completed=true
@@ -650,14 +663,6 @@ pub mod pallet {
                 actor,
                 new_key_hash: rotation.new_key_hash,
             });
-
-            Ok(())
-        }
-    }
-
-    impl<T: Config> Pallet<T> {
-        fn account_to_actor(account: T::AccountId) -> ActorId {
-            seveny_primitives::crypto::derive_actor_id(&account.encode())
         }
 
         fn finalize_destruction(actor: ActorId) -> DispatchResult {
@@ -785,4 +790,27 @@ pub mod pallet {
             ActiveActors::<T>::get()
         }
     }
+
+    impl<T: Config> seveny_primitives::traits::ActorStatusProvider for Pallet<T> {
+        fn is_actor_active(actor: ActorId) -> bool {
+            Pallet::<T>::is_actor_active(actor)
+        }
+    }
+
+    impl<T: Config> seveny_primitives::traits::KeyRotationProvider for Pallet<T> {
+        fn is_rotation_pending(actor: ActorId, new_key_hash: H256) -> bool {
+            KeyRotations::<T>::get(actor)
+                .is_some_and(|r| !r.completed && r.new_key_hash == new_key_hash)
+        }
+
+        fn complete_rotation(actor: ActorId, new_key_hash: H256) -> bool {
+            match KeyRotations::<T>::get(actor) {
+                Some(rotation) if !rotation.completed && rotation.new_key_hash == new_key_hash => {
+                    Pallet::<T>::finish_rotation(actor, rotation);
+                    true
+                }
+                _ => false,
+            }
+        }
+    }
 }
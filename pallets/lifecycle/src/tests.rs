@@ -5,6 +5,7 @@ use frame_support::{
     assert_noop, assert_ok, derive_impl, parameter_types,
     traits::{ConstU32, ConstU64},
 };
+use seveny_primitives::traits::KeyRotationProvider;
 use sp_core::H256;
 use sp_runtime::{
     traits::{BlakeTwo256, IdentityLookup},
@@ -322,9 +323,42 @@ fn complete_rotation_success() {
             new_key
         ));
 
+        let actor = account_to_actor(1);
+        assert!(Lifecycle::is_rotation_pending(actor, new_key));
+        assert!(!Lifecycle::is_rotation_pending(actor, old_key));
+
         assert_ok!(Lifecycle::complete_rotation(RuntimeOrigin::signed(1)));
+        assert!(!Lifecycle::is_rotation_pending(actor, new_key));
+
+        let lifecycle = Lifecycle::actors(actor).expect("actor should exist");
+        assert_eq!(lifecycle.key_hash, new_key);
+        assert_eq!(lifecycle.key_status, KeyStatus::Active);
+    });
+}
+
+#[test]
+fn provider_completes_only_the_pending_rotation() {
+    new_test_ext().execute_with(|| {
+        let old_key = create_key_hash(1);
+        let new_key = create_key_hash(2);
+        register_and_activate(1, old_key);
+        assert_ok!(Lifecycle::initiate_rotation(
+            RuntimeOrigin::signed(1),
+            new_key
+        ));
 
         let actor = account_to_actor(1);
+        assert!(!<Lifecycle as KeyRotationProvider>::complete_rotation(
+            actor, old_key
+        ));
+        assert!(<Lifecycle as KeyRotationProvider>::complete_rotation(
+            actor, new_key
+        ));
+        assert!(!Lifecycle::is_rotation_pending(actor, new_key));
+        assert!(!<Lifecycle as KeyRotationProvider>::complete_rotation(
+            actor, new_key
+        ));
+
         let lifecycle = Lifecycle::actors(actor).expect("actor should exist");
         assert_eq!(lifecycle.key_hash, new_key);
         assert_eq!(lifecycle.key_status, KeyStatus::Active);
@@ -16,6 +16,9 @@ pub const MIN_TRIANGULATION_NODES: u32 = 3;
 pub const POSITION_TOLERANCE_CM: u32 = 5000;
 pub const MAX_DEVICES_FOR_FULL_SCORE: u32 = 10;
 pub const CONSISTENCY_DECAY_FACTOR: u8 = 5;
+/// Percentage of full credit an observation earns toward the device score
+/// until its commitment is revealed.
+pub const UNVERIFIED_OBSERVATION_WEIGHT: u32 = 50;
 
 #[derive(
     Clone,
@@ -56,6 +59,42 @@ impl FusionWeights {
     }
 }
 
+/// Named `FusionWeights` combinations selectable with `set_fusion_preset`.
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    PartialEq,
+    Eq,
+    Encode,
+    Decode,
+    parity_scale_codec::DecodeWithMemTracking,
+    TypeInfo,
+    MaxEncodedLen,
+)]
+pub enum FusionPreset {
+    /// 34 / 33 / 33
+    Balanced,
+    /// 60 / 20 / 20
+    HeartbeatHeavy,
+    /// 20 / 60 / 20
+    DeviceHeavy,
+    /// 20 / 20 / 60
+    PositionHeavy,
+}
+
+impl FusionPreset {
+    /// Heartbeat, device and position weights for the preset.
+    pub fn weights(&self) -> Option<FusionWeights> {
+        match self {
+            Self::Balanced => FusionWeights::new(34, 33, 33),
+            Self::HeartbeatHeavy => FusionWeights::new(60, 20, 20),
+            Self::DeviceHeavy => FusionWeights::new(20, 60, 20),
+            Self::PositionHeavy => FusionWeights::new(20, 20, 60),
+        }
+    }
+}
+
 #[derive(
     Clone,
     Debug,
@@ -111,6 +150,10 @@ pub struct DeviceObservationMetrics {
     pub total_observations: u32,
     pub consistency_score: u8,
     pub average_device_count: u8,
+    /// Observations whose commitment was opened by a matching reveal.
+    pub verified_observations: u32,
+    /// Whether `last_commitment` still awaits its reveal.
+    pub reveal_pending: bool,
 }
 
 impl DeviceObservationMetrics {
@@ -126,10 +169,24 @@ impl DeviceObservationMetrics {
         self.total_observations = total;
         self.last_commitment = Some(commitment);
         self.last_reveal_block = block;
+        self.reveal_pending = true;
 
         self.update_consistency(device_count);
     }
 
+    /// Counts the latest observation as verified. `false` if nothing awaits a reveal.
+    pub fn mark_revealed(&mut self) -> bool {
+        if !self.reveal_pending {
+            return false;
+        }
+        self.reveal_pending = false;
+        self.verified_observations = self
+            .verified_observations
+            .saturating_add(1)
+            .min(self.total_observations);
+        true
+    }
+
     fn update_consistency(&mut self, device_count: u8) {
         let expected = self.average_device_count;
         let diff = device_count.abs_diff(expected);
@@ -149,7 +206,20 @@ impl DeviceObservationMetrics {
         let base_score = (self.average_device_count as u32 * 100
             / MAX_DEVICES_FOR_FULL_SCORE.max(1))
         .min(100) as u8;
-        ((base_score as u32 * self.consistency_score as u32) / 100) as u8
+        let score = (base_score as u32 * self.consistency_score as u32) / 100;
+        (score * self.verification_factor() / 100) as u8
+    }
+
+    /// Percentage credit across all observations, unverified ones earning
+    /// `UNVERIFIED_OBSERVATION_WEIGHT`. Full credit before any observation.
+    fn verification_factor(&self) -> u32 {
+        if self.total_observations == 0 {
+            return 100;
+        }
+        let total = u64::from(self.total_observations);
+        let verified = u64::from(self.verified_observations.min(self.total_observations));
+        let credit = verified * 100 + (total - verified) * u64::from(UNVERIFIED_OBSERVATION_WEIGHT);
+        (credit / total) as u32
     }
 }
 
@@ -170,6 +240,8 @@ pub struct PositionMetrics {
     pub position_variance: u32,
     pub triangulation_confirmations: u32,
     pub last_confirmation_block: u64,
+    pub last_confirmed_position: Option<Position>,
+    pub velocity_anomaly: bool,
 }
 
 impl PositionMetrics {
@@ -187,9 +259,36 @@ impl PositionMetrics {
         self.position_variance = new_var.min(u32::MAX as u64) as u32;
         self.triangulation_confirmations = confirmations;
         self.last_confirmation_block = block;
+        self.last_confirmed_position = Some(confirmed_position);
+        self.velocity_anomaly = false;
+    }
+
+    /// Shed `decay_percent` of the recorded variance for every block since the
+    /// last confirmation, so a past spike fades once reports settle down.
+    pub fn decay_variance(&mut self, block: u64, decay_percent: u8) {
+        if self.triangulation_confirmations == 0 {
+            return;
+        }
+        let elapsed = block.saturating_sub(self.last_confirmation_block);
+        let shed = elapsed.saturating_mul(u64::from(decay_percent)).min(100);
+        let kept = u64::from(self.position_variance) * (100 - shed) / 100;
+        self.position_variance = kept as u32;
+    }
+
+    /// Distance per block implied by moving from the last confirmed position
+    /// to `position` at `block`, or `None` before the first confirmation.
+    pub fn implied_velocity(&self, position: &Position, block: u64) -> Option<u64> {
+        let last = self.last_confirmed_position.as_ref()?;
+        let distance = integer_sqrt(last.distance_squared(position));
+        let elapsed = block.saturating_sub(self.last_confirmation_block).max(1);
+        Some(distance / elapsed)
     }
 
     pub fn position_score(&self) -> u8 {
+        if self.velocity_anomaly {
+            return 0;
+        }
+
         if self.triangulation_confirmations < MIN_TRIANGULATION_NODES {
             return 50;
         }
@@ -275,21 +374,48 @@ impl FusedHealthMetrics {
         self.recalculate_fused_score(weights);
     }
 
+    /// Fold a confirmed position in after decaying the recorded variance by
+    /// `variance_decay` percent per block since the previous confirmation.
     pub fn record_position_confirmation(
         &mut self,
         position: Position,
         block: u64,
+        variance_decay: u8,
         weights: &FusionWeights,
     ) {
+        self.position_metrics.decay_variance(block, variance_decay);
         self.position_metrics.record_triangulation(position, block);
         self.last_update_block = block;
         self.recalculate_fused_score(weights);
     }
 
+    pub fn record_position_anomaly(&mut self, block: u64, weights: &FusionWeights) {
+        self.position_metrics.velocity_anomaly = true;
+        self.last_update_block = block;
+        self.recalculate_fused_score(weights);
+    }
+
     pub fn is_critical(&self) -> bool {
         self.fused_score < CRITICAL_HEALTH_THRESHOLD
     }
 
+    /// Lowest-scoring input; ties resolve heartbeat, then device, then position.
+    pub fn weakest_component(&self) -> HealthComponent {
+        let scores = [
+            (self.heartbeat_score, HealthComponent::Heartbeat),
+            (self.device_metrics.device_score(), HealthComponent::Device),
+            (
+                self.position_metrics.position_score(),
+                HealthComponent::Position,
+            ),
+        ];
+        scores
+            .iter()
+            .min_by_key(|(score, _)| *score)
+            .map(|(_, component)| *component)
+            .unwrap_or(HealthComponent::Heartbeat)
+    }
+
     pub fn is_warning(&self) -> bool {
         self.fused_score < WARNING_HEALTH_THRESHOLD && self.fused_score >= CRITICAL_HEALTH_THRESHOLD
     }
@@ -315,7 +441,86 @@ pub enum HealingTrigger {
     HeartbeatTimeout,
     DeviceObservationMissing,
     PositionMismatch,
-    FusedScoreCritical,
+    /// Fused score fell below the critical threshold; `weakest` is the
+    /// lowest-scoring component at the time.
+    FusedScoreCritical {
+        weakest: HealthComponent,
+    },
+}
+
+impl HealingTrigger {
+    /// Component responsible for this trigger.
+    pub fn component(&self) -> HealthComponent {
+        match self {
+            Self::HeartbeatTimeout => HealthComponent::Heartbeat,
+            Self::DeviceObservationMissing => HealthComponent::Device,
+            Self::PositionMismatch => HealthComponent::Position,
+            Self::FusedScoreCritical { weakest } => *weakest,
+        }
+    }
+}
+
+/// Input signal feeding the fused health score.
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    PartialEq,
+    Eq,
+    Encode,
+    Decode,
+    parity_scale_codec::DecodeWithMemTracking,
+    TypeInfo,
+    MaxEncodedLen,
+)]
+pub enum HealthComponent {
+    Heartbeat,
+    Device,
+    Position,
+}
+
+/// Thresholds deciding when fused health metrics trigger healing.
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    PartialEq,
+    Eq,
+    Encode,
+    Decode,
+    parity_scale_codec::DecodeWithMemTracking,
+    TypeInfo,
+    MaxEncodedLen,
+)]
+pub struct FusionHealingConfig {
+    /// Fused score below which a subnode is critical and gets failed.
+    pub critical_fused_score: u8,
+    /// Heartbeat score below which a heartbeat timeout is reported.
+    pub heartbeat_critical_score: u8,
+    /// Blocks without a device reveal before observations count as missing.
+    pub reveal_timeout_blocks: u64,
+    /// Position variance (cm) above which a confirmed position is a mismatch.
+    pub position_tolerance_cm: u32,
+}
+
+impl Default for FusionHealingConfig {
+    fn default() -> Self {
+        Self {
+            critical_fused_score: CRITICAL_HEALTH_THRESHOLD,
+            heartbeat_critical_score: HEARTBEAT_CRITICAL_THRESHOLD,
+            reveal_timeout_blocks: REVEAL_TIMEOUT_BLOCKS,
+            position_tolerance_cm: POSITION_TOLERANCE_CM,
+        }
+    }
+}
+
+impl FusionHealingConfig {
+    pub fn is_valid(&self) -> bool {
+        self.critical_fused_score <= 100
+            && self.heartbeat_critical_score <= 100
+            && self.reveal_timeout_blocks > 0
+            && self.position_tolerance_cm > 0
+    }
 }
 
 #[derive(
@@ -364,25 +569,28 @@ pub fn compute_fused_health(
 pub fn should_trigger_healing(
     metrics: &FusedHealthMetrics,
     current_block: u64,
+    config: &FusionHealingConfig,
 ) -> Option<HealingTrigger> {
-    if metrics.fused_score < CRITICAL_HEALTH_THRESHOLD {
-        return Some(HealingTrigger::FusedScoreCritical);
+    if metrics.fused_score < config.critical_fused_score {
+        return Some(HealingTrigger::FusedScoreCritical {
+            weakest: metrics.weakest_component(),
+        });
     }
 
-    if metrics.heartbeat_score < HEARTBEAT_CRITICAL_THRESHOLD {
+    if metrics.heartbeat_score < config.heartbeat_critical_score {
         return Some(HealingTrigger::HeartbeatTimeout);
     }
 
     if metrics.device_metrics.total_observations > 0 {
         let blocks_since_reveal =
             current_block.saturating_sub(metrics.device_metrics.last_reveal_block);
-        if blocks_since_reveal > REVEAL_TIMEOUT_BLOCKS {
+        if blocks_since_reveal > config.reveal_timeout_blocks {
             return Some(HealingTrigger::DeviceObservationMissing);
         }
     }
 
     if metrics.position_metrics.triangulation_confirmations >= MIN_TRIANGULATION_NODES
-        && metrics.position_metrics.position_variance > POSITION_TOLERANCE_CM
+        && metrics.position_metrics.position_variance > config.position_tolerance_cm
     {
         return Some(HealingTrigger::PositionMismatch);
     }
@@ -424,6 +632,76 @@ mod tests {
         assert!(!p1.within_tolerance(&p2, 99));
     }
 
+    #[test]
+    fn test_implied_velocity() {
+        let mut metrics = PositionMetrics::default();
+        assert_eq!(
+            metrics.implied_velocity(&Position::new(500, 0, 0), 10),
+            None
+        );
+
+        metrics.record_triangulation(Position::new(0, 0, 0), 10);
+        assert_eq!(
+            metrics.implied_velocity(&Position::new(500, 0, 0), 20),
+            Some(50)
+        );
+        assert_eq!(
+            metrics.implied_velocity(&Position::new(500, 0, 0), 10),
+            Some(500)
+        );
+
+        metrics.velocity_anomaly = true;
+        assert_eq!(metrics.position_score(), 0);
+        metrics.record_triangulation(Position::new(100, 0, 0), 20);
+        assert!(!metrics.velocity_anomaly);
+    }
+
+    #[test]
+    fn test_variance_spike_decays_with_stable_confirmations() {
+        let weights = FusionWeights::default_weights();
+        let mut metrics = FusedHealthMetrics::new(Position::default());
+        for block in 1..=3 {
+            metrics.record_position_confirmation(Position::default(), block, 5, &weights);
+        }
+        assert_eq!(metrics.position_metrics.position_score(), 100);
+
+        // One 4000 cm jitter averages in as 1000 cm of variance.
+        metrics.record_position_confirmation(Position::new(4000, 0, 0), 4, 5, &weights);
+        assert_eq!(metrics.position_metrics.position_variance, 1000);
+        assert_eq!(metrics.position_metrics.position_score(), 80);
+
+        let mut previous = 80;
+        for block in (6..=32).step_by(2) {
+            metrics.record_position_confirmation(Position::default(), block, 5, &weights);
+            let score = metrics.position_metrics.position_score();
+            assert!(score >= previous);
+            previous = score;
+        }
+        assert_eq!(metrics.position_metrics.position_score(), 100);
+
+        // A long quiet spell wipes the remaining variance entirely.
+        metrics.record_position_confirmation(Position::default(), 100, 5, &weights);
+        assert_eq!(metrics.position_metrics.position_variance, 0);
+    }
+
+    #[test]
+    fn test_variance_without_decay_only_averages_out() {
+        let weights = FusionWeights::default_weights();
+        let mut metrics = FusedHealthMetrics::new(Position::default());
+        for block in 1..=3 {
+            metrics.record_position_confirmation(Position::default(), block, 0, &weights);
+        }
+        metrics.record_position_confirmation(Position::new(4000, 0, 0), 4, 0, &weights);
+        metrics.record_position_confirmation(Position::default(), 100, 0, &weights);
+        assert_eq!(metrics.position_metrics.position_variance, 800);
+
+        let mut decayed = metrics.position_metrics.clone();
+        decayed.decay_variance(105, 10);
+        assert_eq!(decayed.position_variance, 400);
+        decayed.decay_variance(200, 10);
+        assert_eq!(decayed.position_variance, 0);
+    }
+
     #[test]
     fn test_device_score_calculation() {
         let mut metrics = DeviceObservationMetrics {
@@ -437,6 +715,24 @@ mod tests {
         assert_eq!(metrics.device_score(), 50);
     }
 
+    #[test]
+    fn test_unverified_observations_count_less() {
+        let mut metrics = DeviceObservationMetrics {
+            consistency_score: 95,
+            average_device_count: 10,
+            ..Default::default()
+        };
+        metrics.record_observation(10, 1, H256::repeat_byte(1));
+        assert_eq!(metrics.device_score(), 50);
+
+        assert!(metrics.mark_revealed());
+        assert_eq!(metrics.device_score(), 100);
+        assert!(!metrics.mark_revealed());
+
+        metrics.record_observation(10, 2, H256::repeat_byte(2));
+        assert_eq!(metrics.device_score(), 75);
+    }
+
     #[test]
     fn test_fused_health_calculation() {
         let score = compute_fused_health(100, 10, 100, 0, 3);
@@ -451,47 +747,54 @@ mod tests {
 
     #[test]
     fn test_healing_triggers() {
+        let config = FusionHealingConfig::default();
         let mut metrics = FusedHealthMetrics::new(Position::default());
         metrics.fused_score = 15;
         assert_eq!(
-            should_trigger_healing(&metrics, 100),
-            Some(HealingTrigger::FusedScoreCritical)
+            should_trigger_healing(&metrics, 100, &config),
+            Some(HealingTrigger::FusedScoreCritical {
+                weakest: HealthComponent::Device
+            })
         );
 
         metrics.fused_score = 50;
         metrics.heartbeat_score = 20;
         assert_eq!(
-            should_trigger_healing(&metrics, 100),
+            should_trigger_healing(&metrics, 100, &config),
             Some(HealingTrigger::HeartbeatTimeout)
         );
     }
 
     #[test]
     fn test_healing_trigger_uses_named_constants() {
+        let config = FusionHealingConfig::default();
         let mut metrics = FusedHealthMetrics::new(Position::default());
         metrics.fused_score = CRITICAL_HEALTH_THRESHOLD;
         metrics.heartbeat_score = 100;
-        assert_eq!(should_trigger_healing(&metrics, 0), None);
+        assert_eq!(should_trigger_healing(&metrics, 0, &config), None);
 
         metrics.fused_score = CRITICAL_HEALTH_THRESHOLD - 1;
         assert_eq!(
-            should_trigger_healing(&metrics, 0),
-            Some(HealingTrigger::FusedScoreCritical)
+            should_trigger_healing(&metrics, 0, &config),
+            Some(HealingTrigger::FusedScoreCritical {
+                weakest: HealthComponent::Device
+            })
         );
 
         metrics.fused_score = 100;
         metrics.heartbeat_score = HEARTBEAT_CRITICAL_THRESHOLD;
-        assert_eq!(should_trigger_healing(&metrics, 0), None);
+        assert_eq!(should_trigger_healing(&metrics, 0, &config), None);
 
         metrics.heartbeat_score = HEARTBEAT_CRITICAL_THRESHOLD - 1;
         assert_eq!(
-            should_trigger_healing(&metrics, 0),
+            should_trigger_healing(&metrics, 0, &config),
             Some(HealingTrigger::HeartbeatTimeout)
         );
     }
 
     #[test]
     fn test_reveal_timeout_trigger() {
+        let config = FusionHealingConfig::default();
         let mut metrics = FusedHealthMetrics::new(Position::default());
         metrics.fused_score = 100;
         metrics.heartbeat_score = 100;
@@ -499,12 +802,47 @@ mod tests {
         metrics.device_metrics.last_reveal_block = 0;
 
         assert_eq!(
-            should_trigger_healing(&metrics, REVEAL_TIMEOUT_BLOCKS),
+            should_trigger_healing(&metrics, REVEAL_TIMEOUT_BLOCKS, &config),
             None
         );
         assert_eq!(
-            should_trigger_healing(&metrics, REVEAL_TIMEOUT_BLOCKS + 1),
+            should_trigger_healing(&metrics, REVEAL_TIMEOUT_BLOCKS + 1, &config),
             Some(HealingTrigger::DeviceObservationMissing)
         );
     }
+
+    #[test]
+    fn test_healing_config_thresholds() {
+        let mut metrics = FusedHealthMetrics::new(Position::default());
+        metrics.fused_score = 40;
+        metrics.heartbeat_score = 35;
+
+        let config = FusionHealingConfig::default();
+        assert_eq!(should_trigger_healing(&metrics, 0, &config), None);
+
+        let strict = FusionHealingConfig {
+            critical_fused_score: 50,
+            ..config
+        };
+        assert!(strict.is_valid());
+        assert_eq!(
+            should_trigger_healing(&metrics, 0, &strict).map(|t| t.component()),
+            Some(HealthComponent::Device)
+        );
+
+        let heartbeat_strict = FusionHealingConfig {
+            heartbeat_critical_score: 40,
+            ..config
+        };
+        assert_eq!(
+            should_trigger_healing(&metrics, 0, &heartbeat_strict),
+            Some(HealingTrigger::HeartbeatTimeout)
+        );
+
+        assert!(!FusionHealingConfig {
+            reveal_timeout_blocks: 0,
+            ..config
+        }
+        .is_valid());
+    }
 }
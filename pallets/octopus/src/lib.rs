@@ -7,7 +7,8 @@ pub mod fusion;
 pub mod weights;
 
 pub use fusion::{
-    FusedHealthMetrics, FusionWeights, HealingAction, HealingTrigger, Position as FusionPosition,
+    FusedHealthMetrics, FusionHealingConfig, FusionPreset, FusionWeights, HealingAction,
+    HealingTrigger, HealthComponent, Position as FusionPosition,
 };
 
 #[cfg(test)]
@@ -18,9 +19,13 @@ use frame_support::pallet_prelude::*;
 use frame_system::pallet_prelude::*;
 use parity_scale_codec::{Decode, Encode, MaxEncodedLen};
 use scale_info::TypeInfo;
-use seveny_primitives::types::ActorId;
-use sp_arithmetic::Perbill;
-use sp_runtime::Saturating;
+use seveny_primitives::fusion::DeviceReveal;
+use seveny_primitives::traits::{
+    CapabilityCheck, DeviceRegistry, OnPositionConfirmed, PresenceLoad,
+};
+use seveny_primitives::types::{ActorId, NamedCapability};
+use sp_arithmetic::{PerThing, Perbill};
+use sp_runtime::{traits::Zero, Saturating};
 
 #[derive(
     Clone,
@@ -128,6 +133,8 @@ pub enum ClusterStatus {
     Scaling,
     Degraded,
     Shutdown,
+    /// Winding down: subnodes are deactivating and the cluster shuts down once none remain active
+    Draining,
 }
 
 #[derive(
@@ -155,6 +162,13 @@ pub struct Subnode<T: Config> {
     pub last_heartbeat: BlockNumberFor<T>,
     pub consecutive_misses: u8,
     pub health_score: u8,
+    /// Sequence number of the last accepted heartbeat; must strictly increase.
+    pub heartbeat_sequence: u64,
+    /// Upper bound for `health_score`; lowered to a probation value after
+    /// recovering from failure.
+    pub health_ceiling: u8,
+    /// Scale-down protection; lower-priority subnodes are shed first.
+    pub priority: u8,
 }
 
 #[derive(
@@ -239,6 +253,8 @@ pub enum DiagnosticAction {
     Eq,
     Encode,
     Decode,
+    PartialOrd,
+    Ord,
     parity_scale_codec::DecodeWithMemTracking,
     TypeInfo,
     MaxEncodedLen,
@@ -255,6 +271,28 @@ pub enum DiagnosticSeverity {
     Failed,
 }
 
+/// Direction of a subnode's diagnostic severity over its recent reports
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    PartialEq,
+    Eq,
+    Encode,
+    Decode,
+    parity_scale_codec::DecodeWithMemTracking,
+    TypeInfo,
+    MaxEncodedLen,
+)]
+pub enum Trend {
+    /// Severity fell and never rose across the window
+    Improving,
+    /// Mixed, flat, or too few reports
+    Stable,
+    /// Severity rose and never fell across the window
+    Worsening,
+}
+
 /// Health checks performed by diagnostics
 #[derive(
     Clone,
@@ -309,6 +347,42 @@ pub struct DiagnosticReport<BlockNumber> {
     pub generated_at: BlockNumber,
 }
 
+/// Throughput margin past a band boundary required before the scaling target moves.
+pub const SCALING_HYSTERESIS: Perbill = Perbill::from_percent(2);
+
+/// Priority given to subnodes registered without an explicit one.
+pub const DEFAULT_SUBNODE_PRIORITY: u8 = 128;
+
+/// Aggregate health snapshot across all clusters and subnodes
+#[derive(
+    Clone,
+    Debug,
+    Default,
+    PartialEq,
+    Eq,
+    Encode,
+    Decode,
+    parity_scale_codec::DecodeWithMemTracking,
+    TypeInfo,
+    MaxEncodedLen,
+)]
+pub struct FleetSummary {
+    /// Number of clusters in storage
+    pub total_clusters: u32,
+    /// Clusters in `Running` status
+    pub running_clusters: u32,
+    /// Clusters in `Degraded` status
+    pub degraded_clusters: u32,
+    /// Clusters in `Shutdown` status
+    pub shutdown_clusters: u32,
+    /// Active subnodes across the fleet
+    pub total_active_subnodes: u32,
+    /// Mean fused score over all `FusedHealth` records, 0 if none
+    pub average_fused_score: u8,
+    /// Subnodes in `Failed` status
+    pub failed_subnodes: u32,
+}
+
 #[frame_support::pallet]
 pub mod pallet {
     use super::*;
@@ -350,8 +424,102 @@ pub mod pallet {
 
         #[pallet::constant]
         type HealthScoreRecovery: Get<u8>;
+
+        /// Missed heartbeats forgiven by each accepted heartbeat.
+        #[pallet::constant]
+        type MissRecoveryDecay: Get<u8>;
+
+        /// Number of misses before failure at which `SubnodeAtRisk` is raised.
+        #[pallet::constant]
+        type AtRiskMissGrace: Get<u8>;
+
+        /// Health ceiling applied to a subnode recovered from `Failed`.
+        #[pallet::constant]
+        type ProbationHealthCeiling: Get<u8>;
+
+        /// Ceiling regained per heartbeat while the subnode has no outstanding misses.
+        #[pallet::constant]
+        type HealthCeilingRecovery: Get<u8>;
+
+        /// Maximum plausible subnode movement between position confirmations, in cm per block.
+        #[pallet::constant]
+        type MaxSubnodeVelocity: Get<u64>;
+
+        /// Require the `CreateCluster` capability to create clusters; permissionless when false.
+        #[pallet::constant]
+        type GateClusterCreation: Get<bool>;
+
+        /// Capability source consulted when cluster creation is gated.
+        type CapabilityCheck: CapabilityCheck;
+
+        /// Position variance (cm) at or above which diagnostics flag a subnode for recalibration.
+        #[pallet::constant]
+        type PositionVarianceThreshold: Get<u32>;
+
+        /// Minimum cluster throughput expected by operators; zero disables SLA checks.
+        #[pallet::constant]
+        type ThroughputSla: Get<Perbill>;
+
+        /// Blocks a cluster may stay below `ThroughputSla` before it is flagged.
+        #[pallet::constant]
+        type SlaBreachBlocks: Get<BlockNumberFor<Self>>;
+
+        /// Throughput samples a cluster must accumulate before scaling acts on them.
+        #[pallet::constant]
+        type MinSamplesForScaling: Get<u32>;
+
+        /// Source of registered devices that operators may bind to their subnodes.
+        type DeviceRegistry: DeviceRegistry<DeviceId: Parameter + MaxEncodedLen + Copy>;
+
+        /// Maximum devices that can be bound to a single subnode.
+        #[pallet::constant]
+        type MaxDevicesPerSubnode: Get<u32>;
+
+        /// Throughput samples retained per subnode; the oldest is dropped when full.
+        #[pallet::constant]
+        type MaxSubnodeThroughputSamples: Get<u32>;
+
+        /// Health and fused heartbeat score given to a failed subnode when auto-heal resets it.
+        #[pallet::constant]
+        type AutoHealResetScore: Get<u8>;
+
+        /// Diagnostic reports retained per subnode and compared by `severity_trend`.
+        #[pallet::constant]
+        type SeverityTrendWindow: Get<u32>;
+
+        /// Upper bound for any cluster's `max_subnodes`, however it is set.
+        #[pallet::constant]
+        type MaxSubnodesHardCeiling: Get<u32>;
+
+        /// Percent of recorded position variance shed per block between position
+        /// confirmations; zero keeps the plain running average.
+        #[pallet::constant]
+        type PositionVarianceDecay: Get<u8>;
+
+        /// Subnodes one operator may have registered across all clusters.
+        #[pallet::constant]
+        type MaxSubnodesPerOperator: Get<u32>;
+
+        /// Presence demand consulted by scaling decisions.
+        type PresenceLoad: PresenceLoad;
+
+        /// Scale up when the current epoch's presence count outgrows the active
+        /// subnodes, whatever the throughput.
+        #[pallet::constant]
+        type PresenceAwareScaling: Get<bool>;
+
+        /// Presences one active subnode is expected to serve per epoch.
+        #[pallet::constant]
+        type PresencesPerSubnode: Get<u32>;
+
+        /// Blocks within which a subnode must have heartbeated to be activated;
+        /// zero disables the check.
+        #[pallet::constant]
+        type ActivationHeartbeatWindow: Get<BlockNumberFor<Self>>;
     }
 
+    pub type DeviceIdOf<T> = <<T as Config>::DeviceRegistry as DeviceRegistry>::DeviceId;
+
     #[pallet::storage]
     #[pallet::getter(fn subnode_count)]
     pub type SubnodeCount<T> = StorageValue<_, u64, ValueQuery>;
@@ -396,6 +564,107 @@ pub mod pallet {
     #[pallet::getter(fn fusion_weights)]
     pub type GlobalFusionWeights<T> = StorageValue<_, FusionWeights, ValueQuery>;
 
+    /// Thresholds used by the per-block fusion healing check.
+    #[pallet::storage]
+    #[pallet::getter(fn fusion_healing_config)]
+    pub type HealingConfig<T> = StorageValue<_, FusionHealingConfig, ValueQuery>;
+
+    /// Proposed new owner per cluster, awaiting acceptance.
+    #[pallet::storage]
+    #[pallet::getter(fn pending_cluster_transfers)]
+    pub type PendingClusterTransfers<T: Config> =
+        StorageMap<_, Blake2_128Concat, ClusterId, ActorId>;
+
+    /// Block at which each cluster's throughput first dropped below the SLA.
+    #[pallet::storage]
+    #[pallet::getter(fn sla_below_since)]
+    pub type SlaBelowSince<T: Config> =
+        StorageMap<_, Blake2_128Concat, ClusterId, BlockNumberFor<T>>;
+
+    /// Clusters currently flagged for an SLA breach, with the block it was raised.
+    #[pallet::storage]
+    #[pallet::getter(fn sla_breaches)]
+    pub type SlaBreaches<T: Config> = StorageMap<_, Blake2_128Concat, ClusterId, BlockNumberFor<T>>;
+
+    /// Raw `Clusters` key where the next throughput SLA check resumes.
+    #[pallet::storage]
+    pub type SlaSweepCursor<T: Config> =
+        StorageValue<_, BoundedVec<u8, ConstU32<128>>, OptionQuery>;
+
+    /// Clusters being drained, with the block draining started.
+    #[pallet::storage]
+    #[pallet::getter(fn draining_clusters)]
+    pub type DrainingClusters<T: Config> =
+        StorageMap<_, Blake2_128Concat, ClusterId, BlockNumberFor<T>>;
+
+    /// Raw `DrainingClusters` key where the next drain completion check resumes.
+    #[pallet::storage]
+    pub type DrainSweepCursor<T: Config> =
+        StorageValue<_, BoundedVec<u8, ConstU32<128>>, OptionQuery>;
+
+    /// Most recent throughput reports per subnode, oldest first.
+    #[pallet::storage]
+    #[pallet::getter(fn subnode_throughput_history)]
+    pub type SubnodeThroughputHistory<T: Config> = StorageMap<
+        _,
+        Blake2_128Concat,
+        SubnodeId,
+        BoundedVec<(Perbill, BlockNumberFor<T>), T::MaxSubnodeThroughputSamples>,
+        ValueQuery,
+    >;
+
+    /// Severity of the most recent diagnostic reports per subnode, oldest first.
+    #[pallet::storage]
+    #[pallet::getter(fn diagnostic_history)]
+    pub type DiagnosticHistory<T: Config> = StorageMap<
+        _,
+        Blake2_128Concat,
+        SubnodeId,
+        BoundedVec<(DiagnosticSeverity, BlockNumberFor<T>), T::SeverityTrendWindow>,
+        ValueQuery,
+    >;
+
+    /// Block of the latest report already escalated for a worsening trend.
+    #[pallet::storage]
+    #[pallet::getter(fn trend_escalated_at)]
+    pub type TrendEscalatedAt<T: Config> =
+        StorageMap<_, Blake2_128Concat, SubnodeId, BlockNumberFor<T>>;
+
+    /// Devices bound to each subnode; only the active ones count toward device health.
+    #[pallet::storage]
+    #[pallet::getter(fn subnode_devices)]
+    pub type SubnodeDevices<T: Config> =
+        StorageDoubleMap<_, Blake2_128Concat, SubnodeId, Blake2_128Concat, DeviceIdOf<T>, ()>;
+
+    /// Subnode each device is bound to, so a device backs at most one subnode.
+    #[pallet::storage]
+    #[pallet::getter(fn device_binding)]
+    pub type DeviceBindings<T: Config> = StorageMap<_, Blake2_128Concat, DeviceIdOf<T>, SubnodeId>;
+
+    /// Number of devices bound to each subnode.
+    #[pallet::storage]
+    #[pallet::getter(fn subnode_device_count)]
+    pub type SubnodeDeviceCount<T: Config> =
+        StorageMap<_, Blake2_128Concat, SubnodeId, u32, ValueQuery>;
+
+    /// Triangulated device whose confirmed position stands in for each subnode's.
+    #[pallet::storage]
+    #[pallet::getter(fn subnode_position_device)]
+    pub type SubnodePositionDevice<T: Config> =
+        StorageMap<_, Blake2_128Concat, SubnodeId, sp_core::H256>;
+
+    /// Subnode each triangulated device reports the position of.
+    #[pallet::storage]
+    #[pallet::getter(fn position_device_subnode)]
+    pub type PositionDevices<T: Config> = StorageMap<_, Blake2_128Concat, sp_core::H256, SubnodeId>;
+
+    /// Triangulated device an operator asked to link to each subnode, awaiting
+    /// approval by the cluster owner or root.
+    #[pallet::storage]
+    #[pallet::getter(fn pending_position_device)]
+    pub type PendingPositionDevices<T: Config> =
+        StorageMap<_, Blake2_128Concat, SubnodeId, sp_core::H256>;
+
     #[pallet::genesis_config]
     #[derive(frame_support::DefaultNoBound)]
     pub struct GenesisConfig<T: Config> {
@@ -420,6 +689,40 @@ pub mod pallet {
             cluster_id: ClusterId,
             owner: ActorId,
         },
+        ClusterTransferProposed {
+            cluster_id: ClusterId,
+            current_owner: ActorId,
+            new_owner: ActorId,
+        },
+        ClusterTransferred {
+            cluster_id: ClusterId,
+            old_owner: ActorId,
+            new_owner: ActorId,
+        },
+        /// Registered device bound to a subnode
+        DeviceBound {
+            subnode_id: SubnodeId,
+            device_id: DeviceIdOf<T>,
+        },
+        /// Device unbound from a subnode
+        DeviceUnbound {
+            subnode_id: SubnodeId,
+            device_id: DeviceIdOf<T>,
+        },
+        /// Triangulated position device set or cleared for a subnode
+        PositionDeviceSet {
+            subnode_id: SubnodeId,
+            mac_hash: Option<sp_core::H256>,
+        },
+        /// Operator asked to link a triangulated position device to a subnode
+        PositionDeviceRequested {
+            subnode_id: SubnodeId,
+            mac_hash: sp_core::H256,
+        },
+        ClusterCapacityUpdated {
+            cluster_id: ClusterId,
+            max_subnodes: u32,
+        },
         SubnodeRegistered {
             subnode_id: SubnodeId,
             cluster_id: ClusterId,
@@ -442,6 +745,12 @@ pub mod pallet {
             decision: ScalingDecision,
             throughput: Perbill,
         },
+        /// Scaling held at `Maintain` because too few throughput samples exist
+        ScalingSamplesInsufficient {
+            cluster_id: ClusterId,
+            sample_count: u32,
+            required: u32,
+        },
         ThroughputUpdated {
             cluster_id: ClusterId,
             throughput: Perbill,
@@ -460,6 +769,11 @@ pub mod pallet {
             cluster_id: ClusterId,
             consecutive_misses: u8,
         },
+        SubnodeAtRisk {
+            subnode_id: SubnodeId,
+            consecutive_misses: u8,
+            misses_remaining: u8,
+        },
         AutoHealingInitiated {
             cluster_id: ClusterId,
             failed_count: u32,
@@ -482,11 +796,33 @@ pub mod pallet {
             device_count: u8,
             commitment: sp_core::H256,
         },
+        DeviceObservationVerified {
+            subnode_id: SubnodeId,
+            commitment: sp_core::H256,
+        },
         PositionConfirmed {
             subnode_id: SubnodeId,
             position: FusionPosition,
             variance: u32,
         },
+        PositionAnomalyDetected {
+            subnode_id: SubnodeId,
+            position: FusionPosition,
+            velocity: u64,
+        },
+        /// Cluster throughput stayed below the SLA for the full breach window
+        ClusterSlaBreached {
+            cluster_id: ClusterId,
+            observed: Perbill,
+        },
+        /// Cluster throughput is back at or above the SLA
+        ClusterSlaRecovered {
+            cluster_id: ClusterId,
+            observed: Perbill,
+        },
+        FusionHealingConfigUpdated {
+            config: FusionHealingConfig,
+        },
         FusionHealingTriggered {
             subnode_id: SubnodeId,
             trigger: HealingTrigger,
@@ -504,7 +840,9 @@ pub mod pallet {
             actions_applied: u32,
         },
         /// Inactive subnode was pruned
-        SubnodePruned { subnode_id: SubnodeId },
+        SubnodePruned {
+            subnode_id: SubnodeId,
+        },
         /// Operator escalation required
         OperatorEscalationRequired {
             subnode_id: SubnodeId,
@@ -533,15 +871,57 @@ pub mod pallet {
         InvalidCommitment,
         NoFusedHealthRecord,
         InvalidFusionWeights,
+        /// Healing thresholds are out of range
+        InvalidHealingConfig,
+        /// Heartbeat sequence is not greater than the last accepted one
+        StaleHeartbeat,
+        /// No ownership transfer has been proposed for this cluster
+        NoPendingTransfer,
+        /// Caller is not the proposed new owner of the cluster
+        NotProposedOwner,
+        /// Caller lacks the capability required for this call
+        MissingCapability,
+        /// Device is not registered to the subnode operator
+        DeviceNotOwned,
+        /// Device is not active in the device registry
+        DeviceNotActive,
+        /// Device is already bound to a subnode
+        DeviceAlreadyBound,
+        /// Device is not bound to this subnode
+        DeviceNotBound,
+        /// Subnode already has the maximum number of bound devices
+        TooManyDevices,
+        /// Triangulated device already reports another subnode's position
+        PositionDeviceAlreadyBound,
+        /// Cluster is draining and accepts no new subnodes or scaling
+        ClusterDraining,
+        /// Cluster is already draining or shut down
+        ClusterNotDrainable,
+        /// Requested capacity exceeds `MaxSubnodesHardCeiling`
+        ClusterCapacityTooHigh,
+        /// Requested capacity is below `MinSubnodes` or the registered subnode count
+        ClusterCapacityTooLow,
+        /// The latest device observation has already been revealed, or none exists
+        NoPendingReveal,
+        /// Operator already runs `MaxSubnodesPerOperator` subnodes
+        OperatorSubnodeLimitReached,
+        /// Subnode has not heartbeated within `ActivationHeartbeatWindow`
+        NoRecentHeartbeat,
+        /// No position device link is awaiting approval for this subnode
+        NoPendingPositionDevice,
     }
 
     #[pallet::hooks]
     impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
         fn on_initialize(n: BlockNumberFor<T>) -> Weight {
             Self::process_deactivations(n);
+            let drains = Self::complete_drains();
             Self::detect_failed_nodes(n);
             Self::auto_heal_clusters(n);
-            T::DbWeight::get().reads(1)
+            T::DbWeight::get()
+                .reads(1)
+                .saturating_add(drains)
+                .saturating_add(Self::check_throughput_sla(n))
         }
     }
 
@@ -553,6 +933,12 @@ pub mod pallet {
             let caller = ensure_signed(origin)?;
             let owner = Self::account_to_actor(&caller);
 
+            ensure!(
+                !T::GateClusterCreation::get()
+                    || T::CapabilityCheck::has_capability(owner, NamedCapability::CreateCluster),
+                Error::<T>::MissingCapability
+            );
+
             let block_number = frame_system::Pallet::<T>::block_number();
             let cluster_id = Self::next_cluster_id();
 
@@ -580,11 +966,16 @@ pub mod pallet {
             origin: OriginFor<T>,
             cluster_id: ClusterId,
             _operator: ActorId,
+            priority: Option<u8>,
         ) -> DispatchResult {
             let caller = ensure_signed(origin)?;
             let operator = Self::account_to_actor(&caller);
 
             let cluster = Clusters::<T>::get(cluster_id).ok_or(Error::<T>::ClusterNotFound)?;
+            ensure!(
+                cluster.status != ClusterStatus::Draining,
+                Error::<T>::ClusterDraining
+            );
 
             let subnode_count = ClusterSubnodes::<T>::iter_prefix(cluster_id).count() as u32;
             ensure!(
@@ -592,6 +983,12 @@ pub mod pallet {
                 Error::<T>::MaxSubnodesReached
             );
 
+            let operator_count = OperatorSubnodes::<T>::iter_prefix(operator).count() as u32;
+            ensure!(
+                operator_count < T::MaxSubnodesPerOperator::get(),
+                Error::<T>::OperatorSubnodeLimitReached
+            );
+
             let block_number = frame_system::Pallet::<T>::block_number();
             let subnode_id = Self::next_subnode_id();
 
@@ -608,6 +1005,9 @@ pub mod pallet {
                 last_heartbeat: block_number,
                 consecutive_misses: 0,
                 health_score: 100,
+                heartbeat_sequence: 0,
+                health_ceiling: 100,
+                priority: priority.unwrap_or(DEFAULT_SUBNODE_PRIORITY),
             };
 
             Subnodes::<T>::insert(subnode_id, subnode);
@@ -639,12 +1039,21 @@ pub mod pallet {
                     s.status == SubnodeStatus::Inactive,
                     Error::<T>::SubnodeAlreadyActive
                 );
+                let window = T::ActivationHeartbeatWindow::get();
+                ensure!(
+                    window.is_zero() || block_number.saturating_sub(s.last_heartbeat) <= window,
+                    Error::<T>::NoRecentHeartbeat
+                );
+
+                let cluster_id = s.cluster;
+                ensure!(
+                    !DrainingClusters::<T>::contains_key(cluster_id),
+                    Error::<T>::ClusterDraining
+                );
 
                 s.status = SubnodeStatus::Active;
                 s.activated_at = Some(block_number);
 
-                let cluster_id = s.cluster;
-
                 Clusters::<T>::mutate(cluster_id, |cluster| {
                     if let Some(ref mut c) = cluster {
                         c.active_subnodes = c.active_subnodes.saturating_add(1);
@@ -751,6 +1160,10 @@ pub mod pallet {
 
             let cluster = Clusters::<T>::get(cluster_id).ok_or(Error::<T>::ClusterNotFound)?;
             ensure!(cluster.owner == caller_actor, Error::<T>::NotClusterOwner);
+            ensure!(
+                cluster.status != ClusterStatus::Draining,
+                Error::<T>::ClusterDraining
+            );
             let block_number = frame_system::Pallet::<T>::block_number();
 
             let cooldown_elapsed = block_number.saturating_sub(cluster.last_scaling_at)
@@ -759,7 +1172,17 @@ pub mod pallet {
             ensure!(cooldown_elapsed, Error::<T>::ScalingCooldownActive);
 
             let throughput = cluster.total_throughput;
-            let decision = Self::compute_scaling_decision(throughput, cluster.active_subnodes);
+            let sample_count = Self::throughput_sample_count(cluster_id);
+            let decision = Self::compute_scaling_decision(&cluster, sample_count);
+
+            let required = T::MinSamplesForScaling::get();
+            if sample_count < required {
+                Self::deposit_event(Event::ScalingSamplesInsufficient {
+                    cluster_id,
+                    sample_count,
+                    required,
+                });
+            }
 
             if decision != ScalingDecision::Maintain {
                 Clusters::<T>::mutate(cluster_id, |c| {
@@ -796,48 +1219,32 @@ pub mod pallet {
                 s.throughput = throughput;
                 s.processed_count = s.processed_count.saturating_add(processed);
                 Ok(())
-            })
+            })?;
+
+            let block_number = frame_system::Pallet::<T>::block_number();
+            SubnodeThroughputHistory::<T>::mutate(subnode_id, |history| {
+                if history.is_full() {
+                    history.remove(0);
+                }
+                let _ = history.try_push((throughput, block_number));
+            });
+
+            Ok(())
         }
 
         #[pallet::call_index(7)]
         #[pallet::weight(T::WeightInfo::activate_subnode())]
-        pub fn record_heartbeat(origin: OriginFor<T>, subnode_id: SubnodeId) -> DispatchResult {
+        pub fn record_heartbeat(
+            origin: OriginFor<T>,
+            subnode_id: SubnodeId,
+            sequence: u64,
+        ) -> DispatchResult {
             let caller = ensure_signed(origin)?;
             let caller_actor = Self::account_to_actor(&caller);
 
             let block_number = frame_system::Pallet::<T>::block_number();
 
-            Subnodes::<T>::try_mutate(subnode_id, |subnode| -> DispatchResult {
-                let s = subnode.as_mut().ok_or(Error::<T>::SubnodeNotFound)?;
-
-                ensure!(s.operator == caller_actor, Error::<T>::NotSubnodeOperator);
-                ensure!(
-                    s.status == SubnodeStatus::Active,
-                    Error::<T>::SubnodeNotActive
-                );
-
-                let old_score = s.health_score;
-                s.last_heartbeat = block_number;
-                s.consecutive_misses = 0;
-                s.health_score = old_score
-                    .saturating_add(T::HealthScoreRecovery::get())
-                    .min(100);
-
-                Self::deposit_event(Event::HeartbeatReceived {
-                    subnode_id,
-                    health_score: s.health_score,
-                });
-
-                if old_score != s.health_score {
-                    Self::deposit_event(Event::SubnodeHealthUpdated {
-                        subnode_id,
-                        old_score,
-                        new_score: s.health_score,
-                    });
-                }
-
-                Ok(())
-            })
+            Self::apply_heartbeat(subnode_id, &caller_actor, sequence, block_number)
         }
 
         #[pallet::call_index(8)]
@@ -862,27 +1269,22 @@ pub mod pallet {
                 Error::<T>::NotSubnodeOperator
             );
 
+            let device_count = Self::verified_device_count(subnode_id, device_count);
             let weights = GlobalFusionWeights::<T>::get();
 
             FusedHealth::<T>::mutate(subnode_id, |maybe_health| {
                 let health = maybe_health
                     .get_or_insert_with(|| FusedHealthMetrics::new(FusionPosition::default()));
 
-                health.record_device_observation(device_count, block_u64, commitment, &weights);
-
-                Self::deposit_event(Event::DeviceObservationRecorded {
+                Self::apply_device_observation(
+                    health,
                     subnode_id,
                     device_count,
                     commitment,
-                });
-
-                Self::deposit_event(Event::FusedHealthUpdated {
-                    subnode_id,
-                    heartbeat_component: health.heartbeat_score,
-                    device_component: health.device_metrics.device_score(),
-                    position_component: health.position_metrics.position_score(),
-                    fused_score: health.fused_score,
-                });
+                    block_u64,
+                    &weights,
+                );
+                Self::deposit_fused_health(subnode_id, health);
             });
 
             Ok(())
@@ -912,28 +1314,7 @@ pub mod pallet {
             );
 
             let position = FusionPosition::new(position_x, position_y, position_z);
-            let weights = GlobalFusionWeights::<T>::get();
-
-            FusedHealth::<T>::mutate(subnode_id, |maybe_health| {
-                let health =
-                    maybe_health.get_or_insert_with(|| FusedHealthMetrics::new(position.clone()));
-
-                health.record_position_confirmation(position.clone(), block_u64, &weights);
-
-                Self::deposit_event(Event::PositionConfirmed {
-                    subnode_id,
-                    position,
-                    variance: health.position_metrics.position_variance,
-                });
-
-                Self::deposit_event(Event::FusedHealthUpdated {
-                    subnode_id,
-                    heartbeat_component: health.heartbeat_score,
-                    device_component: health.device_metrics.device_score(),
-                    position_component: health.position_metrics.position_score(),
-                    fused_score: health.fused_score,
-                });
-            });
+            Self::confirm_position(subnode_id, position, block_u64);
 
             Ok(())
         }
@@ -943,6 +1324,7 @@ pub mod pallet {
         pub fn heartbeat_with_device_proof(
             origin: OriginFor<T>,
             subnode_id: SubnodeId,
+            sequence: u64,
             device_count: u8,
             commitment: sp_core::H256,
         ) -> DispatchResult {
@@ -962,13 +1344,11 @@ pub mod pallet {
                     s.status == SubnodeStatus::Active,
                     Error::<T>::SubnodeNotActive
                 );
+                ensure!(sequence > s.heartbeat_sequence, Error::<T>::StaleHeartbeat);
 
-                let old_score = s.health_score;
+                s.heartbeat_sequence = sequence;
                 s.last_heartbeat = block_number;
-                s.consecutive_misses = 0;
-                s.health_score = old_score
-                    .saturating_add(T::HealthScoreRecovery::get())
-                    .min(100);
+                Self::apply_heartbeat_recovery(s);
 
                 Self::deposit_event(Event::HeartbeatReceived {
                     subnode_id,
@@ -978,6 +1358,7 @@ pub mod pallet {
                 Ok(())
             })?;
 
+            let device_count = Self::verified_device_count(subnode_id, device_count);
             let weights = GlobalFusionWeights::<T>::get();
 
             FusedHealth::<T>::mutate(subnode_id, |maybe_health| {
@@ -989,21 +1370,15 @@ pub mod pallet {
                     .unwrap_or(100);
 
                 health.update_heartbeat(new_heartbeat_score, block_u64, &weights);
-                health.record_device_observation(device_count, block_u64, commitment, &weights);
-
-                Self::deposit_event(Event::DeviceObservationRecorded {
+                Self::apply_device_observation(
+                    health,
                     subnode_id,
                     device_count,
                     commitment,
-                });
-
-                Self::deposit_event(Event::FusedHealthUpdated {
-                    subnode_id,
-                    heartbeat_component: health.heartbeat_score,
-                    device_component: health.device_metrics.device_score(),
-                    position_component: health.position_metrics.position_score(),
-                    fused_score: health.fused_score,
-                });
+                    block_u64,
+                    &weights,
+                );
+                Self::deposit_fused_health(subnode_id, health);
             });
 
             Ok(())
@@ -1026,35 +1401,530 @@ pub mod pallet {
 
             Ok(())
         }
-    }
 
-    impl<T: Config> Pallet<T> {
-        fn account_to_actor(account: &T::AccountId) -> ActorId {
-            let encoded = account.encode();
-            let hash = sp_core::blake2_256(&encoded);
-            ActorId::from_raw(hash)
-        }
+        #[pallet::call_index(14)]
+        #[pallet::weight(T::WeightInfo::set_fusion_healing_config())]
+        pub fn set_fusion_healing_config(
+            origin: OriginFor<T>,
+            config: FusionHealingConfig,
+        ) -> DispatchResult {
+            ensure_root(origin)?;
+            ensure!(config.is_valid(), Error::<T>::InvalidHealingConfig);
 
-        fn next_subnode_id() -> SubnodeId {
-            let id = SubnodeCount::<T>::get();
-            SubnodeCount::<T>::put(id.saturating_add(1));
-            SubnodeId::new(id)
-        }
+            HealingConfig::<T>::put(config);
 
-        fn next_cluster_id() -> ClusterId {
-            let id = ClusterCount::<T>::get();
-            ClusterCount::<T>::put(id.saturating_add(1));
-            ClusterId::new(id)
-        }
+            Self::deposit_event(Event::FusionHealingConfigUpdated { config });
 
-        fn compute_scaling_decision(throughput: Perbill, current_subnodes: u32) -> ScalingDecision {
-            let activation_threshold = T::ActivationThreshold::get();
-            let deactivation_threshold = T::DeactivationThreshold::get();
-            let max_subnodes = T::MaxSubnodesPerCluster::get();
+            Ok(())
+        }
 
-            if throughput >= activation_threshold && current_subnodes < max_subnodes {
-                let target = Self::calculate_target_subnodes(throughput);
-                if target > current_subnodes {
+        /// Propose handing a cluster to `new_owner`. Ownership only changes once the
+        /// new owner accepts; a later proposal replaces an earlier one.
+        #[pallet::call_index(12)]
+        #[pallet::weight(T::WeightInfo::propose_cluster_transfer())]
+        pub fn propose_cluster_transfer(
+            origin: OriginFor<T>,
+            cluster_id: ClusterId,
+            new_owner: ActorId,
+        ) -> DispatchResult {
+            let caller = ensure_signed(origin)?;
+            let caller_actor = Self::account_to_actor(&caller);
+
+            let cluster = Clusters::<T>::get(cluster_id).ok_or(Error::<T>::ClusterNotFound)?;
+            ensure!(cluster.owner == caller_actor, Error::<T>::NotClusterOwner);
+
+            PendingClusterTransfers::<T>::insert(cluster_id, new_owner);
+
+            Self::deposit_event(Event::ClusterTransferProposed {
+                cluster_id,
+                current_owner: caller_actor,
+                new_owner,
+            });
+
+            Ok(())
+        }
+
+        #[pallet::call_index(13)]
+        #[pallet::weight(T::WeightInfo::accept_cluster_transfer())]
+        pub fn accept_cluster_transfer(
+            origin: OriginFor<T>,
+            cluster_id: ClusterId,
+        ) -> DispatchResult {
+            let caller = ensure_signed(origin)?;
+            let caller_actor = Self::account_to_actor(&caller);
+
+            let proposed = PendingClusterTransfers::<T>::get(cluster_id)
+                .ok_or(Error::<T>::NoPendingTransfer)?;
+            ensure!(proposed == caller_actor, Error::<T>::NotProposedOwner);
+
+            let old_owner = Clusters::<T>::try_mutate(cluster_id, |cluster| {
+                let c = cluster.as_mut().ok_or(Error::<T>::ClusterNotFound)?;
+                let old_owner = c.owner;
+                c.owner = caller_actor;
+                Ok::<_, Error<T>>(old_owner)
+            })?;
+            PendingClusterTransfers::<T>::remove(cluster_id);
+
+            Self::deposit_event(Event::ClusterTransferred {
+                cluster_id,
+                old_owner,
+                new_owner: caller_actor,
+            });
+
+            Ok(())
+        }
+
+        /// Bind one of the operator's active registered devices to a subnode so it
+        /// counts toward the subnode's device health.
+        #[pallet::call_index(15)]
+        #[pallet::weight(T::WeightInfo::bind_device())]
+        pub fn bind_device(
+            origin: OriginFor<T>,
+            subnode_id: SubnodeId,
+            device_id: DeviceIdOf<T>,
+        ) -> DispatchResult {
+            let caller = ensure_signed(origin)?;
+            let caller_actor = Self::account_to_actor(&caller);
+
+            let subnode = Subnodes::<T>::get(subnode_id).ok_or(Error::<T>::SubnodeNotFound)?;
+            ensure!(
+                subnode.operator == caller_actor,
+                Error::<T>::NotSubnodeOperator
+            );
+            ensure!(
+                T::DeviceRegistry::device_owner(device_id) == Some(caller_actor),
+                Error::<T>::DeviceNotOwned
+            );
+            ensure!(
+                T::DeviceRegistry::is_device_active(device_id),
+                Error::<T>::DeviceNotActive
+            );
+            ensure!(
+                !DeviceBindings::<T>::contains_key(device_id),
+                Error::<T>::DeviceAlreadyBound
+            );
+            let bound = SubnodeDeviceCount::<T>::get(subnode_id);
+            ensure!(
+                bound < T::MaxDevicesPerSubnode::get(),
+                Error::<T>::TooManyDevices
+            );
+
+            SubnodeDevices::<T>::insert(subnode_id, device_id, ());
+            DeviceBindings::<T>::insert(device_id, subnode_id);
+            SubnodeDeviceCount::<T>::insert(subnode_id, bound.saturating_add(1));
+
+            Self::deposit_event(Event::DeviceBound {
+                subnode_id,
+                device_id,
+            });
+
+            Ok(())
+        }
+
+        #[pallet::call_index(16)]
+        #[pallet::weight(T::WeightInfo::unbind_device())]
+        pub fn unbind_device(
+            origin: OriginFor<T>,
+            subnode_id: SubnodeId,
+            device_id: DeviceIdOf<T>,
+        ) -> DispatchResult {
+            let caller = ensure_signed(origin)?;
+            let caller_actor = Self::account_to_actor(&caller);
+
+            let subnode = Subnodes::<T>::get(subnode_id).ok_or(Error::<T>::SubnodeNotFound)?;
+            ensure!(
+                subnode.operator == caller_actor,
+                Error::<T>::NotSubnodeOperator
+            );
+            ensure!(
+                SubnodeDevices::<T>::contains_key(subnode_id, device_id),
+                Error::<T>::DeviceNotBound
+            );
+
+            SubnodeDevices::<T>::remove(subnode_id, device_id);
+            DeviceBindings::<T>::remove(device_id);
+            SubnodeDeviceCount::<T>::mutate(subnode_id, |c| *c = c.saturating_sub(1));
+
+            Self::deposit_event(Event::DeviceUnbound {
+                subnode_id,
+                device_id,
+            });
+
+            Ok(())
+        }
+
+        /// Gracefully close a cluster: stop accepting subnodes and start deactivating
+        /// every active one. The cluster moves to `Shutdown` once none remain active.
+        #[pallet::call_index(17)]
+        #[pallet::weight(T::WeightInfo::drain_cluster(T::MaxSubnodesHardCeiling::get()))]
+        pub fn drain_cluster(origin: OriginFor<T>, cluster_id: ClusterId) -> DispatchResult {
+            let caller = ensure_signed(origin)?;
+            let caller_actor = Self::account_to_actor(&caller);
+
+            let cluster = Clusters::<T>::get(cluster_id).ok_or(Error::<T>::ClusterNotFound)?;
+            ensure!(cluster.owner == caller_actor, Error::<T>::NotClusterOwner);
+            ensure!(
+                !matches!(
+                    cluster.status,
+                    ClusterStatus::Draining | ClusterStatus::Shutdown
+                ),
+                Error::<T>::ClusterNotDrainable
+            );
+
+            let block_number = frame_system::Pallet::<T>::block_number();
+            DrainingClusters::<T>::insert(cluster_id, block_number);
+            SlaBelowSince::<T>::remove(cluster_id);
+            SlaBreaches::<T>::remove(cluster_id);
+            Self::transition_cluster_status(&cluster, ClusterStatus::Draining);
+
+            // The MinSubnodes floor does not apply: the cluster is closing on purpose.
+            for subnode_id in Self::get_active_subnodes(cluster_id) {
+                Self::begin_drain_deactivation(subnode_id, block_number);
+            }
+
+            Ok(())
+        }
+
+        /// Heartbeat, device observation and position confirmation in one call,
+        /// with a single `FusedHealthUpdated` for all three components.
+        #[pallet::call_index(18)]
+        #[pallet::weight(T::WeightInfo::activate_subnode())]
+        #[allow(clippy::too_many_arguments)]
+        pub fn full_health_report(
+            origin: OriginFor<T>,
+            subnode_id: SubnodeId,
+            sequence: u64,
+            device_count: u8,
+            commitment: sp_core::H256,
+            position_x: i64,
+            position_y: i64,
+            position_z: i64,
+        ) -> DispatchResult {
+            let caller = ensure_signed(origin)?;
+            let caller_actor = Self::account_to_actor(&caller);
+
+            let block_number = frame_system::Pallet::<T>::block_number();
+            let block_u64: u64 = block_number
+                .try_into()
+                .map_err(|_| Error::<T>::SubnodeNotFound)?;
+
+            Self::apply_heartbeat(subnode_id, &caller_actor, sequence, block_number)?;
+
+            let heartbeat_score = Subnodes::<T>::get(subnode_id)
+                .map(|s| s.health_score)
+                .unwrap_or(100);
+            let device_count = Self::verified_device_count(subnode_id, device_count);
+            let position = FusionPosition::new(position_x, position_y, position_z);
+            let weights = GlobalFusionWeights::<T>::get();
+
+            FusedHealth::<T>::mutate(subnode_id, |maybe_health| {
+                let health = maybe_health
+                    .get_or_insert_with(|| FusedHealthMetrics::new(FusionPosition::default()));
+
+                health.update_heartbeat(heartbeat_score, block_u64, &weights);
+                Self::apply_device_observation(
+                    health,
+                    subnode_id,
+                    device_count,
+                    commitment,
+                    block_u64,
+                    &weights,
+                );
+                Self::apply_position_report(health, subnode_id, position, block_u64, &weights);
+                Self::deposit_fused_health(subnode_id, health);
+            });
+
+            Ok(())
+        }
+
+        /// Replace the global fusion weights with a named preset.
+        #[pallet::call_index(19)]
+        #[pallet::weight(T::WeightInfo::update_throughput())]
+        pub fn set_fusion_preset(origin: OriginFor<T>, preset: FusionPreset) -> DispatchResult {
+            ensure_root(origin)?;
+
+            let weights = preset.weights().ok_or(Error::<T>::InvalidFusionWeights)?;
+
+            GlobalFusionWeights::<T>::put(weights);
+
+            Ok(())
+        }
+
+        /// Ask for a triangulated device's confirmed positions to stand in for the
+        /// subnode's, or clear the link with `None`. A new link only takes effect
+        /// once the cluster owner or root approves it with `approve_position_device`.
+        #[pallet::call_index(20)]
+        #[pallet::weight(T::WeightInfo::set_position_device())]
+        pub fn set_position_device(
+            origin: OriginFor<T>,
+            subnode_id: SubnodeId,
+            mac_hash: Option<sp_core::H256>,
+        ) -> DispatchResult {
+            let caller = ensure_signed(origin)?;
+            let caller_actor = Self::account_to_actor(&caller);
+
+            let subnode = Subnodes::<T>::get(subnode_id).ok_or(Error::<T>::SubnodeNotFound)?;
+            ensure!(
+                subnode.operator == caller_actor,
+                Error::<T>::NotSubnodeOperator
+            );
+            if let Some(mac_hash) = mac_hash {
+                ensure!(
+                    PositionDevices::<T>::get(mac_hash).is_none_or(|bound| bound == subnode_id),
+                    Error::<T>::PositionDeviceAlreadyBound
+                );
+            }
+
+            match mac_hash {
+                Some(mac_hash) => {
+                    PendingPositionDevices::<T>::insert(subnode_id, mac_hash);
+                    Self::deposit_event(Event::PositionDeviceRequested {
+                        subnode_id,
+                        mac_hash,
+                    });
+                }
+                None => {
+                    PendingPositionDevices::<T>::remove(subnode_id);
+                    Self::clear_position_device(subnode_id);
+                    Self::deposit_event(Event::PositionDeviceSet {
+                        subnode_id,
+                        mac_hash: None,
+                    });
+                }
+            }
+
+            Ok(())
+        }
+
+        /// Resize how many subnodes a cluster may register. Owner or root;
+        /// `recommended_capacity` suggests a value from throughput history.
+        #[pallet::call_index(21)]
+        #[pallet::weight(T::WeightInfo::set_cluster_max_subnodes())]
+        pub fn set_cluster_max_subnodes(
+            origin: OriginFor<T>,
+            cluster_id: ClusterId,
+            max_subnodes: u32,
+        ) -> DispatchResult {
+            let caller = ensure_signed_or_root(origin)?;
+
+            let cluster = Clusters::<T>::get(cluster_id).ok_or(Error::<T>::ClusterNotFound)?;
+            if let Some(caller) = caller {
+                ensure!(
+                    cluster.owner == Self::account_to_actor(&caller),
+                    Error::<T>::NotClusterOwner
+                );
+            }
+            ensure!(
+                max_subnodes <= T::MaxSubnodesHardCeiling::get(),
+                Error::<T>::ClusterCapacityTooHigh
+            );
+            let registered = ClusterSubnodes::<T>::iter_key_prefix(cluster_id).count() as u32;
+            ensure!(
+                max_subnodes >= T::MinSubnodes::get() && max_subnodes >= registered,
+                Error::<T>::ClusterCapacityTooLow
+            );
+
+            Clusters::<T>::mutate(cluster_id, |c| {
+                if let Some(ref mut cluster) = c {
+                    cluster.max_subnodes = max_subnodes;
+                }
+            });
+
+            Self::deposit_event(Event::ClusterCapacityUpdated {
+                cluster_id,
+                max_subnodes,
+            });
+
+            Ok(())
+        }
+
+        /// Open the commitment of the subnode's latest device observation. A
+        /// matching reveal counts the observation as verified, giving it full
+        /// weight in the device score.
+        #[pallet::call_index(22)]
+        #[pallet::weight(T::WeightInfo::reveal_device_observation())]
+        pub fn reveal_device_observation(
+            origin: OriginFor<T>,
+            subnode_id: SubnodeId,
+            reveal: DeviceReveal,
+        ) -> DispatchResult {
+            let caller = ensure_signed(origin)?;
+            let caller_actor = Self::account_to_actor(&caller);
+
+            let subnode = Subnodes::<T>::get(subnode_id).ok_or(Error::<T>::SubnodeNotFound)?;
+            ensure!(
+                subnode.operator == caller_actor,
+                Error::<T>::NotSubnodeOperator
+            );
+
+            FusedHealth::<T>::try_mutate(subnode_id, |maybe_health| {
+                let health = maybe_health
+                    .as_mut()
+                    .ok_or(Error::<T>::NoFusedHealthRecord)?;
+                let commitment = health
+                    .device_metrics
+                    .last_commitment
+                    .filter(|_| health.device_metrics.reveal_pending)
+                    .ok_or(Error::<T>::NoPendingReveal)?;
+                ensure!(reveal.opens(&commitment), Error::<T>::InvalidCommitment);
+
+                health.device_metrics.mark_revealed();
+                health.recalculate_fused_score(&GlobalFusionWeights::<T>::get());
+
+                Self::deposit_event(Event::DeviceObservationVerified {
+                    subnode_id,
+                    commitment,
+                });
+                Self::deposit_fused_health(subnode_id, health);
+
+                Ok(())
+            })
+        }
+
+        /// Link the subnode to the triangulated device its operator requested.
+        /// Cluster owner or root, vouching that the device belongs to the operator.
+        #[pallet::call_index(23)]
+        #[pallet::weight(T::WeightInfo::approve_position_device())]
+        pub fn approve_position_device(
+            origin: OriginFor<T>,
+            subnode_id: SubnodeId,
+        ) -> DispatchResult {
+            let caller = ensure_signed_or_root(origin)?;
+
+            let subnode = Subnodes::<T>::get(subnode_id).ok_or(Error::<T>::SubnodeNotFound)?;
+            if let Some(caller) = caller {
+                let cluster =
+                    Clusters::<T>::get(subnode.cluster).ok_or(Error::<T>::ClusterNotFound)?;
+                ensure!(
+                    cluster.owner == Self::account_to_actor(&caller),
+                    Error::<T>::NotClusterOwner
+                );
+            }
+            let mac_hash = PendingPositionDevices::<T>::get(subnode_id)
+                .ok_or(Error::<T>::NoPendingPositionDevice)?;
+            ensure!(
+                PositionDevices::<T>::get(mac_hash).is_none_or(|bound| bound == subnode_id),
+                Error::<T>::PositionDeviceAlreadyBound
+            );
+
+            PendingPositionDevices::<T>::remove(subnode_id);
+            Self::clear_position_device(subnode_id);
+            SubnodePositionDevice::<T>::insert(subnode_id, mac_hash);
+            PositionDevices::<T>::insert(mac_hash, subnode_id);
+
+            Self::deposit_event(Event::PositionDeviceSet {
+                subnode_id,
+                mac_hash: Some(mac_hash),
+            });
+
+            Ok(())
+        }
+    }
+
+    impl<T: Config> Pallet<T> {
+        /// Bound devices that the registry currently reports as active.
+        pub fn active_bound_devices(subnode_id: SubnodeId) -> u32 {
+            SubnodeDevices::<T>::iter_key_prefix(subnode_id)
+                .filter(|device_id| T::DeviceRegistry::is_device_active(*device_id))
+                .count() as u32
+        }
+
+        /// Caps a reported device count at the number of active devices bound to the subnode.
+        fn verified_device_count(subnode_id: SubnodeId, reported: u8) -> u8 {
+            let active = Self::active_bound_devices(subnode_id).min(u8::MAX as u32) as u8;
+            reported.min(active)
+        }
+
+        fn account_to_actor(account: &T::AccountId) -> ActorId {
+            let encoded = account.encode();
+            let hash = sp_core::blake2_256(&encoded);
+            ActorId::from_raw(hash)
+        }
+
+        fn next_subnode_id() -> SubnodeId {
+            let id = SubnodeCount::<T>::get();
+            SubnodeCount::<T>::put(id.saturating_add(1));
+            SubnodeId::new(id)
+        }
+
+        fn next_cluster_id() -> ClusterId {
+            let id = ClusterCount::<T>::get();
+            ClusterCount::<T>::put(id.saturating_add(1));
+            ClusterId::new(id)
+        }
+
+        /// Nearest-rank `p`-th percentile of the subnode's retained throughput
+        /// samples. `None` without samples or when `p` exceeds 100.
+        pub fn subnode_throughput_percentile(subnode_id: SubnodeId, p: u8) -> Option<Perbill> {
+            if p > 100 {
+                return None;
+            }
+            let mut samples: Vec<Perbill> = SubnodeThroughputHistory::<T>::get(subnode_id)
+                .iter()
+                .map(|(throughput, _)| *throughput)
+                .collect();
+            if samples.is_empty() {
+                return None;
+            }
+            samples.sort();
+
+            let rank = (p as usize * samples.len()).div_ceil(100).max(1);
+            samples.get(rank - 1).copied()
+        }
+
+        /// Number of throughput samples recorded for a cluster.
+        fn throughput_sample_count(cluster_id: ClusterId) -> u32 {
+            ThroughputHistory::<T>::get(cluster_id)
+                .map(|m| m.sample_count)
+                .unwrap_or(0)
+        }
+
+        /// Capacity suggested by the cluster's throughput history: `MinSubnodes`
+        /// plus the throughput's share of the headroom up to
+        /// `MaxSubnodesHardCeiling`, never below the registered subnode count.
+        /// Keeps the current `max_subnodes` until `MinSamplesForScaling` samples
+        /// exist. `None` for an unknown cluster.
+        pub fn recommended_capacity(cluster_id: ClusterId) -> Option<u32> {
+            let cluster = Clusters::<T>::get(cluster_id)?;
+            let metric = match ThroughputHistory::<T>::get(cluster_id) {
+                Some(m) if m.sample_count >= T::MinSamplesForScaling::get() => m,
+                _ => return Some(cluster.max_subnodes),
+            };
+
+            let ceiling = T::MaxSubnodesHardCeiling::get();
+            let min = T::MinSubnodes::get().min(ceiling);
+            let headroom = u64::from(ceiling - min);
+            let extra = (u64::from(metric.throughput.deconstruct()) * headroom)
+                .div_ceil(u64::from(Perbill::ACCURACY)) as u32;
+            let registered = ClusterSubnodes::<T>::iter_key_prefix(cluster_id).count() as u32;
+
+            Some(min.saturating_add(extra).max(registered).min(ceiling))
+        }
+
+        /// Always `Maintain` until `sample_count` reaches `MinSamplesForScaling`,
+        /// so sparse data cannot trigger scaling.
+        fn compute_scaling_decision(cluster: &Cluster<T>, sample_count: u32) -> ScalingDecision {
+            let throughput = cluster.total_throughput;
+            let current_subnodes = cluster.active_subnodes;
+            if let Some(target) = Self::presence_target_subnodes(current_subnodes) {
+                if target > current_subnodes && current_subnodes < cluster.max_subnodes {
+                    return ScalingDecision::ScaleUp(target.min(cluster.max_subnodes));
+                }
+            }
+
+            let max_subnodes = cluster.max_subnodes;
+
+            if sample_count < T::MinSamplesForScaling::get() {
+                return ScalingDecision::Maintain;
+            }
+
+            let activation_threshold = T::ActivationThreshold::get();
+            let deactivation_threshold = T::DeactivationThreshold::get();
+
+            if throughput >= activation_threshold && current_subnodes < max_subnodes {
+                let target =
+                    Self::calculate_target_subnodes(throughput, current_subnodes, max_subnodes);
+                if target > current_subnodes {
                     return ScalingDecision::ScaleUp(target);
                 }
             }
@@ -1066,16 +1936,59 @@ pub mod pallet {
             ScalingDecision::Maintain
         }
 
-        fn calculate_target_subnodes(throughput: Perbill) -> u32 {
-            let pct = throughput.deconstruct() / 10_000_000;
-            let scaled = pct.saturating_mul(10);
-            let divisor = 225u32;
-            let result = scaled.saturating_add(divisor.saturating_sub(1)) / divisor;
+        /// Subnodes a cluster with `active_subnodes` needs to serve its share of the
+        /// current epoch's presences at `PresencesPerSubnode` each, or `None` when
+        /// presence-aware scaling is off. The network-wide presence count is split
+        /// across clusters in proportion to their share of all active subnodes.
+        fn presence_target_subnodes(active_subnodes: u32) -> Option<u32> {
+            if !T::PresenceAwareScaling::get() {
+                return None;
+            }
+            let total_active = ActiveSubnodeCount::<T>::get().max(active_subnodes);
+            if total_active == 0 {
+                return Some(0);
+            }
+            let share = (u64::from(T::PresenceLoad::current_presence_count())
+                * u64::from(active_subnodes))
+            .div_ceil(u64::from(total_active));
+            let per_subnode = u64::from(T::PresencesPerSubnode::get().max(1));
+            Some(share.div_ceil(per_subnode).min(u64::from(u32::MAX)) as u32)
+        }
 
-            let max = T::MaxSubnodesPerCluster::get();
+        /// Map throughput onto a subnode target in `[MinSubnodes, max_subnodes]`, where
+        /// `max_subnodes` is the cluster's own capacity.
+        ///
+        /// The throughput range is split into `max - min + 1` equal bands, band `k`
+        /// mapping to `min + k`. Leaving the band of `current_subnodes` additionally
+        /// requires clearing the boundary by `SCALING_HYSTERESIS`, so throughput
+        /// hovering near a boundary keeps the current target.
+        pub fn calculate_target_subnodes(
+            throughput: Perbill,
+            current_subnodes: u32,
+            max_subnodes: u32,
+        ) -> u32 {
             let min = T::MinSubnodes::get();
+            let max = max_subnodes.max(min);
+            let band = |t: Perbill| -> u32 {
+                let span = u64::from(max - min);
+                let parts = u64::from(t.deconstruct());
+                let index =
+                    parts.saturating_mul(span.saturating_add(1)) / u64::from(Perbill::ACCURACY);
+                min.saturating_add(index.min(span) as u32)
+            };
 
-            result.clamp(min, max)
+            let target = band(throughput);
+            if current_subnodes < min || current_subnodes > max {
+                return target;
+            }
+
+            if target > current_subnodes {
+                band(throughput.saturating_sub(SCALING_HYSTERESIS)).max(current_subnodes)
+            } else if target < current_subnodes {
+                band(throughput.saturating_add(SCALING_HYSTERESIS)).min(current_subnodes)
+            } else {
+                target
+            }
         }
 
         #[allow(clippy::excessive_nesting)]
@@ -1119,6 +2032,71 @@ pub mod pallet {
             }
         }
 
+        fn begin_drain_deactivation(subnode_id: SubnodeId, block_number: BlockNumberFor<T>) {
+            let Some(mut s) = Subnodes::<T>::get(subnode_id) else {
+                return;
+            };
+            s.status = SubnodeStatus::Deactivating;
+            s.deactivation_started = Some(block_number);
+            let cluster_id = s.cluster;
+            Subnodes::<T>::insert(subnode_id, s);
+            Self::deposit_event(Event::SubnodeDeactivationStarted {
+                subnode_id,
+                cluster_id,
+            });
+        }
+
+        /// Shut down draining clusters with no live subnodes left, resuming from
+        /// `DrainSweepCursor` and checking at most 10 per invocation. Returns the
+        /// weight used.
+        fn complete_drains() -> Weight {
+            const MAX_PER_BLOCK: usize = 10;
+            let mut iter = match DrainSweepCursor::<T>::get() {
+                Some(key) => DrainingClusters::<T>::iter_keys_from(key.into_inner()),
+                None => DrainingClusters::<T>::iter_keys(),
+            };
+            let draining: Vec<ClusterId> = iter.by_ref().take(MAX_PER_BLOCK).collect();
+            if draining.len() < MAX_PER_BLOCK {
+                DrainSweepCursor::<T>::kill();
+            } else {
+                DrainSweepCursor::<T>::put(BoundedVec::truncate_from(iter.last_raw_key().to_vec()));
+            }
+
+            let mut reads = (draining.len() as u64).saturating_add(1);
+            let mut writes: u64 = 1;
+            for cluster_id in draining {
+                let (live, scanned) = Self::has_live_subnodes(cluster_id);
+                reads = reads.saturating_add(scanned);
+                if live {
+                    continue;
+                }
+                DrainingClusters::<T>::remove(cluster_id);
+                reads = reads.saturating_add(2);
+                writes = writes.saturating_add(2);
+                if let Some(cluster) = Clusters::<T>::get(cluster_id) {
+                    Self::transition_cluster_status(&cluster, ClusterStatus::Shutdown);
+                }
+            }
+
+            T::DbWeight::get().reads_writes(reads, writes)
+        }
+
+        /// Whether the cluster still has an active or deactivating subnode, with the
+        /// number of storage reads spent finding out.
+        fn has_live_subnodes(cluster_id: ClusterId) -> (bool, u64) {
+            let mut reads: u64 = 1;
+            let live = ClusterSubnodes::<T>::iter_key_prefix(cluster_id).any(|subnode_id| {
+                reads = reads.saturating_add(2);
+                Subnodes::<T>::get(subnode_id).is_some_and(|s| {
+                    matches!(
+                        s.status,
+                        SubnodeStatus::Active | SubnodeStatus::Deactivating
+                    )
+                })
+            });
+            (live, reads)
+        }
+
         pub fn get_cluster_subnodes(cluster_id: ClusterId) -> Vec<SubnodeId> {
             ClusterSubnodes::<T>::iter_prefix(cluster_id)
                 .map(|(subnode_id, _)| subnode_id)
@@ -1135,6 +2113,16 @@ pub mod pallet {
                 .collect()
         }
 
+        /// Active subnode to deactivate first on scale-down: lowest priority, then
+        /// lowest throughput, then oldest id.
+        pub fn scale_down_candidate(cluster_id: ClusterId) -> Option<SubnodeId> {
+            ClusterSubnodes::<T>::iter_key_prefix(cluster_id)
+                .filter_map(Subnodes::<T>::get)
+                .filter(|s| s.status == SubnodeStatus::Active)
+                .min_by_key(|s| (s.priority, s.throughput, s.id.0))
+                .map(|s| s.id)
+        }
+
         pub fn get_cluster_throughput(cluster_id: ClusterId) -> Perbill {
             Clusters::<T>::get(cluster_id)
                 .map(|c| c.total_throughput)
@@ -1142,8 +2130,8 @@ pub mod pallet {
         }
 
         pub fn is_scaling_needed(cluster_id: ClusterId) -> Option<ScalingDecision> {
-            Clusters::<T>::get(cluster_id)
-                .map(|c| Self::compute_scaling_decision(c.total_throughput, c.active_subnodes))
+            let sample_count = Self::throughput_sample_count(cluster_id);
+            Clusters::<T>::get(cluster_id).map(|c| Self::compute_scaling_decision(&c, sample_count))
         }
 
         #[allow(clippy::excessive_nesting)]
@@ -1151,6 +2139,7 @@ pub mod pallet {
             let timeout = T::HeartbeatTimeoutBlocks::get();
             let max_misses = T::MaxConsecutiveMisses::get();
             let decay = T::HealthScoreDecay::get();
+            let grace = T::AtRiskMissGrace::get();
             const MAX_PER_BLOCK: u32 = 100;
             let mut processed: u32 = 0;
 
@@ -1190,7 +2179,9 @@ pub mod pallet {
                     Clusters::<T>::mutate(cluster_id, |cluster| {
                         if let Some(ref mut c) = cluster {
                             c.active_subnodes = c.active_subnodes.saturating_sub(1);
-                            if c.active_subnodes < T::MinSubnodes::get() {
+                            if c.active_subnodes < T::MinSubnodes::get()
+                                && c.status != ClusterStatus::Draining
+                            {
                                 c.status = ClusterStatus::Degraded;
                             }
                         }
@@ -1204,6 +2195,14 @@ pub mod pallet {
                         consecutive_misses: subnode.consecutive_misses,
                     });
                 } else {
+                    let misses_remaining = max_misses.saturating_sub(subnode.consecutive_misses);
+                    if misses_remaining <= grace {
+                        Self::deposit_event(Event::SubnodeAtRisk {
+                            subnode_id,
+                            consecutive_misses: subnode.consecutive_misses,
+                            misses_remaining,
+                        });
+                    }
                     Subnodes::<T>::insert(subnode_id, subnode);
                 }
             }
@@ -1233,7 +2232,8 @@ pub mod pallet {
         fn decrement_cluster_active(cluster_id: ClusterId) {
             if let Some(mut c) = Clusters::<T>::get(cluster_id) {
                 c.active_subnodes = c.active_subnodes.saturating_sub(1);
-                if c.active_subnodes < T::MinSubnodes::get() {
+                if c.active_subnodes < T::MinSubnodes::get() && c.status != ClusterStatus::Draining
+                {
                     c.status = ClusterStatus::Degraded;
                 }
                 Clusters::<T>::insert(cluster_id, c);
@@ -1247,12 +2247,171 @@ pub mod pallet {
             }
         }
 
+        /// Accept a heartbeat from the subnode's operator: advance the sequence,
+        /// record the block and run heartbeat recovery. Inactive subnodes may
+        /// heartbeat too, so they can prove liveness before activation.
+        fn apply_heartbeat(
+            subnode_id: SubnodeId,
+            caller_actor: &ActorId,
+            sequence: u64,
+            block_number: BlockNumberFor<T>,
+        ) -> DispatchResult {
+            Subnodes::<T>::try_mutate(subnode_id, |subnode| -> DispatchResult {
+                let s = subnode.as_mut().ok_or(Error::<T>::SubnodeNotFound)?;
+
+                ensure!(s.operator == *caller_actor, Error::<T>::NotSubnodeOperator);
+                ensure!(
+                    matches!(s.status, SubnodeStatus::Active | SubnodeStatus::Inactive),
+                    Error::<T>::SubnodeNotActive
+                );
+                ensure!(sequence > s.heartbeat_sequence, Error::<T>::StaleHeartbeat);
+
+                let old_score = s.health_score;
+                s.heartbeat_sequence = sequence;
+                s.last_heartbeat = block_number;
+                Self::apply_heartbeat_recovery(s);
+
+                Self::deposit_event(Event::HeartbeatReceived {
+                    subnode_id,
+                    health_score: s.health_score,
+                });
+
+                if old_score != s.health_score {
+                    Self::deposit_event(Event::SubnodeHealthUpdated {
+                        subnode_id,
+                        old_score,
+                        new_score: s.health_score,
+                    });
+                }
+
+                Ok(())
+            })
+        }
+
+        /// Record a verified device count into the fused metrics.
+        fn apply_device_observation(
+            health: &mut FusedHealthMetrics,
+            subnode_id: SubnodeId,
+            device_count: u8,
+            commitment: sp_core::H256,
+            block_u64: u64,
+            weights: &FusionWeights,
+        ) {
+            health.record_device_observation(device_count, block_u64, commitment, weights);
+
+            Self::deposit_event(Event::DeviceObservationRecorded {
+                subnode_id,
+                device_count,
+                commitment,
+            });
+        }
+
+        /// Confirm a reported position, or flag it as an anomaly when the implied
+        /// velocity since the last confirmation exceeds `MaxSubnodeVelocity`.
+        fn apply_position_report(
+            health: &mut FusedHealthMetrics,
+            subnode_id: SubnodeId,
+            position: FusionPosition,
+            block_u64: u64,
+            weights: &FusionWeights,
+        ) {
+            let velocity = health
+                .position_metrics
+                .implied_velocity(&position, block_u64)
+                .unwrap_or(0);
+
+            if velocity > T::MaxSubnodeVelocity::get() {
+                health.record_position_anomaly(block_u64, weights);
+                Self::deposit_event(Event::PositionAnomalyDetected {
+                    subnode_id,
+                    position,
+                    velocity,
+                });
+            } else {
+                health.record_position_confirmation(
+                    position.clone(),
+                    block_u64,
+                    T::PositionVarianceDecay::get(),
+                    weights,
+                );
+                Self::deposit_event(Event::PositionConfirmed {
+                    subnode_id,
+                    position,
+                    variance: health.position_metrics.position_variance,
+                });
+            }
+        }
+
+        /// Fold a position report into the subnode's fused health, creating it
+        /// around `position` on first report.
+        pub(crate) fn confirm_position(
+            subnode_id: SubnodeId,
+            position: FusionPosition,
+            block_u64: u64,
+        ) {
+            let weights = GlobalFusionWeights::<T>::get();
+
+            FusedHealth::<T>::mutate(subnode_id, |maybe_health| {
+                let health =
+                    maybe_health.get_or_insert_with(|| FusedHealthMetrics::new(position.clone()));
+
+                Self::apply_position_report(health, subnode_id, position, block_u64, &weights);
+                Self::deposit_fused_health(subnode_id, health);
+            });
+        }
+
+        fn clear_device_bindings(subnode_id: SubnodeId) {
+            for (device_id, ()) in SubnodeDevices::<T>::drain_prefix(subnode_id) {
+                DeviceBindings::<T>::remove(device_id);
+            }
+            SubnodeDeviceCount::<T>::remove(subnode_id);
+        }
+
+        fn clear_position_device(subnode_id: SubnodeId) {
+            if let Some(mac_hash) = SubnodePositionDevice::<T>::take(subnode_id) {
+                PositionDevices::<T>::remove(mac_hash);
+            }
+        }
+
+        fn deposit_fused_health(subnode_id: SubnodeId, health: &FusedHealthMetrics) {
+            Self::deposit_event(Event::FusedHealthUpdated {
+                subnode_id,
+                heartbeat_component: health.heartbeat_score,
+                device_component: health.device_metrics.device_score(),
+                position_component: health.position_metrics.position_score(),
+                fused_score: health.fused_score,
+            });
+        }
+
+        /// Decay missed heartbeats, recover health up to the ceiling, then regain
+        /// ceiling once the subnode has no outstanding misses.
+        fn apply_heartbeat_recovery(s: &mut Subnode<T>) {
+            s.consecutive_misses = s
+                .consecutive_misses
+                .saturating_sub(T::MissRecoveryDecay::get());
+            s.health_score = s
+                .health_score
+                .saturating_add(T::HealthScoreRecovery::get())
+                .min(s.health_ceiling);
+            if s.consecutive_misses == 0 {
+                s.health_ceiling = s
+                    .health_ceiling
+                    .saturating_add(T::HealthCeilingRecovery::get())
+                    .min(100);
+            }
+        }
+
+        fn enter_probation(s: &mut Subnode<T>) {
+            s.health_ceiling = T::ProbationHealthCeiling::get().min(100);
+            s.health_score = s.health_ceiling.min(T::AutoHealResetScore::get());
+        }
+
         fn reset_failed_subnode(subnode_id: SubnodeId, block_number: BlockNumberFor<T>) {
             if let Some(mut s) = Subnodes::<T>::get(subnode_id) {
                 if s.status == SubnodeStatus::Failed {
                     s.status = SubnodeStatus::Inactive;
                     s.consecutive_misses = 0;
-                    s.health_score = 50;
+                    Self::enter_probation(&mut s);
                     s.last_heartbeat = block_number;
                     Subnodes::<T>::insert(subnode_id, s);
                 }
@@ -1262,7 +2421,7 @@ pub mod pallet {
         fn refresh_fused_heartbeat(subnode_id: SubnodeId, block_u64: u64) {
             if let Some(mut health) = FusedHealth::<T>::get(subnode_id) {
                 let weights = GlobalFusionWeights::<T>::get();
-                health.update_heartbeat(50, block_u64, &weights);
+                health.update_heartbeat(T::AutoHealResetScore::get().min(100), block_u64, &weights);
                 FusedHealth::<T>::insert(subnode_id, health);
             }
         }
@@ -1280,12 +2439,18 @@ pub mod pallet {
                     break;
                 }
                 cluster_count = cluster_count.saturating_add(1);
+                Self::escalate_worsening_subnodes(cluster_id);
                 if cluster.status != ClusterStatus::Degraded {
                     continue;
                 }
 
                 let min_subnodes = T::MinSubnodes::get();
-                if cluster.active_subnodes >= min_subnodes {
+                let staffed = cluster.active_subnodes >= min_subnodes;
+                // An SLA breach keeps the cluster degraded until throughput recovers
+                if staffed && SlaBreaches::<T>::contains_key(cluster_id) {
+                    continue;
+                }
+                if staffed {
                     Self::set_cluster_status(cluster_id, ClusterStatus::Running);
                     continue;
                 }
@@ -1308,16 +2473,110 @@ pub mod pallet {
             }
         }
 
+        /// Flag clusters whose throughput stayed below `ThroughputSla` for
+        /// `SlaBreachBlocks`, and clear the flag once throughput recovers. Resumes
+        /// from `SlaSweepCursor`, checking at most 10 clusters per invocation.
+        /// Returns the weight used.
+        fn check_throughput_sla(block_number: BlockNumberFor<T>) -> Weight {
+            let sla = T::ThroughputSla::get();
+            if sla == Perbill::zero() {
+                return Weight::zero();
+            }
+
+            const MAX_CLUSTERS_PER_BLOCK: usize = 10;
+            let mut iter = match SlaSweepCursor::<T>::get() {
+                Some(key) => Clusters::<T>::iter_from(key.into_inner()),
+                None => Clusters::<T>::iter(),
+            };
+            let scanned: Vec<(ClusterId, Cluster<T>)> =
+                iter.by_ref().take(MAX_CLUSTERS_PER_BLOCK).collect();
+            if scanned.len() < MAX_CLUSTERS_PER_BLOCK {
+                SlaSweepCursor::<T>::kill();
+            } else {
+                SlaSweepCursor::<T>::put(BoundedVec::truncate_from(iter.last_raw_key().to_vec()));
+            }
+
+            let mut reads = (scanned.len() as u64).saturating_add(1);
+            let mut writes: u64 = 1;
+            for (cluster_id, cluster) in scanned {
+                if matches!(
+                    cluster.status,
+                    ClusterStatus::Initializing | ClusterStatus::Shutdown | ClusterStatus::Draining
+                ) {
+                    continue;
+                }
+
+                if cluster.total_throughput >= sla {
+                    Self::clear_sla_breach(&cluster);
+                    reads = reads.saturating_add(2);
+                    writes = writes.saturating_add(3);
+                    continue;
+                }
+
+                reads = reads.saturating_add(1);
+                let Some(since) = SlaBelowSince::<T>::get(cluster_id) else {
+                    SlaBelowSince::<T>::insert(cluster_id, block_number);
+                    writes = writes.saturating_add(1);
+                    continue;
+                };
+                reads = reads.saturating_add(1);
+                if SlaBreaches::<T>::contains_key(cluster_id)
+                    || block_number.saturating_sub(since) < T::SlaBreachBlocks::get()
+                {
+                    continue;
+                }
+
+                SlaBreaches::<T>::insert(cluster_id, block_number);
+                Self::transition_cluster_status(&cluster, ClusterStatus::Degraded);
+                reads = reads.saturating_add(1);
+                writes = writes.saturating_add(2);
+                Self::deposit_event(Event::ClusterSlaBreached {
+                    cluster_id,
+                    observed: cluster.total_throughput,
+                });
+            }
+
+            T::DbWeight::get().reads_writes(reads, writes)
+        }
+
+        fn clear_sla_breach(cluster: &Cluster<T>) {
+            SlaBelowSince::<T>::remove(cluster.id);
+            if SlaBreaches::<T>::take(cluster.id).is_none() {
+                return;
+            }
+            if cluster.active_subnodes >= T::MinSubnodes::get() {
+                Self::transition_cluster_status(cluster, ClusterStatus::Running);
+            }
+            Self::deposit_event(Event::ClusterSlaRecovered {
+                cluster_id: cluster.id,
+                observed: cluster.total_throughput,
+            });
+        }
+
+        fn transition_cluster_status(cluster: &Cluster<T>, new_status: ClusterStatus) {
+            if cluster.status == new_status {
+                return;
+            }
+            Self::set_cluster_status(cluster.id, new_status);
+            Self::deposit_event(Event::ClusterStatusChanged {
+                cluster_id: cluster.id,
+                old_status: cluster.status,
+                new_status,
+            });
+        }
+
         fn check_fusion_healing_triggers(current_block: u64) {
             const MAX_PER_BLOCK: u32 = 50;
             let mut processed: u32 = 0;
+            let config = HealingConfig::<T>::get();
 
             for (subnode_id, health) in FusedHealth::<T>::iter() {
                 if processed >= MAX_PER_BLOCK {
                     break;
                 }
                 processed = processed.saturating_add(1);
-                let Some(trigger) = fusion::should_trigger_healing(&health, current_block) else {
+                let Some(trigger) = fusion::should_trigger_healing(&health, current_block, &config)
+                else {
                     continue;
                 };
                 Self::deposit_event(Event::FusionHealingTriggered {
@@ -1325,7 +2584,7 @@ pub mod pallet {
                     trigger,
                     previous_score: health.fused_score,
                 });
-                if health.is_critical() {
+                if health.fused_score < config.critical_fused_score {
                     Self::mark_subnode_failed(subnode_id);
                 }
             }
@@ -1335,6 +2594,52 @@ pub mod pallet {
             ActiveSubnodeCount::<T>::get()
         }
 
+        /// Summarize fleet health for monitoring.
+        ///
+        /// Read-only, but O(clusters + subnodes + fused health records); intended for
+        /// off-chain queries, not for use inside extrinsics or hooks.
+        pub fn fleet_summary() -> FleetSummary {
+            let mut summary = FleetSummary {
+                total_active_subnodes: ActiveSubnodeCount::<T>::get(),
+                ..Default::default()
+            };
+
+            for (_, cluster) in Clusters::<T>::iter() {
+                summary.total_clusters = summary.total_clusters.saturating_add(1);
+                match cluster.status {
+                    ClusterStatus::Running => {
+                        summary.running_clusters = summary.running_clusters.saturating_add(1)
+                    }
+                    ClusterStatus::Degraded => {
+                        summary.degraded_clusters = summary.degraded_clusters.saturating_add(1)
+                    }
+                    ClusterStatus::Shutdown => {
+                        summary.shutdown_clusters = summary.shutdown_clusters.saturating_add(1)
+                    }
+                    ClusterStatus::Initializing
+                    | ClusterStatus::Scaling
+                    | ClusterStatus::Draining => {}
+                }
+            }
+
+            summary.failed_subnodes = Subnodes::<T>::iter_values()
+                .filter(|s| s.status == SubnodeStatus::Failed)
+                .count() as u32;
+
+            let (score_sum, records) =
+                FusedHealth::<T>::iter_values().fold((0u64, 0u64), |(sum, n), h| {
+                    (
+                        sum.saturating_add(h.fused_score as u64),
+                        n.saturating_add(1),
+                    )
+                });
+            if records > 0 {
+                summary.average_fused_score = (score_sum / records) as u8;
+            }
+
+            summary
+        }
+
         /// Run diagnostics on a subnode and generate a report.
         pub fn run_diagnostics(
             subnode_id: SubnodeId,
@@ -1352,7 +2657,7 @@ pub mod pallet {
                     .unwrap_or(false),
                 position_consistency_ok: health
                     .as_ref()
-                    .map(|h| h.position_metrics.position_variance < 5000)
+                    .map(Self::position_consistent)
                     .unwrap_or(true),
                 cluster_connectivity_ok: subnode.status == SubnodeStatus::Active,
                 fused_health_score: health.as_ref().map(|h| h.fused_score).unwrap_or(0),
@@ -1385,6 +2690,13 @@ pub mod pallet {
                 let _ = actions.try_push(DiagnosticAction::EscalateOperator);
             }
 
+            DiagnosticHistory::<T>::mutate(subnode_id, |history| {
+                if history.is_full() {
+                    history.remove(0);
+                }
+                let _ = history.try_push((severity, block_number));
+            });
+
             Self::deposit_event(Event::DiagnosticReportGenerated {
                 subnode_id,
                 severity,
@@ -1400,6 +2712,52 @@ pub mod pallet {
             })
         }
 
+        /// Compare the subnode's last `SeverityTrendWindow` diagnostic reports.
+        /// Fewer reports than the window is `Stable`.
+        pub fn severity_trend(subnode_id: SubnodeId) -> Trend {
+            let history = DiagnosticHistory::<T>::get(subnode_id);
+            let window = T::SeverityTrendWindow::get() as usize;
+            if window < 2 || history.len() < window {
+                return Trend::Stable;
+            }
+
+            let (first, last) = (history[0].0, history[history.len() - 1].0);
+            if last > first && history.windows(2).all(|w| w[0].0 <= w[1].0) {
+                Trend::Worsening
+            } else if last < first && history.windows(2).all(|w| w[0].0 >= w[1].0) {
+                Trend::Improving
+            } else {
+                Trend::Stable
+            }
+        }
+
+        /// Escalate subnodes of the cluster whose severity is worsening but not
+        /// yet `Critical`, at most once per diagnostic report.
+        fn escalate_worsening_subnodes(cluster_id: ClusterId) {
+            for (subnode_id, _) in ClusterSubnodes::<T>::iter_prefix(cluster_id) {
+                let Some(&(severity, reported_at)) = DiagnosticHistory::<T>::get(subnode_id).last()
+                else {
+                    continue;
+                };
+                if severity >= DiagnosticSeverity::Critical
+                    || TrendEscalatedAt::<T>::get(subnode_id) == Some(reported_at)
+                    || Self::severity_trend(subnode_id) != Trend::Worsening
+                {
+                    continue;
+                }
+
+                TrendEscalatedAt::<T>::insert(subnode_id, reported_at);
+                Self::deposit_event(Event::OperatorEscalationRequired {
+                    subnode_id,
+                    reason: severity,
+                });
+            }
+        }
+
+        fn position_consistent(health: &FusedHealthMetrics) -> bool {
+            health.position_metrics.position_variance < T::PositionVarianceThreshold::get()
+        }
+
         /// Calculate diagnostic severity based on checks and subnode state.
         fn calculate_severity(
             checks: &DiagnosticChecks,
@@ -1468,7 +2826,7 @@ pub mod pallet {
             if let Some(mut s) = Subnodes::<T>::get(subnode_id) {
                 if s.status == SubnodeStatus::Failed {
                     s.status = SubnodeStatus::Inactive;
-                    s.health_score = 50;
+                    Self::enter_probation(&mut s);
                     Subnodes::<T>::insert(subnode_id, s);
                 }
             }
@@ -1476,6 +2834,9 @@ pub mod pallet {
 
         fn reset_position_variance(subnode_id: SubnodeId) {
             if let Some(mut h) = FusedHealth::<T>::get(subnode_id) {
+                if Self::position_consistent(&h) {
+                    return;
+                }
                 h.position_metrics.position_variance = 0;
                 FusedHealth::<T>::insert(subnode_id, h);
             }
@@ -1541,6 +2902,12 @@ pub mod pallet {
                     // Remove subnode from all storage
                     Subnodes::<T>::remove(subnode_id);
                     FusedHealth::<T>::remove(subnode_id);
+                    SubnodeThroughputHistory::<T>::remove(subnode_id);
+                    DiagnosticHistory::<T>::remove(subnode_id);
+                    TrendEscalatedAt::<T>::remove(subnode_id);
+                    Self::clear_position_device(subnode_id);
+                    PendingPositionDevices::<T>::remove(subnode_id);
+                    Self::clear_device_bindings(subnode_id);
                     ClusterSubnodes::<T>::remove(subnode.cluster, subnode_id);
                     OperatorSubnodes::<T>::remove(subnode.operator, subnode_id);
 
@@ -1554,3 +2921,18 @@ pub mod pallet {
         }
     }
 }
+
+impl<T: Config> OnPositionConfirmed for Pallet<T> {
+    fn on_position_confirmed(device: sp_core::H256, x: i64, y: i64, z: i64, _confidence: u8) {
+        let Some(subnode_id) = PositionDevices::<T>::get(device) else {
+            return;
+        };
+        if !Subnodes::<T>::contains_key(subnode_id) {
+            return;
+        }
+        let Ok(block_u64) = frame_system::Pallet::<T>::block_number().try_into() else {
+            return;
+        };
+        Self::confirm_position(subnode_id, FusionPosition::new(x, y, z), block_u64);
+    }
+}
@@ -1,21 +1,28 @@
-#![allow(clippy::disallowed_macros)]
+#![allow(clippy::disallowed_macros, clippy::missing_const_for_thread_local)]
 
 use crate::{
-    self as pallet_octopus, ClusterId, ClusterStatus, Error, Event, ScalingDecision, SubnodeId,
-    SubnodeStatus,
+    self as pallet_octopus, ClusterId, ClusterStatus, DiagnosticAction, DiagnosticSeverity, Error,
+    Event, FusionHealingConfig, FusionPreset, HealingTrigger, HealthComponent, ScalingDecision,
+    SubnodeId, SubnodeStatus, Trend,
 };
 use frame_support::{
     assert_noop, assert_ok, derive_impl, parameter_types,
     traits::{ConstU32, Hooks},
 };
 use frame_system as system;
-use seveny_primitives::types::ActorId;
+use seveny_primitives::fusion::{DeviceCommitment, DeviceReveal};
+use seveny_primitives::traits::{
+    CapabilityCheck, DeviceRegistry, OnPositionConfirmed, PresenceLoad,
+};
+use seveny_primitives::types::{ActorId, NamedCapability};
 use sp_arithmetic::Perbill;
 use sp_core::H256;
 use sp_runtime::{
     traits::{BlakeTwo256, IdentityLookup},
     BuildStorage,
 };
+use std::cell::RefCell;
+use std::collections::BTreeMap;
 
 type Block = frame_system::mocking::MockBlock<Test>;
 
@@ -63,6 +70,27 @@ parameter_types! {
     pub const MaxConsecutiveMisses: u8 = 3;
     pub const HealthScoreDecay: u8 = 10;
     pub const HealthScoreRecovery: u8 = 5;
+    pub const MissRecoveryDecay: u8 = 1;
+    pub const AtRiskMissGrace: u8 = 1;
+    pub const ProbationHealthCeiling: u8 = 60;
+    pub const HealthCeilingRecovery: u8 = 2;
+    pub const MaxSubnodeVelocity: u64 = 100;
+    pub static GateClusterCreation: bool = false;
+    pub const PositionVarianceThreshold: u32 = 2000;
+    pub static ThroughputSla: Perbill = Perbill::zero();
+    pub const SlaBreachBlocks: u64 = 5;
+    pub static MinSamplesForScaling: u32 = 1;
+    pub const MaxDevicesPerSubnode: u32 = 10;
+    pub const MaxSubnodeThroughputSamples: u32 = 10;
+    pub static AutoHealResetScore: u8 = 50;
+    pub const SeverityTrendWindow: u32 = 3;
+    pub const MaxSubnodesHardCeiling: u32 = 12;
+    pub const PositionVarianceDecay: u8 = 0;
+    pub static MaxSubnodesPerOperator: u32 = 100;
+    pub static PresenceAwareScaling: bool = false;
+    pub const PresencesPerSubnode: u32 = 10;
+    pub static CurrentPresenceCount: u32 = 0;
+    pub static ActivationHeartbeatWindow: u64 = 0;
 }
 
 impl pallet_octopus::Config for Test {
@@ -77,6 +105,79 @@ impl pallet_octopus::Config for Test {
     type MaxConsecutiveMisses = MaxConsecutiveMisses;
     type HealthScoreDecay = HealthScoreDecay;
     type HealthScoreRecovery = HealthScoreRecovery;
+    type MissRecoveryDecay = MissRecoveryDecay;
+    type AtRiskMissGrace = AtRiskMissGrace;
+    type ProbationHealthCeiling = ProbationHealthCeiling;
+    type HealthCeilingRecovery = HealthCeilingRecovery;
+    type MaxSubnodeVelocity = MaxSubnodeVelocity;
+    type GateClusterCreation = GateClusterCreation;
+    type CapabilityCheck = MockCapabilityCheck;
+    type PositionVarianceThreshold = PositionVarianceThreshold;
+    type ThroughputSla = ThroughputSla;
+    type SlaBreachBlocks = SlaBreachBlocks;
+    type MinSamplesForScaling = MinSamplesForScaling;
+    type DeviceRegistry = MockDeviceRegistry;
+    type MaxDevicesPerSubnode = MaxDevicesPerSubnode;
+    type MaxSubnodeThroughputSamples = MaxSubnodeThroughputSamples;
+    type AutoHealResetScore = AutoHealResetScore;
+    type SeverityTrendWindow = SeverityTrendWindow;
+    type MaxSubnodesHardCeiling = MaxSubnodesHardCeiling;
+    type PositionVarianceDecay = PositionVarianceDecay;
+    type MaxSubnodesPerOperator = MaxSubnodesPerOperator;
+    type PresenceLoad = MockPresenceLoad;
+    type PresenceAwareScaling = PresenceAwareScaling;
+    type PresencesPerSubnode = PresencesPerSubnode;
+    type ActivationHeartbeatWindow = ActivationHeartbeatWindow;
+}
+
+/// Grants `CreateCluster` to account 1 only.
+pub struct MockCapabilityCheck;
+impl CapabilityCheck for MockCapabilityCheck {
+    fn has_capability(actor: ActorId, capability: NamedCapability) -> bool {
+        capability == NamedCapability::CreateCluster && actor == account_to_actor(1)
+    }
+}
+
+thread_local! {
+    static DEVICES: RefCell<BTreeMap<u64, (ActorId, bool)>> = RefCell::new(BTreeMap::new());
+}
+
+/// Registers a device owned by `owner`; `active` mirrors `DeviceStatus::Active`.
+fn set_device(device_id: u64, owner: ActorId, active: bool) {
+    DEVICES.with(|d| d.borrow_mut().insert(device_id, (owner, active)));
+}
+
+pub struct MockDeviceRegistry;
+impl DeviceRegistry for MockDeviceRegistry {
+    type DeviceId = u64;
+
+    fn device_owner(device_id: u64) -> Option<ActorId> {
+        DEVICES.with(|d| d.borrow().get(&device_id).map(|(owner, _)| *owner))
+    }
+
+    fn is_device_active(device_id: u64) -> bool {
+        DEVICES.with(|d| {
+            d.borrow()
+                .get(&device_id)
+                .is_some_and(|(_, active)| *active)
+        })
+    }
+
+    fn has_active_device(actor: ActorId) -> bool {
+        DEVICES.with(|d| {
+            d.borrow()
+                .values()
+                .any(|(owner, active)| *owner == actor && *active)
+        })
+    }
+}
+
+/// Reports `CurrentPresenceCount` as the current epoch's presences.
+pub struct MockPresenceLoad;
+impl PresenceLoad for MockPresenceLoad {
+    fn current_presence_count() -> u32 {
+        CurrentPresenceCount::get()
+    }
 }
 
 fn new_test_ext() -> sp_io::TestExternalities {
@@ -90,6 +191,8 @@ fn new_test_ext() -> sp_io::TestExternalities {
     .assimilate_storage(&mut t)
     .expect("genesis build failed");
 
+    DEVICES.with(|d| d.borrow_mut().clear());
+
     let mut ext = sp_io::TestExternalities::new(t);
     ext.execute_with(|| System::set_block_number(1));
     ext
@@ -132,7 +235,8 @@ fn register_subnode_success() {
         assert_ok!(Octopus::register_subnode(
             RuntimeOrigin::signed(2),
             cluster_id,
-            operator
+            operator,
+            None
         ));
 
         let subnode_id = SubnodeId::new(0);
@@ -154,7 +258,8 @@ fn activate_subnode_success() {
         assert_ok!(Octopus::register_subnode(
             RuntimeOrigin::signed(2),
             ClusterId::new(0),
-            operator
+            operator,
+            None
         ));
 
         let subnode_id = SubnodeId::new(0);
@@ -185,7 +290,8 @@ fn cannot_activate_already_active() {
         assert_ok!(Octopus::register_subnode(
             RuntimeOrigin::signed(2),
             ClusterId::new(0),
-            operator
+            operator,
+            None
         ));
 
         let subnode_id = SubnodeId::new(0);
@@ -212,12 +318,14 @@ fn start_deactivation_success() {
         assert_ok!(Octopus::register_subnode(
             RuntimeOrigin::signed(2),
             ClusterId::new(0),
-            operator1
+            operator1,
+            None
         ));
         assert_ok!(Octopus::register_subnode(
             RuntimeOrigin::signed(3),
             ClusterId::new(0),
-            operator2
+            operator2,
+            None
         ));
 
         assert_ok!(Octopus::activate_subnode(
@@ -250,7 +358,8 @@ fn cannot_deactivate_below_minimum() {
         assert_ok!(Octopus::register_subnode(
             RuntimeOrigin::signed(2),
             ClusterId::new(0),
-            operator
+            operator,
+            None
         ));
         assert_ok!(Octopus::activate_subnode(
             RuntimeOrigin::signed(2),
@@ -275,12 +384,14 @@ fn deactivation_completes_after_duration() {
         assert_ok!(Octopus::register_subnode(
             RuntimeOrigin::signed(2),
             ClusterId::new(0),
-            operator1
+            operator1,
+            None
         ));
         assert_ok!(Octopus::register_subnode(
             RuntimeOrigin::signed(3),
             ClusterId::new(0),
-            operator2
+            operator2,
+            None
         ));
 
         assert_ok!(Octopus::activate_subnode(
@@ -344,7 +455,8 @@ fn scaling_decision_scale_up() {
         assert_ok!(Octopus::register_subnode(
             RuntimeOrigin::signed(2),
             ClusterId::new(0),
-            operator
+            operator,
+            None
         ));
         assert_ok!(Octopus::activate_subnode(
             RuntimeOrigin::signed(2),
@@ -380,12 +492,14 @@ fn scaling_decision_scale_down() {
         assert_ok!(Octopus::register_subnode(
             RuntimeOrigin::signed(2),
             ClusterId::new(0),
-            operator1
+            operator1,
+            None
         ));
         assert_ok!(Octopus::register_subnode(
             RuntimeOrigin::signed(3),
             ClusterId::new(0),
-            operator2
+            operator2,
+            None
         ));
 
         assert_ok!(Octopus::activate_subnode(
@@ -418,7 +532,8 @@ fn scaling_cooldown_enforced() {
         assert_ok!(Octopus::register_subnode(
             RuntimeOrigin::signed(2),
             ClusterId::new(0),
-            operator
+            operator,
+            None
         ));
         assert_ok!(Octopus::activate_subnode(
             RuntimeOrigin::signed(2),
@@ -456,7 +571,8 @@ fn max_subnodes_enforced() {
             assert_ok!(Octopus::register_subnode(
                 RuntimeOrigin::signed(1),
                 ClusterId::new(0),
-                account_to_actor(i + 10)
+                account_to_actor(i + 10),
+                None
             ));
         }
 
@@ -464,7 +580,8 @@ fn max_subnodes_enforced() {
             Octopus::register_subnode(
                 RuntimeOrigin::signed(1),
                 ClusterId::new(0),
-                account_to_actor(100)
+                account_to_actor(100),
+                None
             ),
             Error::<Test>::MaxSubnodesReached
         );
@@ -482,7 +599,8 @@ fn get_cluster_subnodes_helper() {
             assert_ok!(Octopus::register_subnode(
                 RuntimeOrigin::signed(1),
                 ClusterId::new(0),
-                account_to_actor(i + 10)
+                account_to_actor(i + 10),
+                None
             ));
         }
 
@@ -502,7 +620,8 @@ fn get_active_subnodes_helper() {
             assert_ok!(Octopus::register_subnode(
                 RuntimeOrigin::signed(i + 10),
                 ClusterId::new(0),
-                account_to_actor(i + 10)
+                account_to_actor(i + 10),
+                None
             ));
         }
 
@@ -553,7 +672,8 @@ fn update_subnode_throughput_success() {
         assert_ok!(Octopus::register_subnode(
             RuntimeOrigin::signed(2),
             ClusterId::new(0),
-            operator
+            operator,
+            None
         ));
 
         assert_ok!(Octopus::update_subnode_throughput(
@@ -568,3 +688,2269 @@ fn update_subnode_throughput_success() {
         assert_eq!(subnode.processed_count, 1000);
     });
 }
+
+fn report_throughput_series(subnode_id: SubnodeId, percents: &[u32]) {
+    for (i, percent) in percents.iter().enumerate() {
+        System::set_block_number(i as u64 + 1);
+        assert_ok!(Octopus::update_subnode_throughput(
+            RuntimeOrigin::signed(2),
+            subnode_id,
+            Perbill::from_percent(*percent),
+            1
+        ));
+    }
+}
+
+#[test]
+fn subnode_throughput_percentiles_over_history() {
+    new_test_ext().execute_with(|| {
+        let subnode_id = setup_active_subnode();
+        assert_eq!(Octopus::subnode_throughput_percentile(subnode_id, 50), None);
+
+        report_throughput_series(subnode_id, &[70, 10, 90, 30, 50, 100, 20, 80, 40, 60]);
+
+        let history = Octopus::subnode_throughput_history(subnode_id);
+        assert_eq!(history.len(), 10);
+        assert_eq!(history[0], (Perbill::from_percent(70), 1));
+
+        let pct = |p| Octopus::subnode_throughput_percentile(subnode_id, p);
+        assert_eq!(pct(50), Some(Perbill::from_percent(50)));
+        assert_eq!(pct(90), Some(Perbill::from_percent(90)));
+        assert_eq!(pct(0), Some(Perbill::from_percent(10)));
+        assert_eq!(pct(100), Some(Perbill::from_percent(100)));
+        assert_eq!(pct(101), None);
+    });
+}
+
+#[test]
+fn subnode_throughput_history_drops_oldest_sample() {
+    new_test_ext().execute_with(|| {
+        let subnode_id = setup_active_subnode();
+
+        // Twelve samples into a ten-slot buffer: 5% and 95% are evicted.
+        report_throughput_series(subnode_id, &[5, 95, 20, 20, 20, 20, 20, 60, 60, 60, 60, 60]);
+
+        let history = Octopus::subnode_throughput_history(subnode_id);
+        assert_eq!(history.len(), 10);
+        assert_eq!(history[0], (Perbill::from_percent(20), 3));
+        assert_eq!(
+            Octopus::subnode_throughput_percentile(subnode_id, 50),
+            Some(Perbill::from_percent(20))
+        );
+        assert_eq!(
+            Octopus::subnode_throughput_percentile(subnode_id, 90),
+            Some(Perbill::from_percent(60))
+        );
+    });
+}
+
+// ============================================================================
+// Fleet Summary Tests
+// ============================================================================
+
+#[test]
+fn fleet_summary_empty() {
+    new_test_ext().execute_with(|| {
+        assert_eq!(Octopus::fleet_summary(), crate::FleetSummary::default());
+    });
+}
+
+#[test]
+fn fleet_summary_mixed_states() {
+    new_test_ext().execute_with(|| {
+        let owner = account_to_actor(1);
+        let operator = account_to_actor(2);
+
+        for _ in 0..4 {
+            assert_ok!(Octopus::create_cluster(RuntimeOrigin::signed(1), owner));
+        }
+        for cluster in [0, 0, 1, 1] {
+            assert_ok!(Octopus::register_subnode(
+                RuntimeOrigin::signed(2),
+                ClusterId::new(cluster),
+                operator,
+                None
+            ));
+        }
+        for subnode in 0..4 {
+            assert_ok!(Octopus::activate_subnode(
+                RuntimeOrigin::signed(2),
+                SubnodeId::new(subnode)
+            ));
+        }
+
+        // Cluster 1 loses both subnodes and degrades; cluster 2 is shut down;
+        // cluster 3 stays Initializing.
+        crate::Subnodes::<Test>::mutate(SubnodeId::new(2), |s| {
+            s.as_mut().expect("subnode should exist").status = SubnodeStatus::Failed;
+        });
+        crate::Subnodes::<Test>::mutate(SubnodeId::new(3), |s| {
+            s.as_mut().expect("subnode should exist").status = SubnodeStatus::Failed;
+        });
+        crate::ActiveSubnodeCount::<Test>::put(2);
+        crate::Clusters::<Test>::mutate(ClusterId::new(1), |c| {
+            c.as_mut().expect("cluster should exist").status = ClusterStatus::Degraded;
+        });
+        crate::Clusters::<Test>::mutate(ClusterId::new(2), |c| {
+            c.as_mut().expect("cluster should exist").status = ClusterStatus::Shutdown;
+        });
+
+        for (subnode, score) in [(0, 90u8), (1, 70), (2, 20)] {
+            let mut health = crate::FusedHealthMetrics::new(crate::FusionPosition::default());
+            health.fused_score = score;
+            crate::FusedHealth::<Test>::insert(SubnodeId::new(subnode), health);
+        }
+
+        let summary = Octopus::fleet_summary();
+        assert_eq!(summary.total_clusters, 4);
+        assert_eq!(summary.running_clusters, 1);
+        assert_eq!(summary.degraded_clusters, 1);
+        assert_eq!(summary.shutdown_clusters, 1);
+        assert_eq!(summary.total_active_subnodes, 2);
+        assert_eq!(summary.average_fused_score, 60);
+        assert_eq!(summary.failed_subnodes, 2);
+    });
+}
+
+// ============================================================================
+// Scaling Target Tests
+// ============================================================================
+
+#[test]
+fn scaling_target_bounded_and_monotonic() {
+    new_test_ext().execute_with(|| {
+        let mut previous = 0;
+        for pct in 0..=100 {
+            let target = Octopus::calculate_target_subnodes(Perbill::from_percent(pct), 0, 8);
+            assert!((1..=8).contains(&target));
+            assert!(target >= previous);
+            previous = target;
+        }
+        assert_eq!(Octopus::calculate_target_subnodes(Perbill::zero(), 0, 8), 1);
+        assert_eq!(Octopus::calculate_target_subnodes(Perbill::one(), 0, 8), 8);
+    });
+}
+
+#[test]
+fn scaling_target_bands_are_even() {
+    new_test_ext().execute_with(|| {
+        // Min 1, max 8: eight bands of 12.5% each.
+        let target = |permille| {
+            Octopus::calculate_target_subnodes(Perbill::from_perthousand(permille), 0, 8)
+        };
+        assert_eq!(target(124), 1);
+        assert_eq!(target(125), 2);
+        assert_eq!(target(499), 4);
+        assert_eq!(target(500), 5);
+        assert_eq!(target(875), 8);
+    });
+}
+
+#[test]
+fn scaling_target_hysteresis_holds_near_boundary() {
+    new_test_ext().execute_with(|| {
+        // 50% is the boundary between targets 4 and 5.
+        let just_above = Perbill::from_perthousand(510);
+        let just_below = Perbill::from_perthousand(490);
+
+        assert_eq!(Octopus::calculate_target_subnodes(just_above, 4, 8), 4);
+        assert_eq!(Octopus::calculate_target_subnodes(just_below, 5, 8), 5);
+
+        let clear_above = Perbill::from_perthousand(521);
+        let clear_below = Perbill::from_perthousand(479);
+
+        assert_eq!(Octopus::calculate_target_subnodes(clear_above, 4, 8), 5);
+        assert_eq!(Octopus::calculate_target_subnodes(clear_below, 5, 8), 4);
+    });
+}
+
+#[test]
+fn scaling_target_hysteresis_allows_large_moves() {
+    new_test_ext().execute_with(|| {
+        assert_eq!(
+            Octopus::calculate_target_subnodes(Perbill::from_percent(95), 2, 8),
+            8
+        );
+        assert_eq!(
+            Octopus::calculate_target_subnodes(Perbill::from_percent(5), 7, 8),
+            1
+        );
+    });
+}
+
+#[test]
+fn scaling_target_follows_cluster_capacity() {
+    new_test_ext().execute_with(|| {
+        let ceiling = MaxSubnodesHardCeiling::get();
+        assert_eq!(
+            Octopus::calculate_target_subnodes(Perbill::one(), 0, ceiling),
+            ceiling
+        );
+        assert_eq!(Octopus::calculate_target_subnodes(Perbill::one(), 0, 3), 3);
+    });
+}
+
+// ============================================================================
+// Heartbeat Sequence Tests
+// ============================================================================
+
+fn setup_active_subnode() -> SubnodeId {
+    let owner = account_to_actor(1);
+    let operator = account_to_actor(2);
+
+    assert_ok!(Octopus::create_cluster(RuntimeOrigin::signed(1), owner));
+    assert_ok!(Octopus::register_subnode(
+        RuntimeOrigin::signed(2),
+        ClusterId::new(0),
+        operator,
+        None
+    ));
+
+    let subnode_id = SubnodeId::new(0);
+    assert_ok!(Octopus::activate_subnode(
+        RuntimeOrigin::signed(2),
+        subnode_id
+    ));
+    subnode_id
+}
+
+#[test]
+fn heartbeat_sequence_accepts_monotonic_values() {
+    new_test_ext().execute_with(|| {
+        let subnode_id = setup_active_subnode();
+
+        assert_ok!(Octopus::record_heartbeat(
+            RuntimeOrigin::signed(2),
+            subnode_id,
+            1
+        ));
+        assert_ok!(Octopus::record_heartbeat(
+            RuntimeOrigin::signed(2),
+            subnode_id,
+            5
+        ));
+        assert_ok!(Octopus::heartbeat_with_device_proof(
+            RuntimeOrigin::signed(2),
+            subnode_id,
+            6,
+            3,
+            H256([1u8; 32])
+        ));
+
+        let subnode = Octopus::subnodes(subnode_id).expect("subnode should exist");
+        assert_eq!(subnode.heartbeat_sequence, 6);
+    });
+}
+
+#[test]
+fn heartbeat_sequence_rejects_replay() {
+    new_test_ext().execute_with(|| {
+        let subnode_id = setup_active_subnode();
+
+        assert_noop!(
+            Octopus::record_heartbeat(RuntimeOrigin::signed(2), subnode_id, 0),
+            Error::<Test>::StaleHeartbeat
+        );
+
+        assert_ok!(Octopus::record_heartbeat(
+            RuntimeOrigin::signed(2),
+            subnode_id,
+            3
+        ));
+        assert_noop!(
+            Octopus::record_heartbeat(RuntimeOrigin::signed(2), subnode_id, 3),
+            Error::<Test>::StaleHeartbeat
+        );
+        assert_noop!(
+            Octopus::heartbeat_with_device_proof(
+                RuntimeOrigin::signed(2),
+                subnode_id,
+                2,
+                3,
+                H256([1u8; 32])
+            ),
+            Error::<Test>::StaleHeartbeat
+        );
+    });
+}
+
+// ============================================================================
+// Miss Grace Tests
+// ============================================================================
+
+fn run_failure_detection(block: u64) {
+    System::set_block_number(block);
+    Octopus::on_initialize(block);
+}
+
+#[test]
+fn at_risk_warning_fires_one_miss_before_failure() {
+    new_test_ext().execute_with(|| {
+        let subnode_id = setup_active_subnode();
+
+        run_failure_detection(11);
+        let subnode = Octopus::subnodes(subnode_id).expect("subnode should exist");
+        assert_eq!(subnode.consecutive_misses, 1);
+        assert!(!System::events()
+            .iter()
+            .any(|r| matches!(r.event, RuntimeEvent::Octopus(Event::SubnodeAtRisk { .. }))));
+
+        run_failure_detection(21);
+        System::assert_has_event(
+            Event::SubnodeAtRisk {
+                subnode_id,
+                consecutive_misses: 2,
+                misses_remaining: 1,
+            }
+            .into(),
+        );
+        let subnode = Octopus::subnodes(subnode_id).expect("subnode should exist");
+        assert_eq!(subnode.status, SubnodeStatus::Active);
+
+        run_failure_detection(31);
+        System::assert_has_event(
+            Event::SubnodeFailed {
+                subnode_id,
+                cluster_id: ClusterId::new(0),
+                consecutive_misses: 3,
+            }
+            .into(),
+        );
+    });
+}
+
+#[test]
+fn intermittent_heartbeats_decay_misses() {
+    new_test_ext().execute_with(|| {
+        let subnode_id = setup_active_subnode();
+
+        run_failure_detection(11);
+        run_failure_detection(21);
+        assert_ok!(Octopus::record_heartbeat(
+            RuntimeOrigin::signed(2),
+            subnode_id,
+            1
+        ));
+        let subnode = Octopus::subnodes(subnode_id).expect("subnode should exist");
+        assert_eq!(subnode.consecutive_misses, 1);
+
+        run_failure_detection(31);
+        let subnode = Octopus::subnodes(subnode_id).expect("subnode should exist");
+        assert_eq!(subnode.consecutive_misses, 2);
+        assert_eq!(subnode.status, SubnodeStatus::Active);
+
+        assert_ok!(Octopus::record_heartbeat(
+            RuntimeOrigin::signed(2),
+            subnode_id,
+            2
+        ));
+        assert_ok!(Octopus::record_heartbeat(
+            RuntimeOrigin::signed(2),
+            subnode_id,
+            3
+        ));
+        let subnode = Octopus::subnodes(subnode_id).expect("subnode should exist");
+        assert_eq!(subnode.consecutive_misses, 0);
+    });
+}
+
+// ============================================================================
+// Cluster Transfer Tests
+// ============================================================================
+
+#[test]
+fn cluster_transfer_two_step_flow() {
+    new_test_ext().execute_with(|| {
+        let owner = account_to_actor(1);
+        let new_owner = account_to_actor(3);
+        let cluster_id = ClusterId::new(0);
+        assert_ok!(Octopus::create_cluster(RuntimeOrigin::signed(1), owner));
+
+        assert_noop!(
+            Octopus::propose_cluster_transfer(RuntimeOrigin::signed(3), cluster_id, new_owner),
+            Error::<Test>::NotClusterOwner
+        );
+        assert_ok!(Octopus::propose_cluster_transfer(
+            RuntimeOrigin::signed(1),
+            cluster_id,
+            new_owner
+        ));
+        System::assert_last_event(
+            Event::ClusterTransferProposed {
+                cluster_id,
+                current_owner: owner,
+                new_owner,
+            }
+            .into(),
+        );
+
+        // Ownership is unchanged until acceptance.
+        let cluster = Octopus::clusters(cluster_id).expect("cluster should exist");
+        assert_eq!(cluster.owner, owner);
+
+        assert_ok!(Octopus::accept_cluster_transfer(
+            RuntimeOrigin::signed(3),
+            cluster_id
+        ));
+        System::assert_last_event(
+            Event::ClusterTransferred {
+                cluster_id,
+                old_owner: owner,
+                new_owner,
+            }
+            .into(),
+        );
+
+        let cluster = Octopus::clusters(cluster_id).expect("cluster should exist");
+        assert_eq!(cluster.owner, new_owner);
+        assert!(Octopus::pending_cluster_transfers(cluster_id).is_none());
+
+        assert_noop!(
+            Octopus::evaluate_scaling(RuntimeOrigin::signed(1), cluster_id),
+            Error::<Test>::NotClusterOwner
+        );
+    });
+}
+
+#[test]
+fn cluster_transfer_rejects_unproposed_acceptor() {
+    new_test_ext().execute_with(|| {
+        let owner = account_to_actor(1);
+        let cluster_id = ClusterId::new(0);
+        assert_ok!(Octopus::create_cluster(RuntimeOrigin::signed(1), owner));
+
+        assert_noop!(
+            Octopus::accept_cluster_transfer(RuntimeOrigin::signed(3), cluster_id),
+            Error::<Test>::NoPendingTransfer
+        );
+
+        assert_ok!(Octopus::propose_cluster_transfer(
+            RuntimeOrigin::signed(1),
+            cluster_id,
+            account_to_actor(3)
+        ));
+        assert_noop!(
+            Octopus::accept_cluster_transfer(RuntimeOrigin::signed(4), cluster_id),
+            Error::<Test>::NotProposedOwner
+        );
+
+        let cluster = Octopus::clusters(cluster_id).expect("cluster should exist");
+        assert_eq!(cluster.owner, owner);
+    });
+}
+
+// ============================================================================
+// Health Ceiling Tests
+// ============================================================================
+
+fn fail_and_rehabilitate(subnode_id: SubnodeId) {
+    for block in [11, 21, 31] {
+        run_failure_detection(block);
+    }
+    let subnode = Octopus::subnodes(subnode_id).expect("subnode should exist");
+    assert_eq!(subnode.status, SubnodeStatus::Inactive);
+    assert_ok!(Octopus::activate_subnode(
+        RuntimeOrigin::signed(2),
+        subnode_id
+    ));
+}
+
+#[test]
+fn rehabilitated_subnode_capped_during_probation() {
+    new_test_ext().execute_with(|| {
+        let subnode_id = setup_active_subnode();
+        let subnode = Octopus::subnodes(subnode_id).expect("subnode should exist");
+        assert_eq!(subnode.health_ceiling, 100);
+
+        fail_and_rehabilitate(subnode_id);
+        let subnode = Octopus::subnodes(subnode_id).expect("subnode should exist");
+        assert_eq!(subnode.health_ceiling, 60);
+        assert_eq!(subnode.health_score, 50);
+
+        for sequence in 1..=5 {
+            assert_ok!(Octopus::record_heartbeat(
+                RuntimeOrigin::signed(2),
+                subnode_id,
+                sequence
+            ));
+        }
+
+        // Uncapped recovery would reach 75; the ceiling only rose to 70.
+        let subnode = Octopus::subnodes(subnode_id).expect("subnode should exist");
+        assert_eq!(subnode.health_ceiling, 70);
+        assert_eq!(subnode.health_score, 68);
+    });
+}
+
+#[test]
+fn sustained_heartbeats_restore_full_ceiling() {
+    new_test_ext().execute_with(|| {
+        let subnode_id = setup_active_subnode();
+        fail_and_rehabilitate(subnode_id);
+
+        for sequence in 1..=20 {
+            assert_ok!(Octopus::record_heartbeat(
+                RuntimeOrigin::signed(2),
+                subnode_id,
+                sequence
+            ));
+        }
+
+        let subnode = Octopus::subnodes(subnode_id).expect("subnode should exist");
+        assert_eq!(subnode.health_ceiling, 100);
+        assert_eq!(subnode.health_score, 98);
+
+        assert_ok!(Octopus::record_heartbeat(
+            RuntimeOrigin::signed(2),
+            subnode_id,
+            21
+        ));
+        let subnode = Octopus::subnodes(subnode_id).expect("subnode should exist");
+        assert_eq!(subnode.health_score, 100);
+    });
+}
+
+// ============================================================================
+// Position Velocity Tests
+// ============================================================================
+
+#[test]
+fn plausible_position_move_keeps_score() {
+    new_test_ext().execute_with(|| {
+        let subnode_id = setup_active_subnode();
+
+        System::set_block_number(10);
+        assert_ok!(Octopus::record_position_confirmation(
+            RuntimeOrigin::signed(2),
+            subnode_id,
+            0,
+            0,
+            0
+        ));
+        let before = Octopus::fused_health(subnode_id).expect("health should exist");
+
+        System::set_block_number(20);
+        assert_ok!(Octopus::record_position_confirmation(
+            RuntimeOrigin::signed(2),
+            subnode_id,
+            500,
+            0,
+            0
+        ));
+
+        let health = Octopus::fused_health(subnode_id).expect("health should exist");
+        assert!(!health.position_metrics.velocity_anomaly);
+        assert_eq!(health.position_metrics.triangulation_confirmations, 2);
+        assert_eq!(
+            health.position_metrics.position_score(),
+            before.position_metrics.position_score()
+        );
+        assert_eq!(
+            health.position_metrics.last_confirmed_position,
+            Some(crate::FusionPosition::new(500, 0, 0))
+        );
+    });
+}
+
+#[test]
+fn teleporting_subnode_triggers_position_anomaly() {
+    new_test_ext().execute_with(|| {
+        let subnode_id = setup_active_subnode();
+
+        System::set_block_number(10);
+        assert_ok!(Octopus::record_position_confirmation(
+            RuntimeOrigin::signed(2),
+            subnode_id,
+            0,
+            0,
+            0
+        ));
+        let before = Octopus::fused_health(subnode_id).expect("health should exist");
+
+        System::set_block_number(11);
+        assert_ok!(Octopus::record_position_confirmation(
+            RuntimeOrigin::signed(2),
+            subnode_id,
+            100_000,
+            0,
+            0
+        ));
+
+        System::assert_has_event(RuntimeEvent::Octopus(Event::PositionAnomalyDetected {
+            subnode_id,
+            position: crate::FusionPosition::new(100_000, 0, 0),
+            velocity: 100_000,
+        }));
+
+        let health = Octopus::fused_health(subnode_id).expect("health should exist");
+        assert!(health.position_metrics.velocity_anomaly);
+        assert_eq!(health.position_metrics.position_score(), 0);
+        assert!(health.fused_score < before.fused_score);
+        assert_eq!(health.position_metrics.triangulation_confirmations, 1);
+        assert_eq!(
+            health.position_metrics.last_confirmed_position,
+            Some(crate::FusionPosition::new(0, 0, 0))
+        );
+    });
+}
+
+// ============================================================================
+// Cluster Creation Capability Tests
+// ============================================================================
+
+#[test]
+fn gated_cluster_creation_allows_capable_caller() {
+    new_test_ext().execute_with(|| {
+        GateClusterCreation::set(true);
+
+        assert_ok!(Octopus::create_cluster(
+            RuntimeOrigin::signed(1),
+            account_to_actor(1)
+        ));
+        assert!(Octopus::clusters(ClusterId::new(0)).is_some());
+    });
+}
+
+#[test]
+fn gated_cluster_creation_rejects_caller_without_capability() {
+    new_test_ext().execute_with(|| {
+        GateClusterCreation::set(true);
+
+        assert_noop!(
+            Octopus::create_cluster(RuntimeOrigin::signed(3), account_to_actor(3)),
+            Error::<Test>::MissingCapability
+        );
+
+        GateClusterCreation::set(false);
+        assert_ok!(Octopus::create_cluster(
+            RuntimeOrigin::signed(3),
+            account_to_actor(3)
+        ));
+    });
+}
+
+// ============================================================================
+// Position Variance Threshold Tests
+// ============================================================================
+
+fn set_position_variance(subnode_id: SubnodeId, variance: u32) {
+    let mut health = crate::FusedHealthMetrics::new(crate::FusionPosition::default());
+    health.position_metrics.position_variance = variance;
+    crate::FusedHealth::<Test>::insert(subnode_id, health);
+}
+
+#[test]
+fn variance_below_threshold_is_position_consistent() {
+    new_test_ext().execute_with(|| {
+        let subnode_id = setup_active_subnode();
+        set_position_variance(subnode_id, PositionVarianceThreshold::get() - 1);
+
+        let report = Octopus::run_diagnostics(subnode_id).expect("report should exist");
+        assert!(report.checks.position_consistency_ok);
+        assert!(!report
+            .actions
+            .contains(&DiagnosticAction::RecalibratePosition));
+
+        // Recalibration leaves an already consistent position untouched.
+        assert_ok!(Octopus::apply_auto_fix(
+            subnode_id,
+            &[DiagnosticAction::RecalibratePosition]
+        ));
+        let health = Octopus::fused_health(subnode_id).expect("health should exist");
+        assert_eq!(
+            health.position_metrics.position_variance,
+            PositionVarianceThreshold::get() - 1
+        );
+    });
+}
+
+#[test]
+fn variance_at_threshold_triggers_recalibration() {
+    new_test_ext().execute_with(|| {
+        let subnode_id = setup_active_subnode();
+        set_position_variance(subnode_id, PositionVarianceThreshold::get());
+
+        let report = Octopus::run_diagnostics(subnode_id).expect("report should exist");
+        assert!(!report.checks.position_consistency_ok);
+        assert!(report
+            .actions
+            .contains(&DiagnosticAction::RecalibratePosition));
+
+        assert_ok!(Octopus::apply_auto_fix(subnode_id, &report.actions));
+        let health = Octopus::fused_health(subnode_id).expect("health should exist");
+        assert_eq!(health.position_metrics.position_variance, 0);
+    });
+}
+
+// ============================================================================
+// Throughput SLA Tests
+// ============================================================================
+
+#[test]
+fn sustained_sub_sla_throughput_breaches_cluster() {
+    new_test_ext().execute_with(|| {
+        ThroughputSla::set(Perbill::from_percent(50));
+        setup_active_subnode();
+        let cluster_id = ClusterId::new(0);
+        let observed = Perbill::from_percent(30);
+        assert_ok!(Octopus::update_throughput(
+            RuntimeOrigin::root(),
+            cluster_id,
+            observed
+        ));
+
+        run_failure_detection(2);
+        assert_eq!(Octopus::sla_below_since(cluster_id), Some(2));
+
+        run_failure_detection(6);
+        assert!(Octopus::sla_breaches(cluster_id).is_none());
+        let cluster = Octopus::clusters(cluster_id).expect("cluster should exist");
+        assert_eq!(cluster.status, ClusterStatus::Running);
+
+        run_failure_detection(7);
+        assert_eq!(Octopus::sla_breaches(cluster_id), Some(7));
+        let cluster = Octopus::clusters(cluster_id).expect("cluster should exist");
+        assert_eq!(cluster.status, ClusterStatus::Degraded);
+        System::assert_has_event(RuntimeEvent::Octopus(Event::ClusterSlaBreached {
+            cluster_id,
+            observed,
+        }));
+
+        // Auto-healing must not restore a cluster that is still below SLA
+        run_failure_detection(8);
+        let cluster = Octopus::clusters(cluster_id).expect("cluster should exist");
+        assert_eq!(cluster.status, ClusterStatus::Degraded);
+    });
+}
+
+#[test]
+fn throughput_recovery_clears_sla_breach() {
+    new_test_ext().execute_with(|| {
+        ThroughputSla::set(Perbill::from_percent(50));
+        setup_active_subnode();
+        let cluster_id = ClusterId::new(0);
+        assert_ok!(Octopus::update_throughput(
+            RuntimeOrigin::root(),
+            cluster_id,
+            Perbill::from_percent(30)
+        ));
+        run_failure_detection(2);
+        run_failure_detection(7);
+        assert!(Octopus::sla_breaches(cluster_id).is_some());
+
+        let observed = Perbill::from_percent(60);
+        assert_ok!(Octopus::update_throughput(
+            RuntimeOrigin::root(),
+            cluster_id,
+            observed
+        ));
+        run_failure_detection(8);
+
+        assert!(Octopus::sla_breaches(cluster_id).is_none());
+        assert!(Octopus::sla_below_since(cluster_id).is_none());
+        let cluster = Octopus::clusters(cluster_id).expect("cluster should exist");
+        assert_eq!(cluster.status, ClusterStatus::Running);
+        System::assert_has_event(RuntimeEvent::Octopus(Event::ClusterSlaRecovered {
+            cluster_id,
+            observed,
+        }));
+    });
+}
+
+#[test]
+fn sla_check_resumes_across_blocks() {
+    new_test_ext().execute_with(|| {
+        ThroughputSla::set(Perbill::from_percent(50));
+        for _ in 0..15 {
+            assert_ok!(Octopus::create_cluster(
+                RuntimeOrigin::signed(1),
+                account_to_actor(1)
+            ));
+        }
+        for id in 0..15 {
+            crate::Clusters::<Test>::mutate(ClusterId::new(id), |c| {
+                let cluster = c.as_mut().expect("cluster should exist");
+                cluster.status = ClusterStatus::Running;
+                cluster.total_throughput = Perbill::from_percent(30);
+            });
+        }
+        let below = || {
+            (0..15)
+                .filter(|id| Octopus::sla_below_since(ClusterId::new(*id)).is_some())
+                .count()
+        };
+
+        run_failure_detection(2);
+        assert_eq!(below(), 10);
+        run_failure_detection(3);
+        assert_eq!(below(), 15);
+    });
+}
+
+// ============================================================================
+// Scaling Sample Threshold Tests
+// ============================================================================
+
+#[test]
+fn scaling_suppressed_until_enough_samples() {
+    new_test_ext().execute_with(|| {
+        MinSamplesForScaling::set(3);
+        let cluster_id = ClusterId::new(0);
+        setup_active_subnode();
+
+        for _ in 0..2 {
+            assert_ok!(Octopus::update_throughput(
+                RuntimeOrigin::root(),
+                cluster_id,
+                Perbill::from_percent(50)
+            ));
+        }
+        assert_eq!(
+            Octopus::is_scaling_needed(cluster_id),
+            Some(ScalingDecision::Maintain)
+        );
+
+        System::set_block_number(15);
+        assert_ok!(Octopus::evaluate_scaling(
+            RuntimeOrigin::signed(1),
+            cluster_id
+        ));
+        System::assert_has_event(RuntimeEvent::Octopus(Event::ScalingSamplesInsufficient {
+            cluster_id,
+            sample_count: 2,
+            required: 3,
+        }));
+        System::assert_has_event(RuntimeEvent::Octopus(Event::ScalingDecisionMade {
+            cluster_id,
+            decision: ScalingDecision::Maintain,
+            throughput: Perbill::from_percent(50),
+        }));
+        let cluster = Octopus::clusters(cluster_id).expect("cluster should exist");
+        assert_eq!(cluster.status, ClusterStatus::Running);
+    });
+}
+
+#[test]
+fn scaling_acts_once_samples_accumulate() {
+    new_test_ext().execute_with(|| {
+        MinSamplesForScaling::set(3);
+        let cluster_id = ClusterId::new(0);
+        setup_active_subnode();
+
+        for _ in 0..3 {
+            assert_ok!(Octopus::update_throughput(
+                RuntimeOrigin::root(),
+                cluster_id,
+                Perbill::from_percent(50)
+            ));
+        }
+
+        System::set_block_number(15);
+        assert_ok!(Octopus::evaluate_scaling(
+            RuntimeOrigin::signed(1),
+            cluster_id
+        ));
+
+        assert!(matches!(
+            Octopus::is_scaling_needed(cluster_id),
+            Some(ScalingDecision::ScaleUp(_))
+        ));
+        let cluster = Octopus::clusters(cluster_id).expect("cluster should exist");
+        assert_eq!(cluster.status, ClusterStatus::Scaling);
+    });
+}
+
+// ============================================================================
+// Fusion Healing Config Tests
+// ============================================================================
+
+fn insert_fused_health(subnode: u64, configure: impl FnOnce(&mut crate::FusedHealthMetrics)) {
+    let mut health = crate::FusedHealthMetrics::new(crate::FusionPosition::default());
+    configure(&mut health);
+    crate::FusedHealth::<Test>::insert(SubnodeId::new(subnode), health);
+}
+
+fn healing_triggers() -> Vec<(SubnodeId, HealingTrigger)> {
+    System::events()
+        .into_iter()
+        .filter_map(|record| match record.event {
+            RuntimeEvent::Octopus(Event::FusionHealingTriggered {
+                subnode_id,
+                trigger,
+                ..
+            }) => Some((subnode_id, trigger)),
+            _ => None,
+        })
+        .collect()
+}
+
+#[test]
+fn healing_config_controls_which_subnodes_trigger() {
+    new_test_ext().execute_with(|| {
+        insert_fused_health(0, |h| h.fused_score = 30);
+        insert_fused_health(1, |h| h.fused_score = 60);
+
+        run_failure_detection(2);
+        assert!(healing_triggers().is_empty());
+
+        let config = FusionHealingConfig {
+            critical_fused_score: 40,
+            ..Default::default()
+        };
+        assert_ok!(Octopus::set_fusion_healing_config(
+            RuntimeOrigin::root(),
+            config
+        ));
+        assert_eq!(Octopus::fusion_healing_config(), config);
+
+        run_failure_detection(3);
+        assert_eq!(
+            healing_triggers(),
+            vec![(
+                SubnodeId::new(0),
+                HealingTrigger::FusedScoreCritical {
+                    weakest: HealthComponent::Device
+                }
+            )]
+        );
+    });
+}
+
+#[test]
+fn healing_trigger_identifies_failing_component() {
+    new_test_ext().execute_with(|| {
+        insert_fused_health(0, |h| {
+            h.fused_score = 60;
+            h.heartbeat_score = 35;
+        });
+        insert_fused_health(1, |h| {
+            h.fused_score = 60;
+            h.position_metrics.triangulation_confirmations = 3;
+            h.position_metrics.position_variance = 3000;
+        });
+        insert_fused_health(2, |h| {
+            h.fused_score = 60;
+            h.device_metrics.total_observations = 1;
+        });
+
+        assert_ok!(Octopus::set_fusion_healing_config(
+            RuntimeOrigin::root(),
+            FusionHealingConfig {
+                critical_fused_score: 20,
+                heartbeat_critical_score: 40,
+                reveal_timeout_blocks: 5,
+                position_tolerance_cm: 2000,
+            }
+        ));
+        run_failure_detection(10);
+
+        let mut components: Vec<(u64, HealthComponent)> = healing_triggers()
+            .into_iter()
+            .map(|(id, trigger)| (id.0, trigger.component()))
+            .collect();
+        components.sort_by_key(|(id, _)| *id);
+        assert_eq!(
+            components,
+            vec![
+                (0, HealthComponent::Heartbeat),
+                (1, HealthComponent::Position),
+                (2, HealthComponent::Device),
+            ]
+        );
+    });
+}
+
+#[test]
+fn invalid_healing_config_rejected() {
+    new_test_ext().execute_with(|| {
+        assert_noop!(
+            Octopus::set_fusion_healing_config(
+                RuntimeOrigin::root(),
+                FusionHealingConfig {
+                    reveal_timeout_blocks: 0,
+                    ..Default::default()
+                }
+            ),
+            Error::<Test>::InvalidHealingConfig
+        );
+        assert_noop!(
+            Octopus::set_fusion_healing_config(
+                RuntimeOrigin::signed(1),
+                FusionHealingConfig::default()
+            ),
+            sp_runtime::DispatchError::BadOrigin
+        );
+    });
+}
+
+// ============================================================================
+// Device Binding Tests
+// ============================================================================
+
+fn register_active_subnode() -> SubnodeId {
+    assert_ok!(Octopus::register_subnode(
+        RuntimeOrigin::signed(2),
+        ClusterId::new(0),
+        account_to_actor(2),
+        None
+    ));
+    let subnode_id = SubnodeId::new(Octopus::subnode_count() - 1);
+    assert_ok!(Octopus::activate_subnode(
+        RuntimeOrigin::signed(2),
+        subnode_id
+    ));
+    subnode_id
+}
+
+fn bind_devices(subnode_id: SubnodeId, device_ids: core::ops::RangeInclusive<u64>) {
+    for device_id in device_ids {
+        set_device(device_id, account_to_actor(2), true);
+        assert_ok!(Octopus::bind_device(
+            RuntimeOrigin::signed(2),
+            subnode_id,
+            device_id
+        ));
+    }
+}
+
+/// Each subnode reports `reported` devices once per block for `rounds` blocks.
+fn observe_devices(subnodes: &[SubnodeId], from_block: u64, rounds: u64, reported: u8) {
+    for block in from_block..from_block + rounds {
+        System::set_block_number(block);
+        subnodes.iter().for_each(|subnode_id| {
+            assert_ok!(Octopus::record_device_observation(
+                RuntimeOrigin::signed(2),
+                *subnode_id,
+                reported,
+                H256([block as u8; 32])
+            ));
+        });
+    }
+}
+
+fn device_component(subnode_id: SubnodeId) -> u8 {
+    Octopus::fused_health(subnode_id)
+        .expect("health should exist")
+        .device_metrics
+        .device_score()
+}
+
+#[test]
+fn bind_device_requires_owned_active_unbound_device() {
+    new_test_ext().execute_with(|| {
+        let subnode_id = setup_active_subnode();
+        let operator = account_to_actor(2);
+        set_device(1, operator, true);
+        set_device(2, account_to_actor(3), true);
+        set_device(3, operator, false);
+
+        assert_noop!(
+            Octopus::bind_device(RuntimeOrigin::signed(3), subnode_id, 1),
+            Error::<Test>::NotSubnodeOperator
+        );
+        assert_noop!(
+            Octopus::bind_device(RuntimeOrigin::signed(2), subnode_id, 2),
+            Error::<Test>::DeviceNotOwned
+        );
+        assert_noop!(
+            Octopus::bind_device(RuntimeOrigin::signed(2), subnode_id, 3),
+            Error::<Test>::DeviceNotActive
+        );
+
+        assert_ok!(Octopus::bind_device(
+            RuntimeOrigin::signed(2),
+            subnode_id,
+            1
+        ));
+        System::assert_has_event(RuntimeEvent::Octopus(Event::DeviceBound {
+            subnode_id,
+            device_id: 1,
+        }));
+        assert_eq!(Octopus::device_binding(1), Some(subnode_id));
+        assert_eq!(Octopus::subnode_device_count(subnode_id), 1);
+
+        assert_noop!(
+            Octopus::bind_device(RuntimeOrigin::signed(2), subnode_id, 1),
+            Error::<Test>::DeviceAlreadyBound
+        );
+        assert_noop!(
+            Octopus::unbind_device(RuntimeOrigin::signed(2), subnode_id, 3),
+            Error::<Test>::DeviceNotBound
+        );
+    });
+}
+
+#[test]
+fn bound_active_devices_raise_device_component() {
+    new_test_ext().execute_with(|| {
+        let unbound = setup_active_subnode();
+        let bound = register_active_subnode();
+        bind_devices(bound, 1..=5);
+        assert_eq!(Octopus::active_bound_devices(bound), 5);
+
+        observe_devices(&[unbound, bound], 2, 10, 5);
+
+        System::assert_has_event(RuntimeEvent::Octopus(Event::DeviceObservationRecorded {
+            subnode_id: unbound,
+            device_count: 0,
+            commitment: H256([11u8; 32]),
+        }));
+        assert_eq!(device_component(unbound), 0);
+        assert!(device_component(bound) > 0);
+    });
+}
+
+#[test]
+fn unbinding_or_compromising_devices_lowers_device_component() {
+    new_test_ext().execute_with(|| {
+        let control = setup_active_subnode();
+        let unbound = register_active_subnode();
+        let compromised = register_active_subnode();
+        bind_devices(control, 1..=5);
+        bind_devices(unbound, 6..=10);
+        bind_devices(compromised, 11..=15);
+
+        let subnodes = [control, unbound, compromised];
+        observe_devices(&subnodes, 2, 10, 5);
+        assert_eq!(device_component(unbound), device_component(control));
+        assert_eq!(device_component(compromised), device_component(control));
+
+        for device_id in 6..=7 {
+            assert_ok!(Octopus::unbind_device(
+                RuntimeOrigin::signed(2),
+                unbound,
+                device_id
+            ));
+        }
+        System::assert_has_event(RuntimeEvent::Octopus(Event::DeviceUnbound {
+            subnode_id: unbound,
+            device_id: 7,
+        }));
+        assert_eq!(Octopus::subnode_device_count(unbound), 3);
+        assert_eq!(Octopus::device_binding(6), None);
+
+        set_device(11, account_to_actor(2), false);
+        set_device(12, account_to_actor(2), false);
+        assert_eq!(Octopus::active_bound_devices(compromised), 3);
+
+        observe_devices(&subnodes, 12, 10, 5);
+        assert!(device_component(unbound) < device_component(control));
+        assert!(device_component(compromised) < device_component(control));
+    });
+}
+
+#[test]
+fn pruned_subnode_releases_bound_devices() {
+    new_test_ext().execute_with(|| {
+        let subnode_id = setup_active_subnode();
+        bind_devices(subnode_id, 1..=3);
+        crate::Subnodes::<Test>::mutate(subnode_id, |s| {
+            s.as_mut().expect("subnode should exist").status = SubnodeStatus::Failed;
+        });
+
+        assert_eq!(Octopus::prune_inactive_subnodes(200), 1);
+        assert!(Octopus::subnodes(subnode_id).is_none());
+        assert_eq!(Octopus::subnode_device_count(subnode_id), 0);
+        assert_eq!(Octopus::device_binding(1), None);
+        assert!(!crate::SubnodeDevices::<Test>::contains_key(subnode_id, 1));
+
+        let other = register_active_subnode();
+        assert_ok!(Octopus::bind_device(RuntimeOrigin::signed(2), other, 1));
+    });
+}
+
+// ============================================================================
+// Auto-Heal Reset Score Tests
+// ============================================================================
+
+fn auto_heal_failed_subnode() -> (u8, u8) {
+    let subnode_id = setup_active_subnode();
+    crate::Subnodes::<Test>::mutate(subnode_id, |s| {
+        s.as_mut().expect("subnode should exist").status = SubnodeStatus::Failed;
+    });
+    crate::Clusters::<Test>::mutate(ClusterId::new(0), |c| {
+        let cluster = c.as_mut().expect("cluster should exist");
+        cluster.status = ClusterStatus::Degraded;
+        cluster.active_subnodes = 0;
+    });
+    let mut health = crate::FusedHealthMetrics::new(crate::FusionPosition::default());
+    health.heartbeat_score = 10;
+    crate::FusedHealth::<Test>::insert(subnode_id, health);
+
+    System::set_block_number(5);
+    Octopus::on_initialize(5);
+
+    let subnode = Octopus::subnodes(subnode_id).expect("subnode should exist");
+    assert_eq!(subnode.status, SubnodeStatus::Inactive);
+    let health = Octopus::fused_health(subnode_id).expect("fused health should exist");
+    (subnode.health_score, health.heartbeat_score)
+}
+
+#[test]
+fn auto_heal_resets_to_configured_score() {
+    new_test_ext().execute_with(|| {
+        assert_eq!(auto_heal_failed_subnode(), (50, 50));
+    });
+
+    AutoHealResetScore::set(30);
+    new_test_ext().execute_with(|| {
+        assert_eq!(auto_heal_failed_subnode(), (30, 30));
+    });
+    AutoHealResetScore::set(50);
+}
+
+#[test]
+fn auto_heal_reset_score_capped_by_probation_ceiling() {
+    AutoHealResetScore::set(90);
+    new_test_ext().execute_with(|| {
+        // Probation ceiling (60) bounds subnode health; the fused heartbeat is not.
+        assert_eq!(auto_heal_failed_subnode(), (60, 90));
+    });
+    AutoHealResetScore::set(50);
+}
+
+// ============================================================================
+// Cluster Draining Tests
+// ============================================================================
+
+#[test]
+fn draining_deactivates_all_subnodes_then_shuts_down() {
+    new_test_ext().execute_with(|| {
+        let cluster_id = ClusterId::new(0);
+        let first = setup_active_subnode();
+        let subnodes = [first, register_active_subnode(), register_active_subnode()];
+
+        assert_ok!(Octopus::drain_cluster(RuntimeOrigin::signed(1), cluster_id));
+        System::assert_has_event(RuntimeEvent::Octopus(Event::ClusterStatusChanged {
+            cluster_id,
+            old_status: ClusterStatus::Running,
+            new_status: ClusterStatus::Draining,
+        }));
+        for subnode_id in subnodes {
+            let subnode = Octopus::subnodes(subnode_id).expect("subnode should exist");
+            assert_eq!(subnode.status, SubnodeStatus::Deactivating);
+        }
+        assert_noop!(
+            Octopus::register_subnode(
+                RuntimeOrigin::signed(2),
+                cluster_id,
+                account_to_actor(2),
+                None
+            ),
+            Error::<Test>::ClusterDraining
+        );
+
+        // Still draining while subnodes wind down.
+        Octopus::on_initialize(10);
+        let cluster = Octopus::clusters(cluster_id).expect("cluster should exist");
+        assert_eq!(cluster.status, ClusterStatus::Draining);
+
+        System::set_block_number(51);
+        Octopus::on_initialize(51);
+        let cluster = Octopus::clusters(cluster_id).expect("cluster should exist");
+        assert_eq!(cluster.status, ClusterStatus::Shutdown);
+        assert_eq!(cluster.active_subnodes, 0);
+        assert!(Octopus::get_active_subnodes(cluster_id).is_empty());
+        assert_eq!(Octopus::active_subnode_count(), 0);
+        assert_eq!(Octopus::draining_clusters(cluster_id), None);
+        for subnode_id in subnodes {
+            let subnode = Octopus::subnodes(subnode_id).expect("subnode should exist");
+            assert_eq!(subnode.status, SubnodeStatus::Inactive);
+        }
+    });
+}
+
+#[test]
+fn drain_cluster_requires_owner_and_open_cluster() {
+    new_test_ext().execute_with(|| {
+        let cluster_id = ClusterId::new(0);
+        setup_active_subnode();
+
+        assert_noop!(
+            Octopus::drain_cluster(RuntimeOrigin::signed(2), cluster_id),
+            Error::<Test>::NotClusterOwner
+        );
+        assert_ok!(Octopus::drain_cluster(RuntimeOrigin::signed(1), cluster_id));
+        assert_noop!(
+            Octopus::drain_cluster(RuntimeOrigin::signed(1), cluster_id),
+            Error::<Test>::ClusterNotDrainable
+        );
+        assert_noop!(
+            Octopus::evaluate_scaling(RuntimeOrigin::signed(1), cluster_id),
+            Error::<Test>::ClusterDraining
+        );
+
+        System::set_block_number(51);
+        Octopus::on_initialize(51);
+        assert_noop!(
+            Octopus::drain_cluster(RuntimeOrigin::signed(1), cluster_id),
+            Error::<Test>::ClusterNotDrainable
+        );
+    });
+}
+
+#[test]
+fn drain_completion_resumes_across_blocks() {
+    new_test_ext().execute_with(|| {
+        let live = setup_active_subnode();
+        let draining: Vec<ClusterId> = (100..115).map(ClusterId::new).collect();
+        for cluster_id in &draining {
+            crate::DrainingClusters::<Test>::insert(cluster_id, 1);
+            crate::ClusterSubnodes::<Test>::insert(cluster_id, live, ());
+        }
+
+        // Every cluster still has a live subnode; the sweep stops after ten.
+        Octopus::on_initialize(2);
+        assert_eq!(crate::DrainingClusters::<Test>::iter().count(), 15);
+        assert!(crate::DrainSweepCursor::<Test>::get().is_some());
+
+        for cluster_id in &draining {
+            crate::ClusterSubnodes::<Test>::remove(cluster_id, live);
+        }
+
+        // Resumes with the five clusters the previous block did not reach.
+        Octopus::on_initialize(3);
+        assert_eq!(crate::DrainingClusters::<Test>::iter().count(), 10);
+        assert!(crate::DrainSweepCursor::<Test>::get().is_none());
+
+        Octopus::on_initialize(4);
+        assert_eq!(crate::DrainingClusters::<Test>::iter().count(), 0);
+    });
+}
+
+// ============================================================================
+// Subnode Priority Tests
+// ============================================================================
+
+fn register_with_priority(priority: Option<u8>, throughput: u32, activate: bool) -> SubnodeId {
+    assert_ok!(Octopus::register_subnode(
+        RuntimeOrigin::signed(2),
+        ClusterId::new(0),
+        account_to_actor(2),
+        priority
+    ));
+    let subnode_id = SubnodeId::new(Octopus::subnode_count() - 1);
+    if activate {
+        assert_ok!(Octopus::activate_subnode(
+            RuntimeOrigin::signed(2),
+            subnode_id
+        ));
+    }
+    assert_ok!(Octopus::update_subnode_throughput(
+        RuntimeOrigin::signed(2),
+        subnode_id,
+        Perbill::from_percent(throughput),
+        0
+    ));
+    subnode_id
+}
+
+#[test]
+fn scale_down_picks_lowest_priority_then_throughput() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(Octopus::create_cluster(
+            RuntimeOrigin::signed(1),
+            account_to_actor(1)
+        ));
+        let _default = register_with_priority(None, 10, true);
+        let _busy_low = register_with_priority(Some(50), 90, true);
+        let idle_low = register_with_priority(Some(50), 20, true);
+        let _inactive_lowest = register_with_priority(Some(10), 0, false);
+        let _critical = register_with_priority(Some(255), 0, true);
+
+        assert_eq!(
+            Octopus::scale_down_candidate(ClusterId::new(0)),
+            Some(idle_low)
+        );
+    });
+}
+
+#[test]
+fn scale_down_candidate_defaults_and_ties() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(Octopus::create_cluster(
+            RuntimeOrigin::signed(1),
+            account_to_actor(1)
+        ));
+        assert_eq!(Octopus::scale_down_candidate(ClusterId::new(0)), None);
+
+        let first = register_with_priority(None, 30, true);
+        let second = register_with_priority(None, 30, true);
+        let subnode = Octopus::subnodes(second).expect("subnode should exist");
+        assert_eq!(subnode.priority, crate::DEFAULT_SUBNODE_PRIORITY);
+
+        // Equal priority and throughput: the oldest subnode goes first.
+        assert_eq!(
+            Octopus::scale_down_candidate(ClusterId::new(0)),
+            Some(first)
+        );
+    });
+}
+
+// ============================================================================
+// Full Health Report Tests
+// ============================================================================
+
+fn fused_health_events(subnode_id: SubnodeId) -> usize {
+    System::events()
+        .iter()
+        .filter(|record| {
+            matches!(
+                record.event,
+                RuntimeEvent::Octopus(Event::FusedHealthUpdated { subnode_id: id, .. })
+                    if id == subnode_id
+            )
+        })
+        .count()
+}
+
+#[test]
+fn full_health_report_matches_separate_calls() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(Octopus::create_cluster(
+            RuntimeOrigin::signed(1),
+            account_to_actor(1)
+        ));
+        let combined = register_with_priority(None, 50, true);
+        let separate = register_with_priority(None, 50, true);
+        bind_devices(combined, 1..=3);
+        bind_devices(separate, 4..=6);
+
+        // The second round jumps far enough to be a velocity anomaly.
+        for (round, (x, device_count)) in [(10i64, 2u8), (5_000, 5)].into_iter().enumerate() {
+            let block = 10 + round as u64;
+            let sequence = 1 + round as u64;
+            let commitment = H256([block as u8; 32]);
+            System::set_block_number(block);
+
+            assert_ok!(Octopus::full_health_report(
+                RuntimeOrigin::signed(2),
+                combined,
+                sequence,
+                device_count,
+                commitment,
+                x,
+                20,
+                0
+            ));
+
+            assert_ok!(Octopus::record_heartbeat(
+                RuntimeOrigin::signed(2),
+                separate,
+                sequence
+            ));
+            assert_ok!(Octopus::record_device_observation(
+                RuntimeOrigin::signed(2),
+                separate,
+                device_count,
+                commitment
+            ));
+            assert_ok!(Octopus::record_position_confirmation(
+                RuntimeOrigin::signed(2),
+                separate,
+                x,
+                20,
+                0
+            ));
+        }
+
+        let combined_health = Octopus::fused_health(combined).expect("health should exist");
+        assert_eq!(
+            Some(combined_health.clone()),
+            Octopus::fused_health(separate)
+        );
+        assert!(combined_health.position_metrics.velocity_anomaly);
+        assert_eq!(combined_health.device_metrics.last_reveal_block, 11);
+
+        let a = Octopus::subnodes(combined).expect("subnode should exist");
+        let b = Octopus::subnodes(separate).expect("subnode should exist");
+        assert_eq!(
+            (a.heartbeat_sequence, a.last_heartbeat, a.health_score),
+            (b.heartbeat_sequence, b.last_heartbeat, b.health_score)
+        );
+    });
+}
+
+#[test]
+fn full_health_report_is_one_update_and_all_or_nothing() {
+    new_test_ext().execute_with(|| {
+        let subnode_id = setup_active_subnode();
+        System::set_block_number(5);
+
+        assert_ok!(Octopus::full_health_report(
+            RuntimeOrigin::signed(2),
+            subnode_id,
+            3,
+            0,
+            H256([5u8; 32]),
+            0,
+            0,
+            0
+        ));
+        assert_eq!(fused_health_events(subnode_id), 1);
+        assert_eq!(
+            Octopus::subnodes(subnode_id)
+                .expect("subnode should exist")
+                .heartbeat_sequence,
+            3
+        );
+
+        // A stale sequence rejects the device and position parts too.
+        System::set_block_number(6);
+        assert_noop!(
+            Octopus::full_health_report(
+                RuntimeOrigin::signed(2),
+                subnode_id,
+                3,
+                0,
+                H256([6u8; 32]),
+                10,
+                0,
+                0
+            ),
+            Error::<Test>::StaleHeartbeat
+        );
+        assert_noop!(
+            Octopus::full_health_report(
+                RuntimeOrigin::signed(3),
+                subnode_id,
+                4,
+                0,
+                H256([6u8; 32]),
+                10,
+                0,
+                0
+            ),
+            Error::<Test>::NotSubnodeOperator
+        );
+    });
+}
+
+// ============================================================================
+// Severity Trend Tests
+// ============================================================================
+
+fn diagnose_at(subnode_id: SubnodeId, block: u64, severity: DiagnosticSeverity) {
+    let mut health = crate::FusedHealthMetrics::new(crate::FusionPosition::default());
+    health.device_metrics.total_observations = 1;
+    if severity == DiagnosticSeverity::Warning {
+        health.position_metrics.position_variance = PositionVarianceThreshold::get();
+    }
+    crate::FusedHealth::<Test>::insert(subnode_id, health);
+
+    System::set_block_number(block);
+    let report = Octopus::run_diagnostics(subnode_id).expect("report should exist");
+    assert_eq!(report.severity, severity);
+}
+
+fn trend_escalations(subnode_id: SubnodeId) -> usize {
+    System::events()
+        .iter()
+        .filter(|record| {
+            matches!(
+                record.event,
+                RuntimeEvent::Octopus(Event::OperatorEscalationRequired { subnode_id: id, .. })
+                    if id == subnode_id
+            )
+        })
+        .count()
+}
+
+#[test]
+fn worsening_severity_escalates_before_critical() {
+    new_test_ext().execute_with(|| {
+        let subnode_id = setup_active_subnode();
+        diagnose_at(subnode_id, 1, DiagnosticSeverity::Healthy);
+        diagnose_at(subnode_id, 2, DiagnosticSeverity::Healthy);
+        assert_eq!(Octopus::severity_trend(subnode_id), Trend::Stable);
+
+        diagnose_at(subnode_id, 3, DiagnosticSeverity::Warning);
+        assert_eq!(Octopus::severity_trend(subnode_id), Trend::Worsening);
+
+        Octopus::on_initialize(3);
+        System::assert_has_event(RuntimeEvent::Octopus(Event::OperatorEscalationRequired {
+            subnode_id,
+            reason: DiagnosticSeverity::Warning,
+        }));
+        assert_eq!(Octopus::trend_escalated_at(subnode_id), Some(3));
+
+        // The same report is not escalated twice.
+        Octopus::on_initialize(3);
+        assert_eq!(trend_escalations(subnode_id), 1);
+    });
+}
+
+#[test]
+fn improving_or_mixed_severity_not_escalated() {
+    new_test_ext().execute_with(|| {
+        let subnode_id = setup_active_subnode();
+        diagnose_at(subnode_id, 1, DiagnosticSeverity::Warning);
+        diagnose_at(subnode_id, 2, DiagnosticSeverity::Warning);
+        diagnose_at(subnode_id, 3, DiagnosticSeverity::Healthy);
+        assert_eq!(Octopus::severity_trend(subnode_id), Trend::Improving);
+
+        // The window slides: Warning, Healthy, Warning is mixed.
+        diagnose_at(subnode_id, 4, DiagnosticSeverity::Warning);
+        assert_eq!(Octopus::diagnostic_history(subnode_id).len(), 3);
+        assert_eq!(Octopus::severity_trend(subnode_id), Trend::Stable);
+
+        Octopus::on_initialize(4);
+        assert_eq!(trend_escalations(subnode_id), 0);
+    });
+}
+
+// ============================================================================
+// Fusion Preset Tests
+// ============================================================================
+
+#[test]
+fn fusion_presets_yield_documented_weights() {
+    new_test_ext().execute_with(|| {
+        let cases = [
+            (FusionPreset::Balanced, (34, 33, 33)),
+            (FusionPreset::HeartbeatHeavy, (60, 20, 20)),
+            (FusionPreset::DeviceHeavy, (20, 60, 20)),
+            (FusionPreset::PositionHeavy, (20, 20, 60)),
+        ];
+
+        for (preset, (heartbeat, device, position)) in cases {
+            assert_ok!(Octopus::set_fusion_preset(RuntimeOrigin::root(), preset));
+            let weights = Octopus::fusion_weights();
+            assert_eq!(weights.heartbeat_weight, heartbeat);
+            assert_eq!(weights.device_weight, device);
+            assert_eq!(weights.position_weight, position);
+        }
+
+        assert_noop!(
+            Octopus::set_fusion_preset(RuntimeOrigin::signed(1), FusionPreset::Balanced),
+            sp_runtime::DispatchError::BadOrigin
+        );
+    });
+}
+
+#[test]
+fn fusion_presets_change_fused_score() {
+    let mut health = crate::FusedHealthMetrics::new(crate::FusionPosition::default());
+    // Heartbeat 100, no devices (0), unconfirmed position (50).
+    let mut score_for = |preset: FusionPreset| {
+        health.recalculate_fused_score(&preset.weights().unwrap_or_default());
+        health.fused_score
+    };
+
+    assert_eq!(score_for(FusionPreset::HeartbeatHeavy), 70);
+    assert_eq!(score_for(FusionPreset::PositionHeavy), 50);
+    assert_eq!(score_for(FusionPreset::DeviceHeavy), 30);
+    assert_eq!(score_for(FusionPreset::Balanced), 50);
+}
+
+// ============================================================================
+// Triangulated Position Tests
+// ============================================================================
+
+fn position_component(subnode_id: SubnodeId) -> u8 {
+    Octopus::fused_health(subnode_id)
+        .expect("fused health should exist")
+        .position_metrics
+        .position_score()
+}
+
+#[test]
+fn triangulated_confirmation_raises_position_component() {
+    new_test_ext().execute_with(|| {
+        let subnode_id = setup_active_subnode();
+        let mac_hash = sp_core::H256([5u8; 32]);
+
+        // Not linked yet: the confirmation is ignored.
+        <Octopus as OnPositionConfirmed>::on_position_confirmed(mac_hash, 100, 200, 0, 90);
+        assert!(Octopus::fused_health(subnode_id).is_none());
+
+        assert_ok!(Octopus::set_position_device(
+            RuntimeOrigin::signed(2),
+            subnode_id,
+            Some(mac_hash)
+        ));
+        assert_ok!(Octopus::approve_position_device(
+            RuntimeOrigin::signed(1),
+            subnode_id
+        ));
+        System::assert_last_event(RuntimeEvent::Octopus(Event::PositionDeviceSet {
+            subnode_id,
+            mac_hash: Some(mac_hash),
+        }));
+
+        <Octopus as OnPositionConfirmed>::on_position_confirmed(mac_hash, 100, 200, 0, 90);
+        assert_eq!(position_component(subnode_id), 50);
+        let before = Octopus::fused_health(subnode_id)
+            .expect("fused health should exist")
+            .fused_score;
+
+        for block in 2..=3 {
+            System::set_block_number(block);
+            <Octopus as OnPositionConfirmed>::on_position_confirmed(mac_hash, 100, 200, 0, 90);
+        }
+
+        let health = Octopus::fused_health(subnode_id).expect("fused health should exist");
+        assert_eq!(health.position_metrics.triangulation_confirmations, 3);
+        assert_eq!(position_component(subnode_id), 100);
+        assert!(health.fused_score > before);
+    });
+}
+
+#[test]
+fn position_device_links_one_subnode() {
+    new_test_ext().execute_with(|| {
+        let subnode_id = setup_active_subnode();
+        assert_ok!(Octopus::register_subnode(
+            RuntimeOrigin::signed(2),
+            ClusterId::new(0),
+            account_to_actor(2),
+            None
+        ));
+        let other = SubnodeId::new(1);
+        let mac_hash = sp_core::H256([5u8; 32]);
+
+        assert_noop!(
+            Octopus::set_position_device(RuntimeOrigin::signed(1), subnode_id, Some(mac_hash)),
+            Error::<Test>::NotSubnodeOperator
+        );
+        assert_ok!(Octopus::set_position_device(
+            RuntimeOrigin::signed(2),
+            subnode_id,
+            Some(mac_hash)
+        ));
+        assert_ok!(Octopus::approve_position_device(
+            RuntimeOrigin::signed(1),
+            subnode_id
+        ));
+        assert_noop!(
+            Octopus::set_position_device(RuntimeOrigin::signed(2), other, Some(mac_hash)),
+            Error::<Test>::PositionDeviceAlreadyBound
+        );
+
+        // Clearing frees the device for another subnode.
+        assert_ok!(Octopus::set_position_device(
+            RuntimeOrigin::signed(2),
+            subnode_id,
+            None
+        ));
+        assert_eq!(Octopus::subnode_position_device(subnode_id), None);
+        assert_ok!(Octopus::set_position_device(
+            RuntimeOrigin::signed(2),
+            other,
+            Some(mac_hash)
+        ));
+        assert_ok!(Octopus::approve_position_device(
+            RuntimeOrigin::root(),
+            other
+        ));
+        assert_eq!(Octopus::position_device_subnode(mac_hash), Some(other));
+    });
+}
+
+#[test]
+fn position_device_link_needs_owner_approval() {
+    new_test_ext().execute_with(|| {
+        let subnode_id = setup_active_subnode();
+        let mac_hash = sp_core::H256([5u8; 32]);
+
+        assert_noop!(
+            Octopus::approve_position_device(RuntimeOrigin::signed(1), subnode_id),
+            Error::<Test>::NoPendingPositionDevice
+        );
+        assert_ok!(Octopus::set_position_device(
+            RuntimeOrigin::signed(2),
+            subnode_id,
+            Some(mac_hash)
+        ));
+        System::assert_last_event(RuntimeEvent::Octopus(Event::PositionDeviceRequested {
+            subnode_id,
+            mac_hash,
+        }));
+        assert_eq!(Octopus::pending_position_device(subnode_id), Some(mac_hash));
+        assert_eq!(Octopus::position_device_subnode(mac_hash), None);
+
+        // Unapproved links do not feed the position component.
+        <Octopus as OnPositionConfirmed>::on_position_confirmed(mac_hash, 100, 200, 0, 90);
+        assert!(Octopus::fused_health(subnode_id).is_none());
+
+        assert_noop!(
+            Octopus::approve_position_device(RuntimeOrigin::signed(2), subnode_id),
+            Error::<Test>::NotClusterOwner
+        );
+        assert_ok!(Octopus::approve_position_device(
+            RuntimeOrigin::signed(1),
+            subnode_id
+        ));
+        assert_eq!(Octopus::pending_position_device(subnode_id), None);
+        assert_eq!(Octopus::subnode_position_device(subnode_id), Some(mac_hash));
+    });
+}
+
+// ============================================================================
+// Cluster Capacity Tests
+// ============================================================================
+
+fn register_subnodes(cluster_id: ClusterId, count: u32) {
+    for _ in 0..count {
+        assert_ok!(Octopus::register_subnode(
+            RuntimeOrigin::signed(2),
+            cluster_id,
+            account_to_actor(2),
+            None
+        ));
+    }
+}
+
+#[test]
+fn cluster_capacity_limits_registration() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(Octopus::create_cluster(
+            RuntimeOrigin::signed(1),
+            account_to_actor(1)
+        ));
+        let cluster_id = ClusterId::new(0);
+        register_subnodes(cluster_id, 2);
+
+        assert_noop!(
+            Octopus::set_cluster_max_subnodes(RuntimeOrigin::signed(2), cluster_id, 3),
+            Error::<Test>::NotClusterOwner
+        );
+        assert_noop!(
+            Octopus::set_cluster_max_subnodes(RuntimeOrigin::signed(1), cluster_id, 1),
+            Error::<Test>::ClusterCapacityTooLow
+        );
+        assert_ok!(Octopus::set_cluster_max_subnodes(
+            RuntimeOrigin::signed(1),
+            cluster_id,
+            3
+        ));
+        System::assert_last_event(
+            Event::ClusterCapacityUpdated {
+                cluster_id,
+                max_subnodes: 3,
+            }
+            .into(),
+        );
+
+        register_subnodes(cluster_id, 1);
+        assert_noop!(
+            Octopus::register_subnode(
+                RuntimeOrigin::signed(2),
+                cluster_id,
+                account_to_actor(2),
+                None
+            ),
+            Error::<Test>::MaxSubnodesReached
+        );
+    });
+}
+
+#[test]
+fn cluster_capacity_bounded_by_hard_ceiling() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(Octopus::create_cluster(
+            RuntimeOrigin::signed(1),
+            account_to_actor(1)
+        ));
+        let cluster_id = ClusterId::new(0);
+
+        assert_noop!(
+            Octopus::set_cluster_max_subnodes(RuntimeOrigin::root(), cluster_id, 13),
+            Error::<Test>::ClusterCapacityTooHigh
+        );
+        assert_ok!(Octopus::set_cluster_max_subnodes(
+            RuntimeOrigin::root(),
+            cluster_id,
+            12
+        ));
+
+        // Past the default per-cluster limit of 8, up to the ceiling.
+        register_subnodes(cluster_id, 12);
+        assert_noop!(
+            Octopus::register_subnode(
+                RuntimeOrigin::signed(2),
+                cluster_id,
+                account_to_actor(2),
+                None
+            ),
+            Error::<Test>::MaxSubnodesReached
+        );
+    });
+}
+
+#[test]
+fn recommended_capacity_follows_throughput() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(Octopus::create_cluster(
+            RuntimeOrigin::signed(1),
+            account_to_actor(1)
+        ));
+        let cluster_id = ClusterId::new(0);
+        assert_eq!(Octopus::recommended_capacity(ClusterId::new(9)), None);
+
+        MinSamplesForScaling::set(2);
+        assert_ok!(Octopus::update_throughput(
+            RuntimeOrigin::root(),
+            cluster_id,
+            Perbill::from_percent(100)
+        ));
+        // Too few samples: keep the current capacity.
+        assert_eq!(Octopus::recommended_capacity(cluster_id), Some(8));
+
+        assert_ok!(Octopus::update_throughput(
+            RuntimeOrigin::root(),
+            cluster_id,
+            Perbill::from_percent(100)
+        ));
+        assert_eq!(Octopus::recommended_capacity(cluster_id), Some(12));
+
+        assert_ok!(Octopus::update_throughput(
+            RuntimeOrigin::root(),
+            cluster_id,
+            Perbill::zero()
+        ));
+        assert_eq!(Octopus::recommended_capacity(cluster_id), Some(1));
+
+        // Never below what is already registered.
+        register_subnodes(cluster_id, 3);
+        assert_eq!(Octopus::recommended_capacity(cluster_id), Some(3));
+        MinSamplesForScaling::set(1);
+    });
+}
+
+// ============================================================================
+// Operator Subnode Limit Tests
+// ============================================================================
+
+#[test]
+fn operator_at_subnode_limit_cannot_register_more() {
+    new_test_ext().execute_with(|| {
+        MaxSubnodesPerOperator::set(2);
+        assert_ok!(Octopus::create_cluster(
+            RuntimeOrigin::signed(1),
+            account_to_actor(1)
+        ));
+        assert_ok!(Octopus::create_cluster(
+            RuntimeOrigin::signed(3),
+            account_to_actor(3)
+        ));
+        register_subnodes(ClusterId::new(0), 1);
+        register_subnodes(ClusterId::new(1), 1);
+
+        for cluster in [0, 1] {
+            assert_noop!(
+                Octopus::register_subnode(
+                    RuntimeOrigin::signed(2),
+                    ClusterId::new(cluster),
+                    account_to_actor(2),
+                    None
+                ),
+                Error::<Test>::OperatorSubnodeLimitReached
+            );
+        }
+    });
+}
+
+#[test]
+fn operator_below_subnode_limit_can_register() {
+    new_test_ext().execute_with(|| {
+        MaxSubnodesPerOperator::set(2);
+        assert_ok!(Octopus::create_cluster(
+            RuntimeOrigin::signed(1),
+            account_to_actor(1)
+        ));
+        let cluster_id = ClusterId::new(0);
+        register_subnodes(cluster_id, 2);
+
+        assert_ok!(Octopus::register_subnode(
+            RuntimeOrigin::signed(4),
+            cluster_id,
+            account_to_actor(4),
+            None
+        ));
+        assert_eq!(
+            Octopus::operator_subnodes(account_to_actor(4), SubnodeId::new(2)),
+            Some(())
+        );
+    });
+}
+
+// ============================================================================
+// Presence-Aware Scaling Tests
+// ============================================================================
+
+/// One active subnode in cluster 0 with a single zero-throughput sample.
+fn setup_idle_cluster() -> ClusterId {
+    assert_ok!(Octopus::create_cluster(
+        RuntimeOrigin::signed(1),
+        account_to_actor(1)
+    ));
+    let cluster_id = ClusterId::new(0);
+    register_subnodes(cluster_id, 1);
+    assert_ok!(Octopus::activate_subnode(
+        RuntimeOrigin::signed(2),
+        SubnodeId::new(0)
+    ));
+    assert_ok!(Octopus::update_throughput(
+        RuntimeOrigin::root(),
+        cluster_id,
+        Perbill::zero()
+    ));
+    cluster_id
+}
+
+#[test]
+fn presence_surge_scales_up_without_throughput() {
+    new_test_ext().execute_with(|| {
+        PresenceAwareScaling::set(true);
+        let cluster_id = setup_idle_cluster();
+
+        CurrentPresenceCount::set(10);
+        assert_eq!(
+            Octopus::is_scaling_needed(cluster_id),
+            Some(ScalingDecision::Maintain)
+        );
+
+        // 35 presences at 10 per subnode need 4 subnodes.
+        CurrentPresenceCount::set(35);
+        assert_eq!(
+            Octopus::is_scaling_needed(cluster_id),
+            Some(ScalingDecision::ScaleUp(4))
+        );
+
+        System::set_block_number(15);
+        assert_ok!(Octopus::evaluate_scaling(
+            RuntimeOrigin::signed(1),
+            cluster_id
+        ));
+        System::assert_last_event(
+            Event::ScalingDecisionMade {
+                cluster_id,
+                decision: ScalingDecision::ScaleUp(4),
+                throughput: Perbill::zero(),
+            }
+            .into(),
+        );
+
+        // Capped at the cluster's own `max_subnodes`.
+        CurrentPresenceCount::set(1_000);
+        assert_eq!(
+            Octopus::is_scaling_needed(cluster_id),
+            Some(ScalingDecision::ScaleUp(MaxSubnodesPerCluster::get()))
+        );
+        assert_ok!(Octopus::set_cluster_max_subnodes(
+            RuntimeOrigin::root(),
+            cluster_id,
+            MaxSubnodesHardCeiling::get()
+        ));
+        assert_eq!(
+            Octopus::is_scaling_needed(cluster_id),
+            Some(ScalingDecision::ScaleUp(MaxSubnodesHardCeiling::get()))
+        );
+        CurrentPresenceCount::set(0);
+    });
+}
+
+#[test]
+fn presence_demand_split_across_clusters() {
+    new_test_ext().execute_with(|| {
+        PresenceAwareScaling::set(true);
+        let first = setup_idle_cluster();
+        assert_ok!(Octopus::create_cluster(
+            RuntimeOrigin::signed(1),
+            account_to_actor(1)
+        ));
+        let second = ClusterId::new(1);
+        register_subnodes(second, 1);
+        assert_ok!(Octopus::activate_subnode(
+            RuntimeOrigin::signed(2),
+            SubnodeId::new(1)
+        ));
+
+        // 40 presences over two equal clusters is 20 each, at 10 per subnode.
+        CurrentPresenceCount::set(40);
+        assert_eq!(
+            Octopus::is_scaling_needed(first),
+            Some(ScalingDecision::ScaleUp(2))
+        );
+        assert_eq!(
+            Octopus::is_scaling_needed(second),
+            Some(ScalingDecision::ScaleUp(2))
+        );
+        CurrentPresenceCount::set(0);
+    });
+}
+
+#[test]
+fn presence_ignored_when_presence_scaling_disabled() {
+    new_test_ext().execute_with(|| {
+        let cluster_id = setup_idle_cluster();
+        CurrentPresenceCount::set(1_000);
+
+        assert_eq!(
+            Octopus::is_scaling_needed(cluster_id),
+            Some(ScalingDecision::Maintain)
+        );
+        CurrentPresenceCount::set(0);
+    });
+}
+
+// ============================================================================
+// Device Reveal Tests
+// ============================================================================
+
+fn committed_reveal(subnode_id: SubnodeId) -> DeviceReveal {
+    let devices = [H256::repeat_byte(1), H256::repeat_byte(2)];
+    let reveal = DeviceReveal {
+        commitment_block: 1,
+        nonce: [9u8; 32],
+        device_merkle_root: DeviceCommitment::compute_device_merkle_root(&devices),
+        rssi_values: vec![-60, -70],
+        revealed_count: 2,
+    };
+    let commitment = DeviceCommitment::compute_commitment(
+        &reveal.device_merkle_root,
+        &reveal.nonce,
+        reveal.commitment_block,
+    );
+    assert_ok!(Octopus::record_device_observation(
+        RuntimeOrigin::signed(2),
+        subnode_id,
+        2,
+        commitment
+    ));
+    reveal
+}
+
+#[test]
+fn matching_reveal_verifies_observation() {
+    new_test_ext().execute_with(|| {
+        let subnode_id = setup_active_subnode();
+        bind_devices(subnode_id, 1..=2);
+        let reveal = committed_reveal(subnode_id);
+        let before = Octopus::fused_health(subnode_id).expect("health should exist");
+
+        assert_noop!(
+            Octopus::reveal_device_observation(
+                RuntimeOrigin::signed(1),
+                subnode_id,
+                reveal.clone()
+            ),
+            Error::<Test>::NotSubnodeOperator
+        );
+        assert_ok!(Octopus::reveal_device_observation(
+            RuntimeOrigin::signed(2),
+            subnode_id,
+            reveal.clone()
+        ));
+
+        let after = Octopus::fused_health(subnode_id).expect("health should exist");
+        assert_eq!(after.device_metrics.verified_observations, 1);
+        assert!(!after.device_metrics.reveal_pending);
+        assert!(after.device_metrics.device_score() > before.device_metrics.device_score());
+        System::assert_has_event(RuntimeEvent::Octopus(Event::DeviceObservationVerified {
+            subnode_id,
+            commitment: after.device_metrics.last_commitment.unwrap_or_default(),
+        }));
+
+        // The same observation cannot be verified twice.
+        assert_noop!(
+            Octopus::reveal_device_observation(RuntimeOrigin::signed(2), subnode_id, reveal),
+            Error::<Test>::NoPendingReveal
+        );
+    });
+}
+
+#[test]
+fn mismatched_reveal_rejected() {
+    new_test_ext().execute_with(|| {
+        let subnode_id = setup_active_subnode();
+        let mut reveal = committed_reveal(subnode_id);
+        reveal.nonce = [8u8; 32];
+
+        assert_noop!(
+            Octopus::reveal_device_observation(
+                RuntimeOrigin::signed(2),
+                subnode_id,
+                reveal.clone()
+            ),
+            Error::<Test>::InvalidCommitment
+        );
+
+        // A correct root under the wrong block does not open the commitment either.
+        reveal.nonce = [9u8; 32];
+        reveal.commitment_block = 2;
+        assert_noop!(
+            Octopus::reveal_device_observation(RuntimeOrigin::signed(2), subnode_id, reveal),
+            Error::<Test>::InvalidCommitment
+        );
+        let health = Octopus::fused_health(subnode_id).expect("health should exist");
+        assert_eq!(health.device_metrics.verified_observations, 0);
+    });
+}
+
+// ============================================================================
+// Activation Heartbeat Tests
+// ============================================================================
+
+fn setup_inactive_subnode() -> SubnodeId {
+    assert_ok!(Octopus::create_cluster(
+        RuntimeOrigin::signed(1),
+        account_to_actor(1)
+    ));
+    register_subnodes(ClusterId::new(0), 1);
+    SubnodeId::new(0)
+}
+
+#[test]
+fn activation_succeeds_with_recent_heartbeat() {
+    new_test_ext().execute_with(|| {
+        ActivationHeartbeatWindow::set(5);
+        let subnode_id = setup_inactive_subnode();
+
+        System::set_block_number(20);
+        assert_ok!(Octopus::record_heartbeat(
+            RuntimeOrigin::signed(2),
+            subnode_id,
+            1
+        ));
+        System::set_block_number(25);
+        assert_ok!(Octopus::activate_subnode(
+            RuntimeOrigin::signed(2),
+            subnode_id
+        ));
+
+        let subnode = Octopus::subnodes(subnode_id).expect("subnode should exist");
+        assert_eq!(subnode.status, SubnodeStatus::Active);
+        assert_eq!(subnode.activated_at, Some(25));
+    });
+    ActivationHeartbeatWindow::set(0);
+}
+
+#[test]
+fn activation_fails_without_recent_heartbeat() {
+    new_test_ext().execute_with(|| {
+        ActivationHeartbeatWindow::set(5);
+        let subnode_id = setup_inactive_subnode();
+
+        // Registration counts as the first heartbeat, but it has gone stale.
+        System::set_block_number(7);
+        assert_noop!(
+            Octopus::activate_subnode(RuntimeOrigin::signed(2), subnode_id),
+            Error::<Test>::NoRecentHeartbeat
+        );
+
+        System::set_block_number(20);
+        assert_ok!(Octopus::record_heartbeat(
+            RuntimeOrigin::signed(2),
+            subnode_id,
+            1
+        ));
+        System::set_block_number(26);
+        assert_noop!(
+            Octopus::activate_subnode(RuntimeOrigin::signed(2), subnode_id),
+            Error::<Test>::NoRecentHeartbeat
+        );
+    });
+    ActivationHeartbeatWindow::set(0);
+}
@@ -17,6 +17,16 @@ pub trait WeightInfo {
     fn evaluate_scaling() -> Weight;
     fn update_subnode_throughput() -> Weight;
     fn record_heartbeat() -> Weight;
+    fn propose_cluster_transfer() -> Weight;
+    fn accept_cluster_transfer() -> Weight;
+    fn set_fusion_healing_config() -> Weight;
+    fn bind_device() -> Weight;
+    fn unbind_device() -> Weight;
+    fn set_position_device() -> Weight;
+    fn approve_position_device() -> Weight;
+    fn set_cluster_max_subnodes() -> Weight;
+    fn reveal_device_observation() -> Weight;
+    fn drain_cluster(n: u32) -> Weight;
 }
 
 pub struct SubstrateWeight<T>(PhantomData<T>);
@@ -30,7 +40,7 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 
     fn register_subnode() -> Weight {
         Weight::from_parts(35_000_000, 0)
-            .saturating_add(T::DbWeight::get().reads(2))
+            .saturating_add(T::DbWeight::get().reads(3))
             .saturating_add(T::DbWeight::get().writes(3))
     }
 
@@ -54,21 +64,84 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 
     fn evaluate_scaling() -> Weight {
         Weight::from_parts(35_000_000, 0)
-            .saturating_add(T::DbWeight::get().reads(2))
+            .saturating_add(T::DbWeight::get().reads(3))
             .saturating_add(T::DbWeight::get().writes(1))
     }
 
     fn update_subnode_throughput() -> Weight {
+        Weight::from_parts(25_000_000, 0)
+            .saturating_add(T::DbWeight::get().reads(2))
+            .saturating_add(T::DbWeight::get().writes(2))
+    }
+
+    fn record_heartbeat() -> Weight {
+        Weight::from_parts(25_000_000, 0)
+            .saturating_add(T::DbWeight::get().reads(1))
+            .saturating_add(T::DbWeight::get().writes(1))
+    }
+
+    fn propose_cluster_transfer() -> Weight {
         Weight::from_parts(20_000_000, 0)
             .saturating_add(T::DbWeight::get().reads(1))
             .saturating_add(T::DbWeight::get().writes(1))
     }
 
-    fn record_heartbeat() -> Weight {
+    fn accept_cluster_transfer() -> Weight {
         Weight::from_parts(25_000_000, 0)
-            .saturating_add(T::DbWeight::get().reads(1))
+            .saturating_add(T::DbWeight::get().reads(2))
+            .saturating_add(T::DbWeight::get().writes(2))
+    }
+
+    fn set_fusion_healing_config() -> Weight {
+        Weight::from_parts(10_000_000, 0).saturating_add(T::DbWeight::get().writes(1))
+    }
+
+    fn bind_device() -> Weight {
+        Weight::from_parts(30_000_000, 0)
+            .saturating_add(T::DbWeight::get().reads(5))
+            .saturating_add(T::DbWeight::get().writes(3))
+    }
+
+    fn unbind_device() -> Weight {
+        Weight::from_parts(25_000_000, 0)
+            .saturating_add(T::DbWeight::get().reads(2))
+            .saturating_add(T::DbWeight::get().writes(3))
+    }
+
+    fn set_position_device() -> Weight {
+        Weight::from_parts(25_000_000, 0)
+            .saturating_add(T::DbWeight::get().reads(3))
+            .saturating_add(T::DbWeight::get().writes(4))
+    }
+
+    fn approve_position_device() -> Weight {
+        Weight::from_parts(25_000_000, 0)
+            .saturating_add(T::DbWeight::get().reads(5))
+            .saturating_add(T::DbWeight::get().writes(5))
+    }
+
+    fn set_cluster_max_subnodes() -> Weight {
+        Weight::from_parts(20_000_000, 0)
+            .saturating_add(T::DbWeight::get().reads(2))
             .saturating_add(T::DbWeight::get().writes(1))
     }
+
+    fn reveal_device_observation() -> Weight {
+        Weight::from_parts(30_000_000, 0)
+            .saturating_add(T::DbWeight::get().reads(3))
+            .saturating_add(T::DbWeight::get().writes(1))
+    }
+
+    fn drain_cluster(n: u32) -> Weight {
+        // Reads: Clusters, then each subnode in the cluster
+        // Writes: DrainingClusters, SLA flags, Clusters, then each deactivating subnode
+        Weight::from_parts(20_000_000, 0)
+            .saturating_add(Weight::from_parts(10_000_000, 0).saturating_mul(n as u64))
+            .saturating_add(T::DbWeight::get().reads(1))
+            .saturating_add(T::DbWeight::get().reads(n as u64))
+            .saturating_add(T::DbWeight::get().writes(4))
+            .saturating_add(T::DbWeight::get().writes(n as u64))
+    }
 }
 
 impl WeightInfo for () {
@@ -80,7 +153,7 @@ impl WeightInfo for () {
 
     fn register_subnode() -> Weight {
         Weight::from_parts(35_000_000, 0)
-            .saturating_add(RocksDbWeight::get().reads(2))
+            .saturating_add(RocksDbWeight::get().reads(3))
             .saturating_add(RocksDbWeight::get().writes(3))
     }
 
@@ -104,19 +177,80 @@ impl WeightInfo for () {
 
     fn evaluate_scaling() -> Weight {
         Weight::from_parts(35_000_000, 0)
-            .saturating_add(RocksDbWeight::get().reads(2))
+            .saturating_add(RocksDbWeight::get().reads(3))
             .saturating_add(RocksDbWeight::get().writes(1))
     }
 
     fn update_subnode_throughput() -> Weight {
+        Weight::from_parts(25_000_000, 0)
+            .saturating_add(RocksDbWeight::get().reads(2))
+            .saturating_add(RocksDbWeight::get().writes(2))
+    }
+
+    fn record_heartbeat() -> Weight {
+        Weight::from_parts(25_000_000, 0)
+            .saturating_add(RocksDbWeight::get().reads(1))
+            .saturating_add(RocksDbWeight::get().writes(1))
+    }
+
+    fn propose_cluster_transfer() -> Weight {
         Weight::from_parts(20_000_000, 0)
             .saturating_add(RocksDbWeight::get().reads(1))
             .saturating_add(RocksDbWeight::get().writes(1))
     }
 
-    fn record_heartbeat() -> Weight {
+    fn accept_cluster_transfer() -> Weight {
         Weight::from_parts(25_000_000, 0)
-            .saturating_add(RocksDbWeight::get().reads(1))
+            .saturating_add(RocksDbWeight::get().reads(2))
+            .saturating_add(RocksDbWeight::get().writes(2))
+    }
+
+    fn set_fusion_healing_config() -> Weight {
+        Weight::from_parts(10_000_000, 0).saturating_add(RocksDbWeight::get().writes(1))
+    }
+
+    fn bind_device() -> Weight {
+        Weight::from_parts(30_000_000, 0)
+            .saturating_add(RocksDbWeight::get().reads(5))
+            .saturating_add(RocksDbWeight::get().writes(3))
+    }
+
+    fn unbind_device() -> Weight {
+        Weight::from_parts(25_000_000, 0)
+            .saturating_add(RocksDbWeight::get().reads(2))
+            .saturating_add(RocksDbWeight::get().writes(3))
+    }
+
+    fn set_position_device() -> Weight {
+        Weight::from_parts(25_000_000, 0)
+            .saturating_add(RocksDbWeight::get().reads(3))
+            .saturating_add(RocksDbWeight::get().writes(4))
+    }
+
+    fn approve_position_device() -> Weight {
+        Weight::from_parts(25_000_000, 0)
+            .saturating_add(RocksDbWeight::get().reads(5))
+            .saturating_add(RocksDbWeight::get().writes(5))
+    }
+
+    fn set_cluster_max_subnodes() -> Weight {
+        Weight::from_parts(20_000_000, 0)
+            .saturating_add(RocksDbWeight::get().reads(2))
             .saturating_add(RocksDbWeight::get().writes(1))
     }
+
+    fn reveal_device_observation() -> Weight {
+        Weight::from_parts(30_000_000, 0)
+            .saturating_add(RocksDbWeight::get().reads(3))
+            .saturating_add(RocksDbWeight::get().writes(1))
+    }
+
+    fn drain_cluster(n: u32) -> Weight {
+        Weight::from_parts(20_000_000, 0)
+            .saturating_add(Weight::from_parts(10_000_000, 0).saturating_mul(n as u64))
+            .saturating_add(RocksDbWeight::get().reads(1))
+            .saturating_add(RocksDbWeight::get().reads(n as u64))
+            .saturating_add(RocksDbWeight::get().writes(4))
+            .saturating_add(RocksDbWeight::get().writes(n as u64))
+    }
 }
@@ -18,18 +18,21 @@ pub mod pallet {
         traits::{Get, StorageVersion},
     };
     use frame_system::pallet_prelude::*;
-    use seveny_primitives::traits::{ConstantTimeEq, EpochProvider, ValidatorProvider};
+    use seveny_primitives::traits::{
+        ActorStatusProvider, ConstantTimeEq, DeviceRegistry, EpochProvider, PresenceDisputes,
+        ValidatorAggregateVerifier, ValidatorProvider,
+    };
     use seveny_primitives::{
         types::{
-            ActorId, BlockRef, EpochId, PresenceRecord, PresenceState, QuorumConfig, ValidatorId,
-            Vote,
+            ActorId, BlockRef, EpochId, PresenceRecord, PresenceState, QuorumConfig, QuorumMode,
+            ValidatorId, Vote,
         },
         witness::{
             triangulate_from_witnesses, LatencyMeasurement, PositionClaim, WitnessAttestation,
         },
-        Position, PresenceCommitment,
+        Nullifier, Position, PresenceCommitment,
     };
-    use sp_runtime::Saturating;
+    use sp_runtime::{traits::Zero, Perbill, Saturating};
 
     use crate::WeightInfo;
 
@@ -49,6 +52,15 @@ pub mod pallet {
         /// Validator set provider -- reads from canonical validator pallet.
         type ValidatorProvider: seveny_primitives::traits::ValidatorProvider;
 
+        /// Verifies aggregate validator signatures for `submit_aggregate_votes`.
+        type AggregateVoteVerifier: ValidatorAggregateVerifier<AggregateSig: Parameter>;
+
+        /// Device registry consulted when `RequireActiveDevice` is set.
+        type DeviceRegistry: DeviceRegistry;
+
+        /// Lifecycle status source consulted when `RequireActiveActor` is set.
+        type ActorStatusProvider: ActorStatusProvider;
+
         #[pallet::constant]
         type MaxVotesPerPresence: Get<u32>;
 
@@ -64,6 +76,19 @@ pub mod pallet {
         #[pallet::constant]
         type RevealWindow: Get<BlockNumberFor<Self>>;
 
+        /// Minimum share of an epoch's commitments that must be revealed before
+        /// commitment-based presences in it can finalize. Zero disables the check.
+        #[pallet::constant]
+        type MinEpochRevealRatio: Get<Perbill>;
+
+        /// Whether mismatched reveals accrue strikes that can slash the presence.
+        #[pallet::constant]
+        type RevealFailurePenaltyEnabled: Get<bool>;
+
+        /// Strikes after which the actor's presence in the failing epoch is slashed.
+        #[pallet::constant]
+        type MaxRevealFailures: Get<u32>;
+
         // Position-Based Triangulation Configuration
 
         /// Minimum number of witness attestations required to verify a position.
@@ -73,6 +98,52 @@ pub mod pallet {
         /// Position tolerance in meters for verification.
         #[pallet::constant]
         type PositionToleranceMeters: Get<u32>;
+
+        /// Minimum blocks between two declarations by the same actor.
+        #[pallet::constant]
+        type DeclarationCooldownBlocks: Get<BlockNumberFor<Self>>;
+
+        /// Maximum validated presences finalized by one `on_idle` pass.
+        #[pallet::constant]
+        type MaxIdleFinalizations: Get<u32>;
+
+        /// Whether declaring presence requires the actor to own an active device.
+        #[pallet::constant]
+        type RequireActiveDevice: Get<bool>;
+
+        /// Whether declaring presence requires the actor to be lifecycle-`Active`.
+        #[pallet::constant]
+        type RequireActiveActor: Get<bool>;
+
+        /// Commitments an epoch needs before its reveal phase may open. Zero disables
+        /// the requirement.
+        #[pallet::constant]
+        type MinCommitmentsForReveal: Get<u32>;
+
+        /// Blocks after the first commitment at which the reveal phase opens even
+        /// without `MinCommitmentsForReveal`; never earlier than the commit-reveal delay.
+        #[pallet::constant]
+        type MaxCommitDuration: Get<BlockNumberFor<Self>>;
+
+        /// Dispute backend that records challenges raised against finalized presences.
+        type PresenceDisputes: PresenceDisputes<Self::AccountId>;
+
+        /// Blocks after finalization during which a presence may be challenged.
+        #[pallet::constant]
+        type ChallengeWindow: Get<BlockNumberFor<Self>>;
+
+        /// Maximum presences in one `finalize_presences_batch` call.
+        #[pallet::constant]
+        type MaxFinalizeBatch: Get<u32>;
+
+        /// Presences one epoch may hold, bounding per-epoch state.
+        #[pallet::constant]
+        type MaxPresencesPerEpoch: Get<u32>;
+
+        /// Challenges one validator may hold open at once, bounding how many of the
+        /// dispute backend's open slots a single challenger can occupy.
+        #[pallet::constant]
+        type MaxOpenChallengesPerValidator: Get<u32>;
     }
 
     #[pallet::storage]
@@ -105,10 +176,58 @@ pub mod pallet {
     pub type VoteCount<T: Config> =
         StorageDoubleMap<_, Blake2_128Concat, EpochId, Blake2_128Concat, ActorId, u32, ValueQuery>;
 
+    /// Block of each actor's most recent declaration, for the declaration cooldown.
+    #[pallet::storage]
+    #[pallet::getter(fn last_declaration)]
+    pub type LastDeclaration<T: Config> =
+        StorageMap<_, Blake2_128Concat, ActorId, BlockNumberFor<T>>;
+
     #[pallet::storage]
     #[pallet::getter(fn presence_count)]
     pub type PresenceCount<T: Config> = StorageMap<_, Blake2_128Concat, EpochId, u32, ValueQuery>;
 
+    /// Block at which each epoch reached `MinCommitmentsForReveal` commitments.
+    #[pallet::storage]
+    #[pallet::getter(fn epoch_min_commitments_at)]
+    pub type EpochMinCommitmentsAt<T: Config> =
+        StorageMap<_, Blake2_128Concat, EpochId, BlockNumberFor<T>>;
+
+    /// Nullifiers consumed by commitment declarations, one per person per epoch.
+    #[pallet::storage]
+    #[pallet::getter(fn epoch_nullifiers)]
+    pub type EpochNullifiers<T: Config> =
+        StorageDoubleMap<_, Blake2_128Concat, EpochId, Blake2_128Concat, Nullifier, ()>;
+
+    /// Presences finalized through `emergency_finalize`, with the vote count they
+    /// had when quorum was bypassed.
+    #[pallet::storage]
+    #[pallet::getter(fn emergency_finalized)]
+    pub type EmergencyFinalized<T: Config> =
+        StorageDoubleMap<_, Blake2_128Concat, EpochId, Blake2_128Concat, ActorId, u32>;
+
+    /// Dispute id of each presence currently in the `Challenged` state.
+    #[pallet::storage]
+    #[pallet::getter(fn presence_challenges)]
+    pub type PresenceChallenges<T: Config> =
+        StorageDoubleMap<_, Blake2_128Concat, EpochId, Blake2_128Concat, ActorId, u64>;
+
+    /// Validator that raised each open presence challenge.
+    #[pallet::storage]
+    pub type PresenceChallengers<T: Config> =
+        StorageDoubleMap<_, Blake2_128Concat, EpochId, Blake2_128Concat, ActorId, ValidatorId>;
+
+    /// Open presence challenges per challenging validator.
+    #[pallet::storage]
+    #[pallet::getter(fn open_challenge_count)]
+    pub type OpenChallengeCount<T: Config> =
+        StorageMap<_, Blake2_128Concat, ValidatorId, u32, ValueQuery>;
+
+    /// Presences cleared by a rejected challenge; they cannot be challenged again.
+    #[pallet::storage]
+    #[pallet::getter(fn challenge_cleared)]
+    pub type ChallengeCleared<T: Config> =
+        StorageDoubleMap<_, Blake2_128Concat, EpochId, Blake2_128Concat, ActorId, (), OptionQuery>;
+
     #[pallet::storage]
     #[pallet::getter(fn declarations)]
     pub type Declarations<T: Config> = StorageDoubleMap<
@@ -125,11 +244,39 @@ pub mod pallet {
     #[pallet::getter(fn quorum_config)]
     pub type QuorumConfigStorage<T: Config> = StorageValue<_, QuorumConfig, ValueQuery>;
 
+    /// Quorum mode overriding the absolute threshold in `QuorumConfigStorage`.
+    #[pallet::storage]
+    #[pallet::getter(fn quorum_mode_override)]
+    pub type QuorumModeOverride<T: Config> = StorageValue<_, QuorumMode, OptionQuery>;
+
     #[pallet::storage]
     #[pallet::getter(fn epoch_commit_start)]
     pub type EpochCommitStart<T: Config> =
         StorageMap<_, Blake2_128Concat, EpochId, BlockNumberFor<T>, OptionQuery>;
 
+    /// Per-epoch `(commit_reveal_delay, reveal_window)` overriding the global constants.
+    #[pallet::storage]
+    #[pallet::getter(fn epoch_commit_reveal_config)]
+    pub type EpochCommitRevealConfig<T: Config> = StorageMap<
+        _,
+        Blake2_128Concat,
+        EpochId,
+        (BlockNumberFor<T>, BlockNumberFor<T>),
+        OptionQuery,
+    >;
+
+    /// Consecutive finalized epochs per actor and the last epoch counted.
+    #[pallet::storage]
+    #[pallet::getter(fn presence_streak)]
+    pub type PresenceStreak<T: Config> =
+        StorageMap<_, Blake2_128Concat, ActorId, (u32, EpochId), OptionQuery>;
+
+    /// Mismatched reveals per actor since their last penalty.
+    #[pallet::storage]
+    #[pallet::getter(fn reveal_failure_strikes)]
+    pub type RevealFailureStrikes<T: Config> =
+        StorageMap<_, Blake2_128Concat, ActorId, u32, ValueQuery>;
+
     #[pallet::storage]
     #[pallet::getter(fn commitment_count)]
     pub type CommitmentCount<T: Config> = StorageMap<_, Blake2_128Concat, EpochId, u32, ValueQuery>;
@@ -181,6 +328,11 @@ pub mod pallet {
     pub type ValidatorPositions<T: Config> =
         StorageMap<_, Blake2_128Concat, ValidatorId, Position, OptionQuery>;
 
+    /// Raw `Presences` key where the next `on_idle` finalization pass resumes.
+    #[pallet::storage]
+    pub type IdleFinalizeCursor<T: Config> =
+        StorageValue<_, BoundedVec<u8, ConstU32<128>>, OptionQuery>;
+
     #[derive(
         Clone,
         PartialEq,
@@ -263,10 +415,29 @@ pub mod pallet {
             actor: ActorId,
             epoch: EpochId,
         },
+        /// A presence was finalized by root without meeting quorum
+        PresenceEmergencyFinalized {
+            actor: ActorId,
+            epoch: EpochId,
+            vote_count: u32,
+        },
         QuorumConfigUpdated {
             threshold: u32,
             total: u32,
         },
+        QuorumModeUpdated {
+            mode: QuorumMode,
+        },
+        AggregateVotesSubmitted {
+            actor: ActorId,
+            epoch: EpochId,
+            votes: u32,
+        },
+        EpochCommitRevealConfigured {
+            epoch: EpochId,
+            delay: BlockNumberFor<T>,
+            window: BlockNumberFor<T>,
+        },
         CommitmentSubmitted {
             actor: ActorId,
             epoch: EpochId,
@@ -282,6 +453,11 @@ pub mod pallet {
             epoch: EpochId,
             reason: RevealFailureReason,
         },
+        RevealFailurePenalized {
+            actor: ActorId,
+            epoch: EpochId,
+            strikes: u32,
+        },
         // Position-Based Triangulation Events
         PositionClaimed {
             actor: ActorId,
@@ -315,6 +491,24 @@ pub mod pallet {
             validator: ValidatorId,
             position: Position,
         },
+        /// A validator challenged a finalized presence and a dispute was opened
+        PresenceChallenged {
+            actor: ActorId,
+            epoch: EpochId,
+            challenger: ValidatorId,
+            dispute_id: u64,
+        },
+        /// A challenge was resolved; `upheld` presences are now slashed
+        PresenceChallengeResolved {
+            actor: ActorId,
+            epoch: EpochId,
+            upheld: bool,
+        },
+        /// Outcome of a `finalize_presences_batch` call
+        PresencesBatchFinalized {
+            finalized: u32,
+            skipped: u32,
+        },
     }
 
     #[derive(
@@ -350,6 +544,8 @@ pub mod pallet {
         EpochNotActive,
         ValidatorNotActive,
         InvalidQuorumConfig,
+        /// Reveal window must be non-zero
+        InvalidRevealWindow,
         ActorNotFound,
         PresenceNotValidated,
         ArithmeticOverflow,
@@ -376,8 +572,38 @@ pub mod pallet {
         MaxVotesExceeded,
         /// M14: Commitment exists but has not been revealed before finalization.
         CommitmentNotRevealed,
+        /// Too few of the epoch's commitments have been revealed to finalize.
+        EpochRevealRatioTooLow,
         /// Actor must have a presence declaration before claiming position
         PresenceDeclarationRequired,
+        /// Actor declared again before `DeclarationCooldownBlocks` elapsed
+        DeclarationTooFrequent,
+        /// Aggregate vote lists no validators
+        EmptyAggregate,
+        /// Aggregate signature does not verify for the listed validators
+        InvalidAggregateSignature,
+        /// Nullifier was already used by a declaration in this epoch
+        NullifierReused,
+        /// Actor has no active device registered
+        NoActiveDevice,
+        /// Actor is not in the `Active` lifecycle state
+        ActorNotActive,
+        /// Only finalized presences can be challenged
+        PresenceNotFinalized,
+        /// `ChallengeWindow` has passed since the presence was finalized
+        ChallengeWindowClosed,
+        /// Epoch already holds `MaxPresencesPerEpoch` presences
+        EpochPresenceCapReached,
+        /// The dispute backend did not open a dispute
+        ChallengeRejected,
+        /// Presence is under challenge and cannot change until it is resolved
+        PresenceUnderChallenge,
+        /// Presence is not under challenge
+        PresenceNotChallenged,
+        /// Validator already holds `MaxOpenChallengesPerValidator` open challenges
+        TooManyOpenChallenges,
+        /// Presence already survived a challenge
+        PresenceAlreadyCleared,
     }
 
     #[pallet::genesis_config]
@@ -407,6 +633,13 @@ pub mod pallet {
         }
     }
 
+    #[pallet::hooks]
+    impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
+        fn on_idle(n: BlockNumberFor<T>, remaining_weight: Weight) -> Weight {
+            Self::finalize_validated_on_idle(n, remaining_weight)
+        }
+    }
+
     #[pallet::call]
     impl<T: Config> Pallet<T> {
         #[pallet::call_index(0)]
@@ -416,8 +649,12 @@ pub mod pallet {
             let actor = Self::account_to_actor(&who);
             let block_number = frame_system::Pallet::<T>::block_number();
 
-            Self::ensure_epoch_active(&epoch)?;
+            let late = Self::ensure_epoch_accepts_declarations(&epoch)?;
             Self::ensure_no_duplicate_presence(&epoch, &actor)?;
+            Self::ensure_epoch_capacity(&epoch)?;
+            Self::ensure_declaration_cooldown(&actor, block_number)?;
+            Self::ensure_active_actor(&actor)?;
+            Self::ensure_active_device(&actor)?;
 
             let record = PresenceRecord {
                 actor,
@@ -427,9 +664,11 @@ pub mod pallet {
                 validated_at: None,
                 finalized_at: None,
                 vote_count: 0,
+                late,
             };
 
             Presences::<T>::insert(epoch, actor, record);
+            LastDeclaration::<T>::insert(actor, block_number);
             PresenceCount::<T>::mutate(epoch, |count| {
                 *count = count.saturating_add(1);
             });
@@ -449,14 +688,25 @@ pub mod pallet {
             origin: OriginFor<T>,
             epoch: EpochId,
             commitment: PresenceCommitment,
+            nullifier: Nullifier,
         ) -> DispatchResult {
             let who = ensure_signed(origin)?;
             let actor = Self::account_to_actor(&who);
             let block_number = frame_system::Pallet::<T>::block_number();
             let block_hash = frame_system::Pallet::<T>::block_hash(block_number);
 
-            Self::ensure_epoch_active(&epoch)?;
+            let late = Self::ensure_epoch_accepts_declarations(&epoch)?;
             Self::ensure_no_duplicate_presence(&epoch, &actor)?;
+            Self::ensure_epoch_capacity(&epoch)?;
+            // One presence per person: the nullifier is derived from the root
+            // secret, so derived identities share it within an epoch.
+            ensure!(
+                !EpochNullifiers::<T>::contains_key(epoch, nullifier),
+                Error::<T>::NullifierReused
+            );
+            Self::ensure_declaration_cooldown(&actor, block_number)?;
+            Self::ensure_active_actor(&actor)?;
+            Self::ensure_active_device(&actor)?;
 
             let block_num: u64 = block_number
                 .try_into()
@@ -476,14 +726,19 @@ pub mod pallet {
             };
 
             Declarations::<T>::insert(epoch, actor, declaration);
+            EpochNullifiers::<T>::insert(epoch, nullifier, ());
 
             if EpochCommitStart::<T>::get(epoch).is_none() {
                 EpochCommitStart::<T>::insert(epoch, block_number);
             }
 
-            CommitmentCount::<T>::mutate(epoch, |count| {
+            let commitments = CommitmentCount::<T>::mutate(epoch, |count| {
                 *count = count.saturating_add(1);
+                *count
             });
+            if commitments == T::MinCommitmentsForReveal::get() {
+                EpochMinCommitmentsAt::<T>::insert(epoch, block_number);
+            }
 
             let record = PresenceRecord {
                 actor,
@@ -493,9 +748,11 @@ pub mod pallet {
                 validated_at: None,
                 finalized_at: None,
                 vote_count: 0,
+                late,
             };
 
             Presences::<T>::insert(epoch, actor, record);
+            LastDeclaration::<T>::insert(actor, block_number);
             PresenceCount::<T>::mutate(epoch, |count| {
                 *count = count.saturating_add(1);
             });
@@ -520,10 +777,10 @@ pub mod pallet {
             let who = ensure_signed(origin)?;
             let validator = Self::account_to_validator(&who);
             let block_number = frame_system::Pallet::<T>::block_number();
-            let block_hash = frame_system::Pallet::<T>::block_hash(block_number);
 
             Self::ensure_validator_active(&validator)?;
-            Self::ensure_epoch_active(&epoch)?;
+            let record = Presences::<T>::get(epoch, actor);
+            Self::ensure_epoch_open_for_record(&epoch, record.as_ref().is_some_and(|r| r.late))?;
             Self::ensure_no_duplicate_vote(&epoch, &actor, &validator)?;
 
             let current_votes = VoteCount::<T>::get(epoch, actor);
@@ -532,20 +789,12 @@ pub mod pallet {
                 Error::<T>::MaxVotesExceeded
             );
 
-            let mut record =
-                Presences::<T>::get(epoch, actor).ok_or(Error::<T>::PresenceNotFound)?;
+            let mut record = record.ok_or(Error::<T>::PresenceNotFound)?;
 
             Self::ensure_not_terminal(&record.state)?;
             Self::ensure_valid_vote_state(&record.state)?;
 
-            let block_num: u64 = block_number
-                .try_into()
-                .map_err(|_| Error::<T>::BlockRefConversionFailed)?;
-            let hash_bytes: [u8; 32] = block_hash
-                .as_ref()
-                .try_into()
-                .map_err(|_| Error::<T>::BlockRefConversionFailed)?;
-            let block_ref = BlockRef::new(block_num, sp_core::H256(hash_bytes));
+            let block_ref = Self::current_block_ref()?;
 
             let vote = Vote {
                 validator,
@@ -561,8 +810,7 @@ pub mod pallet {
                 record.vote_count = record.vote_count.saturating_add(1);
                 VoteCount::<T>::insert(epoch, actor, record.vote_count);
 
-                let quorum = QuorumConfigStorage::<T>::get();
-                if quorum.is_met(record.vote_count) && record.state == PresenceState::Declared {
+                if Self::quorum_met(record.vote_count) && record.state == PresenceState::Declared {
                     record.state = PresenceState::Validated;
                     record.validated_at = Some(block_number);
 
@@ -605,33 +853,16 @@ pub mod pallet {
                 Error::<T>::UnauthorizedDeclaration
             );
 
-            let mut record =
-                Presences::<T>::get(epoch, actor).ok_or(Error::<T>::PresenceNotFound)?;
+            let record = Presences::<T>::get(epoch, actor).ok_or(Error::<T>::PresenceNotFound)?;
 
             Self::ensure_not_terminal(&record.state)?;
             ensure!(
                 record.state == PresenceState::Validated,
                 Error::<T>::PresenceNotValidated
             );
+            Self::ensure_finalizable(epoch, actor, &record)?;
 
-            let quorum = QuorumConfigStorage::<T>::get();
-            ensure!(quorum.is_met(record.vote_count), Error::<T>::QuorumNotMet);
-
-            // M14: if a commitment was submitted, it must be revealed before finalization
-            if let Some(declaration) = Declarations::<T>::get(epoch, actor) {
-                ensure!(declaration.revealed, Error::<T>::CommitmentNotRevealed);
-            }
-
-            record.state = PresenceState::Finalized;
-            record.finalized_at = Some(block_number);
-
-            Presences::<T>::insert(epoch, actor, record);
-
-            Self::deposit_event(Event::PresenceFinalized {
-                actor,
-                epoch,
-                block_number,
-            });
+            Self::do_finalize(epoch, actor, record, block_number);
 
             Ok(())
         }
@@ -671,6 +902,7 @@ pub mod pallet {
             ensure!(config.is_valid(), Error::<T>::InvalidQuorumConfig);
 
             QuorumConfigStorage::<T>::put(config);
+            QuorumModeOverride::<T>::kill();
 
             Self::deposit_event(Event::QuorumConfigUpdated { threshold, total });
 
@@ -689,7 +921,8 @@ pub mod pallet {
             let actor = Self::account_to_actor(&who);
             let block_number = frame_system::Pallet::<T>::block_number();
 
-            Self::ensure_epoch_active(&epoch)?;
+            let late = Presences::<T>::get(epoch, actor).is_some_and(|r| r.late);
+            Self::ensure_epoch_open_for_record(&epoch, late)?;
 
             let phase = Self::get_declaration_phase(epoch, block_number);
             ensure!(
@@ -710,6 +943,11 @@ pub mod pallet {
                     epoch,
                     reason: RevealFailureReason::CommitmentMismatch,
                 });
+                if T::RevealFailurePenaltyEnabled::get() {
+                    // The strike must persist, so the failure is not returned as an error.
+                    Self::record_reveal_failure(actor, epoch);
+                    return Ok(());
+                }
                 return Err(Error::<T>::CommitmentMismatch.into());
             }
 
@@ -960,6 +1198,313 @@ pub mod pallet {
 
             Ok(())
         }
+
+        /// Override the commit-reveal delay and reveal window for one epoch (root only).
+        #[pallet::call_index(13)]
+        #[pallet::weight(T::WeightInfo::set_epoch_commit_reveal_config())]
+        pub fn set_epoch_commit_reveal_config(
+            origin: OriginFor<T>,
+            epoch: EpochId,
+            delay: BlockNumberFor<T>,
+            window: BlockNumberFor<T>,
+        ) -> DispatchResult {
+            ensure_root(origin)?;
+            ensure!(!window.is_zero(), Error::<T>::InvalidRevealWindow);
+
+            EpochCommitRevealConfig::<T>::insert(epoch, (delay, window));
+
+            Self::deposit_event(Event::EpochCommitRevealConfigured {
+                epoch,
+                delay,
+                window,
+            });
+
+            Ok(())
+        }
+
+        /// Switch between an absolute vote threshold and a share of the active
+        /// validator set. `set_quorum_config` reverts to the absolute threshold.
+        #[pallet::call_index(14)]
+        #[pallet::weight(T::WeightInfo::set_quorum_mode())]
+        pub fn set_quorum_mode(origin: OriginFor<T>, mode: QuorumMode) -> DispatchResult {
+            ensure_root(origin)?;
+            ensure!(mode.is_valid(), Error::<T>::InvalidQuorumConfig);
+
+            QuorumModeOverride::<T>::put(mode);
+
+            Self::deposit_event(Event::QuorumModeUpdated { mode });
+
+            Ok(())
+        }
+
+        /// Record approving votes from every listed validator at once, authenticated
+        /// by one aggregate signature over [`Pallet::aggregate_vote_message`]. Any
+        /// invalid entry or a failed signature rejects the whole batch.
+        #[pallet::call_index(15)]
+        #[pallet::weight(T::WeightInfo::submit_aggregate_votes(validators.len() as u32))]
+        pub fn submit_aggregate_votes(
+            origin: OriginFor<T>,
+            actor: ActorId,
+            epoch: EpochId,
+            validators: BoundedVec<ValidatorId, T::MaxVotesPerPresence>,
+            agg_sig: <T::AggregateVoteVerifier as ValidatorAggregateVerifier>::AggregateSig,
+        ) -> DispatchResult {
+            ensure_signed(origin)?;
+            let block_number = frame_system::Pallet::<T>::block_number();
+
+            ensure!(!validators.is_empty(), Error::<T>::EmptyAggregate);
+            let record = Presences::<T>::get(epoch, actor);
+            Self::ensure_epoch_open_for_record(&epoch, record.as_ref().is_some_and(|r| r.late))?;
+
+            let mut record = record.ok_or(Error::<T>::PresenceNotFound)?;
+            Self::ensure_not_terminal(&record.state)?;
+            Self::ensure_valid_vote_state(&record.state)?;
+
+            let mut seen: Vec<ValidatorId> = Vec::with_capacity(validators.len());
+            for validator in validators.iter() {
+                ensure!(!seen.contains(validator), Error::<T>::DuplicateVote);
+                Self::ensure_validator_active(validator)?;
+                Self::ensure_no_duplicate_vote(&epoch, &actor, validator)?;
+                seen.push(*validator);
+            }
+
+            let added = validators.len() as u32;
+            ensure!(
+                VoteCount::<T>::get(epoch, actor).saturating_add(added)
+                    <= T::MaxVotesPerPresence::get(),
+                Error::<T>::MaxVotesExceeded
+            );
+
+            let message = Self::aggregate_vote_message(&actor, &epoch);
+            ensure!(
+                T::AggregateVoteVerifier::verify(&validators, message.as_bytes(), &agg_sig),
+                Error::<T>::InvalidAggregateSignature
+            );
+
+            let block_ref = Self::current_block_ref()?;
+            for validator in validators.iter().copied() {
+                let vote = Vote {
+                    validator,
+                    actor,
+                    epoch,
+                    block_ref,
+                    approve: true,
+                };
+                Votes::<T>::insert((epoch, actor, validator), vote);
+                Self::deposit_event(Event::PresenceVoted {
+                    validator,
+                    actor,
+                    epoch,
+                    approve: true,
+                });
+            }
+
+            record.vote_count = record.vote_count.saturating_add(added);
+            VoteCount::<T>::insert(epoch, actor, record.vote_count);
+
+            if Self::quorum_met(record.vote_count) {
+                record.state = PresenceState::Validated;
+                record.validated_at = Some(block_number);
+
+                Self::deposit_event(Event::PresenceValidated {
+                    actor,
+                    epoch,
+                    vote_count: record.vote_count,
+                });
+            }
+
+            Presences::<T>::insert(epoch, actor, record);
+
+            Self::deposit_event(Event::AggregateVotesSubmitted {
+                actor,
+                epoch,
+                votes: added,
+            });
+
+            Ok(())
+        }
+
+        /// Finalize a declared or validated presence without quorum (root only),
+        /// for when too few validators are online to vote. A commitment must still
+        /// have been revealed. The bypass is recorded in `EmergencyFinalized`.
+        #[pallet::call_index(16)]
+        #[pallet::weight(T::WeightInfo::emergency_finalize())]
+        pub fn emergency_finalize(
+            origin: OriginFor<T>,
+            actor: ActorId,
+            epoch: EpochId,
+        ) -> DispatchResult {
+            ensure_root(origin)?;
+            let block_number = frame_system::Pallet::<T>::block_number();
+
+            let record = Presences::<T>::get(epoch, actor).ok_or(Error::<T>::PresenceNotFound)?;
+            Self::ensure_not_terminal(&record.state)?;
+
+            if let Some(declaration) = Declarations::<T>::get(epoch, actor) {
+                ensure!(declaration.revealed, Error::<T>::CommitmentNotRevealed);
+            }
+
+            let vote_count = record.vote_count;
+            EmergencyFinalized::<T>::insert(epoch, actor, vote_count);
+            Self::do_finalize(epoch, actor, record, block_number);
+
+            Self::deposit_event(Event::PresenceEmergencyFinalized {
+                actor,
+                epoch,
+                vote_count,
+            });
+
+            Ok(())
+        }
+
+        /// Challenge a finalized presence within `ChallengeWindow` blocks of its
+        /// finalization (active validators only). Opens a dispute and holds the
+        /// presence in `Challenged` until `resolve_presence_challenge`. A presence
+        /// cleared by a rejected challenge cannot be challenged again.
+        #[pallet::call_index(17)]
+        #[pallet::weight(T::WeightInfo::challenge_presence())]
+        pub fn challenge_presence(
+            origin: OriginFor<T>,
+            actor: ActorId,
+            epoch: EpochId,
+            evidence_hash: sp_core::H256,
+        ) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+            let validator = Self::account_to_validator(&who);
+            let block_number = frame_system::Pallet::<T>::block_number();
+
+            Self::ensure_validator_active(&validator)?;
+
+            let mut record =
+                Presences::<T>::get(epoch, actor).ok_or(Error::<T>::PresenceNotFound)?;
+            ensure!(
+                record.state == PresenceState::Finalized,
+                Error::<T>::PresenceNotFinalized
+            );
+            let finalized_at = record.finalized_at.unwrap_or_else(Zero::zero);
+            ensure!(
+                block_number <= finalized_at.saturating_add(T::ChallengeWindow::get()),
+                Error::<T>::ChallengeWindowClosed
+            );
+            ensure!(
+                !ChallengeCleared::<T>::contains_key(epoch, actor),
+                Error::<T>::PresenceAlreadyCleared
+            );
+            let open_challenges = OpenChallengeCount::<T>::get(validator);
+            ensure!(
+                open_challenges < T::MaxOpenChallengesPerValidator::get(),
+                Error::<T>::TooManyOpenChallenges
+            );
+
+            let dispute_id =
+                T::PresenceDisputes::open_presence_dispute(&who, actor, epoch, evidence_hash)
+                    .ok_or(Error::<T>::ChallengeRejected)?;
+
+            record.state = PresenceState::Challenged;
+            Presences::<T>::insert(epoch, actor, record);
+            PresenceChallenges::<T>::insert(epoch, actor, dispute_id);
+            PresenceChallengers::<T>::insert(epoch, actor, validator);
+            OpenChallengeCount::<T>::insert(validator, open_challenges.saturating_add(1));
+
+            Self::deposit_event(Event::PresenceChallenged {
+                actor,
+                epoch,
+                challenger: validator,
+                dispute_id,
+            });
+
+            Ok(())
+        }
+
+        /// Settle a challenge (root only): `upheld` slashes the presence,
+        /// otherwise it returns to `Finalized`. Closes the backing dispute.
+        #[pallet::call_index(18)]
+        #[pallet::weight(T::WeightInfo::resolve_presence_challenge())]
+        pub fn resolve_presence_challenge(
+            origin: OriginFor<T>,
+            actor: ActorId,
+            epoch: EpochId,
+            upheld: bool,
+        ) -> DispatchResult {
+            ensure_root(origin)?;
+
+            let mut record =
+                Presences::<T>::get(epoch, actor).ok_or(Error::<T>::PresenceNotFound)?;
+            ensure!(
+                record.state == PresenceState::Challenged,
+                Error::<T>::PresenceNotChallenged
+            );
+
+            record.state = if upheld {
+                PresenceState::Slashed
+            } else {
+                PresenceState::Finalized
+            };
+            Presences::<T>::insert(epoch, actor, record);
+
+            if let Some(dispute_id) = PresenceChallenges::<T>::take(epoch, actor) {
+                T::PresenceDisputes::close_presence_dispute(dispute_id, upheld);
+            }
+            if let Some(challenger) = PresenceChallengers::<T>::take(epoch, actor) {
+                OpenChallengeCount::<T>::mutate(challenger, |count| {
+                    *count = count.saturating_sub(1)
+                });
+            }
+            if !upheld {
+                ChallengeCleared::<T>::insert(epoch, actor, ());
+            }
+
+            Self::deposit_event(Event::PresenceChallengeResolved {
+                actor,
+                epoch,
+                upheld,
+            });
+            if upheld {
+                Self::deposit_event(Event::PresenceSlashed { actor, epoch });
+            }
+
+            Ok(())
+        }
+
+        /// Finalize several presences at once. Entries the caller may not finalize,
+        /// that are not `Validated`, or that fail quorum or reveal checks are skipped
+        /// rather than failing the batch.
+        #[pallet::call_index(19)]
+        #[pallet::weight(T::WeightInfo::finalize_presences_batch(items.len() as u32))]
+        pub fn finalize_presences_batch(
+            origin: OriginFor<T>,
+            items: BoundedVec<(ActorId, EpochId), T::MaxFinalizeBatch>,
+        ) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+            let caller_actor = Self::account_to_actor(&who);
+            let caller_is_validator =
+                T::ValidatorProvider::is_validator_active(Self::account_to_validator(&who));
+            let block_number = frame_system::Pallet::<T>::block_number();
+
+            let mut finalized = 0u32;
+            let mut skipped = 0u32;
+            for (actor, epoch) in items {
+                let eligible = (caller_is_validator || caller_actor == actor)
+                    .then(|| Presences::<T>::get(epoch, actor))
+                    .flatten()
+                    .filter(|record| {
+                        record.state == PresenceState::Validated
+                            && Self::ensure_finalizable(epoch, actor, record).is_ok()
+                    });
+
+                match eligible {
+                    Some(record) => {
+                        Self::do_finalize(epoch, actor, record, block_number);
+                        finalized = finalized.saturating_add(1);
+                    }
+                    None => skipped = skipped.saturating_add(1),
+                }
+            }
+
+            Self::deposit_event(Event::PresencesBatchFinalized { finalized, skipped });
+
+            Ok(())
+        }
     }
 
     impl<T: Config> Pallet<T> {
@@ -982,6 +1527,30 @@ pub mod pallet {
             Ok(())
         }
 
+        /// Accept declarations while the epoch is active, or during its
+        /// grace period after closing. Returns whether the declaration
+        /// is late.
+        fn ensure_epoch_accepts_declarations(epoch: &EpochId) -> Result<bool, DispatchError> {
+            if T::EpochProvider::is_epoch_active(*epoch) {
+                return Ok(false);
+            }
+            ensure!(
+                T::EpochProvider::is_within_grace_period(*epoch),
+                Error::<T>::EpochNotActive
+            );
+            Ok(true)
+        }
+
+        /// Accept follow-ups to a record while the epoch is active; a late record
+        /// keeps accepting them until the grace period closes.
+        fn ensure_epoch_open_for_record(epoch: &EpochId, late: bool) -> DispatchResult {
+            if late {
+                Self::ensure_epoch_accepts_declarations(epoch).map(|_| ())
+            } else {
+                Self::ensure_epoch_active(epoch)
+            }
+        }
+
         fn ensure_no_duplicate_presence(epoch: &EpochId, actor: &ActorId) -> DispatchResult {
             ensure!(
                 !Presences::<T>::contains_key(epoch, actor),
@@ -990,6 +1559,44 @@ pub mod pallet {
             Ok(())
         }
 
+        fn ensure_epoch_capacity(epoch: &EpochId) -> DispatchResult {
+            ensure!(
+                PresenceCount::<T>::get(epoch) < T::MaxPresencesPerEpoch::get(),
+                Error::<T>::EpochPresenceCapReached
+            );
+            Ok(())
+        }
+
+        fn ensure_active_device(actor: &ActorId) -> DispatchResult {
+            ensure!(
+                !T::RequireActiveDevice::get() || T::DeviceRegistry::has_active_device(*actor),
+                Error::<T>::NoActiveDevice
+            );
+            Ok(())
+        }
+
+        fn ensure_active_actor(actor: &ActorId) -> DispatchResult {
+            ensure!(
+                !T::RequireActiveActor::get() || T::ActorStatusProvider::is_actor_active(*actor),
+                Error::<T>::ActorNotActive
+            );
+            Ok(())
+        }
+
+        fn ensure_declaration_cooldown(
+            actor: &ActorId,
+            block_number: BlockNumberFor<T>,
+        ) -> DispatchResult {
+            if let Some(last) = LastDeclaration::<T>::get(actor) {
+                let next_allowed = last.saturating_add(T::DeclarationCooldownBlocks::get());
+                ensure!(
+                    block_number >= next_allowed,
+                    Error::<T>::DeclarationTooFrequent
+                );
+            }
+            Ok(())
+        }
+
         fn ensure_validator_active(validator: &ValidatorId) -> DispatchResult {
             ensure!(
                 T::ValidatorProvider::is_validator_active(*validator),
@@ -1012,6 +1619,10 @@ pub mod pallet {
 
         fn ensure_not_terminal(state: &PresenceState) -> DispatchResult {
             ensure!(!state.is_terminal(), Error::<T>::PresenceImmutable);
+            ensure!(
+                *state != PresenceState::Challenged,
+                Error::<T>::PresenceUnderChallenge
+            );
             Ok(())
         }
 
@@ -1056,6 +1667,17 @@ pub mod pallet {
             Presences::<T>::get(epoch, actor)
         }
 
+        /// Actors whose presence in `epoch` is in `state`, sorted. Reads every
+        /// presence record of the epoch, so keep it to RPC and off-chain callers.
+        pub fn actors_by_state(epoch: EpochId, state: PresenceState) -> Vec<ActorId> {
+            let mut actors: Vec<ActorId> = Presences::<T>::iter_prefix(epoch)
+                .filter(|(_, record)| record.state == state)
+                .map(|(actor, _)| actor)
+                .collect();
+            actors.sort();
+            actors
+        }
+
         pub fn get_vote(epoch: EpochId, actor: ActorId, validator: ValidatorId) -> Option<Vote> {
             Votes::<T>::get((epoch, actor, validator))
         }
@@ -1071,13 +1693,10 @@ pub mod pallet {
             epoch: EpochId,
             current_block: BlockNumberFor<T>,
         ) -> DeclarationPhase {
-            let Some(commit_start) = EpochCommitStart::<T>::get(epoch) else {
+            let Some((reveal_start, reveal_end)) = Self::get_reveal_window(epoch) else {
                 return DeclarationPhase::Commit;
             };
 
-            let reveal_start = commit_start.saturating_add(T::CommitRevealDelay::get());
-            let reveal_end = reveal_start.saturating_add(T::RevealWindow::get());
-
             if current_block < reveal_start {
                 DeclarationPhase::Commit
             } else if current_block < reveal_end {
@@ -1106,6 +1725,43 @@ pub mod pallet {
             PresenceCommitment(hash_with_domain(DOMAIN_COMMITMENT, &data))
         }
 
+        /// Active validators with no vote recorded on `actor`'s presence in `epoch`,
+        /// capped at `MaxVotesPerPresence` entries.
+        pub fn non_voters(epoch: EpochId, actor: ActorId) -> Vec<ValidatorId> {
+            T::ValidatorProvider::active_validators()
+                .into_iter()
+                .filter(|validator| !Votes::<T>::contains_key((epoch, actor, *validator)))
+                .take(T::MaxVotesPerPresence::get() as usize)
+                .collect()
+        }
+
+        /// Message validators co-sign to approve `actor`'s presence in `epoch`
+        /// through `submit_aggregate_votes`.
+        pub fn aggregate_vote_message(actor: &ActorId, epoch: &EpochId) -> sp_core::H256 {
+            use seveny_primitives::crypto::{hash_with_domain, DOMAIN_PRESENCE};
+
+            let mut data = Vec::with_capacity(4 + 32 + 8);
+            data.extend_from_slice(b"vote");
+            data.extend_from_slice(actor.as_bytes());
+            data.extend_from_slice(&epoch.inner().to_le_bytes());
+
+            hash_with_domain(DOMAIN_PRESENCE, &data)
+        }
+
+        fn current_block_ref() -> Result<BlockRef, Error<T>> {
+            let block_number = frame_system::Pallet::<T>::block_number();
+            let block_hash = frame_system::Pallet::<T>::block_hash(block_number);
+
+            let block_num: u64 = block_number
+                .try_into()
+                .map_err(|_| Error::<T>::BlockRefConversionFailed)?;
+            let hash_bytes: [u8; 32] = block_hash
+                .as_ref()
+                .try_into()
+                .map_err(|_| Error::<T>::BlockRefConversionFailed)?;
+            Ok(BlockRef::new(block_num, sp_core::H256(hash_bytes)))
+        }
+
         pub fn is_in_commit_phase(epoch: EpochId) -> bool {
             let current_block = frame_system::Pallet::<T>::block_number();
             Self::get_declaration_phase(epoch, current_block) == DeclarationPhase::Commit
@@ -1116,11 +1772,215 @@ pub mod pallet {
             Self::get_declaration_phase(epoch, current_block) == DeclarationPhase::Reveal
         }
 
+        /// Share of the epoch's commitments that have been revealed. An epoch with
+        /// no commitments reports a full ratio.
+        pub fn epoch_reveal_ratio(epoch: EpochId) -> Perbill {
+            let commitments = CommitmentCount::<T>::get(epoch);
+            if commitments == 0 {
+                return Perbill::one();
+            }
+            Perbill::from_rational(RevealCount::<T>::get(epoch), commitments)
+        }
+
         pub fn get_reveal_window(epoch: EpochId) -> Option<(BlockNumberFor<T>, BlockNumberFor<T>)> {
             let commit_start = EpochCommitStart::<T>::get(epoch)?;
-            let reveal_start = commit_start.saturating_add(T::CommitRevealDelay::get());
-            let reveal_end = reveal_start.saturating_add(T::RevealWindow::get());
+            let (delay, window) = Self::commit_reveal_params(epoch);
+            let reveal_start = Self::reveal_start(epoch, commit_start, delay);
+            let reveal_end = reveal_start.saturating_add(window);
             Some((reveal_start, reveal_end))
         }
+
+        /// The reveal phase opens once the delay has elapsed and the epoch has
+        /// `MinCommitmentsForReveal` commitments, or at `MaxCommitDuration` regardless.
+        fn reveal_start(
+            epoch: EpochId,
+            commit_start: BlockNumberFor<T>,
+            delay: BlockNumberFor<T>,
+        ) -> BlockNumberFor<T> {
+            let earliest = commit_start.saturating_add(delay);
+            if T::MinCommitmentsForReveal::get() == 0 {
+                return earliest;
+            }
+            let cap = commit_start.saturating_add(T::MaxCommitDuration::get().max(delay));
+            EpochMinCommitmentsAt::<T>::get(epoch)
+                .map(|reached| reached.max(earliest).min(cap))
+                .unwrap_or(cap)
+        }
+
+        /// Commit-reveal delay and reveal window for `epoch`, falling back to the
+        /// global constants when no override is set.
+        pub fn commit_reveal_params(epoch: EpochId) -> (BlockNumberFor<T>, BlockNumberFor<T>) {
+            EpochCommitRevealConfig::<T>::get(epoch)
+                .unwrap_or_else(|| (T::CommitRevealDelay::get(), T::RevealWindow::get()))
+        }
+
+        /// Active quorum mode, defaulting to the absolute threshold of the quorum config.
+        pub fn current_quorum_mode() -> QuorumMode {
+            QuorumModeOverride::<T>::get()
+                .unwrap_or_else(|| QuorumMode::Absolute(QuorumConfigStorage::<T>::get().threshold))
+        }
+
+        /// Whether `votes` meets the quorum against the current active validator set.
+        pub fn quorum_met(votes: u32) -> bool {
+            Self::current_quorum_mode()
+                .is_met(votes, T::ValidatorProvider::active_validator_count())
+        }
+
+        /// Add a strike for a mismatched reveal and slash the actor's presence in
+        /// `epoch` once `MaxRevealFailures` is reached, resetting the count.
+        fn record_reveal_failure(actor: ActorId, epoch: EpochId) {
+            let strikes = RevealFailureStrikes::<T>::mutate(actor, |s| {
+                *s = s.saturating_add(1);
+                *s
+            });
+            if strikes < T::MaxRevealFailures::get() {
+                return;
+            }
+
+            RevealFailureStrikes::<T>::remove(actor);
+            Self::deposit_event(Event::RevealFailurePenalized {
+                actor,
+                epoch,
+                strikes,
+            });
+
+            let Some(mut record) = Presences::<T>::get(epoch, actor) else {
+                return;
+            };
+            if Self::ensure_not_terminal(&record.state).is_err() {
+                return;
+            }
+            record.state = PresenceState::Slashed;
+            Presences::<T>::insert(epoch, actor, record);
+            Self::deposit_event(Event::PresenceSlashed { actor, epoch });
+        }
+
+        /// Number of consecutive epochs, ending at the latest one, in which `actor`
+        /// was finalized.
+        pub fn get_presence_streak(actor: ActorId) -> u32 {
+            PresenceStreak::<T>::get(actor)
+                .map(|(streak, _)| streak)
+                .unwrap_or(0)
+        }
+
+        /// Quorum and commitment checks shared by explicit and idle finalization.
+        fn ensure_finalizable(
+            epoch: EpochId,
+            actor: ActorId,
+            record: &PresenceRecord<BlockNumberFor<T>>,
+        ) -> Result<(), Error<T>> {
+            ensure!(
+                Self::quorum_met(record.vote_count),
+                Error::<T>::QuorumNotMet
+            );
+
+            // M14: if a commitment was submitted, it must be revealed before finalization
+            if let Some(declaration) = Declarations::<T>::get(epoch, actor) {
+                ensure!(declaration.revealed, Error::<T>::CommitmentNotRevealed);
+                ensure!(
+                    Self::epoch_reveal_ratio(epoch) >= T::MinEpochRevealRatio::get(),
+                    Error::<T>::EpochRevealRatioTooLow
+                );
+            }
+
+            Ok(())
+        }
+
+        fn do_finalize(
+            epoch: EpochId,
+            actor: ActorId,
+            mut record: PresenceRecord<BlockNumberFor<T>>,
+            block_number: BlockNumberFor<T>,
+        ) {
+            record.state = PresenceState::Finalized;
+            record.finalized_at = Some(block_number);
+
+            Presences::<T>::insert(epoch, actor, record);
+            Self::update_presence_streak(actor, epoch);
+
+            Self::deposit_event(Event::PresenceFinalized {
+                actor,
+                epoch,
+                block_number,
+            });
+        }
+
+        /// Finalize `Validated` presences that still meet quorum, resuming from
+        /// `IdleFinalizeCursor`. Stops after `MaxIdleFinalizations` or once
+        /// `remaining_weight` cannot cover another step, and returns the weight used.
+        fn finalize_validated_on_idle(
+            block_number: BlockNumberFor<T>,
+            remaining_weight: Weight,
+        ) -> Weight {
+            // Reading the record, then for a validated one the quorum override and
+            // config, the active validator count, its declaration, and the epoch's
+            // commitment and reveal counts.
+            let scan_weight = T::DbWeight::get().reads(1);
+            let check_weight = T::DbWeight::get().reads(6);
+            let step_weight = scan_weight
+                .saturating_add(check_weight)
+                .saturating_add(T::WeightInfo::finalize_presence());
+            let mut used = T::DbWeight::get().reads_writes(1, 1);
+            if used.saturating_add(step_weight).any_gt(remaining_weight) {
+                return Weight::zero();
+            }
+
+            let mut iter = match IdleFinalizeCursor::<T>::get() {
+                Some(key) => Presences::<T>::iter_from(key.into_inner()),
+                None => Presences::<T>::iter(),
+            };
+            let max_finalizations = T::MaxIdleFinalizations::get();
+            let mut finalized = 0u32;
+            let mut exhausted = false;
+
+            while finalized < max_finalizations
+                && used.saturating_add(step_weight).all_lte(remaining_weight)
+            {
+                let Some((epoch, actor, record)) = iter.next() else {
+                    exhausted = true;
+                    break;
+                };
+                used.saturating_accrue(scan_weight);
+                if record.state != PresenceState::Validated {
+                    continue;
+                }
+
+                used.saturating_accrue(check_weight);
+                if Self::ensure_finalizable(epoch, actor, &record).is_err() {
+                    continue;
+                }
+
+                Self::do_finalize(epoch, actor, record, block_number);
+                used.saturating_accrue(T::WeightInfo::finalize_presence());
+                finalized = finalized.saturating_add(1);
+            }
+
+            if exhausted {
+                IdleFinalizeCursor::<T>::kill();
+            } else {
+                IdleFinalizeCursor::<T>::put(BoundedVec::truncate_from(
+                    iter.last_raw_key().to_vec(),
+                ));
+            }
+
+            used
+        }
+
+        /// Extend the streak when `epoch` directly follows the last counted epoch,
+        /// otherwise restart it. Finalizing an older epoch leaves it untouched.
+        fn update_presence_streak(actor: ActorId, epoch: EpochId) {
+            let streak = match PresenceStreak::<T>::get(actor) {
+                Some((_, last)) if epoch <= last => return,
+                Some((streak, last)) if last.next() == epoch => streak.saturating_add(1),
+                _ => 1,
+            };
+            PresenceStreak::<T>::insert(actor, (streak, epoch));
+        }
+    }
+
+    impl<T: Config> seveny_primitives::traits::PresenceLoad for Pallet<T> {
+        fn current_presence_count() -> u32 {
+            PresenceCount::<T>::get(T::EpochProvider::current_epoch())
+        }
     }
 }
@@ -1,17 +1,22 @@
 #![allow(clippy::disallowed_macros, clippy::missing_const_for_thread_local)]
 
 use crate::{self as pallet_presence, Error, Event};
-use frame_support::{assert_noop, assert_ok, derive_impl, parameter_types, traits::ConstU32};
+use frame_support::{
+    assert_noop, assert_ok, derive_impl, parameter_types,
+    traits::{ConstU32, Hooks},
+    weights::Weight,
+    BoundedVec,
+};
 use frame_system as system;
 use parity_scale_codec::Encode;
 use seveny_primitives::{
-    types::{ActorId, EpochId, PresenceState, ValidatorId},
-    PresenceCommitment,
+    types::{ActorId, EpochId, PresenceState, QuorumMode, ValidatorId},
+    Nullifier, PresenceCommitment,
 };
 use sp_core::H256;
 use sp_runtime::{
     traits::{BlakeTwo256, IdentityLookup},
-    BuildStorage,
+    BuildStorage, DispatchResult, Perbill,
 };
 use std::cell::RefCell;
 
@@ -31,6 +36,11 @@ frame_support::construct_runtime!(
 thread_local! {
     static ACTIVE_EPOCHS: RefCell<Vec<u64>> = RefCell::new(vec![1]);
     static ACTIVE_VALIDATORS: RefCell<Vec<ValidatorId>> = const { RefCell::new(Vec::new()) };
+    static GRACE_EPOCHS: RefCell<Vec<u64>> = const { RefCell::new(Vec::new()) };
+    static DEVICE_OWNERS: RefCell<Vec<ActorId>> = const { RefCell::new(Vec::new()) };
+    static SUSPENDED_ACTORS: RefCell<Vec<ActorId>> = const { RefCell::new(Vec::new()) };
+    static OPENED_DISPUTES: RefCell<Vec<(u64, ActorId, EpochId)>> = const { RefCell::new(Vec::new()) };
+    static CLOSED_DISPUTES: RefCell<Vec<(u64, bool)>> = const { RefCell::new(Vec::new()) };
 }
 
 pub struct MockEpochProvider;
@@ -41,6 +51,9 @@ impl seveny_primitives::traits::EpochProvider for MockEpochProvider {
     fn current_epoch() -> EpochId {
         EpochId::new(1)
     }
+    fn is_within_grace_period(epoch_id: EpochId) -> bool {
+        GRACE_EPOCHS.with(|e| e.borrow().contains(&epoch_id.inner()))
+    }
 }
 
 pub struct MockValidatorProvider;
@@ -48,6 +61,72 @@ impl seveny_primitives::traits::ValidatorProvider for MockValidatorProvider {
     fn is_validator_active(validator_id: ValidatorId) -> bool {
         ACTIVE_VALIDATORS.with(|v| v.borrow().contains(&validator_id))
     }
+    fn active_validator_count() -> u32 {
+        ACTIVE_VALIDATORS.with(|v| v.borrow().len() as u32)
+    }
+    fn active_validators() -> Vec<ValidatorId> {
+        ACTIVE_VALIDATORS.with(|v| v.borrow().clone())
+    }
+}
+
+/// Treats every actor in `DEVICE_OWNERS` as owning one active device.
+pub struct MockDeviceRegistry;
+impl seveny_primitives::traits::DeviceRegistry for MockDeviceRegistry {
+    type DeviceId = u64;
+
+    fn device_owner(_device_id: u64) -> Option<ActorId> {
+        None
+    }
+    fn is_device_active(_device_id: u64) -> bool {
+        false
+    }
+    fn has_active_device(actor: ActorId) -> bool {
+        DEVICE_OWNERS.with(|d| d.borrow().contains(&actor))
+    }
+}
+
+/// Treats every actor as active except those in `SUSPENDED_ACTORS`.
+pub struct MockActorStatus;
+impl seveny_primitives::traits::ActorStatusProvider for MockActorStatus {
+    fn is_actor_active(actor: ActorId) -> bool {
+        SUSPENDED_ACTORS.with(|s| !s.borrow().contains(&actor))
+    }
+}
+
+/// Records challenges in `OPENED_DISPUTES` / `CLOSED_DISPUTES`, numbering
+/// disputes in opening order.
+pub struct MockPresenceDisputes;
+impl seveny_primitives::traits::PresenceDisputes<u64> for MockPresenceDisputes {
+    fn open_presence_dispute(
+        challenger: &u64,
+        actor: ActorId,
+        epoch: EpochId,
+        _evidence: H256,
+    ) -> Option<u64> {
+        OPENED_DISPUTES.with(|d| {
+            let mut disputes = d.borrow_mut();
+            disputes.push((*challenger, actor, epoch));
+            Some(disputes.len() as u64 - 1)
+        })
+    }
+    fn close_presence_dispute(dispute_id: u64, upheld: bool) {
+        CLOSED_DISPUTES.with(|d| d.borrow_mut().push((dispute_id, upheld)));
+    }
+}
+
+/// Accepts an aggregate equal to the hash of the message and signer set.
+pub struct MockAggregateVerifier;
+impl MockAggregateVerifier {
+    fn sign(validators: &[ValidatorId], msg: &[u8]) -> H256 {
+        H256(sp_io::hashing::blake2_256(&(msg, validators).encode()))
+    }
+}
+impl seveny_primitives::traits::ValidatorAggregateVerifier for MockAggregateVerifier {
+    type AggregateSig = H256;
+
+    fn verify(validators: &[ValidatorId], msg: &[u8], agg: &Self::AggregateSig) -> bool {
+        Self::sign(validators, msg) == *agg
+    }
 }
 
 // =========================================================================
@@ -86,8 +165,21 @@ parameter_types! {
     pub const DefaultQuorumTotal: u32 = 5;
     pub const CommitRevealDelay: u64 = 10;
     pub const RevealWindow: u64 = 20;
+    pub const MinEpochRevealRatio: Perbill = Perbill::from_percent(50);
+    pub static RevealFailurePenaltyEnabled: bool = false;
+    pub const MaxRevealFailures: u32 = 3;
     pub const MinWitnessesForVerification: u32 = 3;
     pub const PositionToleranceMeters: u32 = 1000;
+    pub static DeclarationCooldownBlocks: u64 = 0;
+    pub const MaxIdleFinalizations: u32 = 2;
+    pub static RequireActiveDevice: bool = false;
+    pub static RequireActiveActor: bool = false;
+    pub static MinCommitmentsForReveal: u32 = 0;
+    pub const MaxCommitDuration: u64 = 50;
+    pub const ChallengeWindow: u64 = 10;
+    pub const MaxFinalizeBatch: u32 = 8;
+    pub static MaxPresencesPerEpoch: u32 = 1000;
+    pub static MaxOpenChallengesPerValidator: u32 = 4;
 }
 
 impl pallet_presence::Config for Test {
@@ -97,10 +189,27 @@ impl pallet_presence::Config for Test {
     type DefaultQuorumTotal = DefaultQuorumTotal;
     type CommitRevealDelay = CommitRevealDelay;
     type RevealWindow = RevealWindow;
+    type MinEpochRevealRatio = MinEpochRevealRatio;
+    type RevealFailurePenaltyEnabled = RevealFailurePenaltyEnabled;
+    type MaxRevealFailures = MaxRevealFailures;
     type MinWitnessesForVerification = MinWitnessesForVerification;
     type PositionToleranceMeters = PositionToleranceMeters;
+    type DeclarationCooldownBlocks = DeclarationCooldownBlocks;
+    type MaxIdleFinalizations = MaxIdleFinalizations;
     type EpochProvider = MockEpochProvider;
     type ValidatorProvider = MockValidatorProvider;
+    type AggregateVoteVerifier = MockAggregateVerifier;
+    type DeviceRegistry = MockDeviceRegistry;
+    type RequireActiveDevice = RequireActiveDevice;
+    type ActorStatusProvider = MockActorStatus;
+    type RequireActiveActor = RequireActiveActor;
+    type MinCommitmentsForReveal = MinCommitmentsForReveal;
+    type MaxCommitDuration = MaxCommitDuration;
+    type PresenceDisputes = MockPresenceDisputes;
+    type ChallengeWindow = ChallengeWindow;
+    type MaxFinalizeBatch = MaxFinalizeBatch;
+    type MaxPresencesPerEpoch = MaxPresencesPerEpoch;
+    type MaxOpenChallengesPerValidator = MaxOpenChallengesPerValidator;
 }
 
 // =========================================================================
@@ -111,6 +220,11 @@ fn new_test_ext() -> sp_io::TestExternalities {
     // Reset mock provider state for test isolation
     ACTIVE_EPOCHS.with(|e| *e.borrow_mut() = vec![1]);
     ACTIVE_VALIDATORS.with(|v| v.borrow_mut().clear());
+    GRACE_EPOCHS.with(|e| e.borrow_mut().clear());
+    DEVICE_OWNERS.with(|d| d.borrow_mut().clear());
+    SUSPENDED_ACTORS.with(|s| s.borrow_mut().clear());
+    OPENED_DISPUTES.with(|d| d.borrow_mut().clear());
+    CLOSED_DISPUTES.with(|d| d.borrow_mut().clear());
 
     let mut t = system::GenesisConfig::<Test>::default()
         .build_storage()
@@ -139,7 +253,12 @@ fn account_to_validator(account: u64) -> ValidatorId {
 
 fn setup_validator(account: u64) {
     let validator = account_to_validator(account);
-    ACTIVE_VALIDATORS.with(|v| v.borrow_mut().push(validator));
+    ACTIVE_VALIDATORS.with(|v| {
+        let mut validators = v.borrow_mut();
+        if !validators.contains(&validator) {
+            validators.push(validator);
+        }
+    });
 }
 
 fn run_to_block(n: u64) {
@@ -165,6 +284,10 @@ fn compute_test_commitment(
     PresenceCommitment(hash_with_domain(DOMAIN_COMMITMENT, &data))
 }
 
+fn test_nullifier(account: u64, epoch: EpochId) -> Nullifier {
+    Nullifier::derive(&[account as u8; 32], epoch.inner(), &[0u8; 32])
+}
+
 // =========================================================================
 // Invariant Tests
 // =========================================================================
@@ -399,7 +522,8 @@ fn declare_presence_with_commitment_success() {
         assert_ok!(Presence::declare_presence_with_commitment(
             RuntimeOrigin::signed(1),
             epoch,
-            commitment.clone()
+            commitment.clone(),
+            test_nullifier(1, epoch)
         ));
 
         let actor = account_to_actor(1);
@@ -624,7 +748,8 @@ fn commitment_submitted_event_emitted() {
         assert_ok!(Presence::declare_presence_with_commitment(
             RuntimeOrigin::signed(1),
             epoch,
-            commitment
+            commitment,
+            test_nullifier(1, epoch)
         ));
 
         let actor = account_to_actor(1);
@@ -656,7 +781,8 @@ fn declaration_phase_transitions_to_reveal() {
         assert_ok!(Presence::declare_presence_with_commitment(
             RuntimeOrigin::signed(1),
             epoch,
-            commitment
+            commitment,
+            test_nullifier(1, epoch)
         ));
 
         let phase = Presence::get_declaration_phase(epoch, 1);
@@ -676,7 +802,8 @@ fn declaration_phase_transitions_to_closed() {
         assert_ok!(Presence::declare_presence_with_commitment(
             RuntimeOrigin::signed(1),
             epoch,
-            commitment
+            commitment,
+            test_nullifier(1, epoch)
         ));
 
         let phase = Presence::get_declaration_phase(epoch, 31);
@@ -697,7 +824,8 @@ fn reveal_commitment_success() {
         assert_ok!(Presence::declare_presence_with_commitment(
             RuntimeOrigin::signed(1),
             epoch,
-            commitment
+            commitment,
+            test_nullifier(1, epoch)
         ));
 
         run_to_block(12);
@@ -734,7 +862,8 @@ fn reveal_commitment_fails_before_reveal_window() {
         assert_ok!(Presence::declare_presence_with_commitment(
             RuntimeOrigin::signed(1),
             epoch,
-            commitment
+            commitment,
+            test_nullifier(1, epoch)
         ));
 
         assert_noop!(
@@ -757,7 +886,8 @@ fn reveal_commitment_fails_after_reveal_window() {
         assert_ok!(Presence::declare_presence_with_commitment(
             RuntimeOrigin::signed(1),
             epoch,
-            commitment
+            commitment,
+            test_nullifier(1, epoch)
         ));
 
         run_to_block(35);
@@ -783,7 +913,8 @@ fn reveal_commitment_fails_with_wrong_secret() {
         assert_ok!(Presence::declare_presence_with_commitment(
             RuntimeOrigin::signed(1),
             epoch,
-            commitment
+            commitment,
+            test_nullifier(1, epoch)
         ));
 
         run_to_block(12);
@@ -809,7 +940,8 @@ fn reveal_commitment_fails_with_wrong_randomness() {
         assert_ok!(Presence::declare_presence_with_commitment(
             RuntimeOrigin::signed(1),
             epoch,
-            commitment
+            commitment,
+            test_nullifier(1, epoch)
         ));
 
         run_to_block(12);
@@ -834,7 +966,8 @@ fn reveal_commitment_fails_if_already_revealed() {
         assert_ok!(Presence::declare_presence_with_commitment(
             RuntimeOrigin::signed(1),
             epoch,
-            commitment
+            commitment,
+            test_nullifier(1, epoch)
         ));
 
         run_to_block(12);
@@ -890,12 +1023,14 @@ fn commitment_and_reveal_counts_tracked() {
         assert_ok!(Presence::declare_presence_with_commitment(
             RuntimeOrigin::signed(1),
             epoch,
-            commitment1
+            commitment1,
+            test_nullifier(1, epoch)
         ));
         assert_ok!(Presence::declare_presence_with_commitment(
             RuntimeOrigin::signed(2),
             epoch,
-            commitment2
+            commitment2,
+            test_nullifier(2, epoch)
         ));
 
         assert_eq!(Presence::commitment_count(epoch), 2);
@@ -933,7 +1068,8 @@ fn get_reveal_window_returns_correct_bounds() {
         assert_ok!(Presence::declare_presence_with_commitment(
             RuntimeOrigin::signed(1),
             epoch,
-            commitment
+            commitment,
+            test_nullifier(1, epoch)
         ));
 
         let window = Presence::get_reveal_window(epoch).expect("window should exist");
@@ -953,7 +1089,8 @@ fn is_in_commit_phase_helper() {
         assert_ok!(Presence::declare_presence_with_commitment(
             RuntimeOrigin::signed(1),
             epoch,
-            commitment
+            commitment,
+            test_nullifier(1, epoch)
         ));
 
         assert!(Presence::is_in_commit_phase(epoch));
@@ -975,7 +1112,8 @@ fn is_in_reveal_phase_helper() {
         assert_ok!(Presence::declare_presence_with_commitment(
             RuntimeOrigin::signed(1),
             epoch,
-            commitment
+            commitment,
+            test_nullifier(1, epoch)
         ));
 
         run_to_block(12);
@@ -1135,3 +1273,1643 @@ fn finalize_requires_authorization() {
         ));
     });
 }
+
+// =========================================================================
+// Epoch Grace Period Tests
+// =========================================================================
+
+fn close_epoch_into_grace(epoch: u64) {
+    ACTIVE_EPOCHS.with(|e| e.borrow_mut().retain(|id| *id != epoch));
+    GRACE_EPOCHS.with(|e| e.borrow_mut().push(epoch));
+}
+
+fn end_grace_period(epoch: u64) {
+    GRACE_EPOCHS.with(|e| e.borrow_mut().retain(|id| *id != epoch));
+}
+
+#[test]
+fn on_time_declaration_not_late() {
+    new_test_ext().execute_with(|| {
+        let epoch = EpochId::new(1);
+
+        assert_ok!(Presence::declare_presence(RuntimeOrigin::signed(1), epoch));
+
+        let record = Presence::get_presence(epoch, account_to_actor(1)).expect("record exists");
+        assert!(!record.late);
+    });
+}
+
+#[test]
+fn declaration_within_grace_period_flagged_late() {
+    new_test_ext().execute_with(|| {
+        let epoch = EpochId::new(1);
+        close_epoch_into_grace(1);
+
+        assert_ok!(Presence::declare_presence(RuntimeOrigin::signed(1), epoch));
+
+        let record = Presence::get_presence(epoch, account_to_actor(1)).expect("record exists");
+        assert!(record.late);
+        assert_eq!(record.state, PresenceState::Declared);
+        assert_eq!(Presence::presence_count(epoch), 1);
+    });
+}
+
+#[test]
+fn declaration_past_grace_period_rejected() {
+    new_test_ext().execute_with(|| {
+        let epoch = EpochId::new(1);
+        close_epoch_into_grace(1);
+        end_grace_period(1);
+
+        assert_noop!(
+            Presence::declare_presence(RuntimeOrigin::signed(1), epoch),
+            Error::<Test>::EpochNotActive
+        );
+    });
+}
+
+#[test]
+fn late_presence_accepts_votes_until_grace_period_ends() {
+    new_test_ext().execute_with(|| {
+        let epoch = EpochId::new(1);
+        let actor = account_to_actor(1);
+        assert_ok!(Presence::declare_presence(RuntimeOrigin::signed(2), epoch));
+        close_epoch_into_grace(1);
+        assert_ok!(Presence::declare_presence(RuntimeOrigin::signed(1), epoch));
+        setup_validator(10);
+        setup_validator(11);
+
+        assert_ok!(Presence::vote_presence(
+            RuntimeOrigin::signed(10),
+            actor,
+            epoch,
+            true
+        ));
+        // On-time records stop taking votes once the epoch closes.
+        assert_noop!(
+            Presence::vote_presence(RuntimeOrigin::signed(10), account_to_actor(2), epoch, true),
+            Error::<Test>::EpochNotActive
+        );
+
+        end_grace_period(1);
+        assert_noop!(
+            Presence::vote_presence(RuntimeOrigin::signed(11), actor, epoch, true),
+            Error::<Test>::EpochNotActive
+        );
+    });
+}
+
+#[test]
+fn late_presence_accepts_aggregate_votes_within_grace_period() {
+    new_test_ext().execute_with(|| {
+        for v in 10..13 {
+            setup_validator(v);
+        }
+        let epoch = EpochId::new(1);
+        let actor = account_to_actor(1);
+        close_epoch_into_grace(1);
+        assert_ok!(Presence::declare_presence(RuntimeOrigin::signed(1), epoch));
+
+        let validators: Vec<ValidatorId> = (10..13).map(account_to_validator).collect();
+        let sig = aggregate_for(actor, epoch, &validators);
+        assert_ok!(Presence::submit_aggregate_votes(
+            RuntimeOrigin::signed(99),
+            actor,
+            epoch,
+            validators.try_into().expect("fits bound"),
+            sig,
+        ));
+        assert_eq!(Presence::vote_count(epoch, actor), 3);
+    });
+}
+
+#[test]
+fn commitment_within_grace_period_flagged_late() {
+    new_test_ext().execute_with(|| {
+        let epoch = EpochId::new(1);
+        close_epoch_into_grace(1);
+
+        commit_for(1, epoch);
+        let record = Presence::get_presence(epoch, account_to_actor(1)).expect("record exists");
+        assert!(record.late);
+
+        end_grace_period(1);
+        let actor = account_to_actor(2);
+        let commitment = compute_test_commitment(&actor, &epoch, &[2u8; 32], &[7u8; 32]);
+        assert_noop!(
+            Presence::declare_presence_with_commitment(
+                RuntimeOrigin::signed(2),
+                epoch,
+                commitment,
+                test_nullifier(2, epoch)
+            ),
+            Error::<Test>::EpochNotActive
+        );
+    });
+}
+
+// =========================================================================
+// Epoch Reveal Ratio Tests
+// =========================================================================
+
+fn commit_for(account: u64, epoch: EpochId) {
+    let actor = account_to_actor(account);
+    let commitment = compute_test_commitment(&actor, &epoch, &[account as u8; 32], &[7u8; 32]);
+    assert_ok!(Presence::declare_presence_with_commitment(
+        RuntimeOrigin::signed(account),
+        epoch,
+        commitment,
+        test_nullifier(account, epoch)
+    ));
+}
+
+fn reveal_for(account: u64, epoch: EpochId) {
+    assert_ok!(Presence::reveal_commitment(
+        RuntimeOrigin::signed(account),
+        epoch,
+        [account as u8; 32],
+        [7u8; 32]
+    ));
+}
+
+fn validate_presence(account: u64, epoch: EpochId) {
+    let actor = account_to_actor(account);
+    for validator in [10, 11, 12] {
+        setup_validator(validator);
+        assert_ok!(Presence::vote_presence(
+            RuntimeOrigin::signed(validator),
+            actor,
+            epoch,
+            true
+        ));
+    }
+}
+
+#[test]
+fn epoch_reveal_ratio_tracks_reveals() {
+    new_test_ext().execute_with(|| {
+        let epoch = EpochId::new(1);
+        assert_eq!(Presence::epoch_reveal_ratio(epoch), Perbill::one());
+
+        for account in 1..=4 {
+            commit_for(account, epoch);
+        }
+        assert_eq!(Presence::epoch_reveal_ratio(epoch), Perbill::zero());
+
+        run_to_block(12);
+        reveal_for(1, epoch);
+        assert_eq!(
+            Presence::epoch_reveal_ratio(epoch),
+            Perbill::from_percent(25)
+        );
+    });
+}
+
+#[test]
+fn finalize_rejected_below_reveal_ratio() {
+    new_test_ext().execute_with(|| {
+        let epoch = EpochId::new(1);
+        for account in 1..=4 {
+            commit_for(account, epoch);
+        }
+
+        run_to_block(12);
+        reveal_for(1, epoch);
+        validate_presence(1, epoch);
+
+        assert_noop!(
+            Presence::finalize_presence(RuntimeOrigin::signed(1), account_to_actor(1), epoch),
+            Error::<Test>::EpochRevealRatioTooLow
+        );
+    });
+}
+
+#[test]
+fn finalize_allowed_at_reveal_ratio_boundary() {
+    new_test_ext().execute_with(|| {
+        let epoch = EpochId::new(1);
+        for account in 1..=4 {
+            commit_for(account, epoch);
+        }
+
+        run_to_block(12);
+        reveal_for(1, epoch);
+        reveal_for(2, epoch);
+        validate_presence(1, epoch);
+
+        assert_eq!(
+            Presence::epoch_reveal_ratio(epoch),
+            Perbill::from_percent(50)
+        );
+        assert_ok!(Presence::finalize_presence(
+            RuntimeOrigin::signed(1),
+            account_to_actor(1),
+            epoch
+        ));
+    });
+}
+
+// =========================================================================
+// Per-Epoch Commit-Reveal Config Tests
+// =========================================================================
+
+#[test]
+fn custom_epoch_window_changes_phase_boundaries() {
+    new_test_ext().execute_with(|| {
+        ACTIVE_EPOCHS.with(|e| e.borrow_mut().push(2));
+        let default_epoch = EpochId::new(1);
+        let custom_epoch = EpochId::new(2);
+
+        assert_ok!(Presence::set_epoch_commit_reveal_config(
+            RuntimeOrigin::root(),
+            custom_epoch,
+            5,
+            50
+        ));
+        System::assert_has_event(RuntimeEvent::Presence(Event::EpochCommitRevealConfigured {
+            epoch: custom_epoch,
+            delay: 5,
+            window: 50,
+        }));
+
+        commit_for(1, default_epoch);
+        commit_for(1, custom_epoch);
+
+        assert_eq!(Presence::get_reveal_window(default_epoch), Some((11, 31)));
+        assert_eq!(Presence::get_reveal_window(custom_epoch), Some((6, 56)));
+
+        assert_eq!(
+            Presence::get_declaration_phase(default_epoch, 6),
+            pallet_presence::DeclarationPhase::Commit
+        );
+        assert_eq!(
+            Presence::get_declaration_phase(custom_epoch, 6),
+            pallet_presence::DeclarationPhase::Reveal
+        );
+        assert_eq!(
+            Presence::get_declaration_phase(default_epoch, 40),
+            pallet_presence::DeclarationPhase::Closed
+        );
+        assert_eq!(
+            Presence::get_declaration_phase(custom_epoch, 40),
+            pallet_presence::DeclarationPhase::Reveal
+        );
+
+        // Reveal succeeds in the extended window of the custom epoch only.
+        run_to_block(40);
+        reveal_for(1, custom_epoch);
+        assert_noop!(
+            Presence::reveal_commitment(
+                RuntimeOrigin::signed(1),
+                default_epoch,
+                [1u8; 32],
+                [7u8; 32]
+            ),
+            Error::<Test>::NotInRevealPhase
+        );
+    });
+}
+
+#[test]
+fn epoch_commit_reveal_config_requires_root_and_nonzero_window() {
+    new_test_ext().execute_with(|| {
+        let epoch = EpochId::new(1);
+
+        assert_noop!(
+            Presence::set_epoch_commit_reveal_config(RuntimeOrigin::signed(1), epoch, 5, 50),
+            sp_runtime::DispatchError::BadOrigin
+        );
+        assert_noop!(
+            Presence::set_epoch_commit_reveal_config(RuntimeOrigin::root(), epoch, 5, 0),
+            Error::<Test>::InvalidRevealWindow
+        );
+        assert_eq!(Presence::commit_reveal_params(epoch), (10, 20));
+    });
+}
+
+// ============================================================================
+// Presence Streak Tests
+// ============================================================================
+
+fn finalize_in_epoch(account: u64, epoch: EpochId) {
+    ACTIVE_EPOCHS.with(|e| e.borrow_mut().push(epoch.0));
+    assert_ok!(Presence::declare_presence(
+        RuntimeOrigin::signed(account),
+        epoch
+    ));
+    validate_presence(account, epoch);
+    assert_ok!(Presence::finalize_presence(
+        RuntimeOrigin::signed(account),
+        account_to_actor(account),
+        epoch
+    ));
+}
+
+#[test]
+fn presence_streak_grows_across_consecutive_epochs() {
+    new_test_ext().execute_with(|| {
+        let actor = account_to_actor(1);
+        assert_eq!(Presence::get_presence_streak(actor), 0);
+
+        for epoch in 1..=3 {
+            finalize_in_epoch(1, EpochId::new(epoch));
+        }
+
+        assert_eq!(Presence::get_presence_streak(actor), 3);
+        assert_eq!(Presence::presence_streak(actor), Some((3, EpochId::new(3))));
+    });
+}
+
+#[test]
+fn presence_streak_resets_after_skipped_epoch() {
+    new_test_ext().execute_with(|| {
+        let actor = account_to_actor(1);
+
+        finalize_in_epoch(1, EpochId::new(1));
+        finalize_in_epoch(1, EpochId::new(2));
+        assert_eq!(Presence::get_presence_streak(actor), 2);
+
+        finalize_in_epoch(1, EpochId::new(4));
+        assert_eq!(Presence::get_presence_streak(actor), 1);
+
+        finalize_in_epoch(1, EpochId::new(3));
+        assert_eq!(Presence::presence_streak(actor), Some((1, EpochId::new(4))));
+    });
+}
+
+// ============================================================================
+// Quorum Mode Tests
+// ============================================================================
+
+fn two_thirds() -> QuorumMode {
+    QuorumMode::Fraction(Perbill::from_rational(2u32, 3u32))
+}
+
+#[test]
+fn fractional_quorum_scales_with_validator_set() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(Presence::set_quorum_mode(
+            RuntimeOrigin::root(),
+            two_thirds()
+        ));
+
+        for validator in 10..13 {
+            setup_validator(validator);
+        }
+        assert!(!Presence::quorum_met(1));
+        assert!(Presence::quorum_met(2));
+
+        for validator in 13..19 {
+            setup_validator(validator);
+        }
+        assert!(!Presence::quorum_met(5));
+        assert!(Presence::quorum_met(6));
+
+        // With nine validators, three approvals no longer validate.
+        let epoch = EpochId::new(1);
+        let actor = account_to_actor(1);
+        assert_ok!(Presence::declare_presence(RuntimeOrigin::signed(1), epoch));
+        for validator in 10..13 {
+            assert_ok!(Presence::vote_presence(
+                RuntimeOrigin::signed(validator),
+                actor,
+                epoch,
+                true
+            ));
+        }
+        let record = Presence::presences(epoch, actor).expect("presence should exist");
+        assert_eq!(record.state, PresenceState::Declared);
+
+        for validator in 13..16 {
+            assert_ok!(Presence::vote_presence(
+                RuntimeOrigin::signed(validator),
+                actor,
+                epoch,
+                true
+            ));
+        }
+        let record = Presence::presences(epoch, actor).expect("presence should exist");
+        assert_eq!(record.state, PresenceState::Validated);
+        assert_eq!(record.vote_count, 6);
+    });
+}
+
+#[test]
+fn set_quorum_mode_validation_and_reset() {
+    new_test_ext().execute_with(|| {
+        assert_noop!(
+            Presence::set_quorum_mode(RuntimeOrigin::signed(1), two_thirds()),
+            sp_runtime::DispatchError::BadOrigin
+        );
+        assert_noop!(
+            Presence::set_quorum_mode(RuntimeOrigin::root(), QuorumMode::Fraction(Perbill::zero())),
+            Error::<Test>::InvalidQuorumConfig
+        );
+        assert_noop!(
+            Presence::set_quorum_mode(RuntimeOrigin::root(), QuorumMode::Absolute(0)),
+            Error::<Test>::InvalidQuorumConfig
+        );
+
+        assert_ok!(Presence::set_quorum_mode(
+            RuntimeOrigin::root(),
+            two_thirds()
+        ));
+        System::assert_last_event(Event::QuorumModeUpdated { mode: two_thirds() }.into());
+        assert_eq!(Presence::current_quorum_mode(), two_thirds());
+
+        assert_ok!(Presence::set_quorum_config(RuntimeOrigin::root(), 4, 7));
+        assert_eq!(Presence::current_quorum_mode(), QuorumMode::Absolute(4));
+        assert!(Presence::quorum_mode_override().is_none());
+    });
+}
+
+// ============================================================================
+// Reveal Failure Penalty Tests
+// ============================================================================
+
+fn reveal_wrong(account: u64, epoch: EpochId) {
+    assert_ok!(Presence::reveal_commitment(
+        RuntimeOrigin::signed(account),
+        epoch,
+        [0xEE; 32],
+        [7u8; 32]
+    ));
+}
+
+#[test]
+fn reveal_mismatch_accrues_strikes_when_enabled() {
+    new_test_ext().execute_with(|| {
+        RevealFailurePenaltyEnabled::set(true);
+        let epoch = EpochId::new(1);
+        let actor = account_to_actor(1);
+        commit_for(1, epoch);
+        run_to_block(12);
+
+        reveal_wrong(1, epoch);
+        reveal_wrong(1, epoch);
+        assert_eq!(Presence::reveal_failure_strikes(actor), 2);
+        System::assert_last_event(
+            Event::RevealFailed {
+                actor,
+                epoch,
+                reason: crate::RevealFailureReason::CommitmentMismatch,
+            }
+            .into(),
+        );
+
+        let record = Presence::presences(epoch, actor).expect("presence should exist");
+        assert_eq!(record.state, PresenceState::Declared);
+
+        // A correct reveal is still accepted below the threshold.
+        reveal_for(1, epoch);
+    });
+}
+
+#[test]
+fn reveal_mismatch_threshold_slashes_presence() {
+    new_test_ext().execute_with(|| {
+        RevealFailurePenaltyEnabled::set(true);
+        let epoch = EpochId::new(1);
+        let actor = account_to_actor(1);
+        commit_for(1, epoch);
+        run_to_block(12);
+
+        for _ in 0..3 {
+            reveal_wrong(1, epoch);
+        }
+
+        let record = Presence::presences(epoch, actor).expect("presence should exist");
+        assert_eq!(record.state, PresenceState::Slashed);
+        assert_eq!(Presence::reveal_failure_strikes(actor), 0);
+        System::assert_has_event(
+            Event::RevealFailurePenalized {
+                actor,
+                epoch,
+                strikes: 3,
+            }
+            .into(),
+        );
+        System::assert_has_event(Event::PresenceSlashed { actor, epoch }.into());
+    });
+}
+
+#[test]
+fn reveal_mismatch_without_penalty_is_plain_error() {
+    new_test_ext().execute_with(|| {
+        let epoch = EpochId::new(1);
+        commit_for(1, epoch);
+        run_to_block(12);
+
+        assert_noop!(
+            Presence::reveal_commitment(RuntimeOrigin::signed(1), epoch, [0xEE; 32], [7u8; 32]),
+            Error::<Test>::CommitmentMismatch
+        );
+        assert_eq!(Presence::reveal_failure_strikes(account_to_actor(1)), 0);
+    });
+}
+
+// =========================================================================
+// Aggregate Votes
+// =========================================================================
+
+fn aggregate_for(actor: ActorId, epoch: EpochId, validators: &[ValidatorId]) -> H256 {
+    let msg = Presence::aggregate_vote_message(&actor, &epoch);
+    MockAggregateVerifier::sign(validators, msg.as_bytes())
+}
+
+#[test]
+fn submit_aggregate_votes_credits_all_validators() {
+    new_test_ext().execute_with(|| {
+        for v in 10..13 {
+            setup_validator(v);
+        }
+        let epoch = EpochId::new(1);
+        let actor = account_to_actor(1);
+        assert_ok!(Presence::declare_presence(RuntimeOrigin::signed(1), epoch));
+
+        let validators: Vec<ValidatorId> = (10..13).map(account_to_validator).collect();
+        let sig = aggregate_for(actor, epoch, &validators);
+
+        assert_ok!(Presence::submit_aggregate_votes(
+            RuntimeOrigin::signed(99),
+            actor,
+            epoch,
+            validators.clone().try_into().expect("fits bound"),
+            sig,
+        ));
+
+        for validator in &validators {
+            let vote = Presence::votes((epoch, actor, *validator)).expect("vote should exist");
+            assert!(vote.approve);
+        }
+        assert_eq!(Presence::vote_count(epoch, actor), 3);
+
+        let record = Presence::presences(epoch, actor).expect("presence should exist");
+        assert_eq!(record.vote_count, 3);
+        assert_eq!(record.state, PresenceState::Validated);
+
+        System::assert_has_event(RuntimeEvent::Presence(Event::AggregateVotesSubmitted {
+            actor,
+            epoch,
+            votes: 3,
+        }));
+    });
+}
+
+#[test]
+fn submit_aggregate_votes_rejects_invalid_signature() {
+    new_test_ext().execute_with(|| {
+        for v in 10..13 {
+            setup_validator(v);
+        }
+        let epoch = EpochId::new(1);
+        let actor = account_to_actor(1);
+        assert_ok!(Presence::declare_presence(RuntimeOrigin::signed(1), epoch));
+
+        let validators: Vec<ValidatorId> = (10..13).map(account_to_validator).collect();
+        // Signed over only two of the three listed validators
+        let sig = aggregate_for(actor, epoch, &validators[..2]);
+
+        assert_noop!(
+            Presence::submit_aggregate_votes(
+                RuntimeOrigin::signed(99),
+                actor,
+                epoch,
+                validators.clone().try_into().expect("fits bound"),
+                sig,
+            ),
+            Error::<Test>::InvalidAggregateSignature
+        );
+
+        for validator in &validators {
+            assert!(Presence::votes((epoch, actor, *validator)).is_none());
+        }
+        assert_eq!(Presence::vote_count(epoch, actor), 0);
+    });
+}
+
+#[test]
+fn submit_aggregate_votes_rejects_duplicate_validator() {
+    new_test_ext().execute_with(|| {
+        setup_validator(10);
+        setup_validator(11);
+        let epoch = EpochId::new(1);
+        let actor = account_to_actor(1);
+        assert_ok!(Presence::declare_presence(RuntimeOrigin::signed(1), epoch));
+        assert_ok!(Presence::vote_presence(
+            RuntimeOrigin::signed(10),
+            actor,
+            epoch,
+            true
+        ));
+
+        let validators = vec![account_to_validator(10), account_to_validator(11)];
+        let sig = aggregate_for(actor, epoch, &validators);
+
+        assert_noop!(
+            Presence::submit_aggregate_votes(
+                RuntimeOrigin::signed(99),
+                actor,
+                epoch,
+                validators.try_into().expect("fits bound"),
+                sig,
+            ),
+            Error::<Test>::DuplicateVote
+        );
+    });
+}
+
+// =========================================================================
+// Declaration Cooldown
+// =========================================================================
+
+#[test]
+fn declaration_within_cooldown_rejected() {
+    new_test_ext().execute_with(|| {
+        DeclarationCooldownBlocks::set(10);
+        ACTIVE_EPOCHS.with(|e| e.borrow_mut().push(2));
+
+        assert_ok!(Presence::declare_presence(
+            RuntimeOrigin::signed(1),
+            EpochId::new(1)
+        ));
+        assert_eq!(Presence::last_declaration(account_to_actor(1)), Some(1));
+
+        System::set_block_number(10);
+        assert_noop!(
+            Presence::declare_presence(RuntimeOrigin::signed(1), EpochId::new(2)),
+            Error::<Test>::DeclarationTooFrequent
+        );
+
+        // Other actors are unaffected
+        assert_ok!(Presence::declare_presence(
+            RuntimeOrigin::signed(2),
+            EpochId::new(2)
+        ));
+    });
+}
+
+#[test]
+fn declaration_after_cooldown_succeeds() {
+    new_test_ext().execute_with(|| {
+        DeclarationCooldownBlocks::set(10);
+        ACTIVE_EPOCHS.with(|e| e.borrow_mut().push(2));
+
+        assert_ok!(Presence::declare_presence(
+            RuntimeOrigin::signed(1),
+            EpochId::new(1)
+        ));
+
+        System::set_block_number(11);
+        assert_ok!(Presence::declare_presence(
+            RuntimeOrigin::signed(1),
+            EpochId::new(2)
+        ));
+        assert_eq!(Presence::last_declaration(account_to_actor(1)), Some(11));
+    });
+}
+
+// =========================================================================
+// Non-Voter Query
+// =========================================================================
+
+#[test]
+fn non_voters_returns_abstaining_validators() {
+    new_test_ext().execute_with(|| {
+        for v in 10..15 {
+            setup_validator(v);
+        }
+        let epoch = EpochId::new(1);
+        let actor = account_to_actor(1);
+        assert_ok!(Presence::declare_presence(RuntimeOrigin::signed(1), epoch));
+
+        assert_ok!(Presence::vote_presence(
+            RuntimeOrigin::signed(10),
+            actor,
+            epoch,
+            true
+        ));
+        assert_ok!(Presence::vote_presence(
+            RuntimeOrigin::signed(12),
+            actor,
+            epoch,
+            false
+        ));
+
+        let non_voters = Presence::non_voters(epoch, actor);
+        assert_eq!(
+            non_voters,
+            vec![
+                account_to_validator(11),
+                account_to_validator(13),
+                account_to_validator(14),
+            ]
+        );
+    });
+}
+
+#[test]
+fn non_voters_empty_when_all_voted() {
+    new_test_ext().execute_with(|| {
+        setup_validator(10);
+        setup_validator(11);
+        let epoch = EpochId::new(1);
+        let actor = account_to_actor(1);
+        assert_ok!(Presence::declare_presence(RuntimeOrigin::signed(1), epoch));
+
+        for v in [10, 11] {
+            assert_ok!(Presence::vote_presence(
+                RuntimeOrigin::signed(v),
+                actor,
+                epoch,
+                true
+            ));
+        }
+
+        assert!(Presence::non_voters(epoch, actor).is_empty());
+    });
+}
+
+// =========================================================================
+// Commitment-Backed Finalization Tests
+// =========================================================================
+
+/// Declares a commitment for account 1 and votes it to quorum.
+fn commit_and_reach_quorum(epoch: EpochId, secret: [u8; 32], randomness: [u8; 32]) -> ActorId {
+    let actor = account_to_actor(1);
+    let commitment = compute_test_commitment(&actor, &epoch, &secret, &randomness);
+    assert_ok!(Presence::declare_presence_with_commitment(
+        RuntimeOrigin::signed(1),
+        epoch,
+        commitment,
+        test_nullifier(1, epoch)
+    ));
+
+    for validator in 10..=12 {
+        setup_validator(validator);
+        assert_ok!(Presence::vote_presence(
+            RuntimeOrigin::signed(validator),
+            actor,
+            epoch,
+            true
+        ));
+    }
+
+    let record = Presence::presences(epoch, actor).expect("presence should exist");
+    assert_eq!(record.state, PresenceState::Validated);
+    actor
+}
+
+#[test]
+fn revealed_commitment_presence_finalizes() {
+    new_test_ext().execute_with(|| {
+        let epoch = EpochId::new(1);
+        let secret = [42u8; 32];
+        let randomness = [99u8; 32];
+        let actor = commit_and_reach_quorum(epoch, secret, randomness);
+
+        run_to_block(12);
+        assert_ok!(Presence::reveal_commitment(
+            RuntimeOrigin::signed(1),
+            epoch,
+            secret,
+            randomness
+        ));
+        assert_ok!(Presence::finalize_presence(
+            RuntimeOrigin::signed(1),
+            actor,
+            epoch
+        ));
+
+        let record = Presence::presences(epoch, actor).expect("presence should exist");
+        assert_eq!(record.state, PresenceState::Finalized);
+    });
+}
+
+#[test]
+fn unrevealed_commitment_blocks_finalization_despite_quorum() {
+    new_test_ext().execute_with(|| {
+        let epoch = EpochId::new(1);
+        let actor = commit_and_reach_quorum(epoch, [42u8; 32], [99u8; 32]);
+
+        assert_noop!(
+            Presence::finalize_presence(RuntimeOrigin::signed(1), actor, epoch),
+            Error::<Test>::CommitmentNotRevealed
+        );
+
+        run_to_block(12);
+        assert_noop!(
+            Presence::finalize_presence(RuntimeOrigin::signed(10), actor, epoch),
+            Error::<Test>::CommitmentNotRevealed
+        );
+
+        let record = Presence::presences(epoch, actor).expect("presence should exist");
+        assert_eq!(record.state, PresenceState::Validated);
+    });
+}
+
+// =========================================================================
+// Idle Finalization Tests
+// =========================================================================
+
+fn declare_and_validate(account: u64, epoch: EpochId) -> ActorId {
+    assert_ok!(Presence::declare_presence(
+        RuntimeOrigin::signed(account),
+        epoch
+    ));
+    validate_presence(account, epoch);
+    account_to_actor(account)
+}
+
+fn finalized_count(epoch: EpochId, actors: &[ActorId]) -> usize {
+    actors
+        .iter()
+        .filter(|actor| {
+            Presence::presences(epoch, **actor).is_some_and(|r| r.state == PresenceState::Finalized)
+        })
+        .count()
+}
+
+#[test]
+fn on_idle_finalizes_validated_presences_in_bounded_batches() {
+    new_test_ext().execute_with(|| {
+        let epoch = EpochId::new(1);
+        let actors: Vec<ActorId> = (1..=3).map(|a| declare_and_validate(a, epoch)).collect();
+
+        assert_ok!(Presence::declare_presence(RuntimeOrigin::signed(4), epoch));
+        let unvoted = account_to_actor(4);
+
+        let used = Presence::on_idle(5, Weight::MAX);
+        assert!(used.any_gt(Weight::zero()));
+        assert_eq!(finalized_count(epoch, &actors), 2);
+
+        Presence::on_idle(6, Weight::MAX);
+        assert_eq!(finalized_count(epoch, &actors), 3);
+        let finalized_at: Vec<u64> = System::events()
+            .into_iter()
+            .filter_map(|record| match record.event {
+                RuntimeEvent::Presence(Event::PresenceFinalized { block_number, .. }) => {
+                    Some(block_number)
+                }
+                _ => None,
+            })
+            .collect();
+        assert_eq!(finalized_at, vec![5, 5, 6]);
+
+        let record = Presence::presences(epoch, unvoted).expect("presence should exist");
+        assert_eq!(record.state, PresenceState::Declared);
+    });
+}
+
+#[test]
+fn on_idle_respects_remaining_weight() {
+    new_test_ext().execute_with(|| {
+        let epoch = EpochId::new(1);
+        let actor = declare_and_validate(1, epoch);
+
+        assert_eq!(Presence::on_idle(5, Weight::zero()), Weight::zero());
+
+        let record = Presence::presences(epoch, actor).expect("presence should exist");
+        assert_eq!(record.state, PresenceState::Validated);
+    });
+}
+
+// ============================================================================
+// Nullifier Uniqueness Tests
+// ============================================================================
+
+#[test]
+fn shared_nullifier_blocks_second_identity() {
+    new_test_ext().execute_with(|| {
+        let epoch = EpochId::new(1);
+        let nullifier = test_nullifier(1, epoch);
+
+        assert_ok!(Presence::declare_presence_with_commitment(
+            RuntimeOrigin::signed(1),
+            epoch,
+            PresenceCommitment(H256([1u8; 32])),
+            nullifier
+        ));
+        assert!(Presence::epoch_nullifiers(epoch, nullifier).is_some());
+
+        // A second identity derived from the same root secret reuses the nullifier.
+        assert_noop!(
+            Presence::declare_presence_with_commitment(
+                RuntimeOrigin::signed(2),
+                epoch,
+                PresenceCommitment(H256([2u8; 32])),
+                nullifier
+            ),
+            Error::<Test>::NullifierReused
+        );
+        assert!(Presence::declarations(epoch, account_to_actor(2)).is_none());
+        assert_eq!(Presence::commitment_count(epoch), 1);
+
+        assert_ok!(Presence::declare_presence_with_commitment(
+            RuntimeOrigin::signed(2),
+            epoch,
+            PresenceCommitment(H256([2u8; 32])),
+            test_nullifier(2, epoch)
+        ));
+    });
+}
+
+#[test]
+fn nullifier_scoped_to_epoch() {
+    new_test_ext().execute_with(|| {
+        let first = EpochId::new(1);
+        let second = EpochId::new(2);
+        let nullifier = test_nullifier(1, first);
+        ACTIVE_EPOCHS.with(|e| e.borrow_mut().push(2));
+
+        assert_ok!(Presence::declare_presence_with_commitment(
+            RuntimeOrigin::signed(1),
+            first,
+            PresenceCommitment(H256([1u8; 32])),
+            nullifier
+        ));
+        assert!(Presence::epoch_nullifiers(second, nullifier).is_none());
+
+        assert_ok!(Presence::declare_presence_with_commitment(
+            RuntimeOrigin::signed(2),
+            second,
+            PresenceCommitment(H256([2u8; 32])),
+            nullifier
+        ));
+    });
+}
+
+// ============================================================================
+// Device Requirement Tests
+// ============================================================================
+
+#[test]
+fn actor_with_active_device_can_declare() {
+    RequireActiveDevice::set(true);
+    new_test_ext().execute_with(|| {
+        DEVICE_OWNERS.with(|d| d.borrow_mut().push(account_to_actor(1)));
+        let epoch = EpochId::new(1);
+
+        assert_ok!(Presence::declare_presence(RuntimeOrigin::signed(1), epoch));
+        assert!(Presence::presences(epoch, account_to_actor(1)).is_some());
+    });
+    RequireActiveDevice::set(false);
+}
+
+#[test]
+fn actor_without_device_rejected_when_required() {
+    RequireActiveDevice::set(true);
+    new_test_ext().execute_with(|| {
+        let epoch = EpochId::new(1);
+
+        assert_noop!(
+            Presence::declare_presence(RuntimeOrigin::signed(2), epoch),
+            Error::<Test>::NoActiveDevice
+        );
+        assert_noop!(
+            Presence::declare_presence_with_commitment(
+                RuntimeOrigin::signed(2),
+                epoch,
+                PresenceCommitment(H256([1u8; 32])),
+                test_nullifier(2, epoch)
+            ),
+            Error::<Test>::NoActiveDevice
+        );
+    });
+    RequireActiveDevice::set(false);
+
+    // The requirement is opt-in.
+    new_test_ext().execute_with(|| {
+        assert_ok!(Presence::declare_presence(
+            RuntimeOrigin::signed(2),
+            EpochId::new(1)
+        ));
+    });
+}
+
+// ============================================================================
+// Commit-Phase Participation Tests
+// ============================================================================
+
+fn commit_at(account: u64, epoch: EpochId, block: u64) {
+    System::set_block_number(block);
+    assert_ok!(Presence::declare_presence_with_commitment(
+        RuntimeOrigin::signed(account),
+        epoch,
+        PresenceCommitment(H256([account as u8; 32])),
+        test_nullifier(account, epoch)
+    ));
+}
+
+#[test]
+fn commit_phase_held_until_min_commitments() {
+    MinCommitmentsForReveal::set(3);
+    new_test_ext().execute_with(|| {
+        let epoch = EpochId::new(1);
+        commit_at(1, epoch, 1);
+        commit_at(2, epoch, 1);
+
+        // Delay (10 blocks) has elapsed but only two commitments exist.
+        assert_eq!(
+            Presence::get_declaration_phase(epoch, 11),
+            pallet_presence::DeclarationPhase::Commit
+        );
+        assert_eq!(
+            Presence::get_declaration_phase(epoch, 30),
+            pallet_presence::DeclarationPhase::Commit
+        );
+
+        commit_at(3, epoch, 15);
+        assert_eq!(Presence::epoch_min_commitments_at(epoch), Some(15));
+        assert_eq!(Presence::get_reveal_window(epoch), Some((15, 35)));
+        assert_eq!(
+            Presence::get_declaration_phase(epoch, 15),
+            pallet_presence::DeclarationPhase::Reveal
+        );
+    });
+    MinCommitmentsForReveal::set(0);
+}
+
+#[test]
+fn reveal_opens_at_max_commit_duration_without_min() {
+    MinCommitmentsForReveal::set(3);
+    new_test_ext().execute_with(|| {
+        let epoch = EpochId::new(1);
+        commit_at(1, epoch, 1);
+
+        assert_eq!(
+            Presence::get_declaration_phase(epoch, 50),
+            pallet_presence::DeclarationPhase::Commit
+        );
+        assert_eq!(
+            Presence::get_declaration_phase(epoch, 51),
+            pallet_presence::DeclarationPhase::Reveal
+        );
+        assert_eq!(Presence::get_reveal_window(epoch), Some((51, 71)));
+    });
+    MinCommitmentsForReveal::set(0);
+}
+
+// ============================================================================
+// Actor Status Requirement Tests
+// ============================================================================
+
+#[test]
+fn active_actor_can_declare_when_required() {
+    RequireActiveActor::set(true);
+    new_test_ext().execute_with(|| {
+        let epoch = EpochId::new(1);
+
+        assert_ok!(Presence::declare_presence(RuntimeOrigin::signed(1), epoch));
+        assert!(Presence::presences(epoch, account_to_actor(1)).is_some());
+    });
+    RequireActiveActor::set(false);
+}
+
+#[test]
+fn suspended_actor_rejected_when_required() {
+    RequireActiveActor::set(true);
+    new_test_ext().execute_with(|| {
+        SUSPENDED_ACTORS.with(|s| s.borrow_mut().push(account_to_actor(2)));
+        let epoch = EpochId::new(1);
+
+        assert_noop!(
+            Presence::declare_presence(RuntimeOrigin::signed(2), epoch),
+            Error::<Test>::ActorNotActive
+        );
+        assert_noop!(
+            Presence::declare_presence_with_commitment(
+                RuntimeOrigin::signed(2),
+                epoch,
+                PresenceCommitment(H256([1u8; 32])),
+                test_nullifier(2, epoch)
+            ),
+            Error::<Test>::ActorNotActive
+        );
+    });
+    RequireActiveActor::set(false);
+
+    // Without the flag, lifecycle status is not consulted.
+    new_test_ext().execute_with(|| {
+        SUSPENDED_ACTORS.with(|s| s.borrow_mut().push(account_to_actor(2)));
+        assert_ok!(Presence::declare_presence(
+            RuntimeOrigin::signed(2),
+            EpochId::new(1)
+        ));
+    });
+}
+
+// ============================================================================
+// Emergency Finalization Tests
+// ============================================================================
+
+#[test]
+fn emergency_finalize_bypasses_quorum_and_is_marked() {
+    new_test_ext().execute_with(|| {
+        let epoch = EpochId::new(1);
+        let actor = account_to_actor(1);
+        assert_ok!(Presence::declare_presence(RuntimeOrigin::signed(1), epoch));
+
+        setup_validator(10);
+        assert_ok!(Presence::vote_presence(
+            RuntimeOrigin::signed(10),
+            actor,
+            epoch,
+            true
+        ));
+        assert_noop!(
+            Presence::finalize_presence(RuntimeOrigin::signed(1), actor, epoch),
+            Error::<Test>::PresenceNotValidated
+        );
+
+        assert_ok!(Presence::emergency_finalize(
+            RuntimeOrigin::root(),
+            actor,
+            epoch
+        ));
+
+        let record = Presence::presences(epoch, actor).expect("presence should exist");
+        assert_eq!(record.state, PresenceState::Finalized);
+        assert_eq!(Presence::emergency_finalized(epoch, actor), Some(1));
+        System::assert_last_event(RuntimeEvent::Presence(Event::PresenceEmergencyFinalized {
+            actor,
+            epoch,
+            vote_count: 1,
+        }));
+
+        assert_noop!(
+            Presence::emergency_finalize(RuntimeOrigin::root(), actor, epoch),
+            Error::<Test>::PresenceImmutable
+        );
+    });
+}
+
+#[test]
+fn emergency_finalize_requires_root_and_revealed_commitment() {
+    new_test_ext().execute_with(|| {
+        let epoch = EpochId::new(1);
+        let actor = commit_and_reach_quorum(epoch, [42u8; 32], [99u8; 32]);
+
+        assert_noop!(
+            Presence::emergency_finalize(RuntimeOrigin::signed(1), actor, epoch),
+            sp_runtime::DispatchError::BadOrigin
+        );
+        assert_noop!(
+            Presence::emergency_finalize(RuntimeOrigin::root(), actor, epoch),
+            Error::<Test>::CommitmentNotRevealed
+        );
+
+        // Normal finalization leaves no emergency mark.
+        run_to_block(12);
+        assert_ok!(Presence::reveal_commitment(
+            RuntimeOrigin::signed(1),
+            epoch,
+            [42u8; 32],
+            [99u8; 32]
+        ));
+        assert_ok!(Presence::finalize_presence(
+            RuntimeOrigin::signed(1),
+            actor,
+            epoch
+        ));
+        assert!(Presence::emergency_finalized(epoch, actor).is_none());
+    });
+}
+
+// =========================================================================
+// Presence State Query Tests
+// =========================================================================
+
+#[test]
+fn actors_by_state_groups_epoch_presences() {
+    new_test_ext().execute_with(|| {
+        let epoch = EpochId::new(1);
+
+        for account in [1, 2] {
+            assert_ok!(Presence::declare_presence(
+                RuntimeOrigin::signed(account),
+                epoch
+            ));
+        }
+        let validated: Vec<ActorId> = [3, 4]
+            .into_iter()
+            .map(|a| declare_and_validate(a, epoch))
+            .collect();
+        let finalized = declare_and_validate(5, epoch);
+        assert_ok!(Presence::finalize_presence(
+            RuntimeOrigin::signed(5),
+            finalized,
+            epoch
+        ));
+        assert_ok!(Presence::declare_presence(RuntimeOrigin::signed(6), epoch));
+        let slashed = account_to_actor(6);
+        assert_ok!(Presence::slash_presence(
+            RuntimeOrigin::root(),
+            slashed,
+            epoch
+        ));
+
+        // Another epoch's presences are not included.
+        ACTIVE_EPOCHS.with(|e| e.borrow_mut().push(2));
+        assert_ok!(Presence::declare_presence(
+            RuntimeOrigin::signed(7),
+            EpochId::new(2)
+        ));
+
+        let sorted = |mut actors: Vec<ActorId>| {
+            actors.sort();
+            actors
+        };
+        assert_eq!(
+            Presence::actors_by_state(epoch, PresenceState::Declared),
+            sorted(vec![account_to_actor(1), account_to_actor(2)])
+        );
+        assert_eq!(
+            Presence::actors_by_state(epoch, PresenceState::Validated),
+            sorted(validated)
+        );
+        assert_eq!(
+            Presence::actors_by_state(epoch, PresenceState::Finalized),
+            vec![finalized]
+        );
+        assert_eq!(
+            Presence::actors_by_state(epoch, PresenceState::Slashed),
+            vec![slashed]
+        );
+        assert!(Presence::actors_by_state(epoch, PresenceState::None).is_empty());
+        assert_eq!(
+            Presence::actors_by_state(EpochId::new(2), PresenceState::Declared),
+            vec![account_to_actor(7)]
+        );
+    });
+}
+
+// =========================================================================
+// Presence Challenge Tests
+// =========================================================================
+
+/// Declare, validate and finalize `account`'s presence in epoch 1.
+fn finalize_for(account: u64) -> (ActorId, EpochId) {
+    let epoch = EpochId::new(1);
+    let actor = account_to_actor(account);
+    assert_ok!(Presence::declare_presence(
+        RuntimeOrigin::signed(account),
+        epoch
+    ));
+    validate_presence(account, epoch);
+    assert_ok!(Presence::finalize_presence(
+        RuntimeOrigin::signed(account),
+        actor,
+        epoch
+    ));
+    (actor, epoch)
+}
+
+#[test]
+fn challenge_moves_finalized_presence_to_challenged() {
+    new_test_ext().execute_with(|| {
+        let (actor, epoch) = finalize_for(1);
+
+        assert_noop!(
+            Presence::challenge_presence(RuntimeOrigin::signed(20), actor, epoch, H256([1u8; 32])),
+            Error::<Test>::ValidatorNotActive
+        );
+
+        assert_ok!(Presence::challenge_presence(
+            RuntimeOrigin::signed(10),
+            actor,
+            epoch,
+            H256([1u8; 32])
+        ));
+
+        let record = Presence::presences(epoch, actor).expect("presence should exist");
+        assert_eq!(record.state, PresenceState::Challenged);
+        assert_eq!(Presence::presence_challenges(epoch, actor), Some(0));
+        assert_eq!(
+            OPENED_DISPUTES.with(|d| d.borrow().clone()),
+            vec![(10, actor, epoch)]
+        );
+        System::assert_last_event(RuntimeEvent::Presence(Event::PresenceChallenged {
+            actor,
+            epoch,
+            challenger: account_to_validator(10),
+            dispute_id: 0,
+        }));
+
+        // Held until resolved.
+        assert_noop!(
+            Presence::challenge_presence(RuntimeOrigin::signed(11), actor, epoch, H256([2u8; 32])),
+            Error::<Test>::PresenceNotFinalized
+        );
+        assert_noop!(
+            Presence::slash_presence(RuntimeOrigin::root(), actor, epoch),
+            Error::<Test>::PresenceUnderChallenge
+        );
+        assert_noop!(
+            Presence::emergency_finalize(RuntimeOrigin::root(), actor, epoch),
+            Error::<Test>::PresenceUnderChallenge
+        );
+    });
+}
+
+#[test]
+fn challenge_requires_finalized_presence_within_window() {
+    new_test_ext().execute_with(|| {
+        let epoch = EpochId::new(1);
+        let pending = account_to_actor(2);
+        assert_ok!(Presence::declare_presence(RuntimeOrigin::signed(2), epoch));
+        setup_validator(10);
+        assert_noop!(
+            Presence::challenge_presence(
+                RuntimeOrigin::signed(10),
+                pending,
+                epoch,
+                H256([1u8; 32])
+            ),
+            Error::<Test>::PresenceNotFinalized
+        );
+
+        let (actor, epoch) = finalize_for(1);
+        run_to_block(1 + ChallengeWindow::get() + 1);
+        assert_noop!(
+            Presence::challenge_presence(RuntimeOrigin::signed(10), actor, epoch, H256([1u8; 32])),
+            Error::<Test>::ChallengeWindowClosed
+        );
+    });
+}
+
+#[test]
+fn rejected_challenge_restores_finalized() {
+    new_test_ext().execute_with(|| {
+        let (actor, epoch) = finalize_for(1);
+        assert_ok!(Presence::challenge_presence(
+            RuntimeOrigin::signed(10),
+            actor,
+            epoch,
+            H256([1u8; 32])
+        ));
+
+        assert!(Presence::resolve_presence_challenge(
+            RuntimeOrigin::signed(10),
+            actor,
+            epoch,
+            false
+        )
+        .is_err());
+        assert_ok!(Presence::resolve_presence_challenge(
+            RuntimeOrigin::root(),
+            actor,
+            epoch,
+            false
+        ));
+
+        let record = Presence::presences(epoch, actor).expect("presence should exist");
+        assert_eq!(record.state, PresenceState::Finalized);
+        assert_eq!(Presence::presence_challenges(epoch, actor), None);
+        assert_eq!(
+            CLOSED_DISPUTES.with(|d| d.borrow().clone()),
+            vec![(0, false)]
+        );
+        System::assert_last_event(RuntimeEvent::Presence(Event::PresenceChallengeResolved {
+            actor,
+            epoch,
+            upheld: false,
+        }));
+
+        assert_noop!(
+            Presence::resolve_presence_challenge(RuntimeOrigin::root(), actor, epoch, true),
+            Error::<Test>::PresenceNotChallenged
+        );
+    });
+}
+
+#[test]
+fn upheld_challenge_slashes_presence() {
+    new_test_ext().execute_with(|| {
+        let (actor, epoch) = finalize_for(1);
+        assert_ok!(Presence::challenge_presence(
+            RuntimeOrigin::signed(10),
+            actor,
+            epoch,
+            H256([1u8; 32])
+        ));
+        assert_ok!(Presence::resolve_presence_challenge(
+            RuntimeOrigin::root(),
+            actor,
+            epoch,
+            true
+        ));
+
+        let record = Presence::presences(epoch, actor).expect("presence should exist");
+        assert_eq!(record.state, PresenceState::Slashed);
+        assert_eq!(
+            CLOSED_DISPUTES.with(|d| d.borrow().clone()),
+            vec![(0, true)]
+        );
+        System::assert_has_event(RuntimeEvent::Presence(Event::PresenceSlashed {
+            actor,
+            epoch,
+        }));
+
+        assert_noop!(
+            Presence::challenge_presence(RuntimeOrigin::signed(10), actor, epoch, H256([1u8; 32])),
+            Error::<Test>::PresenceNotFinalized
+        );
+    });
+}
+
+// =========================================================================
+// Batch Finalization Tests
+// =========================================================================
+
+fn finalize_batch(account: u64, items: Vec<(ActorId, EpochId)>) -> DispatchResult {
+    Presence::finalize_presences_batch(
+        RuntimeOrigin::signed(account),
+        BoundedVec::try_from(items).expect("batch within bound"),
+    )
+}
+
+#[test]
+fn batch_finalizes_eligible_presences_and_skips_the_rest() {
+    new_test_ext().execute_with(|| {
+        let epoch = EpochId::new(1);
+        let eligible: Vec<ActorId> = (1..=2).map(|a| declare_and_validate(a, epoch)).collect();
+        let (already_finalized, _) = finalize_for(3);
+        assert_ok!(Presence::declare_presence(RuntimeOrigin::signed(4), epoch));
+        let unvoted = account_to_actor(4);
+        let missing = account_to_actor(5);
+
+        let items = [
+            eligible[0],
+            already_finalized,
+            unvoted,
+            eligible[1],
+            missing,
+        ]
+        .into_iter()
+        .map(|actor| (actor, epoch))
+        .collect();
+        assert_ok!(finalize_batch(10, items));
+
+        assert_eq!(finalized_count(epoch, &eligible), 2);
+        let record = Presence::presences(epoch, unvoted).expect("presence should exist");
+        assert_eq!(record.state, PresenceState::Declared);
+        assert!(Presence::presences(epoch, missing).is_none());
+
+        for actor in &eligible {
+            System::assert_has_event(RuntimeEvent::Presence(Event::PresenceFinalized {
+                actor: *actor,
+                epoch,
+                block_number: 1,
+            }));
+        }
+        System::assert_last_event(RuntimeEvent::Presence(Event::PresencesBatchFinalized {
+            finalized: 2,
+            skipped: 3,
+        }));
+    });
+}
+
+#[test]
+fn batch_from_non_validator_finalizes_only_own_presence() {
+    new_test_ext().execute_with(|| {
+        let epoch = EpochId::new(1);
+        let own = declare_and_validate(6, epoch);
+        let other = declare_and_validate(7, epoch);
+
+        assert_ok!(finalize_batch(6, vec![(own, epoch), (other, epoch)]));
+
+        assert_eq!(finalized_count(epoch, &[own]), 1);
+        assert_eq!(finalized_count(epoch, &[other]), 0);
+        System::assert_last_event(RuntimeEvent::Presence(Event::PresencesBatchFinalized {
+            finalized: 1,
+            skipped: 1,
+        }));
+    });
+}
+
+// =========================================================================
+// Epoch Presence Cap Tests
+// =========================================================================
+
+#[test]
+fn declarations_succeed_up_to_epoch_cap() {
+    MaxPresencesPerEpoch::set(3);
+    new_test_ext().execute_with(|| {
+        let epoch = EpochId::new(1);
+
+        assert_ok!(Presence::declare_presence(RuntimeOrigin::signed(1), epoch));
+        assert_ok!(Presence::declare_presence(RuntimeOrigin::signed(2), epoch));
+        assert_ok!(Presence::declare_presence_with_commitment(
+            RuntimeOrigin::signed(3),
+            epoch,
+            PresenceCommitment::new(&3u64, &[3u8; 32]),
+            test_nullifier(3, epoch)
+        ));
+        assert_eq!(Presence::presence_count(epoch), 3);
+
+        assert_noop!(
+            Presence::declare_presence(RuntimeOrigin::signed(4), epoch),
+            Error::<Test>::EpochPresenceCapReached
+        );
+        assert_noop!(
+            Presence::declare_presence_with_commitment(
+                RuntimeOrigin::signed(5),
+                epoch,
+                PresenceCommitment::new(&5u64, &[5u8; 32]),
+                test_nullifier(5, epoch)
+            ),
+            Error::<Test>::EpochPresenceCapReached
+        );
+    });
+    MaxPresencesPerEpoch::set(1000);
+}
+
+#[test]
+fn new_epoch_resets_presence_capacity() {
+    MaxPresencesPerEpoch::set(2);
+    new_test_ext().execute_with(|| {
+        let epoch1 = EpochId::new(1);
+        let epoch2 = EpochId::new(2);
+        ACTIVE_EPOCHS.with(|e| e.borrow_mut().push(2));
+
+        assert_ok!(Presence::declare_presence(RuntimeOrigin::signed(1), epoch1));
+        assert_ok!(Presence::declare_presence(RuntimeOrigin::signed(2), epoch1));
+        assert_noop!(
+            Presence::declare_presence(RuntimeOrigin::signed(3), epoch1),
+            Error::<Test>::EpochPresenceCapReached
+        );
+
+        assert_ok!(Presence::declare_presence(RuntimeOrigin::signed(3), epoch2));
+        assert_ok!(Presence::declare_presence(RuntimeOrigin::signed(4), epoch2));
+        assert_eq!(Presence::presence_count(epoch2), 2);
+    });
+    MaxPresencesPerEpoch::set(1000);
+}
+
+// =========================================================================
+// Challenge Limit Tests
+// =========================================================================
+
+#[test]
+fn open_challenges_capped_per_validator() {
+    MaxOpenChallengesPerValidator::set(1);
+    new_test_ext().execute_with(|| {
+        let (first, epoch) = finalize_for(1);
+        let (second, _) = finalize_for(2);
+        let challenger = account_to_validator(10);
+
+        assert_ok!(Presence::challenge_presence(
+            RuntimeOrigin::signed(10),
+            first,
+            epoch,
+            H256([1u8; 32])
+        ));
+        assert_eq!(Presence::open_challenge_count(challenger), 1);
+        assert_noop!(
+            Presence::challenge_presence(RuntimeOrigin::signed(10), second, epoch, H256([2u8; 32])),
+            Error::<Test>::TooManyOpenChallenges
+        );
+
+        // Resolving frees the slot.
+        assert_ok!(Presence::resolve_presence_challenge(
+            RuntimeOrigin::root(),
+            first,
+            epoch,
+            true
+        ));
+        assert_eq!(Presence::open_challenge_count(challenger), 0);
+        assert_ok!(Presence::challenge_presence(
+            RuntimeOrigin::signed(10),
+            second,
+            epoch,
+            H256([2u8; 32])
+        ));
+    });
+    MaxOpenChallengesPerValidator::set(4);
+}
+
+#[test]
+fn rejected_challenge_blocks_rechallenge() {
+    new_test_ext().execute_with(|| {
+        let (actor, epoch) = finalize_for(1);
+        assert_ok!(Presence::challenge_presence(
+            RuntimeOrigin::signed(10),
+            actor,
+            epoch,
+            H256([1u8; 32])
+        ));
+        assert_ok!(Presence::resolve_presence_challenge(
+            RuntimeOrigin::root(),
+            actor,
+            epoch,
+            false
+        ));
+        assert!(Presence::challenge_cleared(epoch, actor).is_some());
+
+        assert_noop!(
+            Presence::challenge_presence(RuntimeOrigin::signed(11), actor, epoch, H256([2u8; 32])),
+            Error::<Test>::PresenceAlreadyCleared
+        );
+    });
+}
@@ -20,6 +20,13 @@ pub trait WeightInfo {
     fn claim_position() -> Weight;
     fn submit_witness_attestation() -> Weight;
     fn verify_position() -> Weight;
+    fn set_epoch_commit_reveal_config() -> Weight;
+    fn set_quorum_mode() -> Weight;
+    fn submit_aggregate_votes(n: u32) -> Weight;
+    fn emergency_finalize() -> Weight;
+    fn challenge_presence() -> Weight;
+    fn resolve_presence_challenge() -> Weight;
+    fn finalize_presences_batch(n: u32) -> Weight;
 }
 
 pub struct SubstrateWeight<T>(PhantomData<T>);
@@ -27,26 +34,26 @@ pub struct SubstrateWeight<T>(PhantomData<T>);
 impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
     fn declare_presence() -> Weight {
         Weight::from_parts(25_000_000, 0)
-            .saturating_add(T::DbWeight::get().reads(2))
-            .saturating_add(T::DbWeight::get().writes(2))
+            .saturating_add(T::DbWeight::get().reads(3))
+            .saturating_add(T::DbWeight::get().writes(3))
     }
 
     fn declare_presence_with_commitment() -> Weight {
         Weight::from_parts(35_000_000, 0)
-            .saturating_add(T::DbWeight::get().reads(2))
-            .saturating_add(T::DbWeight::get().writes(3))
+            .saturating_add(T::DbWeight::get().reads(4))
+            .saturating_add(T::DbWeight::get().writes(6))
     }
 
     fn vote_presence() -> Weight {
         Weight::from_parts(40_000_000, 0)
-            .saturating_add(T::DbWeight::get().reads(4))
+            .saturating_add(T::DbWeight::get().reads(6))
             .saturating_add(T::DbWeight::get().writes(3))
     }
 
     fn finalize_presence() -> Weight {
         Weight::from_parts(20_000_000, 0)
-            .saturating_add(T::DbWeight::get().reads(3))
-            .saturating_add(T::DbWeight::get().writes(1))
+            .saturating_add(T::DbWeight::get().reads(8))
+            .saturating_add(T::DbWeight::get().writes(2))
     }
 
     fn slash_presence() -> Weight {
@@ -56,7 +63,7 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
     }
 
     fn set_quorum_config() -> Weight {
-        Weight::from_parts(10_000_000, 0).saturating_add(T::DbWeight::get().writes(1))
+        Weight::from_parts(10_000_000, 0).saturating_add(T::DbWeight::get().writes(2))
     }
 
     fn set_validator_position() -> Weight {
@@ -67,8 +74,8 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 
     fn reveal_commitment() -> Weight {
         Weight::from_parts(40_000_000, 0)
-            .saturating_add(T::DbWeight::get().reads(3))
-            .saturating_add(T::DbWeight::get().writes(3))
+            .saturating_add(T::DbWeight::get().reads(4))
+            .saturating_add(T::DbWeight::get().writes(4))
     }
 
     fn claim_position() -> Weight {
@@ -88,31 +95,84 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
             .saturating_add(T::DbWeight::get().reads(4))
             .saturating_add(T::DbWeight::get().writes(2))
     }
+
+    fn set_epoch_commit_reveal_config() -> Weight {
+        Weight::from_parts(10_000_000, 0).saturating_add(T::DbWeight::get().writes(1))
+    }
+
+    fn set_quorum_mode() -> Weight {
+        Weight::from_parts(10_000_000, 0).saturating_add(T::DbWeight::get().writes(1))
+    }
+
+    fn submit_aggregate_votes(n: u32) -> Weight {
+        Weight::from_parts(40_000_000, 0)
+            .saturating_add(Weight::from_parts(15_000_000, 0).saturating_mul(n as u64))
+            .saturating_add(T::DbWeight::get().reads(5))
+            .saturating_add(T::DbWeight::get().reads((n as u64).saturating_mul(2)))
+            .saturating_add(T::DbWeight::get().writes(2))
+            .saturating_add(T::DbWeight::get().writes(n as u64))
+    }
+
+    fn emergency_finalize() -> Weight {
+        // Reads: Presences, Declarations, PresenceStreak
+        // Writes: Presences, EmergencyFinalized, PresenceStreak
+        Weight::from_parts(20_000_000, 0)
+            .saturating_add(T::DbWeight::get().reads(3))
+            .saturating_add(T::DbWeight::get().writes(3))
+    }
+
+    fn challenge_presence() -> Weight {
+        // Reads: Presences, ChallengeCleared, OpenChallengeCount, DisputeCount, OpenDisputes
+        // Writes: Presences, PresenceChallenges, PresenceChallengers, OpenChallengeCount,
+        //         DisputeCount, OpenDisputes, dispute record
+        Weight::from_parts(25_000_000, 0)
+            .saturating_add(T::DbWeight::get().reads(5))
+            .saturating_add(T::DbWeight::get().writes(7))
+    }
+
+    fn resolve_presence_challenge() -> Weight {
+        // Reads: Presences, PresenceChallenges, PresenceChallengers, OpenChallengeCount,
+        //        dispute record, OpenDisputes
+        // Writes: Presences, PresenceChallenges, PresenceChallengers, OpenChallengeCount,
+        //         ChallengeCleared, dispute record, OpenDisputes
+        Weight::from_parts(20_000_000, 0)
+            .saturating_add(T::DbWeight::get().reads(6))
+            .saturating_add(T::DbWeight::get().writes(7))
+    }
+
+    fn finalize_presences_batch(n: u32) -> Weight {
+        // Per entry: the reads and writes of `finalize_presence`
+        Weight::from_parts(10_000_000, 0)
+            .saturating_add(Weight::from_parts(20_000_000, 0).saturating_mul(n as u64))
+            .saturating_add(T::DbWeight::get().reads(1))
+            .saturating_add(T::DbWeight::get().reads((n as u64).saturating_mul(8)))
+            .saturating_add(T::DbWeight::get().writes((n as u64).saturating_mul(2)))
+    }
 }
 
 impl WeightInfo for () {
     fn declare_presence() -> Weight {
         Weight::from_parts(25_000_000, 0)
-            .saturating_add(RocksDbWeight::get().reads(2))
-            .saturating_add(RocksDbWeight::get().writes(2))
+            .saturating_add(RocksDbWeight::get().reads(3))
+            .saturating_add(RocksDbWeight::get().writes(3))
     }
 
     fn declare_presence_with_commitment() -> Weight {
         Weight::from_parts(35_000_000, 0)
-            .saturating_add(RocksDbWeight::get().reads(2))
-            .saturating_add(RocksDbWeight::get().writes(3))
+            .saturating_add(RocksDbWeight::get().reads(4))
+            .saturating_add(RocksDbWeight::get().writes(6))
     }
 
     fn vote_presence() -> Weight {
         Weight::from_parts(40_000_000, 0)
-            .saturating_add(RocksDbWeight::get().reads(4))
+            .saturating_add(RocksDbWeight::get().reads(6))
             .saturating_add(RocksDbWeight::get().writes(3))
     }
 
     fn finalize_presence() -> Weight {
         Weight::from_parts(20_000_000, 0)
-            .saturating_add(RocksDbWeight::get().reads(3))
-            .saturating_add(RocksDbWeight::get().writes(1))
+            .saturating_add(RocksDbWeight::get().reads(8))
+            .saturating_add(RocksDbWeight::get().writes(2))
     }
 
     fn slash_presence() -> Weight {
@@ -122,7 +182,7 @@ impl WeightInfo for () {
     }
 
     fn set_quorum_config() -> Weight {
-        Weight::from_parts(10_000_000, 0).saturating_add(RocksDbWeight::get().writes(1))
+        Weight::from_parts(10_000_000, 0).saturating_add(RocksDbWeight::get().writes(2))
     }
 
     fn set_validator_position() -> Weight {
@@ -133,8 +193,8 @@ impl WeightInfo for () {
 
     fn reveal_commitment() -> Weight {
         Weight::from_parts(40_000_000, 0)
-            .saturating_add(RocksDbWeight::get().reads(3))
-            .saturating_add(RocksDbWeight::get().writes(3))
+            .saturating_add(RocksDbWeight::get().reads(4))
+            .saturating_add(RocksDbWeight::get().writes(4))
     }
 
     fn claim_position() -> Weight {
@@ -154,4 +214,57 @@ impl WeightInfo for () {
             .saturating_add(RocksDbWeight::get().reads(4))
             .saturating_add(RocksDbWeight::get().writes(2))
     }
+
+    fn set_epoch_commit_reveal_config() -> Weight {
+        Weight::from_parts(10_000_000, 0).saturating_add(RocksDbWeight::get().writes(1))
+    }
+
+    fn set_quorum_mode() -> Weight {
+        Weight::from_parts(10_000_000, 0).saturating_add(RocksDbWeight::get().writes(1))
+    }
+
+    fn submit_aggregate_votes(n: u32) -> Weight {
+        Weight::from_parts(40_000_000, 0)
+            .saturating_add(Weight::from_parts(15_000_000, 0).saturating_mul(n as u64))
+            .saturating_add(RocksDbWeight::get().reads(5))
+            .saturating_add(RocksDbWeight::get().reads((n as u64).saturating_mul(2)))
+            .saturating_add(RocksDbWeight::get().writes(2))
+            .saturating_add(RocksDbWeight::get().writes(n as u64))
+    }
+
+    fn emergency_finalize() -> Weight {
+        // Reads: Presences, Declarations, PresenceStreak
+        // Writes: Presences, EmergencyFinalized, PresenceStreak
+        Weight::from_parts(20_000_000, 0)
+            .saturating_add(RocksDbWeight::get().reads(3))
+            .saturating_add(RocksDbWeight::get().writes(3))
+    }
+
+    fn challenge_presence() -> Weight {
+        // Reads: Presences, ChallengeCleared, OpenChallengeCount, DisputeCount, OpenDisputes
+        // Writes: Presences, PresenceChallenges, PresenceChallengers, OpenChallengeCount,
+        //         DisputeCount, OpenDisputes, dispute record
+        Weight::from_parts(25_000_000, 0)
+            .saturating_add(RocksDbWeight::get().reads(5))
+            .saturating_add(RocksDbWeight::get().writes(7))
+    }
+
+    fn resolve_presence_challenge() -> Weight {
+        // Reads: Presences, PresenceChallenges, PresenceChallengers, OpenChallengeCount,
+        //        dispute record, OpenDisputes
+        // Writes: Presences, PresenceChallenges, PresenceChallengers, OpenChallengeCount,
+        //         ChallengeCleared, dispute record, OpenDisputes
+        Weight::from_parts(20_000_000, 0)
+            .saturating_add(RocksDbWeight::get().reads(6))
+            .saturating_add(RocksDbWeight::get().writes(7))
+    }
+
+    fn finalize_presences_batch(n: u32) -> Weight {
+        // Per entry: the reads and writes of `finalize_presence`
+        Weight::from_parts(10_000_000, 0)
+            .saturating_add(Weight::from_parts(20_000_000, 0).saturating_mul(n as u64))
+            .saturating_add(RocksDbWeight::get().reads(1))
+            .saturating_add(RocksDbWeight::get().reads((n as u64).saturating_mul(8)))
+            .saturating_add(RocksDbWeight::get().writes((n as u64).saturating_mul(2)))
+    }
 }
@@ -12,7 +12,7 @@ pub use weights::WeightInfo;
 
 #[frame_support::pallet]
 pub mod pallet {
-    use alloc::vec::Vec;
+    use alloc::{collections::BTreeSet, vec, vec::Vec};
     use frame_support::{
         pallet_prelude::*,
         traits::{Get, StorageVersion},
@@ -20,7 +20,10 @@ pub mod pallet {
     };
     use frame_system::pallet_prelude::*;
     use seveny_primitives::types::ActorId;
-    use sp_runtime::Saturating;
+    use sp_runtime::{
+        traits::{SaturatedConversion, Zero},
+        Saturating,
+    };
 
     use crate::WeightInfo;
 
@@ -113,6 +116,26 @@ pub mod pallet {
         Expired,
     }
 
+    #[derive(
+        Clone,
+        Copy,
+        PartialEq,
+        Eq,
+        Encode,
+        Decode,
+        parity_scale_codec::DecodeWithMemTracking,
+        MaxEncodedLen,
+        TypeInfo,
+        RuntimeDebug,
+    )]
+    pub enum RelationshipDelta {
+        Created,
+        Accepted,
+        Revoked,
+        Expired,
+        TrustChanged,
+    }
+
     #[derive(
         Clone,
         Copy,
@@ -229,6 +252,23 @@ pub mod pallet {
         pub last_activity_block: u64,
     }
 
+    /// Observed interactions along one relationship.
+    #[derive(
+        Clone,
+        PartialEq,
+        Eq,
+        Encode,
+        Decode,
+        parity_scale_codec::DecodeWithMemTracking,
+        MaxEncodedLen,
+        TypeInfo,
+        RuntimeDebug,
+    )]
+    pub struct InteractionRecord<BlockNumber> {
+        pub count: u32,
+        pub last_interaction: BlockNumber,
+    }
+
     #[pallet::pallet]
     #[pallet::storage_version(STORAGE_VERSION)]
     pub struct Pallet<T>(_);
@@ -251,6 +291,52 @@ pub mod pallet {
 
         #[pallet::constant]
         type MaxTrustLevel: Get<u8>;
+
+        /// Maximum actors visited by a single graph traversal or inbound scan.
+        #[pallet::constant]
+        type MaxGraphTraversal: Get<u32>;
+
+        /// Largest trust level change allowed in one `update_trust_level` call.
+        #[pallet::constant]
+        type MaxTrustDeltaPerUpdate: Get<u8>;
+
+        /// Minimum blocks between trust level updates on the same relationship.
+        #[pallet::constant]
+        type TrustUpdateCooldownBlocks: Get<BlockNumberFor<Self>>;
+
+        /// Discovery result cap for a requester with no trust score.
+        #[pallet::constant]
+        type MinDiscoveryResults: Get<u32>;
+
+        /// Profile trust score at which a requester gets the full `MaxDiscoveryResults`.
+        #[pallet::constant]
+        type FullDiscoveryTrustScore: Get<u32>;
+
+        /// Maximum entries in one `import_relationships` batch.
+        #[pallet::constant]
+        type MaxImportBatch: Get<u32>;
+
+        /// Blocks after which an unfinished discovery request fails, and after which
+        /// a finished one is purged. Zero disables both.
+        #[pallet::constant]
+        type DiscoveryRequestTtl: Get<BlockNumberFor<Self>>;
+
+        /// Whether `record_interaction` is accepted and trust follows interaction frequency.
+        #[pallet::constant]
+        type InteractionTrustAdjustment: Get<bool>;
+
+        /// Interactions that raise a relationship's trust level by one.
+        #[pallet::constant]
+        type InteractionsPerTrustStep: Get<u32>;
+
+        /// Trust level interactions alone can raise a relationship to.
+        #[pallet::constant]
+        type InteractionTrustCap: Get<u8>;
+
+        /// Dormant blocks that cost an interacting relationship one trust level.
+        /// Zero disables decay.
+        #[pallet::constant]
+        type InteractionDecayBlocks: Get<BlockNumberFor<Self>>;
     }
 
     #[pallet::storage]
@@ -284,6 +370,32 @@ pub mod pallet {
         OptionQuery,
     >;
 
+    /// Reverse of `RelationshipIndex`, keyed by the target actor.
+    #[pallet::storage]
+    #[pallet::getter(fn inbound_relationships)]
+    pub type InboundRelationships<T: Config> = StorageDoubleMap<
+        _,
+        Blake2_128Concat,
+        ActorId,
+        Blake2_128Concat,
+        ActorId,
+        RelationshipId,
+        OptionQuery,
+    >;
+
+    /// Per-actor counter bumped whenever a relationship touching the actor changes.
+    #[pallet::storage]
+    #[pallet::getter(fn relationship_version)]
+    pub type RelationshipVersion<T: Config> =
+        StorageMap<_, Blake2_128Concat, ActorId, u64, ValueQuery>;
+
+    /// Lifetime applied to relationships of a type created without an explicit
+    /// expiry. Types without an entry never expire by default.
+    #[pallet::storage]
+    #[pallet::getter(fn type_default_expiry)]
+    pub type TypeDefaultExpiry<T: Config> =
+        StorageMap<_, Blake2_128Concat, RelationshipType, BlockNumberFor<T>, OptionQuery>;
+
     #[pallet::storage]
     #[pallet::getter(fn discovery_requests)]
     pub type DiscoveryRequests<T: Config> =
@@ -308,6 +420,22 @@ pub mod pallet {
     pub type PendingDiscovery<T: Config> =
         StorageValue<_, BoundedVec<DiscoveryRequestId, T::MaxDiscoveryResults>, ValueQuery>;
 
+    /// Lowest discovery request id that may still need purging.
+    #[pallet::storage]
+    pub type DiscoveryPurgeCursor<T: Config> = StorageValue<_, u64, ValueQuery>;
+
+    /// Block of the last trust level update per relationship.
+    #[pallet::storage]
+    #[pallet::getter(fn last_trust_update)]
+    pub type LastTrustUpdate<T: Config> =
+        StorageMap<_, Blake2_128Concat, RelationshipId, BlockNumberFor<T>>;
+
+    /// Interactions recorded per relationship while `InteractionTrustAdjustment` is on.
+    #[pallet::storage]
+    #[pallet::getter(fn relationship_interactions)]
+    pub type RelationshipInteractions<T: Config> =
+        StorageMap<_, Blake2_128Concat, RelationshipId, InteractionRecord<BlockNumberFor<T>>>;
+
     #[pallet::event]
     #[pallet::generate_deposit(pub(super) fn deposit_event)]
     pub enum Event<T: Config> {
@@ -338,6 +466,8 @@ pub mod pallet {
             request_id: DiscoveryRequestId,
             results_count: u32,
         },
+        /// Unfinished discovery request outlived `DiscoveryRequestTtl`
+        DiscoveryExpired { request_id: DiscoveryRequestId },
         DiscoveryRateLimited {
             requester: ActorId,
             next_allowed_block: BlockNumberFor<T>,
@@ -346,6 +476,22 @@ pub mod pallet {
             actor: ActorId,
             discovery_enabled: bool,
         },
+        /// Summary of a change to an actor's relationship subgraph, for indexers
+        RelationshipGraphChanged {
+            actor: ActorId,
+            delta_kind: RelationshipDelta,
+        },
+        TypeDefaultExpiryUpdated {
+            relationship_type: RelationshipType,
+            duration: Option<BlockNumberFor<T>>,
+        },
+        /// Outcome of a bulk `import_relationships` call
+        RelationshipsImported { created: u32, skipped: u32 },
+        InteractionRecorded {
+            relationship_id: RelationshipId,
+            interactions: u32,
+            trust_level: u8,
+        },
     }
 
     #[pallet::error]
@@ -363,6 +509,16 @@ pub mod pallet {
         ProfileNotFound,
         RelationshipExpired,
         PendingDiscoveryFull,
+        /// Trust level change exceeds `MaxTrustDeltaPerUpdate`
+        TrustDeltaTooLarge,
+        /// Trust level was updated too recently
+        TrustUpdateTooFrequent,
+        /// `InteractionTrustAdjustment` is off
+        InteractionTrackingDisabled,
+        /// Proposed trust level is above the target's accepted ceiling
+        TrustLevelTooHigh,
+        /// An interaction was already recorded for this relationship this block
+        InteractionTooFrequent,
     }
 
     #[pallet::genesis_config]
@@ -404,12 +560,16 @@ pub mod pallet {
                         r.status = RelationshipStatus::Expired;
                     }
                 });
+                Self::note_graph_change(&relationship, RelationshipDelta::Expired);
                 expired_count = expired_count.saturating_add(1);
             }
 
             T::DbWeight::get()
                 .reads(expired_count.into())
-                .saturating_add(T::DbWeight::get().writes(expired_count.into()))
+                .saturating_add(
+                    T::DbWeight::get().writes(u64::from(expired_count).saturating_mul(3)),
+                )
+                .saturating_add(Self::expire_discovery_requests(now))
         }
     }
 
@@ -439,44 +599,15 @@ pub mod pallet {
                 Error::<T>::RelationshipAlreadyExists
             );
 
-            let relationship_id = RelationshipId::new(RelationshipCount::<T>::get());
-            RelationshipCount::<T>::put(relationship_id.inner().saturating_add(1));
-
-            let relationship = Relationship {
-                id: relationship_id,
+            Self::insert_relationship(
                 from_actor,
                 to_actor,
                 relationship_type,
-                status: if bidirectional {
-                    RelationshipStatus::Pending
-                } else {
-                    RelationshipStatus::Active
-                },
-                created_at: block_number,
-                updated_at: block_number,
+                trust_level,
                 expires_at,
                 bidirectional,
-                trust_level,
-            };
-
-            Relationships::<T>::insert(relationship_id, relationship);
-            RelationshipIndex::<T>::insert(from_actor, to_actor, relationship_id);
-
-            ActorRelationships::<T>::try_mutate(from_actor, |rels| {
-                rels.try_push(relationship_id)
-                    .map_err(|_| Error::<T>::MaxRelationshipsReached)
-            })?;
-
-            Self::update_profile_relationship_count(from_actor, block_number, true);
-
-            Self::deposit_event(Event::RelationshipCreated {
-                relationship_id,
-                from_actor,
-                to_actor,
-                relationship_type,
-            });
-
-            Ok(())
+                block_number,
+            )
         }
 
         #[pallet::call_index(1)]
@@ -484,6 +615,7 @@ pub mod pallet {
         pub fn accept_relationship(
             origin: OriginFor<T>,
             relationship_id: RelationshipId,
+            max_accepted_trust: Option<u8>,
         ) -> DispatchResult {
             let who = ensure_signed(origin)?;
             let actor = Self::account_to_actor(&who);
@@ -497,6 +629,14 @@ pub mod pallet {
                 relationship.status == RelationshipStatus::Pending,
                 Error::<T>::RelationshipRevoked
             );
+            // The target can refuse an inflated trust claim and counter-propose
+            // a lower level with its own `create_relationship`.
+            if let Some(ceiling) = max_accepted_trust {
+                ensure!(
+                    relationship.trust_level <= ceiling,
+                    Error::<T>::TrustLevelTooHigh
+                );
+            }
 
             relationship.status = RelationshipStatus::Active;
             relationship.updated_at = block_number;
@@ -509,6 +649,7 @@ pub mod pallet {
             })?;
 
             Self::update_profile_relationship_count(actor, block_number, true);
+            Self::note_graph_change(&relationship, RelationshipDelta::Accepted);
 
             Self::deposit_event(Event::RelationshipUpdated {
                 relationship_id,
@@ -554,6 +695,7 @@ pub mod pallet {
             if was_active_bidirectional {
                 Self::update_profile_relationship_count(relationship.to_actor, block_number, false);
             }
+            Self::note_graph_change(&relationship, RelationshipDelta::Revoked);
 
             Self::deposit_event(Event::RelationshipRevoked {
                 relationship_id,
@@ -586,12 +728,23 @@ pub mod pallet {
                 new_trust_level <= T::MaxTrustLevel::get(),
                 Error::<T>::InvalidTrustLevel
             );
+            ensure!(
+                new_trust_level.abs_diff(relationship.trust_level)
+                    <= T::MaxTrustDeltaPerUpdate::get(),
+                Error::<T>::TrustDeltaTooLarge
+            );
+            ensure!(
+                Self::trust_update_allowed(relationship_id, block_number),
+                Error::<T>::TrustUpdateTooFrequent
+            );
 
             let old_level = relationship.trust_level;
             relationship.trust_level = new_trust_level;
             relationship.updated_at = block_number;
 
+            Self::note_graph_change(&relationship, RelationshipDelta::TrustChanged);
             Relationships::<T>::insert(relationship_id, relationship);
+            LastTrustUpdate::<T>::insert(relationship_id, block_number);
 
             Self::deposit_event(Event::TrustLevelChanged {
                 relationship_id,
@@ -700,6 +853,7 @@ pub mod pallet {
 
             let mut request =
                 DiscoveryRequests::<T>::get(request_id).ok_or(Error::<T>::DiscoveryNotFound)?;
+            let results_count = results_count.min(Self::discovery_result_cap(request.requester));
 
             request.status = DiscoveryStatus::Completed;
             request.completed_at = Some(block_number);
@@ -718,6 +872,149 @@ pub mod pallet {
 
             Ok(())
         }
+
+        #[pallet::call_index(7)]
+        #[pallet::weight(T::WeightInfo::set_type_default_expiry())]
+        pub fn set_type_default_expiry(
+            origin: OriginFor<T>,
+            relationship_type: RelationshipType,
+            duration: Option<BlockNumberFor<T>>,
+        ) -> DispatchResult {
+            ensure_root(origin)?;
+
+            match duration {
+                Some(d) => TypeDefaultExpiry::<T>::insert(relationship_type, d),
+                None => TypeDefaultExpiry::<T>::remove(relationship_type),
+            }
+
+            Self::deposit_event(Event::TypeDefaultExpiryUpdated {
+                relationship_type,
+                duration,
+            });
+
+            Ok(())
+        }
+
+        /// Bulk-create active, one-way relationships when migrating an existing
+        /// graph (root only). Self-links, invalid trust levels, existing pairs and
+        /// actors already at `MaxRelationshipsPerActor` are skipped.
+        #[pallet::call_index(8)]
+        #[pallet::weight(T::WeightInfo::import_relationships(batch.len() as u32))]
+        pub fn import_relationships(
+            origin: OriginFor<T>,
+            batch: BoundedVec<(ActorId, ActorId, RelationshipType, u8), T::MaxImportBatch>,
+        ) -> DispatchResult {
+            ensure_root(origin)?;
+            let block_number = frame_system::Pallet::<T>::block_number();
+            let max_per_actor = T::MaxRelationshipsPerActor::get() as usize;
+
+            let mut created = 0u32;
+            let mut skipped = 0u32;
+            for (from_actor, to_actor, relationship_type, trust_level) in batch {
+                let importable = from_actor != to_actor
+                    && trust_level <= T::MaxTrustLevel::get()
+                    && RelationshipIndex::<T>::get(from_actor, to_actor).is_none()
+                    && ActorRelationships::<T>::decode_len(from_actor).unwrap_or(0) < max_per_actor;
+
+                if importable
+                    && Self::insert_relationship(
+                        from_actor,
+                        to_actor,
+                        relationship_type,
+                        trust_level,
+                        None,
+                        false,
+                        block_number,
+                    )
+                    .is_ok()
+                {
+                    created = created.saturating_add(1);
+                } else {
+                    skipped = skipped.saturating_add(1);
+                }
+            }
+
+            Self::deposit_event(Event::RelationshipsImported { created, skipped });
+
+            Ok(())
+        }
+
+        /// Record an interaction from the caller to `to_actor` along their active
+        /// relationship. Dormancy decay since the last interaction is applied first,
+        /// then every `InteractionsPerTrustStep` interactions raise trust by one, up to
+        /// `InteractionTrustCap`. At most one interaction counts per block, and a raise
+        /// falling inside `TrustUpdateCooldownBlocks` of the last trust update is skipped.
+        #[pallet::call_index(9)]
+        #[pallet::weight(T::WeightInfo::record_interaction())]
+        pub fn record_interaction(origin: OriginFor<T>, to_actor: ActorId) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+            let actor = Self::account_to_actor(&who);
+            ensure!(
+                T::InteractionTrustAdjustment::get(),
+                Error::<T>::InteractionTrackingDisabled
+            );
+            let block_number = frame_system::Pallet::<T>::block_number();
+
+            let relationship_id = RelationshipIndex::<T>::get(actor, to_actor)
+                .ok_or(Error::<T>::RelationshipNotFound)?;
+            let mut relationship =
+                Relationships::<T>::get(relationship_id).ok_or(Error::<T>::RelationshipNotFound)?;
+            ensure!(
+                relationship.status == RelationshipStatus::Active,
+                Error::<T>::RelationshipRevoked
+            );
+
+            let record = RelationshipInteractions::<T>::get(relationship_id);
+            ensure!(
+                record
+                    .as_ref()
+                    .is_none_or(|r| r.last_interaction < block_number),
+                Error::<T>::InteractionTooFrequent
+            );
+            let mut trust_level = match &record {
+                Some(record) => Self::decayed_trust_level(&relationship, record, block_number),
+                None => relationship.trust_level,
+            };
+            let interactions = record.map_or(0, |r| r.count).saturating_add(1);
+            let step = T::InteractionsPerTrustStep::get().max(1);
+            let raised = interactions.is_multiple_of(step)
+                && trust_level < T::InteractionTrustCap::get()
+                && T::MaxTrustDeltaPerUpdate::get() > 0
+                && Self::trust_update_allowed(relationship_id, block_number);
+            if raised {
+                trust_level = trust_level.saturating_add(1);
+                LastTrustUpdate::<T>::insert(relationship_id, block_number);
+            }
+
+            RelationshipInteractions::<T>::insert(
+                relationship_id,
+                InteractionRecord {
+                    count: interactions,
+                    last_interaction: block_number,
+                },
+            );
+
+            let old_level = relationship.trust_level;
+            if trust_level != old_level {
+                relationship.trust_level = trust_level;
+                relationship.updated_at = block_number;
+                Self::note_graph_change(&relationship, RelationshipDelta::TrustChanged);
+                Relationships::<T>::insert(relationship_id, relationship);
+                Self::deposit_event(Event::TrustLevelChanged {
+                    relationship_id,
+                    old_level,
+                    new_level: trust_level,
+                });
+            }
+
+            Self::deposit_event(Event::InteractionRecorded {
+                relationship_id,
+                interactions,
+                trust_level,
+            });
+
+            Ok(())
+        }
     }
 
     impl<T: Config> Pallet<T> {
@@ -737,11 +1034,48 @@ pub mod pallet {
             RelationshipIndex::<T>::get(from, to).is_some()
         }
 
+        /// Trust level of the active relationship from `from` to `to`, less any
+        /// dormancy decay when interaction tracking is on.
         pub fn get_trust_level(from: ActorId, to: ActorId) -> Option<u8> {
-            RelationshipIndex::<T>::get(from, to)
-                .and_then(Relationships::<T>::get)
-                .filter(|rel| rel.status == RelationshipStatus::Active)
-                .map(|rel| rel.trust_level)
+            let relationship_id = RelationshipIndex::<T>::get(from, to)?;
+            let relationship = Relationships::<T>::get(relationship_id)
+                .filter(|rel| rel.status == RelationshipStatus::Active)?;
+            if !T::InteractionTrustAdjustment::get() {
+                return Some(relationship.trust_level);
+            }
+            let level = match RelationshipInteractions::<T>::get(relationship_id) {
+                Some(record) => Self::decayed_trust_level(
+                    &relationship,
+                    &record,
+                    frame_system::Pallet::<T>::block_number(),
+                ),
+                None => relationship.trust_level,
+            };
+            Some(level)
+        }
+
+        /// Whether `TrustUpdateCooldownBlocks` has passed since the relationship's
+        /// last trust update.
+        fn trust_update_allowed(relationship_id: RelationshipId, now: BlockNumberFor<T>) -> bool {
+            LastTrustUpdate::<T>::get(relationship_id)
+                .is_none_or(|last| now >= last.saturating_add(T::TrustUpdateCooldownBlocks::get()))
+        }
+
+        /// Stored trust less one level per `InteractionDecayBlocks` since the later of
+        /// the last interaction and the last trust change.
+        fn decayed_trust_level(
+            relationship: &Relationship<T>,
+            record: &InteractionRecord<BlockNumberFor<T>>,
+            now: BlockNumberFor<T>,
+        ) -> u8 {
+            let decay_blocks = T::InteractionDecayBlocks::get();
+            if decay_blocks.is_zero() {
+                return relationship.trust_level;
+            }
+            let since = record.last_interaction.max(relationship.updated_at);
+            let dormant_steps = now.saturating_sub(since) / decay_blocks;
+            let decay = u8::try_from(dormant_steps.saturated_into::<u32>()).unwrap_or(u8::MAX);
+            relationship.trust_level.saturating_sub(decay)
         }
 
         pub fn get_mutual_relationships(
@@ -757,12 +1091,172 @@ pub mod pallet {
             Some((rel1, rel2))
         }
 
+        /// Active relationships the actor takes part in, outbound and inbound.
+        /// The inbound scan is capped at `MaxGraphTraversal`.
+        pub fn degree_centrality(actor: ActorId) -> u32 {
+            let outbound = ActorRelationships::<T>::get(actor)
+                .iter()
+                .copied()
+                .filter_map(Relationships::<T>::get)
+                .filter(|rel| rel.from_actor == actor && rel.status == RelationshipStatus::Active)
+                .count();
+            let inbound = InboundRelationships::<T>::iter_prefix_values(actor)
+                .take(T::MaxGraphTraversal::get() as usize)
+                .filter_map(Relationships::<T>::get)
+                .filter(|rel| rel.status == RelationshipStatus::Active)
+                .count();
+
+            outbound.saturating_add(inbound) as u32
+        }
+
+        /// Distinct actors reachable from `actor` over active relationships within
+        /// `max_hops`. Approximate: the search stops after `MaxGraphTraversal` actors.
+        pub fn reachability(actor: ActorId, max_hops: u8) -> u32 {
+            let limit = T::MaxGraphTraversal::get() as usize;
+            let mut visited = BTreeSet::from([actor]);
+            let mut frontier = vec![actor];
+
+            for _ in 0..max_hops {
+                frontier = Self::expand_frontier(&frontier, &mut visited, limit);
+                if frontier.is_empty() || visited.len() > limit {
+                    break;
+                }
+            }
+
+            visited.len().saturating_sub(1) as u32
+        }
+
+        /// One BFS step: unvisited neighbors of `frontier`, marking them visited.
+        /// Stops early once `limit` actors besides the origin have been visited.
+        fn expand_frontier(
+            frontier: &[ActorId],
+            visited: &mut BTreeSet<ActorId>,
+            limit: usize,
+        ) -> Vec<ActorId> {
+            let mut next = Vec::new();
+            for neighbor in frontier.iter().flat_map(|a| Self::graph_neighbors(*a)) {
+                if visited.len() > limit {
+                    break;
+                }
+                if visited.insert(neighbor) {
+                    next.push(neighbor);
+                }
+            }
+            next
+        }
+
+        /// Actors one active relationship away, following each relationship from
+        /// the side that holds it in `ActorRelationships`.
+        fn graph_neighbors(actor: ActorId) -> Vec<ActorId> {
+            ActorRelationships::<T>::get(actor)
+                .iter()
+                .copied()
+                .filter_map(Relationships::<T>::get)
+                .filter(|rel| rel.status == RelationshipStatus::Active)
+                .map(|rel| {
+                    if rel.from_actor == actor {
+                        rel.to_actor
+                    } else {
+                        rel.from_actor
+                    }
+                })
+                .collect()
+        }
+
         pub fn get_discovery_request(
             request_id: DiscoveryRequestId,
         ) -> Option<DiscoveryRequest<T>> {
             DiscoveryRequests::<T>::get(request_id)
         }
 
+        /// Result cap for a requester, scaled linearly with its profile trust score
+        /// from `MinDiscoveryResults` up to `MaxDiscoveryResults`.
+        pub fn discovery_result_cap(requester: ActorId) -> u32 {
+            let max = T::MaxDiscoveryResults::get();
+            let floor = T::MinDiscoveryResults::get().min(max);
+            let full_trust = T::FullDiscoveryTrustScore::get();
+            if full_trust == 0 {
+                return max;
+            }
+            let trust = SemanticProfiles::<T>::get(requester)
+                .map(|p| p.trust_score.min(full_trust))
+                .unwrap_or(0);
+            let extra = (max - floor) as u64 * trust as u64 / full_trust as u64;
+            floor.saturating_add(extra as u32)
+        }
+
+        /// Fail pending requests older than `DiscoveryRequestTtl`, then purge
+        /// finished requests in id order, stopping at the first one still inside
+        /// its TTL. Ids are issued in creation order, so everything behind the
+        /// cursor is purged within two TTLs of creation.
+        fn expire_discovery_requests(now: BlockNumberFor<T>) -> Weight {
+            const MAX_DISCOVERY_PURGE_PER_BLOCK: u32 = 50;
+            let ttl = T::DiscoveryRequestTtl::get();
+            if ttl.is_zero() {
+                return Weight::zero();
+            }
+            let overdue = |since: BlockNumberFor<T>| now >= since.saturating_add(ttl);
+
+            let mut pending = PendingDiscovery::<T>::get();
+            let before = pending.len();
+            let mut reads = 1u64.saturating_add(before as u64);
+            let mut writes = 0u64;
+            pending.retain(|&request_id| {
+                let Some(mut request) = DiscoveryRequests::<T>::get(request_id) else {
+                    return false;
+                };
+                if !overdue(request.created_at) {
+                    return true;
+                }
+                request.status = DiscoveryStatus::Failed;
+                request.completed_at = Some(now);
+                DiscoveryRequests::<T>::insert(request_id, request);
+                writes = writes.saturating_add(1);
+                Self::deposit_event(Event::DiscoveryExpired { request_id });
+                false
+            });
+            if pending.len() != before {
+                PendingDiscovery::<T>::put(pending);
+                writes = writes.saturating_add(1);
+            }
+
+            let mut cursor = DiscoveryPurgeCursor::<T>::get();
+            let end = DiscoveryCount::<T>::get();
+            let start = cursor;
+            reads = reads.saturating_add(2);
+            while cursor < end
+                && cursor.saturating_sub(start) < MAX_DISCOVERY_PURGE_PER_BLOCK as u64
+            {
+                let request_id = DiscoveryRequestId::new(cursor);
+                reads = reads.saturating_add(1);
+                let request = DiscoveryRequests::<T>::get(request_id);
+                let purgeable = request.as_ref().map(|r| {
+                    let finished = matches!(
+                        r.status,
+                        DiscoveryStatus::Completed
+                            | DiscoveryStatus::Failed
+                            | DiscoveryStatus::RateLimited
+                    );
+                    finished && overdue(r.completed_at.unwrap_or(r.created_at))
+                });
+                match purgeable {
+                    Some(false) => break,
+                    Some(true) => {
+                        DiscoveryRequests::<T>::remove(request_id);
+                        writes = writes.saturating_add(1);
+                    }
+                    None => {}
+                }
+                cursor = cursor.saturating_add(1);
+            }
+            if cursor != start {
+                DiscoveryPurgeCursor::<T>::put(cursor);
+                writes = writes.saturating_add(1);
+            }
+
+            T::DbWeight::get().reads_writes(reads, writes)
+        }
+
         pub fn get_pending_discovery_count() -> u32 {
             PendingDiscovery::<T>::get().len() as u32
         }
@@ -779,6 +1273,73 @@ pub mod pallet {
             block_number >= last_discovery.saturating_add(rate_limit)
         }
 
+        /// Allocate and store a new relationship, applying the type's default
+        /// expiry when none is given. Fails before writing anything if `from_actor`
+        /// is at `MaxRelationshipsPerActor`.
+        fn insert_relationship(
+            from_actor: ActorId,
+            to_actor: ActorId,
+            relationship_type: RelationshipType,
+            trust_level: u8,
+            expires_at: Option<BlockNumberFor<T>>,
+            bidirectional: bool,
+            block_number: BlockNumberFor<T>,
+        ) -> DispatchResult {
+            let expires_at = expires_at.or_else(|| {
+                TypeDefaultExpiry::<T>::get(relationship_type)
+                    .map(|duration| block_number.saturating_add(duration))
+            });
+
+            let relationship_id = RelationshipId::new(RelationshipCount::<T>::get());
+
+            ActorRelationships::<T>::try_mutate(from_actor, |rels| {
+                rels.try_push(relationship_id)
+                    .map_err(|_| Error::<T>::MaxRelationshipsReached)
+            })?;
+            RelationshipCount::<T>::put(relationship_id.inner().saturating_add(1));
+
+            let relationship = Relationship {
+                id: relationship_id,
+                from_actor,
+                to_actor,
+                relationship_type,
+                status: if bidirectional {
+                    RelationshipStatus::Pending
+                } else {
+                    RelationshipStatus::Active
+                },
+                created_at: block_number,
+                updated_at: block_number,
+                expires_at,
+                bidirectional,
+                trust_level,
+            };
+
+            Self::note_graph_change(&relationship, RelationshipDelta::Created);
+            Relationships::<T>::insert(relationship_id, relationship);
+            RelationshipIndex::<T>::insert(from_actor, to_actor, relationship_id);
+            InboundRelationships::<T>::insert(to_actor, from_actor, relationship_id);
+
+            Self::update_profile_relationship_count(from_actor, block_number, true);
+
+            Self::deposit_event(Event::RelationshipCreated {
+                relationship_id,
+                from_actor,
+                to_actor,
+                relationship_type,
+            });
+
+            Ok(())
+        }
+
+        /// Bump both endpoints' graph versions and emit the consolidated event.
+        fn note_graph_change(relationship: &Relationship<T>, delta_kind: RelationshipDelta) {
+            for actor in [relationship.from_actor, relationship.to_actor] {
+                RelationshipVersion::<T>::mutate(actor, |v| *v = v.saturating_add(1));
+                Self::deposit_event(Event::RelationshipGraphChanged { actor, delta_kind });
+            }
+        }
+
         fn account_to_actor(account: &T::AccountId) -> ActorId {
             let encoded = account.encode();
             let hash = sp_core::blake2_256(&encoded);
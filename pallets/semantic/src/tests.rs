@@ -2,7 +2,7 @@
 
 use crate::{
     self as pallet_semantic, DiscoveryCriteria, DiscoveryRequestId, DiscoveryStatus, Error, Event,
-    RelationshipId, RelationshipStatus, RelationshipType,
+    RelationshipDelta, RelationshipId, RelationshipStatus, RelationshipType,
 };
 use frame_support::{
     assert_noop, assert_ok, derive_impl, parameter_types,
@@ -52,11 +52,22 @@ impl system::Config for Test {
 }
 
 parameter_types! {
-    pub const MaxRelationshipsPerActor: u32 = 50;
+    pub static MaxRelationshipsPerActor: u32 = 50;
     pub const MaxDiscoveryResults: u32 = 100;
     pub const DiscoveryRateLimitBlocks: u64 = 10;
     pub const RelationshipExpiryBlocks: u64 = 1000;
     pub const MaxTrustLevel: u8 = 100;
+    pub static MaxGraphTraversal: u32 = 100;
+    pub static MaxTrustDeltaPerUpdate: u8 = 100;
+    pub static TrustUpdateCooldownBlocks: u64 = 0;
+    pub static MinDiscoveryResults: u32 = 100;
+    pub const FullDiscoveryTrustScore: u32 = 100;
+    pub const MaxImportBatch: u32 = 16;
+    pub static DiscoveryRequestTtl: u64 = 0;
+    pub static InteractionTrustAdjustment: bool = false;
+    pub const InteractionsPerTrustStep: u32 = 2;
+    pub const InteractionTrustCap: u8 = 55;
+    pub const InteractionDecayBlocks: u64 = 10;
 }
 
 impl pallet_semantic::Config for Test {
@@ -66,6 +77,17 @@ impl pallet_semantic::Config for Test {
     type DiscoveryRateLimitBlocks = DiscoveryRateLimitBlocks;
     type RelationshipExpiryBlocks = RelationshipExpiryBlocks;
     type MaxTrustLevel = MaxTrustLevel;
+    type MaxGraphTraversal = MaxGraphTraversal;
+    type MaxTrustDeltaPerUpdate = MaxTrustDeltaPerUpdate;
+    type TrustUpdateCooldownBlocks = TrustUpdateCooldownBlocks;
+    type MinDiscoveryResults = MinDiscoveryResults;
+    type FullDiscoveryTrustScore = FullDiscoveryTrustScore;
+    type MaxImportBatch = MaxImportBatch;
+    type DiscoveryRequestTtl = DiscoveryRequestTtl;
+    type InteractionTrustAdjustment = InteractionTrustAdjustment;
+    type InteractionsPerTrustStep = InteractionsPerTrustStep;
+    type InteractionTrustCap = InteractionTrustCap;
+    type InteractionDecayBlocks = InteractionDecayBlocks;
 }
 
 fn new_test_ext() -> sp_io::TestExternalities {
@@ -223,7 +245,8 @@ fn accept_relationship_success() {
 
         assert_ok!(Semantic::accept_relationship(
             RuntimeOrigin::signed(2),
-            relationship_id
+            relationship_id,
+            None
         ));
 
         let relationship =
@@ -249,7 +272,7 @@ fn accept_relationship_not_authorized() {
         let relationship_id = RelationshipId::new(0);
 
         assert_noop!(
-            Semantic::accept_relationship(RuntimeOrigin::signed(3), relationship_id),
+            Semantic::accept_relationship(RuntimeOrigin::signed(3), relationship_id, None),
             Error::<Test>::NotAuthorized
         );
     });
@@ -310,6 +333,76 @@ fn update_trust_level_success() {
     });
 }
 
+#[test]
+fn update_trust_level_bounded_by_max_delta() {
+    new_test_ext().execute_with(|| {
+        MaxTrustDeltaPerUpdate::set(10);
+        assert_ok!(Semantic::create_relationship(
+            RuntimeOrigin::signed(1),
+            account_to_actor(2),
+            RelationshipType::Trust,
+            50,
+            None,
+            false
+        ));
+        let relationship_id = RelationshipId::new(0);
+
+        assert_noop!(
+            Semantic::update_trust_level(RuntimeOrigin::signed(1), relationship_id, 61),
+            Error::<Test>::TrustDeltaTooLarge
+        );
+        assert_noop!(
+            Semantic::update_trust_level(RuntimeOrigin::signed(1), relationship_id, 0),
+            Error::<Test>::TrustDeltaTooLarge
+        );
+        assert_ok!(Semantic::update_trust_level(
+            RuntimeOrigin::signed(1),
+            relationship_id,
+            60
+        ));
+
+        let relationship =
+            Semantic::relationships(relationship_id).expect("relationship should exist");
+        assert_eq!(relationship.trust_level, 60);
+    });
+}
+
+#[test]
+fn update_trust_level_respects_cooldown() {
+    new_test_ext().execute_with(|| {
+        TrustUpdateCooldownBlocks::set(5);
+        assert_ok!(Semantic::create_relationship(
+            RuntimeOrigin::signed(1),
+            account_to_actor(2),
+            RelationshipType::Trust,
+            50,
+            None,
+            false
+        ));
+        let relationship_id = RelationshipId::new(0);
+
+        assert_ok!(Semantic::update_trust_level(
+            RuntimeOrigin::signed(1),
+            relationship_id,
+            55
+        ));
+        assert_eq!(Semantic::last_trust_update(relationship_id), Some(1));
+
+        System::set_block_number(5);
+        assert_noop!(
+            Semantic::update_trust_level(RuntimeOrigin::signed(1), relationship_id, 60),
+            Error::<Test>::TrustUpdateTooFrequent
+        );
+
+        System::set_block_number(6);
+        assert_ok!(Semantic::update_trust_level(
+            RuntimeOrigin::signed(1),
+            relationship_id,
+            60
+        ));
+    });
+}
+
 #[test]
 fn request_discovery_success() {
     new_test_ext().execute_with(|| {
@@ -566,3 +659,698 @@ fn genesis_initializes_counts() {
         assert_eq!(Semantic::discovery_count(), 0);
     });
 }
+
+fn assert_graph_changed(actor: ActorId, delta_kind: RelationshipDelta) {
+    System::assert_has_event(RuntimeEvent::Semantic(Event::RelationshipGraphChanged {
+        actor,
+        delta_kind,
+    }));
+}
+
+#[test]
+fn relationship_version_bumped_on_create_accept_revoke() {
+    new_test_ext().execute_with(|| {
+        let from_actor = account_to_actor(1);
+        let to_actor = account_to_actor(2);
+        let relationship_id = RelationshipId::new(0);
+        assert_eq!(Semantic::relationship_version(from_actor), 0);
+
+        assert_ok!(Semantic::create_relationship(
+            RuntimeOrigin::signed(1),
+            to_actor,
+            RelationshipType::Collaborate,
+            75,
+            None,
+            true
+        ));
+        assert_eq!(Semantic::relationship_version(from_actor), 1);
+        assert_eq!(Semantic::relationship_version(to_actor), 1);
+        assert_graph_changed(from_actor, RelationshipDelta::Created);
+        assert_graph_changed(to_actor, RelationshipDelta::Created);
+
+        assert_ok!(Semantic::accept_relationship(
+            RuntimeOrigin::signed(2),
+            relationship_id,
+            None
+        ));
+        assert_eq!(Semantic::relationship_version(from_actor), 2);
+        assert_eq!(Semantic::relationship_version(to_actor), 2);
+        assert_graph_changed(to_actor, RelationshipDelta::Accepted);
+
+        assert_ok!(Semantic::update_trust_level(
+            RuntimeOrigin::signed(1),
+            relationship_id,
+            80
+        ));
+        assert_eq!(Semantic::relationship_version(from_actor), 3);
+        assert_graph_changed(from_actor, RelationshipDelta::TrustChanged);
+
+        assert_ok!(Semantic::revoke_relationship(
+            RuntimeOrigin::signed(2),
+            relationship_id
+        ));
+        assert_eq!(Semantic::relationship_version(from_actor), 4);
+        assert_eq!(Semantic::relationship_version(to_actor), 4);
+        assert_graph_changed(from_actor, RelationshipDelta::Revoked);
+    });
+}
+
+#[test]
+fn relationship_version_bumped_on_expiry() {
+    new_test_ext().execute_with(|| {
+        let from_actor = account_to_actor(1);
+        let to_actor = account_to_actor(2);
+
+        assert_ok!(Semantic::create_relationship(
+            RuntimeOrigin::signed(1),
+            to_actor,
+            RelationshipType::Trust,
+            50,
+            Some(10),
+            false
+        ));
+
+        System::set_block_number(10);
+        Semantic::on_initialize(10);
+
+        assert_eq!(Semantic::relationship_version(from_actor), 2);
+        assert_eq!(Semantic::relationship_version(to_actor), 2);
+        assert_graph_changed(to_actor, RelationshipDelta::Expired);
+
+        // Already expired relationships are not counted again.
+        Semantic::on_initialize(11);
+        assert_eq!(Semantic::relationship_version(from_actor), 2);
+    });
+}
+
+#[test]
+fn relationship_version_untouched_by_failed_operation() {
+    new_test_ext().execute_with(|| {
+        let actor = account_to_actor(1);
+
+        assert_noop!(
+            Semantic::create_relationship(
+                RuntimeOrigin::signed(1),
+                actor,
+                RelationshipType::Trust,
+                50,
+                None,
+                false
+            ),
+            Error::<Test>::SelfRelationship
+        );
+        assert_eq!(Semantic::relationship_version(actor), 0);
+    });
+}
+
+// ============================================================================
+// Type Default Expiry Tests
+// ============================================================================
+
+#[test]
+fn follow_inherits_type_default_expiry() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(Semantic::set_type_default_expiry(
+            RuntimeOrigin::root(),
+            RelationshipType::Follow,
+            Some(20)
+        ));
+        System::assert_last_event(RuntimeEvent::Semantic(Event::TypeDefaultExpiryUpdated {
+            relationship_type: RelationshipType::Follow,
+            duration: Some(20),
+        }));
+
+        assert_ok!(Semantic::create_relationship(
+            RuntimeOrigin::signed(1),
+            account_to_actor(2),
+            RelationshipType::Follow,
+            10,
+            None,
+            false
+        ));
+
+        let relationship_id = RelationshipId::new(0);
+        let relationship =
+            Semantic::relationships(relationship_id).expect("relationship should exist");
+        assert_eq!(relationship.expires_at, Some(21));
+
+        System::set_block_number(21);
+        Semantic::on_initialize(21);
+
+        let relationship =
+            Semantic::relationships(relationship_id).expect("relationship should exist");
+        assert_eq!(relationship.status, RelationshipStatus::Expired);
+    });
+}
+
+#[test]
+fn trust_without_type_default_persists() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(Semantic::set_type_default_expiry(
+            RuntimeOrigin::root(),
+            RelationshipType::Follow,
+            Some(20)
+        ));
+
+        assert_ok!(Semantic::create_relationship(
+            RuntimeOrigin::signed(1),
+            account_to_actor(2),
+            RelationshipType::Trust,
+            50,
+            None,
+            false
+        ));
+
+        let relationship_id = RelationshipId::new(0);
+        System::set_block_number(1_000);
+        Semantic::on_initialize(1_000);
+
+        let relationship =
+            Semantic::relationships(relationship_id).expect("relationship should exist");
+        assert_eq!(relationship.expires_at, None);
+        assert_eq!(relationship.status, RelationshipStatus::Active);
+    });
+}
+
+#[test]
+fn explicit_expiry_overrides_type_default() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(Semantic::set_type_default_expiry(
+            RuntimeOrigin::root(),
+            RelationshipType::Follow,
+            Some(20)
+        ));
+
+        assert_ok!(Semantic::create_relationship(
+            RuntimeOrigin::signed(1),
+            account_to_actor(2),
+            RelationshipType::Follow,
+            10,
+            Some(500),
+            false
+        ));
+
+        let relationship =
+            Semantic::relationships(RelationshipId::new(0)).expect("relationship should exist");
+        assert_eq!(relationship.expires_at, Some(500));
+
+        assert_noop!(
+            Semantic::set_type_default_expiry(
+                RuntimeOrigin::signed(1),
+                RelationshipType::Follow,
+                None
+            ),
+            sp_runtime::DispatchError::BadOrigin
+        );
+    });
+}
+
+// ============================================================================
+// Graph Centrality Tests
+// ============================================================================
+
+fn link(from: u64, to: u64, bidirectional: bool) -> RelationshipId {
+    let relationship_id = RelationshipId::new(Semantic::relationship_count());
+    assert_ok!(Semantic::create_relationship(
+        RuntimeOrigin::signed(from),
+        account_to_actor(to),
+        RelationshipType::Trust,
+        50,
+        None,
+        bidirectional
+    ));
+    relationship_id
+}
+
+/// 1 -> 2 -> 3 -> 4, 1 <-> 5 accepted, 4 -> 1 revoked.
+fn build_small_graph() {
+    link(1, 2, false);
+    link(2, 3, false);
+    link(3, 4, false);
+    let mutual = link(1, 5, true);
+    assert_ok!(Semantic::accept_relationship(
+        RuntimeOrigin::signed(5),
+        mutual,
+        None
+    ));
+    let revoked = link(4, 1, false);
+    assert_ok!(Semantic::revoke_relationship(
+        RuntimeOrigin::signed(4),
+        revoked
+    ));
+}
+
+#[test]
+fn degree_centrality_counts_active_inbound_and_outbound() {
+    new_test_ext().execute_with(|| {
+        build_small_graph();
+
+        assert_eq!(Semantic::degree_centrality(account_to_actor(1)), 2);
+        assert_eq!(Semantic::degree_centrality(account_to_actor(2)), 2);
+        assert_eq!(Semantic::degree_centrality(account_to_actor(3)), 2);
+        assert_eq!(Semantic::degree_centrality(account_to_actor(4)), 1);
+        assert_eq!(Semantic::degree_centrality(account_to_actor(5)), 1);
+        assert_eq!(Semantic::degree_centrality(account_to_actor(6)), 0);
+    });
+}
+
+#[test]
+fn reachability_follows_active_relationships_within_hops() {
+    new_test_ext().execute_with(|| {
+        build_small_graph();
+        let actor1 = account_to_actor(1);
+
+        assert_eq!(Semantic::reachability(actor1, 0), 0);
+        assert_eq!(Semantic::reachability(actor1, 1), 2);
+        assert_eq!(Semantic::reachability(actor1, 2), 3);
+        assert_eq!(Semantic::reachability(actor1, 3), 4);
+        assert_eq!(Semantic::reachability(actor1, 10), 4);
+
+        assert_eq!(Semantic::reachability(account_to_actor(5), 2), 2);
+        assert_eq!(Semantic::reachability(account_to_actor(4), 10), 0);
+    });
+}
+
+#[test]
+fn reachability_stops_at_traversal_limit() {
+    new_test_ext().execute_with(|| {
+        build_small_graph();
+        MaxGraphTraversal::set(2);
+
+        assert_eq!(Semantic::reachability(account_to_actor(1), 10), 2);
+    });
+}
+
+// ============================================================================
+// Trust-Scaled Discovery Cap Tests
+// ============================================================================
+
+fn set_trust_score(account: u64, trust_score: u32) {
+    assert_ok!(Semantic::update_profile(
+        RuntimeOrigin::signed(account),
+        true
+    ));
+    crate::SemanticProfiles::<Test>::mutate(account_to_actor(account), |p| {
+        p.as_mut().expect("profile should exist").trust_score = trust_score;
+    });
+}
+
+fn discover_with(account: u64, request: u64, available: u32) -> u32 {
+    assert_ok!(Semantic::request_discovery(
+        RuntimeOrigin::signed(account),
+        DiscoveryCriteria::default()
+    ));
+    let request_id = DiscoveryRequestId::new(request);
+    assert_ok!(Semantic::complete_discovery(
+        RuntimeOrigin::root(),
+        request_id,
+        available
+    ));
+    Semantic::discovery_requests(request_id)
+        .expect("request should exist")
+        .results_count
+}
+
+#[test]
+fn low_trust_requester_results_truncated() {
+    MinDiscoveryResults::set(10);
+    new_test_ext().execute_with(|| {
+        set_trust_score(1, 0);
+        set_trust_score(2, 100);
+
+        assert_eq!(discover_with(1, 0, 100), 10);
+        System::assert_has_event(RuntimeEvent::Semantic(Event::DiscoveryCompleted {
+            request_id: DiscoveryRequestId::new(0),
+            results_count: 10,
+        }));
+        assert_eq!(discover_with(2, 1, 100), 100);
+        // Results below the cap pass through untouched.
+        assert_eq!(discover_with(3, 2, 4), 4);
+    });
+    MinDiscoveryResults::set(100);
+}
+
+#[test]
+fn discovery_cap_scales_with_trust() {
+    MinDiscoveryResults::set(10);
+    new_test_ext().execute_with(|| {
+        set_trust_score(1, 50);
+        set_trust_score(2, 500);
+
+        assert_eq!(Semantic::discovery_result_cap(account_to_actor(1)), 55);
+        assert_eq!(Semantic::discovery_result_cap(account_to_actor(2)), 100);
+        // No profile counts as no trust.
+        assert_eq!(Semantic::discovery_result_cap(account_to_actor(9)), 10);
+    });
+    MinDiscoveryResults::set(100);
+}
+
+// ============================================================================
+// Bulk Import Tests
+// ============================================================================
+
+#[test]
+fn import_skips_duplicates_and_full_actors() {
+    MaxRelationshipsPerActor::set(2);
+    new_test_ext().execute_with(|| {
+        let (a, b, c, d) = (
+            account_to_actor(1),
+            account_to_actor(2),
+            account_to_actor(3),
+            account_to_actor(4),
+        );
+        assert_ok!(Semantic::create_relationship(
+            RuntimeOrigin::signed(1),
+            b,
+            RelationshipType::Trust,
+            50,
+            None,
+            false
+        ));
+
+        let batch = vec![
+            // Already exists.
+            (a, b, RelationshipType::Follow, 10),
+            (a, c, RelationshipType::Follow, 20),
+            // `a` is now at the per-actor limit.
+            (a, d, RelationshipType::Follow, 30),
+            (b, a, RelationshipType::Collaborate, 40),
+            // Duplicate within the batch.
+            (b, a, RelationshipType::Follow, 40),
+            (c, c, RelationshipType::Follow, 40),
+        ];
+        assert_ok!(Semantic::import_relationships(
+            RuntimeOrigin::root(),
+            batch.try_into().expect("within bound")
+        ));
+
+        System::assert_last_event(RuntimeEvent::Semantic(Event::RelationshipsImported {
+            created: 2,
+            skipped: 4,
+        }));
+        assert_eq!(Semantic::get_trust_level(a, c), Some(20));
+        assert_eq!(Semantic::get_trust_level(b, a), Some(40));
+        assert!(!Semantic::has_relationship(a, d));
+        assert_eq!(Semantic::get_trust_level(a, b), Some(50));
+        assert_eq!(Semantic::actor_relationships(a).len(), 2);
+        assert_eq!(Semantic::relationship_count(), 3);
+    });
+    MaxRelationshipsPerActor::set(50);
+}
+
+#[test]
+fn import_requires_root() {
+    new_test_ext().execute_with(|| {
+        let batch = vec![(
+            account_to_actor(1),
+            account_to_actor(2),
+            RelationshipType::Follow,
+            10,
+        )];
+        assert_noop!(
+            Semantic::import_relationships(
+                RuntimeOrigin::signed(1),
+                batch.try_into().expect("within bound")
+            ),
+            sp_runtime::DispatchError::BadOrigin
+        );
+    });
+}
+
+// ============================================================================
+// Discovery Request Expiry Tests
+// ============================================================================
+
+fn request_discovery_at(account: u64, block: u64) -> DiscoveryRequestId {
+    System::set_block_number(block);
+    let request_id = DiscoveryRequestId::new(Semantic::discovery_count());
+    assert_ok!(Semantic::request_discovery(
+        RuntimeOrigin::signed(account),
+        DiscoveryCriteria::default()
+    ));
+    request_id
+}
+
+#[test]
+fn abandoned_discovery_request_fails_then_is_purged() {
+    DiscoveryRequestTtl::set(20);
+    new_test_ext().execute_with(|| {
+        let request_id = request_discovery_at(1, 1);
+
+        Semantic::on_initialize(20);
+        assert_eq!(Semantic::get_pending_discovery_count(), 1);
+
+        Semantic::on_initialize(21);
+        let request = Semantic::discovery_requests(request_id).expect("request should exist");
+        assert_eq!(request.status, DiscoveryStatus::Failed);
+        assert_eq!(request.completed_at, Some(21));
+        assert_eq!(Semantic::get_pending_discovery_count(), 0);
+        System::assert_has_event(RuntimeEvent::Semantic(Event::DiscoveryExpired {
+            request_id,
+        }));
+
+        // Purged one TTL after it failed.
+        Semantic::on_initialize(40);
+        assert!(Semantic::discovery_requests(request_id).is_some());
+        Semantic::on_initialize(41);
+        assert!(Semantic::discovery_requests(request_id).is_none());
+    });
+    DiscoveryRequestTtl::set(0);
+}
+
+#[test]
+fn old_completed_discovery_requests_purged() {
+    DiscoveryRequestTtl::set(20);
+    new_test_ext().execute_with(|| {
+        let first = request_discovery_at(1, 1);
+        let second = request_discovery_at(2, 2);
+        let recent = request_discovery_at(3, 10);
+        for request_id in [first, second] {
+            assert_ok!(Semantic::complete_discovery(
+                RuntimeOrigin::root(),
+                request_id,
+                1
+            ));
+        }
+        System::set_block_number(15);
+        assert_ok!(Semantic::complete_discovery(
+            RuntimeOrigin::root(),
+            recent,
+            1
+        ));
+
+        Semantic::on_initialize(29);
+        assert!(Semantic::discovery_requests(first).is_some());
+
+        Semantic::on_initialize(30);
+        assert!(Semantic::discovery_requests(first).is_none());
+        assert!(Semantic::discovery_requests(second).is_none());
+        assert!(Semantic::discovery_requests(recent).is_some());
+
+        Semantic::on_initialize(35);
+        assert!(Semantic::discovery_requests(recent).is_none());
+    });
+    DiscoveryRequestTtl::set(0);
+}
+
+// ============================================================================
+// Interaction-Driven Trust Tests
+// ============================================================================
+
+fn interact(times: u32) {
+    for _ in 0..times {
+        System::set_block_number(System::block_number() + 1);
+        assert_ok!(Semantic::record_interaction(
+            RuntimeOrigin::signed(1),
+            account_to_actor(2)
+        ));
+    }
+}
+
+fn create_trust_relationship(trust_level: u8) {
+    assert_ok!(Semantic::create_relationship(
+        RuntimeOrigin::signed(1),
+        account_to_actor(2),
+        RelationshipType::Trust,
+        trust_level,
+        None,
+        false
+    ));
+}
+
+#[test]
+fn record_interaction_requires_flag() {
+    new_test_ext().execute_with(|| {
+        create_trust_relationship(50);
+        assert_noop!(
+            Semantic::record_interaction(RuntimeOrigin::signed(1), account_to_actor(2)),
+            Error::<Test>::InteractionTrackingDisabled
+        );
+    });
+}
+
+#[test]
+fn frequent_interactions_raise_trust_up_to_cap() {
+    InteractionTrustAdjustment::set(true);
+    new_test_ext().execute_with(|| {
+        create_trust_relationship(50);
+        let from = account_to_actor(1);
+        let to = account_to_actor(2);
+
+        interact(6);
+        assert_eq!(Semantic::get_trust_level(from, to), Some(53));
+        let record = Semantic::relationship_interactions(RelationshipId::new(0))
+            .expect("interactions should be recorded");
+        assert_eq!(record.count, 6);
+        System::assert_last_event(RuntimeEvent::Semantic(Event::InteractionRecorded {
+            relationship_id: RelationshipId::new(0),
+            interactions: 6,
+            trust_level: 53,
+        }));
+
+        interact(10);
+        assert_eq!(
+            Semantic::get_trust_level(from, to),
+            Some(InteractionTrustCap::get())
+        );
+    });
+    InteractionTrustAdjustment::set(false);
+}
+
+#[test]
+fn dormant_relationship_trust_decays() {
+    InteractionTrustAdjustment::set(true);
+    new_test_ext().execute_with(|| {
+        create_trust_relationship(50);
+        let from = account_to_actor(1);
+        let to = account_to_actor(2);
+        interact(4);
+        assert_eq!(Semantic::get_trust_level(from, to), Some(52));
+
+        System::set_block_number(System::block_number() + 3 * InteractionDecayBlocks::get());
+        assert_eq!(Semantic::get_trust_level(from, to), Some(49));
+        let stored = Semantic::relationships(RelationshipId::new(0))
+            .expect("relationship should exist")
+            .trust_level;
+        assert_eq!(stored, 52);
+
+        // The next interaction settles the decay before counting.
+        interact(1);
+        let relationship =
+            Semantic::relationships(RelationshipId::new(0)).expect("relationship should exist");
+        assert_eq!(relationship.trust_level, 49);
+        assert_eq!(Semantic::get_trust_level(from, to), Some(49));
+    });
+    InteractionTrustAdjustment::set(false);
+}
+
+#[test]
+fn interaction_counted_once_per_block() {
+    InteractionTrustAdjustment::set(true);
+    new_test_ext().execute_with(|| {
+        create_trust_relationship(50);
+        interact(1);
+
+        assert_noop!(
+            Semantic::record_interaction(RuntimeOrigin::signed(1), account_to_actor(2)),
+            Error::<Test>::InteractionTooFrequent
+        );
+        let record = Semantic::relationship_interactions(RelationshipId::new(0))
+            .expect("interactions should be recorded");
+        assert_eq!(record.count, 1);
+    });
+    InteractionTrustAdjustment::set(false);
+}
+
+#[test]
+fn interaction_raise_respects_trust_update_cooldown() {
+    InteractionTrustAdjustment::set(true);
+    TrustUpdateCooldownBlocks::set(5);
+    new_test_ext().execute_with(|| {
+        create_trust_relationship(50);
+        let from = account_to_actor(1);
+        let to = account_to_actor(2);
+        let relationship_id = RelationshipId::new(0);
+
+        interact(2);
+        assert_eq!(Semantic::get_trust_level(from, to), Some(51));
+        assert_eq!(Semantic::last_trust_update(relationship_id), Some(3));
+
+        // The next step lands inside the cooldown and is skipped.
+        interact(2);
+        assert_eq!(Semantic::get_trust_level(from, to), Some(51));
+
+        // Manual updates share the same cooldown.
+        assert_noop!(
+            Semantic::update_trust_level(RuntimeOrigin::signed(1), relationship_id, 60),
+            Error::<Test>::TrustUpdateTooFrequent
+        );
+
+        interact(4);
+        assert_eq!(Semantic::get_trust_level(from, to), Some(52));
+    });
+    TrustUpdateCooldownBlocks::set(0);
+    InteractionTrustAdjustment::set(false);
+}
+
+// ============================================================================
+// Acceptance Trust Ceiling Tests
+// ============================================================================
+
+fn create_pending_trust_relationship(trust_level: u8) {
+    assert_ok!(Semantic::create_relationship(
+        RuntimeOrigin::signed(1),
+        account_to_actor(2),
+        RelationshipType::Trust,
+        trust_level,
+        None,
+        true
+    ));
+}
+
+#[test]
+fn accept_within_trust_ceiling() {
+    new_test_ext().execute_with(|| {
+        create_pending_trust_relationship(60);
+        let relationship_id = RelationshipId::new(0);
+
+        assert_ok!(Semantic::accept_relationship(
+            RuntimeOrigin::signed(2),
+            relationship_id,
+            Some(60)
+        ));
+
+        let relationship =
+            Semantic::relationships(relationship_id).expect("relationship should exist");
+        assert_eq!(relationship.status, RelationshipStatus::Active);
+    });
+}
+
+#[test]
+fn accept_above_trust_ceiling_rejected() {
+    new_test_ext().execute_with(|| {
+        create_pending_trust_relationship(90);
+        let relationship_id = RelationshipId::new(0);
+
+        assert_noop!(
+            Semantic::accept_relationship(RuntimeOrigin::signed(2), relationship_id, Some(40)),
+            Error::<Test>::TrustLevelTooHigh
+        );
+        let relationship =
+            Semantic::relationships(relationship_id).expect("relationship should exist");
+        assert_eq!(relationship.status, RelationshipStatus::Pending);
+
+        // The target counter-proposes at the level it is willing to hold.
+        assert_ok!(Semantic::create_relationship(
+            RuntimeOrigin::signed(2),
+            account_to_actor(1),
+            RelationshipType::Trust,
+            40,
+            None,
+            false
+        ));
+        let counter =
+            Semantic::relationships(RelationshipId::new(1)).expect("relationship should exist");
+        assert_eq!(counter.trust_level, 40);
+    });
+}
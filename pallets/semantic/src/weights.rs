@@ -16,6 +16,9 @@ pub trait WeightInfo {
     fn request_discovery() -> Weight;
     fn update_profile() -> Weight;
     fn complete_discovery() -> Weight;
+    fn set_type_default_expiry() -> Weight;
+    fn import_relationships(n: u32) -> Weight;
+    fn record_interaction() -> Weight;
 }
 
 pub struct SubstrateWeight<T>(PhantomData<T>);
@@ -23,26 +26,26 @@ pub struct SubstrateWeight<T>(PhantomData<T>);
 impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
     fn create_relationship() -> Weight {
         Weight::from_parts(45_000_000, 0)
-            .saturating_add(T::DbWeight::get().reads(3))
-            .saturating_add(T::DbWeight::get().writes(4))
+            .saturating_add(T::DbWeight::get().reads(4))
+            .saturating_add(T::DbWeight::get().writes(6))
     }
 
     fn accept_relationship() -> Weight {
         Weight::from_parts(35_000_000, 0)
             .saturating_add(T::DbWeight::get().reads(2))
-            .saturating_add(T::DbWeight::get().writes(3))
+            .saturating_add(T::DbWeight::get().writes(5))
     }
 
     fn revoke_relationship() -> Weight {
         Weight::from_parts(30_000_000, 0)
             .saturating_add(T::DbWeight::get().reads(1))
-            .saturating_add(T::DbWeight::get().writes(2))
+            .saturating_add(T::DbWeight::get().writes(4))
     }
 
     fn update_trust_level() -> Weight {
         Weight::from_parts(25_000_000, 0)
-            .saturating_add(T::DbWeight::get().reads(1))
-            .saturating_add(T::DbWeight::get().writes(1))
+            .saturating_add(T::DbWeight::get().reads(2))
+            .saturating_add(T::DbWeight::get().writes(4))
     }
 
     fn request_discovery() -> Weight {
@@ -59,34 +62,54 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 
     fn complete_discovery() -> Weight {
         Weight::from_parts(30_000_000, 0)
-            .saturating_add(T::DbWeight::get().reads(1))
+            .saturating_add(T::DbWeight::get().reads(2))
             .saturating_add(T::DbWeight::get().writes(2))
     }
+
+    fn set_type_default_expiry() -> Weight {
+        Weight::from_parts(15_000_000, 0).saturating_add(T::DbWeight::get().writes(1))
+    }
+
+    fn import_relationships(n: u32) -> Weight {
+        // Per entry: reads RelationshipIndex, ActorRelationships, RelationshipCount,
+        // TypeDefaultExpiry, SemanticProfiles; writes those plus Relationships,
+        // InboundRelationships and both RelationshipVersion entries
+        Weight::from_parts(10_000_000, 0)
+            .saturating_add(Weight::from_parts(40_000_000, 0).saturating_mul(n as u64))
+            .saturating_add(T::DbWeight::get().reads(n as u64 * 5))
+            .saturating_add(T::DbWeight::get().writes(n as u64 * 8))
+    }
+
+    fn record_interaction() -> Weight {
+        Weight::from_parts(25_000_000, 0)
+            .saturating_add(T::DbWeight::get().reads(4))
+            .saturating_add(T::DbWeight::get().writes(5))
+    }
 }
 
 impl WeightInfo for () {
     fn create_relationship() -> Weight {
         Weight::from_parts(45_000_000, 0)
-            .saturating_add(RocksDbWeight::get().reads(3))
-            .saturating_add(RocksDbWeight::get().writes(4))
+            .saturating_add(RocksDbWeight::get().reads(4))
+            .saturating_add(RocksDbWeight::get().writes(6))
     }
 
     fn accept_relationship() -> Weight {
         Weight::from_parts(35_000_000, 0)
             .saturating_add(RocksDbWeight::get().reads(2))
-            .saturating_add(RocksDbWeight::get().writes(3))
+            .saturating_add(RocksDbWeight::get().writes(5))
     }
 
     fn revoke_relationship() -> Weight {
         Weight::from_parts(30_000_000, 0)
             .saturating_add(RocksDbWeight::get().reads(1))
-            .saturating_add(RocksDbWeight::get().writes(2))
+            .saturating_add(RocksDbWeight::get().writes(4))
     }
 
     fn update_trust_level() -> Weight {
         Weight::from_parts(25_000_000, 0)
-            .saturating_add(RocksDbWeight::get().reads(1))
-            .saturating_add(RocksDbWeight::get().writes(1))
+            .saturating_add(RocksDbWeight::get().reads(2))
+            .saturating_add(RocksDbWeight::get().writes(4))
     }
 
     fn request_discovery() -> Weight {
@@ -103,7 +126,27 @@ impl WeightInfo for () {
 
     fn complete_discovery() -> Weight {
         Weight::from_parts(30_000_000, 0)
-            .saturating_add(RocksDbWeight::get().reads(1))
+            .saturating_add(RocksDbWeight::get().reads(2))
             .saturating_add(RocksDbWeight::get().writes(2))
     }
+
+    fn set_type_default_expiry() -> Weight {
+        Weight::from_parts(15_000_000, 0).saturating_add(RocksDbWeight::get().writes(1))
+    }
+
+    fn import_relationships(n: u32) -> Weight {
+        // Per entry: reads RelationshipIndex, ActorRelationships, RelationshipCount,
+        // TypeDefaultExpiry, SemanticProfiles; writes those plus Relationships,
+        // InboundRelationships and both RelationshipVersion entries
+        Weight::from_parts(10_000_000, 0)
+            .saturating_add(Weight::from_parts(40_000_000, 0).saturating_mul(n as u64))
+            .saturating_add(RocksDbWeight::get().reads(n as u64 * 5))
+            .saturating_add(RocksDbWeight::get().writes(n as u64 * 8))
+    }
+
+    fn record_interaction() -> Weight {
+        Weight::from_parts(25_000_000, 0)
+            .saturating_add(RocksDbWeight::get().reads(4))
+            .saturating_add(RocksDbWeight::get().writes(5))
+    }
 }
@@ -13,8 +13,19 @@ use frame_support::pallet_prelude::*;
 use frame_system::pallet_prelude::*;
 use parity_scale_codec::{Decode, Encode, MaxEncodedLen};
 use scale_info::TypeInfo;
+use seveny_primitives::crypto::{hash_with_domain, BinaryMerkleTree, MerkleProof, StateRoot};
+use seveny_primitives::traits::{MerkleTree, OnPositionConfirmed};
+use seveny_primitives::triangulation::{
+    distance_squared_cm_to_rssi, multilateration, SignalObservation, TriangulationConfig,
+};
 use sp_core::H256;
-use sp_runtime::Saturating;
+use sp_runtime::{traits::Zero, Saturating};
+
+/// Domain separator for tracked-device set leaves.
+pub const DOMAIN_DEVICE_SET: &[u8] = b"7ay:device-set:v1";
+
+/// Readings a reporter's anomaly score is smoothed over.
+pub const ANOMALY_SMOOTHING: u32 = 4;
 
 #[derive(
     Clone,
@@ -149,6 +160,30 @@ impl DeviceState {
             Self::Suspicious | Self::Lost | Self::Disputed | Self::Unverifiable
         )
     }
+
+    /// Whether moving from this state to `next` is a legal transition.
+    ///
+    /// | From                                           | To                      |
+    /// |------------------------------------------------|-------------------------|
+    /// | Active, LowPower, Sleeping, Verified, Disputed | any state               |
+    /// | Unverifiable, Offline, Lost                    | Active (recovery), Lost |
+    /// | Suspicious                                     | Unverifiable (clearing) |
+    ///
+    /// Staying in the same state is always allowed.
+    pub fn can_transition_to(&self, next: &Self) -> bool {
+        if self == next {
+            return true;
+        }
+        match self {
+            Self::Active | Self::LowPower | Self::Sleeping | Self::Verified | Self::Disputed => {
+                true
+            }
+            Self::Unverifiable | Self::Offline | Self::Lost => {
+                matches!(next, Self::Active | Self::Lost)
+            }
+            Self::Suspicious => matches!(next, Self::Unverifiable),
+        }
+    }
 }
 
 #[derive(
@@ -169,6 +204,31 @@ pub struct Position {
     pub z: i64,
 }
 
+impl Position {
+    /// Narrow to the `i32` position used by the shared triangulation helpers,
+    /// saturating out-of-range coordinates.
+    pub fn to_fusion(&self) -> seveny_primitives::fusion::Position {
+        let narrow = |v: i64| v.clamp(i32::MIN as i64, i32::MAX as i64) as i32;
+        seveny_primitives::fusion::Position::new(narrow(self.x), narrow(self.y), narrow(self.z))
+    }
+
+    /// Squared Euclidean distance, widened to avoid overflow.
+    pub fn distance_squared(&self, other: &Self) -> u128 {
+        let dx = (self.x as i128)
+            .saturating_sub(other.x as i128)
+            .unsigned_abs();
+        let dy = (self.y as i128)
+            .saturating_sub(other.y as i128)
+            .unsigned_abs();
+        let dz = (self.z as i128)
+            .saturating_sub(other.z as i128)
+            .unsigned_abs();
+        dx.saturating_mul(dx)
+            .saturating_add(dy.saturating_mul(dy))
+            .saturating_add(dz.saturating_mul(dz))
+    }
+}
+
 #[derive(
     Clone,
     Debug,
@@ -211,6 +271,27 @@ pub struct TrackedDevice<BlockNumber> {
     pub consecutive_misses: u32,
 }
 
+/// Reporter position at the start of the block of its latest accepted move.
+#[derive(
+    Clone,
+    Debug,
+    PartialEq,
+    Eq,
+    Encode,
+    Decode,
+    parity_scale_codec::DecodeWithMemTracking,
+    TypeInfo,
+    MaxEncodedLen,
+)]
+pub struct PositionAnchor<BlockNumber> {
+    /// Block the anchor applies to
+    pub block: BlockNumber,
+    /// Block of the last accepted move before `block`, where the allowance starts
+    pub since: BlockNumber,
+    /// Position held before the first move in `block`
+    pub position: Position,
+}
+
 #[derive(
     Clone,
     Debug,
@@ -228,6 +309,14 @@ pub struct Reporter<BlockNumber> {
     pub registered_at: BlockNumber,
     pub active: bool,
     pub reading_count: u64,
+    /// Block of the last accepted position change
+    pub position_updated_at: BlockNumber,
+    /// Track record from 0 to 100; grows with accepted readings and is zeroed on a
+    /// guilty fraud verdict
+    pub reputation: u8,
+    /// Site the reporter belongs to; region-scoped fusion only combines reporters
+    /// sharing the same value
+    pub region: Option<u16>,
 }
 
 #[derive(
@@ -351,6 +440,25 @@ impl FraudProof {
     pub fn is_valid(&self) -> bool {
         self.conflicting_readings.len() >= 3 && self.z_score_scaled >= 350
     }
+
+    /// Scaled Z-score needed to accuse a reporter with the given reputation.
+    /// Ranges from 2.5 sigma at reputation 0 to 4.5 sigma at 100, with the
+    /// flat 3.5 sigma at the midpoint.
+    pub fn required_z_score(reputation: u8) -> u32 {
+        250u32.saturating_add(2u32.saturating_mul(reputation.min(100) as u32))
+    }
+
+    /// Validate the fraud proof against an accused reporter's reputation:
+    /// requires min 3 readings and Z >= `required_z_score(reputation)`
+    pub fn is_valid_against(&self, reputation: u8) -> bool {
+        self.conflicting_readings.len() >= 3
+            && self.z_score_scaled >= Self::required_z_score(reputation)
+    }
+
+    /// Strong evidence: valid even against a reporter with full reputation.
+    pub fn is_strong(&self) -> bool {
+        self.is_valid_against(100)
+    }
 }
 
 /// A fraud case filed against a reporter
@@ -374,6 +482,8 @@ pub struct FraudCase<BlockNumber> {
     pub submitted_at: BlockNumber,
     /// Current status of the case
     pub status: FraudCaseStatus,
+    /// When the case was slashed or dismissed
+    pub resolved_at: Option<BlockNumber>,
 }
 
 #[frame_support::pallet]
@@ -408,6 +518,121 @@ pub mod pallet {
 
         #[pallet::constant]
         type SignalRetentionBlocks: Get<BlockNumberFor<Self>>;
+
+        /// Maximum distance (cm) a reporter may move per elapsed block.
+        #[pallet::constant]
+        type MaxReporterMovementCm: Get<u64>;
+
+        /// Average RSSI at or above which a vanishing device is treated as suspicious
+        /// rather than a normal fade-out.
+        #[pallet::constant]
+        type StrongSignalRssi: Get<i8>;
+
+        /// Blocks a slashed or dismissed fraud case is kept before being pruned.
+        #[pallet::constant]
+        type FraudCaseRetentionBlocks: Get<BlockNumberFor<Self>>;
+
+        /// Blocks a fraud case may stay pending before it is settled automatically.
+        /// Zero leaves pending cases to `resolve_fraud_case` alone.
+        #[pallet::constant]
+        type FraudResolutionTimeout: Get<BlockNumberFor<Self>>;
+
+        /// Uphold timed-out cases backed by strong evidence instead of dismissing them.
+        #[pallet::constant]
+        type AutoUpholdStrongEvidence: Get<bool>;
+
+        /// Reputation assigned to newly registered reporters (0-100).
+        #[pallet::constant]
+        type InitialReporterReputation: Get<u8>;
+
+        /// Blocks between recomputations of the tracked-device set root.
+        #[pallet::constant]
+        type DeviceRootInterval: Get<BlockNumberFor<Self>>;
+
+        /// Maximum devices committed to in one device set root; devices beyond
+        /// the first `MaxDeviceSetLeaves` in storage hash order are left out.
+        #[pallet::constant]
+        type MaxDeviceSetLeaves: Get<u32>;
+
+        /// Reference RSSI at one metre used to derive expected readings.
+        #[pallet::constant]
+        type ReferenceTxPower: Get<i8>;
+
+        /// Path-loss exponent scaled by 100 used to derive expected readings.
+        #[pallet::constant]
+        type PathLossExponentX100: Get<u16>;
+
+        /// Maximum readings accepted in one `report_signals_batch` call.
+        #[pallet::constant]
+        type MaxBatchReadings: Get<u32>;
+
+        /// Blocks a ghost event is kept before it is purged along with its lost device.
+        #[pallet::constant]
+        type GhostRetentionBlocks: Get<BlockNumberFor<Self>>;
+
+        /// History entries retained per reporter; its oldest entry is evicted when exceeded.
+        #[pallet::constant]
+        type MaxHistoryPerReporter: Get<u32>;
+
+        /// Anomaly score (mean dB deviation x100) at which a reporter is flagged; 0 disables.
+        #[pallet::constant]
+        type AnomalyThreshold: Get<u32>;
+
+        /// Distinct reporters a device needs before its confidence may exceed
+        /// `SingleReporterConfidenceCap`.
+        #[pallet::constant]
+        type MinReportersForTrust: Get<u32>;
+
+        /// Confidence ceiling for devices seen by fewer than `MinReportersForTrust` reporters.
+        #[pallet::constant]
+        type SingleReporterConfidenceCap: Get<u8>;
+
+        /// Notified whenever an active device's position is updated at or above
+        /// `PositionConfirmationThreshold` confidence.
+        type PositionConfirmed: OnPositionConfirmed;
+
+        /// Confidence from which position updates are reported to `PositionConfirmed`.
+        #[pallet::constant]
+        type PositionConfirmationThreshold: Get<u8>;
+
+        /// Active reporters that must have seen a device before its disappearance is
+        /// declared `Lost`; 0 relies on `LostTimeoutBlocks` alone. Witnesses come from
+        /// `DeviceReporters`, so values above `MinReportersForTrust` act as that.
+        #[pallet::constant]
+        type GhostWitnessQuorum: Get<u32>;
+
+        /// Blocks unseen after which a device short of `GhostWitnessQuorum` is declared
+        /// `Lost` anyway, so single-reporter devices still age out. Should exceed
+        /// `LostTimeoutBlocks`.
+        #[pallet::constant]
+        type UnwitnessedLostTimeoutBlocks: Get<BlockNumberFor<Self>>;
+
+        /// Readings one reporter may submit per block, single or batched; 0 disables the cap.
+        #[pallet::constant]
+        type MaxReadingsPerReporterPerBlock: Get<u32>;
+
+        /// Confidence a device position needs to be returned by
+        /// `get_authoritative_position`.
+        #[pallet::constant]
+        type MinAuthoritativeConfidence: Get<u8>;
+
+        /// Let a tracked device switch `SignalType`; when false a change flags it
+        /// `Suspicious` as a possible MAC spoof across radios.
+        #[pallet::constant]
+        type AllowSignalTypeMigration: Get<bool>;
+
+        /// Furthest a fraud proof submitter may be from the accused when either
+        /// reporter has no region tag; 0 disables the distance check.
+        #[pallet::constant]
+        type MaxAccusationDistanceCm: Get<u64>;
+
+        /// Blocks over which `MaxReputationGainPerWindow` applies; 0 disables the cap.
+        #[pallet::constant]
+        type ReputationGainWindow: Get<BlockNumberFor<Self>>;
+
+        /// Reputation a reporter may earn from readings within one `ReputationGainWindow`.
+        #[pallet::constant]
+        type MaxReputationGainPerWindow: Get<u8>;
     }
 
     #[pallet::storage]
@@ -439,6 +664,17 @@ pub mod pallet {
         SignalHistoryEntry<BlockNumberFor<T>>,
     >;
 
+    /// `SignalHistory` keys written by each reporter, oldest first.
+    #[pallet::storage]
+    #[pallet::getter(fn reporter_history)]
+    pub type ReporterHistory<T: Config> = StorageMap<
+        _,
+        Blake2_128Concat,
+        ReporterId,
+        BoundedVec<(H256, BlockNumberFor<T>), T::MaxHistoryPerReporter>,
+        ValueQuery,
+    >;
+
     #[pallet::storage]
     #[pallet::getter(fn ghost_events)]
     pub type GhostEvents<T: Config> =
@@ -458,6 +694,80 @@ pub mod pallet {
     pub type FraudCases<T: Config> =
         StorageMap<_, Blake2_128Concat, ReporterId, FraudCase<BlockNumberFor<T>>>;
 
+    /// Raw `FraudCases` key where the next expiry and pruning sweep resumes.
+    #[pallet::storage]
+    pub type FraudSweepCursor<T: Config> =
+        StorageValue<_, BoundedVec<u8, ConstU32<128>>, OptionQuery>;
+
+    /// Raw `GhostEvents` key where the next stale ghost purge resumes.
+    #[pallet::storage]
+    pub type GhostPurgeCursor<T: Config> =
+        StorageValue<_, BoundedVec<u8, ConstU32<128>>, OptionQuery>;
+
+    /// Implausible position updates recorded per reporter, for slashing review
+    #[pallet::storage]
+    #[pallet::getter(fn movement_violations)]
+    pub type MovementViolations<T: Config> =
+        StorageMap<_, Blake2_128Concat, ReporterId, u32, ValueQuery>;
+
+    /// Start of each reporter's current reputation gain window and the
+    /// reputation earned in it.
+    #[pallet::storage]
+    pub type ReputationGains<T: Config> =
+        StorageMap<_, Blake2_128Concat, ReporterId, (BlockNumberFor<T>, u8)>;
+
+    /// Where each reporter stood before its first accepted move in `block`, so
+    /// further moves that block share one allowance instead of each earning a new one.
+    #[pallet::storage]
+    pub type PositionAnchors<T: Config> =
+        StorageMap<_, Blake2_128Concat, ReporterId, PositionAnchor<BlockNumberFor<T>>>;
+
+    /// Reporters that have read each device, recorded until `MinReportersForTrust`
+    /// distinct reporters are known
+    #[pallet::storage]
+    #[pallet::getter(fn device_reporters)]
+    pub type DeviceReporters<T: Config> =
+        StorageDoubleMap<_, Blake2_128Concat, H256, Blake2_128Concat, ReporterId, ()>;
+
+    /// Distinct reporters recorded in `DeviceReporters` per device
+    #[pallet::storage]
+    #[pallet::getter(fn device_reporter_count)]
+    pub type DeviceReporterCount<T: Config> =
+        StorageMap<_, Blake2_128Concat, H256, u32, ValueQuery>;
+
+    /// Running mean of `|rssi - expected_rssi|` per reporter in dB scaled by 100,
+    /// smoothed over `ANOMALY_SMOOTHING` readings
+    #[pallet::storage]
+    #[pallet::getter(fn reporter_anomaly)]
+    pub type ReporterAnomaly<T: Config> =
+        StorageMap<_, Blake2_128Concat, ReporterId, u32, ValueQuery>;
+
+    /// Reporters whose anomaly score crossed `AnomalyThreshold`, with the block
+    /// they were flagged; fraud proofs against them use the minimum Z-score
+    #[pallet::storage]
+    #[pallet::getter(fn anomaly_flagged)]
+    pub type AnomalyFlagged<T: Config> =
+        StorageMap<_, Blake2_128Concat, ReporterId, BlockNumberFor<T>>;
+
+    /// Merkle root over `(mac_hash, estimated_position, last_seen)` of tracked devices,
+    /// refreshed every `DeviceRootInterval` blocks
+    #[pallet::storage]
+    #[pallet::getter(fn device_set_root)]
+    pub type DeviceSetRoot<T: Config> = StorageValue<_, StateRoot, ValueQuery>;
+
+    /// `(mac_hash, leaf)` pairs behind `DeviceSetRoot`, sorted by `mac_hash`
+    #[pallet::storage]
+    #[pallet::getter(fn device_set_leaves)]
+    pub type DeviceSetLeaves<T: Config> =
+        StorageValue<_, BoundedVec<(H256, H256), T::MaxDeviceSetLeaves>, ValueQuery>;
+
+    /// Readings accepted from each reporter in the block they were counted in;
+    /// a count from an earlier block reads as zero.
+    #[pallet::storage]
+    #[pallet::getter(fn reporter_block_readings)]
+    pub type ReporterBlockReadings<T: Config> =
+        StorageMap<_, Blake2_128Concat, ReporterId, (BlockNumberFor<T>, u32)>;
+
     #[pallet::genesis_config]
     #[derive(frame_support::DefaultNoBound)]
     pub struct GenesisConfig<T: Config> {
@@ -480,7 +790,18 @@ pub mod pallet {
         fn on_initialize(block_number: BlockNumberFor<T>) -> Weight {
             Self::detect_ghosts(block_number);
             Self::cleanup_old_history(block_number);
+            let sweep_weight = Self::sweep_fraud_cases(block_number);
+            let purge_weight = Self::purge_stale_ghosts(block_number);
+            let interval = T::DeviceRootInterval::get();
+            let root_weight = if !interval.is_zero() && (block_number % interval).is_zero() {
+                Self::update_device_set_root()
+            } else {
+                Weight::zero()
+            };
             Weight::from_parts(50_000, 0)
+                .saturating_add(sweep_weight)
+                .saturating_add(purge_weight)
+                .saturating_add(root_weight)
         }
     }
 
@@ -500,6 +821,11 @@ pub mod pallet {
             rssi: i8,
             signal_type: SignalType,
         },
+        SignalsBatchReported {
+            reporter_id: ReporterId,
+            accepted: u32,
+            skipped: u32,
+        },
         DeviceStateChanged {
             mac_hash: H256,
             old_state: DeviceState,
@@ -514,6 +840,23 @@ pub mod pallet {
             mac_hash: H256,
             new_position: Position,
         },
+        /// A device vanished while its recent signal was strong
+        StrongSignalVanished {
+            mac_hash: H256,
+            average_rssi: i8,
+        },
+        /// A suspicious device was cleared and must recover through a new reading
+        SuspicionCleared {
+            mac_hash: H256,
+        },
+        /// A tracked device was reported under a different signal type
+        SignalTypeChanged {
+            mac_hash: H256,
+            reporter_id: ReporterId,
+            old_type: SignalType,
+            new_type: SignalType,
+            migrated: bool,
+        },
         PositionUpdated {
             mac_hash: H256,
             position: Position,
@@ -534,10 +877,47 @@ pub mod pallet {
         ReporterSlashed {
             reporter_id: ReporterId,
         },
+        /// A reporter's anomaly score crossed `AnomalyThreshold`
+        ReporterAnomalyFlagged {
+            reporter_id: ReporterId,
+            score: u32,
+        },
+        /// A flagged reporter's anomaly score fell back below `AnomalyThreshold`
+        ReporterAnomalyCleared {
+            reporter_id: ReporterId,
+            score: u32,
+        },
         /// A fraud case was dismissed
         FraudCaseDismissed {
             reporter_id: ReporterId,
         },
+        /// A resolved fraud case was removed after its retention period
+        FraudCasePruned {
+            reporter_id: ReporterId,
+        },
+        /// A ghost event outlived its retention period; a still-lost device is removed with it
+        GhostPurged {
+            mac_hash: H256,
+            device_removed: bool,
+        },
+        /// The tracked-device set root was recomputed
+        DeviceSetRootUpdated {
+            root: StateRoot,
+            leaf_count: u32,
+        },
+        /// A reporter moved to a new position
+        ReporterPositionUpdated {
+            reporter_id: ReporterId,
+            position: Position,
+        },
+        /// A reporter position update exceeded the movement bound
+        /// and was not applied
+        ImplausibleMovementRejected {
+            reporter_id: ReporterId,
+            attempted_position: Position,
+            max_allowed_cm: u64,
+            violations: u32,
+        },
     }
 
     #[pallet::error]
@@ -549,14 +929,20 @@ pub mod pallet {
         ReporterNotActive,
         InvalidRssi,
         MaxReadingsReached,
-        /// Fraud proof is invalid (min 3 readings with Z >= 3.5 required)
+        /// Fraud proof is invalid (min 3 readings and a Z-score scaled to the accused's reputation)
         InvalidFraudProof,
-        /// A fraud case already exists for this reporter
+        /// A pending fraud case already exists for this reporter
         FraudCaseAlreadyExists,
         /// No fraud case found for this reporter
         FraudCaseNotFound,
         /// Caller is not the owner of this reporter
         NotReporterOwner,
+        /// The requested device state change is not in the transition table
+        IllegalStateTransition,
+        /// Reporter already submitted `MaxReadingsPerReporterPerBlock` readings this block
+        ReporterRateLimited,
+        /// Submitter and accused reporter are in different regions or too far apart
+        CrossRegionAccusation,
     }
 
     /// Maps ReporterId to the AccountId that registered it.
@@ -567,7 +953,11 @@ pub mod pallet {
     impl<T: Config> Pallet<T> {
         #[pallet::call_index(0)]
         #[pallet::weight(T::WeightInfo::register_reporter())]
-        pub fn register_reporter(origin: OriginFor<T>, position: Position) -> DispatchResult {
+        pub fn register_reporter(
+            origin: OriginFor<T>,
+            position: Position,
+            region: Option<u16>,
+        ) -> DispatchResult {
             let caller = ensure_signed(origin)?;
 
             let count = ReporterCount::<T>::get();
@@ -585,6 +975,9 @@ pub mod pallet {
                 registered_at: block_number,
                 active: true,
                 reading_count: 0,
+                position_updated_at: block_number,
+                reputation: T::InitialReporterReputation::get().min(100),
+                region,
             };
 
             Reporters::<T>::insert(reporter_id, reporter);
@@ -639,98 +1032,29 @@ pub mod pallet {
             ensure!(reporter.active, Error::<T>::ReporterNotActive);
 
             let block_number = frame_system::Pallet::<T>::block_number();
+            ensure!(
+                Self::remaining_block_readings(reporter_id, block_number) > 0,
+                Error::<T>::ReporterRateLimited
+            );
+            Self::count_block_readings(reporter_id, block_number, 1);
 
-            let reading = SignalReading {
-                reporter_id,
-                rssi,
-                signal_type,
-                frequency,
-                recorded_at: block_number,
-            };
-
+            let gain = Self::reputation_gain(reporter_id, 1, block_number);
             Reporters::<T>::mutate(reporter_id, |r| {
                 if let Some(rep) = r {
                     rep.reading_count = rep.reading_count.saturating_add(1);
+                    rep.reputation = rep.reputation.saturating_add(gain).min(100);
                 }
             });
 
-            let is_new_device = !TrackedDevices::<T>::contains_key(mac_hash);
-
-            if is_new_device {
-                let device = TrackedDevice {
-                    mac_hash,
-                    signal_type,
-                    state: DeviceState::Active,
-                    estimated_position: reporter.position.clone(),
-                    confidence: 30,
-                    first_seen: block_number,
-                    last_seen: block_number,
-                    reading_count: 1,
-                    consecutive_misses: 0,
-                };
-
-                TrackedDevices::<T>::insert(mac_hash, device);
-                DeviceCount::<T>::mutate(|c| *c = c.saturating_add(1));
-                ActiveDeviceCount::<T>::mutate(|c| *c = c.saturating_add(1));
-            } else {
-                TrackedDevices::<T>::mutate(mac_hash, |device| {
-                    if let Some(d) = device {
-                        let old_state = d.state;
-
-                        d.last_seen = block_number;
-                        d.reading_count = d.reading_count.saturating_add(1);
-                        d.consecutive_misses = 0;
-
-                        let new_position = Self::calculate_position(
-                            &reporter.position,
-                            &d.estimated_position,
-                            rssi,
-                        );
-                        d.estimated_position = new_position.clone();
-
-                        d.confidence = d.confidence.saturating_add(5).min(100);
-
-                        if d.reading_count >= T::MinReadingsForActive::get() {
-                            d.state = DeviceState::Active;
-                        }
-
-                        if old_state != d.state {
-                            Self::deposit_event(Event::DeviceStateChanged {
-                                mac_hash,
-                                old_state,
-                                new_state: d.state,
-                            });
-                        }
-
-                        if matches!(
-                            old_state,
-                            DeviceState::Lost | DeviceState::Unverifiable | DeviceState::Offline
-                        ) {
-                            GhostEvents::<T>::remove(mac_hash);
-                            GhostCount::<T>::mutate(|c| *c = c.saturating_sub(1));
-
-                            Self::deposit_event(Event::DeviceRecovered {
-                                mac_hash,
-                                new_position,
-                            });
-                        }
-                    }
-                });
-            }
-
-            let history_entry = SignalHistoryEntry {
-                reading,
-                position_at_time: reporter.position.clone(),
-            };
-
-            SignalHistory::<T>::insert(mac_hash, block_number, history_entry);
-
-            Self::deposit_event(Event::SignalDetected {
-                mac_hash,
+            Self::record_signal(
                 reporter_id,
+                &reporter.position,
+                mac_hash,
                 rssi,
                 signal_type,
-            });
+                frequency,
+                block_number,
+            );
 
             Ok(())
         }
@@ -746,11 +1070,59 @@ pub mod pallet {
             let owner = ReporterOwner::<T>::get(reporter_id).ok_or(Error::<T>::ReporterNotFound)?;
             ensure!(caller == owner, Error::<T>::NotReporterOwner);
 
-            Reporters::<T>::try_mutate(reporter_id, |reporter| -> DispatchResult {
-                let r = reporter.as_mut().ok_or(Error::<T>::ReporterNotFound)?;
-                r.position = new_position;
-                Ok(())
-            })
+            let mut reporter =
+                Reporters::<T>::get(reporter_id).ok_or(Error::<T>::ReporterNotFound)?;
+            let block_number = frame_system::Pallet::<T>::block_number();
+
+            // Bound movement by elapsed blocks since the last accepted update,
+            // measured from where the reporter stood before this block's first
+            // move; same-block updates share a single block's allowance.
+            let anchor = PositionAnchors::<T>::get(reporter_id)
+                .filter(|a| a.block == block_number)
+                .unwrap_or(PositionAnchor {
+                    block: block_number,
+                    since: reporter.position_updated_at,
+                    position: reporter.position.clone(),
+                });
+            let elapsed: u64 = block_number
+                .saturating_sub(anchor.since)
+                .try_into()
+                .unwrap_or(u64::MAX)
+                .max(1);
+            let max_allowed_cm = T::MaxReporterMovementCm::get().saturating_mul(elapsed);
+            let max_allowed = max_allowed_cm as u128;
+
+            if anchor.position.distance_squared(&new_position)
+                > max_allowed.saturating_mul(max_allowed)
+            {
+                // Flag rather than fail so the violation persists for
+                // slashing review; the position is left unchanged.
+                let violations = MovementViolations::<T>::mutate(reporter_id, |v| {
+                    *v = v.saturating_add(1);
+                    *v
+                });
+
+                Self::deposit_event(Event::ImplausibleMovementRejected {
+                    reporter_id,
+                    attempted_position: new_position,
+                    max_allowed_cm,
+                    violations,
+                });
+
+                return Ok(());
+            }
+
+            reporter.position = new_position.clone();
+            reporter.position_updated_at = block_number;
+            Reporters::<T>::insert(reporter_id, reporter);
+            PositionAnchors::<T>::insert(reporter_id, anchor);
+
+            Self::deposit_event(Event::ReporterPositionUpdated {
+                reporter_id,
+                position: new_position,
+            });
+
+            Ok(())
         }
 
         /// Submit a fraud proof against a reporter.
@@ -773,17 +1145,29 @@ pub mod pallet {
             ensure!(submitter.active, Error::<T>::ReporterNotActive);
 
             // Validate the accused reporter exists
+            let accused_reporter =
+                Reporters::<T>::get(proof.accused_reporter).ok_or(Error::<T>::ReporterNotFound)?;
             ensure!(
-                Reporters::<T>::contains_key(proof.accused_reporter),
-                Error::<T>::ReporterNotFound
+                Self::can_accuse(&submitter, &accused_reporter),
+                Error::<T>::CrossRegionAccusation
             );
 
-            // Validate the fraud proof (min 3 readings, Z >= 3.5)
-            ensure!(proof.is_valid(), Error::<T>::InvalidFraudProof);
+            // Validate the fraud proof (min 3 readings, Z scaled by the accused's
+            // reputation); anomaly-flagged reporters are held to the minimum Z
+            let reputation = if AnomalyFlagged::<T>::contains_key(proof.accused_reporter) {
+                0
+            } else {
+                accused_reporter.reputation
+            };
+            ensure!(
+                proof.is_valid_against(reputation),
+                Error::<T>::InvalidFraudProof
+            );
 
-            // Ensure no existing fraud case
+            // Only a pending case blocks new evidence; resolved cases are replaced
             ensure!(
-                !FraudCases::<T>::contains_key(proof.accused_reporter),
+                !FraudCases::<T>::get(proof.accused_reporter)
+                    .is_some_and(|c| c.status == FraudCaseStatus::Pending),
                 Error::<T>::FraudCaseAlreadyExists
             );
 
@@ -796,6 +1180,7 @@ pub mod pallet {
                 proof,
                 submitted_at: block_number,
                 status: FraudCaseStatus::Pending,
+                resolved_at: None,
             };
 
             FraudCases::<T>::insert(accused, fraud_case);
@@ -820,32 +1205,133 @@ pub mod pallet {
         ) -> DispatchResult {
             ensure_root(origin)?;
 
+            let block_number = frame_system::Pallet::<T>::block_number();
+
             FraudCases::<T>::try_mutate(reporter_id, |case| -> DispatchResult {
                 let c = case.as_mut().ok_or(Error::<T>::FraudCaseNotFound)?;
+                Self::settle_fraud_case(reporter_id, c, guilty, block_number);
+                Ok(())
+            })
+        }
 
-                if guilty {
-                    // Slash the reporter (deactivate them)
-                    Reporters::<T>::mutate(reporter_id, |r| {
-                        if let Some(reporter) = r {
-                            reporter.active = false;
-                        }
-                    });
+        /// Clear a suspicious device (root only).
+        /// The device moves to Unverifiable and returns to Active on its next reading.
+        #[pallet::call_index(6)]
+        #[pallet::weight(Weight::from_parts(20_000, 0))]
+        pub fn clear_suspicious_device(origin: OriginFor<T>, mac_hash: H256) -> DispatchResult {
+            ensure_root(origin)?;
 
-                    c.status = FraudCaseStatus::Slashed;
+            TrackedDevices::<T>::try_mutate(mac_hash, |device| -> DispatchResult {
+                let d = device.as_mut().ok_or(Error::<T>::DeviceNotFound)?;
+                ensure!(
+                    d.state == DeviceState::Suspicious,
+                    Error::<T>::IllegalStateTransition
+                );
 
-                    Self::deposit_event(Event::ReporterSlashed { reporter_id });
-                } else {
-                    c.status = FraudCaseStatus::Dismissed;
+                let old_state = d.state;
+                d.state = Self::transition_device_state(old_state, DeviceState::Unverifiable)?;
 
-                    Self::deposit_event(Event::FraudCaseDismissed { reporter_id });
-                }
+                Self::deposit_event(Event::SuspicionCleared { mac_hash });
+                Self::deposit_event(Event::DeviceStateChanged {
+                    mac_hash,
+                    old_state,
+                    new_state: d.state,
+                });
 
                 Ok(())
             })
         }
+
+        #[pallet::call_index(7)]
+        #[pallet::weight(T::WeightInfo::report_signals_batch(readings.len() as u32))]
+        pub fn report_signals_batch(
+            origin: OriginFor<T>,
+            reporter_id: ReporterId,
+            readings: BoundedVec<(H256, i8, SignalType, u16), T::MaxBatchReadings>,
+        ) -> DispatchResult {
+            let caller = ensure_signed(origin)?;
+            let owner = ReporterOwner::<T>::get(reporter_id).ok_or(Error::<T>::ReporterNotFound)?;
+            ensure!(caller == owner, Error::<T>::NotReporterOwner);
+
+            let reporter = Reporters::<T>::get(reporter_id).ok_or(Error::<T>::ReporterNotFound)?;
+            ensure!(reporter.active, Error::<T>::ReporterNotActive);
+
+            let block_number = frame_system::Pallet::<T>::block_number();
+            let allowance = Self::remaining_block_readings(reporter_id, block_number);
+            let mut accepted: u32 = 0;
+            let mut skipped: u32 = 0;
+
+            // Readings past the block allowance are skipped like invalid ones.
+            for (mac_hash, rssi, signal_type, frequency) in readings {
+                if !(-120..=0).contains(&rssi) || accepted >= allowance {
+                    skipped = skipped.saturating_add(1);
+                    continue;
+                }
+                Self::record_signal(
+                    reporter_id,
+                    &reporter.position,
+                    mac_hash,
+                    rssi,
+                    signal_type,
+                    frequency,
+                    block_number,
+                );
+                accepted = accepted.saturating_add(1);
+            }
+            Self::count_block_readings(reporter_id, block_number, accepted);
+
+            let gain = Self::reputation_gain(reporter_id, accepted, block_number);
+            Reporters::<T>::mutate(reporter_id, |r| {
+                if let Some(rep) = r {
+                    rep.reading_count = rep.reading_count.saturating_add(accepted as u64);
+                    rep.reputation = rep.reputation.saturating_add(gain).min(100);
+                }
+            });
+
+            Self::deposit_event(Event::SignalsBatchReported {
+                reporter_id,
+                accepted,
+                skipped,
+            });
+
+            Ok(())
+        }
     }
 
     impl<T: Config> Pallet<T> {
+        /// Readings `reporter_id` may still submit in `block_number`.
+        fn remaining_block_readings(
+            reporter_id: ReporterId,
+            block_number: BlockNumberFor<T>,
+        ) -> u32 {
+            let cap = T::MaxReadingsPerReporterPerBlock::get();
+            if cap == 0 {
+                return u32::MAX;
+            }
+            let used = match ReporterBlockReadings::<T>::get(reporter_id) {
+                Some((block, count)) if block == block_number => count,
+                _ => 0,
+            };
+            cap.saturating_sub(used)
+        }
+
+        fn count_block_readings(
+            reporter_id: ReporterId,
+            block_number: BlockNumberFor<T>,
+            readings: u32,
+        ) {
+            if T::MaxReadingsPerReporterPerBlock::get() == 0 || readings == 0 {
+                return;
+            }
+            ReporterBlockReadings::<T>::mutate(reporter_id, |entry| {
+                let used = match *entry {
+                    Some((block, count)) if block == block_number => count,
+                    _ => 0,
+                };
+                *entry = Some((block_number, used.saturating_add(readings)));
+            });
+        }
+
         fn calculate_position(
             reporter_pos: &Position,
             current_pos: &Position,
@@ -873,6 +1359,303 @@ pub mod pallet {
             }
         }
 
+        /// Apply one accepted reading: create or update the tracked device, append
+        /// it to the signal history and emit `SignalDetected`. The caller has
+        /// already validated the reporter and the reading.
+        fn record_signal(
+            reporter_id: ReporterId,
+            reporter_position: &Position,
+            mac_hash: H256,
+            rssi: i8,
+            signal_type: SignalType,
+            frequency: u16,
+            block_number: BlockNumberFor<T>,
+        ) {
+            let reading = SignalReading {
+                reporter_id,
+                rssi,
+                signal_type,
+                frequency,
+                recorded_at: block_number,
+            };
+
+            let is_new_device = !TrackedDevices::<T>::contains_key(mac_hash);
+            let confidence_cap = Self::note_device_reporter(mac_hash, reporter_id);
+
+            if is_new_device {
+                let device = TrackedDevice {
+                    mac_hash,
+                    signal_type,
+                    state: DeviceState::Active,
+                    estimated_position: reporter_position.clone(),
+                    confidence: 30,
+                    first_seen: block_number,
+                    last_seen: block_number,
+                    reading_count: 1,
+                    consecutive_misses: 0,
+                };
+
+                TrackedDevices::<T>::insert(mac_hash, device);
+                DeviceCount::<T>::mutate(|c| *c = c.saturating_add(1));
+                ActiveDeviceCount::<T>::mutate(|c| *c = c.saturating_add(1));
+            } else if let Some(mut device) = TrackedDevices::<T>::get(mac_hash) {
+                if device.signal_type != signal_type {
+                    Self::handle_signal_type_change(&mut device, reporter_id, signal_type);
+                }
+                // Score against the consensus estimate before this reading moves it
+                let expected = distance_squared_cm_to_rssi(
+                    reporter_position.distance_squared(&device.estimated_position),
+                    T::ReferenceTxPower::get(),
+                    T::PathLossExponentX100::get(),
+                );
+                Self::update_anomaly_score(reporter_id, rssi, expected, block_number);
+                Self::apply_reading(
+                    &mut device,
+                    reporter_position,
+                    rssi,
+                    block_number,
+                    confidence_cap,
+                );
+                TrackedDevices::<T>::insert(mac_hash, device);
+            }
+
+            let history_entry = SignalHistoryEntry {
+                reading,
+                position_at_time: reporter_position.clone(),
+            };
+
+            Self::insert_history(reporter_id, mac_hash, block_number, history_entry);
+
+            Self::deposit_event(Event::SignalDetected {
+                mac_hash,
+                reporter_id,
+                rssi,
+                signal_type,
+            });
+        }
+
+        /// Migrate the device to `new_type`, or flag it `Suspicious` when
+        /// `AllowSignalTypeMigration` is off, and record the change.
+        fn handle_signal_type_change(
+            device: &mut TrackedDevice<BlockNumberFor<T>>,
+            reporter_id: ReporterId,
+            new_type: SignalType,
+        ) {
+            let mac_hash = device.mac_hash;
+            let old_type = device.signal_type;
+            let migrated = T::AllowSignalTypeMigration::get();
+
+            if migrated {
+                device.signal_type = new_type;
+            } else if let Ok(new_state) =
+                Self::transition_device_state(device.state, DeviceState::Suspicious)
+            {
+                if new_state != device.state {
+                    Self::deposit_event(Event::DeviceStateChanged {
+                        mac_hash,
+                        old_state: device.state,
+                        new_state,
+                    });
+                    device.state = new_state;
+                }
+            }
+
+            Self::deposit_event(Event::SignalTypeChanged {
+                mac_hash,
+                reporter_id,
+                old_type,
+                new_type,
+                migrated,
+            });
+        }
+
+        /// Count `reporter_id` towards the device's distinct reporters and return
+        /// the confidence ceiling that applies to it.
+        fn note_device_reporter(mac_hash: H256, reporter_id: ReporterId) -> u8 {
+            let min_reporters = T::MinReportersForTrust::get();
+            let mut distinct = DeviceReporterCount::<T>::get(mac_hash);
+            if distinct < min_reporters
+                && !DeviceReporters::<T>::contains_key(mac_hash, reporter_id)
+            {
+                DeviceReporters::<T>::insert(mac_hash, reporter_id, ());
+                distinct = distinct.saturating_add(1);
+                DeviceReporterCount::<T>::insert(mac_hash, distinct);
+            }
+
+            if distinct >= min_reporters {
+                100
+            } else {
+                T::SingleReporterConfidenceCap::get().min(100)
+            }
+        }
+
+        /// Reputation earned by `accepted` readings: one each, nothing while the
+        /// reporter is anomaly-flagged, and at most `MaxReputationGainPerWindow`
+        /// per `ReputationGainWindow` so flooding readings cannot buy fraud-proof
+        /// immunity.
+        fn reputation_gain(
+            reporter_id: ReporterId,
+            accepted: u32,
+            block_number: BlockNumberFor<T>,
+        ) -> u8 {
+            if accepted == 0 || AnomalyFlagged::<T>::contains_key(reporter_id) {
+                return 0;
+            }
+            let earned = accepted.min(u8::MAX as u32) as u8;
+            let window = T::ReputationGainWindow::get();
+            if window.is_zero() {
+                return earned;
+            }
+
+            let (start, gained) = match ReputationGains::<T>::get(reporter_id) {
+                Some((start, gained)) if block_number < start.saturating_add(window) => {
+                    (start, gained)
+                }
+                _ => (block_number, 0),
+            };
+            let gain = earned.min(T::MaxReputationGainPerWindow::get().saturating_sub(gained));
+            ReputationGains::<T>::insert(reporter_id, (start, gained.saturating_add(gain)));
+            gain
+        }
+
+        /// Fold one reading's deviation from the expected RSSI into the
+        /// reporter's anomaly score and flag or clear it against `AnomalyThreshold`.
+        fn update_anomaly_score(
+            reporter_id: ReporterId,
+            rssi: i8,
+            expected: i8,
+            block_number: BlockNumberFor<T>,
+        ) {
+            let deviation = (rssi as i32 - expected as i32)
+                .unsigned_abs()
+                .saturating_mul(100);
+            let previous = ReporterAnomaly::<T>::get(reporter_id);
+            let score = previous
+                .saturating_mul(ANOMALY_SMOOTHING - 1)
+                .saturating_add(deviation)
+                / ANOMALY_SMOOTHING;
+            ReporterAnomaly::<T>::insert(reporter_id, score);
+
+            let threshold = T::AnomalyThreshold::get();
+            let flagged = AnomalyFlagged::<T>::contains_key(reporter_id);
+            if threshold > 0 && score >= threshold && !flagged {
+                AnomalyFlagged::<T>::insert(reporter_id, block_number);
+                Self::deposit_event(Event::ReporterAnomalyFlagged { reporter_id, score });
+            } else if flagged && (threshold == 0 || score < threshold) {
+                AnomalyFlagged::<T>::remove(reporter_id);
+                Self::deposit_event(Event::ReporterAnomalyCleared { reporter_id, score });
+            }
+        }
+
+        /// Insert a history entry, evicting the reporter's oldest entry once it
+        /// holds `MaxHistoryPerReporter`. Entries since overwritten by another
+        /// reporter or time-pruned are left alone.
+        fn insert_history(
+            reporter_id: ReporterId,
+            mac_hash: H256,
+            block_number: BlockNumberFor<T>,
+            entry: SignalHistoryEntry<BlockNumberFor<T>>,
+        ) {
+            let owned = |key: &(H256, BlockNumberFor<T>)| {
+                SignalHistory::<T>::get(key.0, key.1)
+                    .is_some_and(|e| e.reading.reporter_id == reporter_id)
+            };
+            let key = (mac_hash, block_number);
+            let rewrite = owned(&key);
+            SignalHistory::<T>::insert(mac_hash, block_number, entry);
+            if rewrite {
+                return;
+            }
+
+            let mut keys = ReporterHistory::<T>::get(reporter_id);
+            if keys.is_full() {
+                let oldest = keys.remove(0);
+                if owned(&oldest) {
+                    SignalHistory::<T>::remove(oldest.0, oldest.1);
+                }
+            }
+            let _ = keys.try_push(key);
+            ReporterHistory::<T>::insert(reporter_id, keys);
+        }
+
+        /// Fold a reading into an already tracked device, promoting it to
+        /// `Active` once it has enough readings.
+        fn apply_reading(
+            d: &mut TrackedDevice<BlockNumberFor<T>>,
+            reporter_position: &Position,
+            rssi: i8,
+            block_number: BlockNumberFor<T>,
+            confidence_cap: u8,
+        ) {
+            let mac_hash = d.mac_hash;
+            let old_state = d.state;
+
+            d.last_seen = block_number;
+            d.reading_count = d.reading_count.saturating_add(1);
+            d.consecutive_misses = 0;
+
+            let new_position =
+                Self::calculate_position(reporter_position, &d.estimated_position, rssi);
+            d.estimated_position = new_position.clone();
+
+            d.confidence = d.confidence.saturating_add(5).min(confidence_cap);
+
+            if d.reading_count >= T::MinReadingsForActive::get() {
+                // Suspicious devices stay put until explicitly cleared.
+                d.state =
+                    Self::transition_device_state(d.state, DeviceState::Active).unwrap_or(d.state);
+            }
+
+            if d.state == DeviceState::Active
+                && d.confidence >= T::PositionConfirmationThreshold::get()
+            {
+                T::PositionConfirmed::on_position_confirmed(
+                    mac_hash,
+                    new_position.x,
+                    new_position.y,
+                    new_position.z,
+                    d.confidence,
+                );
+            }
+
+            if old_state != d.state {
+                Self::deposit_event(Event::DeviceStateChanged {
+                    mac_hash,
+                    old_state,
+                    new_state: d.state,
+                });
+            }
+
+            if old_state != d.state
+                && matches!(
+                    old_state,
+                    DeviceState::Lost | DeviceState::Unverifiable | DeviceState::Offline
+                )
+            {
+                if GhostEvents::<T>::take(mac_hash).is_some() {
+                    GhostCount::<T>::mutate(|c| *c = c.saturating_sub(1));
+                }
+
+                Self::deposit_event(Event::DeviceRecovered {
+                    mac_hash,
+                    new_position,
+                });
+            }
+        }
+
+        /// Validate a device state change against [`DeviceState::can_transition_to`].
+        /// Every site that mutates a tracked device's state goes through here.
+        pub fn transition_device_state(
+            old: DeviceState,
+            new: DeviceState,
+        ) -> Result<DeviceState, Error<T>> {
+            ensure!(
+                old.can_transition_to(&new),
+                Error::<T>::IllegalStateTransition
+            );
+            Ok(new)
+        }
+
         fn detect_ghosts(current_block: BlockNumberFor<T>) {
             const MAX_GHOST_DETECTION_PER_BLOCK: u32 = 200;
             let inactive_timeout = T::InactiveTimeoutBlocks::get();
@@ -888,7 +1671,23 @@ pub mod pallet {
                 let old_state = device.state;
 
                 if blocks_since >= lost_timeout {
-                    Self::handle_lost_device(mac_hash, &mut device, old_state, current_block);
+                    Self::handle_lost_device(
+                        mac_hash,
+                        &mut device,
+                        old_state,
+                        blocks_since,
+                        current_block,
+                    );
+                } else if blocks_since >= inactive_timeout
+                    && device.state.is_reachable()
+                    && Self::vanished_with_strong_signal(mac_hash, device.last_seen)
+                {
+                    Self::handle_suspicious_disappearance(
+                        mac_hash,
+                        &mut device,
+                        old_state,
+                        current_block,
+                    );
                 } else if blocks_since >= inactive_timeout
                     && matches!(device.state, DeviceState::Active)
                 {
@@ -897,16 +1696,40 @@ pub mod pallet {
             }
         }
 
+        /// Whether at least `GhostWitnessQuorum` still-active reporters had seen the
+        /// device, so that all of them missing it confirms the disappearance.
+        fn disappearance_witnessed(mac_hash: H256) -> bool {
+            let quorum = T::GhostWitnessQuorum::get().min(T::MinReportersForTrust::get());
+            if quorum == 0 {
+                return true;
+            }
+            let witnesses = DeviceReporters::<T>::iter_key_prefix(mac_hash)
+                .filter(|reporter_id| Reporters::<T>::get(reporter_id).is_some_and(|r| r.active))
+                .take(quorum as usize)
+                .count() as u32;
+            witnesses >= quorum
+        }
+
         fn handle_lost_device(
             mac_hash: H256,
             device: &mut TrackedDevice<BlockNumberFor<T>>,
             old_state: DeviceState,
+            blocks_since: BlockNumberFor<T>,
             current_block: BlockNumberFor<T>,
         ) {
-            if matches!(device.state, DeviceState::Lost) {
+            // `last_seen` moves with every reporter's reading, so the device is already
+            // unseen by all of them; the quorum guards against a lone witness's blind spot
+            // until `UnwitnessedLostTimeoutBlocks` rules that out too.
+            if old_state == DeviceState::Lost
+                || (blocks_since < T::UnwitnessedLostTimeoutBlocks::get()
+                    && !Self::disappearance_witnessed(mac_hash))
+            {
                 return;
             }
-            device.state = DeviceState::Lost;
+            let Ok(new_state) = Self::transition_device_state(old_state, DeviceState::Lost) else {
+                return;
+            };
+            device.state = new_state;
             device.consecutive_misses = device.consecutive_misses.saturating_add(1);
             let ghost = GhostEvent {
                 mac_hash,
@@ -930,17 +1753,87 @@ pub mod pallet {
             TrackedDevices::<T>::insert(mac_hash, device.clone());
         }
 
-        fn handle_inactive_device(
+        /// Average RSSI of the device's readings within `InactiveTimeoutBlocks` of
+        /// its last sighting, if any were retained.
+        pub fn recent_average_rssi(mac_hash: H256, last_seen: BlockNumberFor<T>) -> Option<i8> {
+            let window_start = last_seen.saturating_sub(T::InactiveTimeoutBlocks::get());
+            let (sum, count) = SignalHistory::<T>::iter_prefix(mac_hash)
+                .filter(|(block, _)| *block >= window_start)
+                .take(T::MaxReadingsPerDevice::get() as usize)
+                .fold((0i64, 0i64), |(sum, count), (_, entry)| {
+                    (
+                        sum.saturating_add(entry.reading.rssi as i64),
+                        count.saturating_add(1),
+                    )
+                });
+            if count == 0 {
+                return None;
+            }
+            Some((sum / count) as i8)
+        }
+
+        fn vanished_with_strong_signal(mac_hash: H256, last_seen: BlockNumberFor<T>) -> bool {
+            Self::recent_average_rssi(mac_hash, last_seen)
+                .is_some_and(|rssi| rssi >= T::StrongSignalRssi::get())
+        }
+
+        /// A device that drops out while its signal was strong is more likely shielded
+        /// or spoofed than out of range, so it skips the Sleeping/Lost path.
+        fn handle_suspicious_disappearance(
             mac_hash: H256,
             device: &mut TrackedDevice<BlockNumberFor<T>>,
             old_state: DeviceState,
+            current_block: BlockNumberFor<T>,
         ) {
+            let Ok(new_state) = Self::transition_device_state(old_state, DeviceState::Suspicious)
+            else {
+                return;
+            };
+            let average_rssi =
+                Self::recent_average_rssi(mac_hash, device.last_seen).unwrap_or_default();
+            device.state = new_state;
             device.consecutive_misses = device.consecutive_misses.saturating_add(1);
-            device.state = if device.consecutive_misses >= 3 {
+            let ghost = GhostEvent {
+                mac_hash,
+                last_position: device.estimated_position.clone(),
+                last_seen: device.last_seen,
+                disappeared_at: current_block,
+                previous_state: old_state,
+            };
+            GhostEvents::<T>::insert(mac_hash, ghost);
+            GhostCount::<T>::mutate(|c| *c = c.saturating_add(1));
+            Self::deposit_event(Event::StrongSignalVanished {
+                mac_hash,
+                average_rssi,
+            });
+            Self::deposit_event(Event::GhostDetected {
+                mac_hash,
+                last_position: device.estimated_position.clone(),
+                last_seen: device.last_seen,
+            });
+            Self::deposit_event(Event::DeviceStateChanged {
+                mac_hash,
+                old_state,
+                new_state: DeviceState::Suspicious,
+            });
+            TrackedDevices::<T>::insert(mac_hash, device.clone());
+        }
+
+        fn handle_inactive_device(
+            mac_hash: H256,
+            device: &mut TrackedDevice<BlockNumberFor<T>>,
+            old_state: DeviceState,
+        ) {
+            let target = if device.consecutive_misses.saturating_add(1) >= 3 {
                 DeviceState::Unverifiable
             } else {
                 DeviceState::Sleeping
             };
+            let Ok(new_state) = Self::transition_device_state(old_state, target) else {
+                return;
+            };
+            device.consecutive_misses = device.consecutive_misses.saturating_add(1);
+            device.state = new_state;
             device.confidence = device.confidence.saturating_sub(10);
             Self::deposit_event(Event::DeviceStateChanged {
                 mac_hash,
@@ -984,6 +1877,275 @@ pub mod pallet {
             }
         }
 
+        /// Mark a fraud case slashed or dismissed; a guilty verdict deactivates the
+        /// reporter and zeroes its reputation.
+        #[allow(clippy::excessive_nesting)]
+        fn settle_fraud_case(
+            reporter_id: ReporterId,
+            case: &mut FraudCase<BlockNumberFor<T>>,
+            guilty: bool,
+            block_number: BlockNumberFor<T>,
+        ) {
+            case.resolved_at = Some(block_number);
+
+            if guilty {
+                // Slash the reporter (deactivate them)
+                Reporters::<T>::mutate(reporter_id, |r| {
+                    if let Some(reporter) = r {
+                        reporter.active = false;
+                        reporter.reputation = 0;
+                    }
+                });
+
+                case.status = FraudCaseStatus::Slashed;
+
+                Self::deposit_event(Event::ReporterSlashed { reporter_id });
+            } else {
+                case.status = FraudCaseStatus::Dismissed;
+
+                Self::deposit_event(Event::FraudCaseDismissed { reporter_id });
+            }
+        }
+
+        /// Sweep fraud cases, resuming from `FraudSweepCursor` and reading at most
+        /// 50 per invocation. Pending cases older than `FraudResolutionTimeout` are
+        /// settled: dismissed, or upheld when `AutoUpholdStrongEvidence` is set and
+        /// the proof is strong. Resolved cases older than `FraudCaseRetentionBlocks`
+        /// are removed. Returns the weight used.
+        fn sweep_fraud_cases(current_block: BlockNumberFor<T>) -> Weight {
+            const MAX_FRAUD_SCAN_PER_BLOCK: usize = 50;
+            let timeout = T::FraudResolutionTimeout::get();
+            let retention = T::FraudCaseRetentionBlocks::get();
+
+            let mut iter = match FraudSweepCursor::<T>::get() {
+                Some(key) => FraudCases::<T>::iter_from(key.into_inner()),
+                None => FraudCases::<T>::iter(),
+            };
+            let scanned: Vec<(ReporterId, FraudCase<BlockNumberFor<T>>)> =
+                iter.by_ref().take(MAX_FRAUD_SCAN_PER_BLOCK).collect();
+            if scanned.len() < MAX_FRAUD_SCAN_PER_BLOCK {
+                FraudSweepCursor::<T>::kill();
+            } else {
+                FraudSweepCursor::<T>::put(BoundedVec::truncate_from(iter.last_raw_key().to_vec()));
+            }
+
+            let mut writes: u64 = 1;
+            for (reporter_id, mut case) in scanned.iter().cloned() {
+                let timed_out = !timeout.is_zero()
+                    && case.status == FraudCaseStatus::Pending
+                    && current_block.saturating_sub(case.submitted_at) >= timeout;
+                if timed_out {
+                    let uphold = T::AutoUpholdStrongEvidence::get() && case.proof.is_strong();
+                    Self::settle_fraud_case(reporter_id, &mut case, uphold, current_block);
+                    FraudCases::<T>::insert(reporter_id, case);
+                    writes = writes.saturating_add(2);
+                    continue;
+                }
+
+                let expired = case
+                    .resolved_at
+                    .is_some_and(|at| current_block.saturating_sub(at) >= retention);
+                if expired {
+                    FraudCases::<T>::remove(reporter_id);
+                    Self::deposit_event(Event::FraudCasePruned { reporter_id });
+                    writes = writes.saturating_add(1);
+                }
+            }
+
+            T::DbWeight::get().reads_writes((scanned.len() as u64).saturating_add(1), writes)
+        }
+
+        /// Purge ghosts older than `GhostRetentionBlocks`, resuming from
+        /// `GhostPurgeCursor` and reading at most 50 per invocation. A purged ghost
+        /// whose device is still `Lost` takes the device with it. Returns the
+        /// weight used.
+        fn purge_stale_ghosts(current_block: BlockNumberFor<T>) -> Weight {
+            const MAX_GHOST_SCAN_PER_BLOCK: usize = 50;
+            let retention = T::GhostRetentionBlocks::get();
+
+            let mut iter = match GhostPurgeCursor::<T>::get() {
+                Some(key) => GhostEvents::<T>::iter_from(key.into_inner()),
+                None => GhostEvents::<T>::iter(),
+            };
+            let scanned: Vec<(H256, GhostEvent<BlockNumberFor<T>>)> =
+                iter.by_ref().take(MAX_GHOST_SCAN_PER_BLOCK).collect();
+            if scanned.len() < MAX_GHOST_SCAN_PER_BLOCK {
+                GhostPurgeCursor::<T>::kill();
+            } else {
+                GhostPurgeCursor::<T>::put(BoundedVec::truncate_from(iter.last_raw_key().to_vec()));
+            }
+
+            let mut reads = (scanned.len() as u64).saturating_add(1);
+            let mut writes: u64 = 1;
+            let stale = scanned.into_iter().filter(|(_, ghost)| {
+                current_block.saturating_sub(ghost.disappeared_at) >= retention
+            });
+            for (mac_hash, _) in stale {
+                GhostEvents::<T>::remove(mac_hash);
+                GhostCount::<T>::mutate(|c| *c = c.saturating_sub(1));
+                reads = reads.saturating_add(2);
+                writes = writes.saturating_add(2);
+
+                let device_removed = TrackedDevices::<T>::get(mac_hash)
+                    .is_some_and(|device| device.state == DeviceState::Lost);
+                if device_removed {
+                    let reporters = DeviceReporterCount::<T>::take(mac_hash);
+                    TrackedDevices::<T>::remove(mac_hash);
+                    let _ = DeviceReporters::<T>::clear_prefix(mac_hash, u32::MAX, None);
+                    DeviceCount::<T>::mutate(|c| *c = c.saturating_sub(1));
+                    reads = reads.saturating_add(2);
+                    writes = writes.saturating_add(u64::from(reporters).saturating_add(4));
+                }
+
+                Self::deposit_event(Event::GhostPurged {
+                    mac_hash,
+                    device_removed,
+                });
+            }
+
+            T::DbWeight::get().reads_writes(reads, writes)
+        }
+
+        /// Leaf committed to in `DeviceSetRoot` for a device snapshot.
+        pub fn device_set_leaf(
+            mac_hash: H256,
+            position: &Position,
+            last_seen: BlockNumberFor<T>,
+        ) -> H256 {
+            hash_with_domain(DOMAIN_DEVICE_SET, &(mac_hash, position, last_seen).encode())
+        }
+
+        /// Rebuild `DeviceSetRoot` from at most `MaxDeviceSetLeaves` tracked devices,
+        /// returning the weight consumed.
+        ///
+        /// Only the first `MaxDeviceSetLeaves` devices in `TrackedDevices` hash order
+        /// are committed; the emitted `leaf_count` falls short of `DeviceCount` when
+        /// more devices are tracked.
+        pub fn update_device_set_root() -> Weight {
+            let mut leaves: Vec<(H256, H256)> = TrackedDevices::<T>::iter()
+                .take(T::MaxDeviceSetLeaves::get() as usize)
+                .map(|(mac_hash, device)| {
+                    let leaf = Self::device_set_leaf(
+                        mac_hash,
+                        &device.estimated_position,
+                        device.last_seen,
+                    );
+                    (mac_hash, leaf)
+                })
+                .collect();
+            leaves.sort_by_key(|(mac_hash, _)| *mac_hash);
+
+            let hashes: Vec<H256> = leaves.iter().map(|(_, leaf)| *leaf).collect();
+            let root = BinaryMerkleTree::new(&hashes).state_root();
+            let leaf_count = hashes.len() as u32;
+
+            DeviceSetRoot::<T>::put(root);
+            DeviceSetLeaves::<T>::put(BoundedVec::truncate_from(leaves));
+
+            Self::deposit_event(Event::DeviceSetRootUpdated { root, leaf_count });
+
+            T::DbWeight::get().reads_writes(u64::from(leaf_count), 2)
+        }
+
+        /// Inclusion proof for a device against the current `DeviceSetRoot`, or `None`
+        /// if the device was not part of the last snapshot.
+        pub fn device_inclusion_proof(mac_hash: H256) -> Option<MerkleProof> {
+            let leaves = DeviceSetLeaves::<T>::get();
+            let index = leaves
+                .binary_search_by_key(&mac_hash, |(mac, _)| *mac)
+                .ok()?;
+            let hashes: Vec<H256> = leaves.iter().map(|(_, leaf)| *leaf).collect();
+            BinaryMerkleTree::new(&hashes).prove(index)
+        }
+
+        /// RSSI the reporter should observe for the device at its estimated
+        /// position under the configured path-loss model; the reference value
+        /// for fraud proofs.
+        pub fn expected_rssi(reporter_id: ReporterId, mac_hash: H256) -> Option<i8> {
+            let reporter = Reporters::<T>::get(reporter_id)?;
+            let device = TrackedDevices::<T>::get(mac_hash)?;
+            Some(distance_squared_cm_to_rssi(
+                reporter
+                    .position
+                    .distance_squared(&device.estimated_position),
+                T::ReferenceTxPower::get(),
+                T::PathLossExponentX100::get(),
+            ))
+        }
+
+        /// Whether `submitter` is close enough to `accused` to credibly dispute its
+        /// readings: same region when both are tagged, otherwise within
+        /// `MaxAccusationDistanceCm`.
+        fn can_accuse(
+            submitter: &Reporter<BlockNumberFor<T>>,
+            accused: &Reporter<BlockNumberFor<T>>,
+        ) -> bool {
+            if let (Some(a), Some(b)) = (submitter.region, accused.region) {
+                return a == b;
+            }
+            let max_cm = T::MaxAccusationDistanceCm::get() as u128;
+            max_cm == 0
+                || submitter.position.distance_squared(&accused.position)
+                    <= max_cm.saturating_mul(max_cm)
+        }
+
+        /// Active reporters registered in `region`.
+        pub fn reporters_in_region(region: u16) -> Vec<ReporterId> {
+            Reporters::<T>::iter_values()
+                .filter(|r| r.active && r.region == Some(region))
+                .map(|r| r.id)
+                .collect()
+        }
+
+        /// Multilateration over the device's recent readings, restricted to
+        /// reporters in the same region as the reporter of its last sighting.
+        /// Uses the latest reading per reporter within `InactiveTimeoutBlocks`.
+        pub fn region_scoped_position(mac_hash: H256) -> Option<Position> {
+            let device = TrackedDevices::<T>::get(mac_hash)?;
+            let last_sighting = SignalHistory::<T>::get(mac_hash, device.last_seen)?;
+            let region = Reporters::<T>::get(last_sighting.reading.reporter_id)?.region;
+            let window_start = device
+                .last_seen
+                .saturating_sub(T::InactiveTimeoutBlocks::get());
+
+            let mut entries: Vec<(BlockNumberFor<T>, SignalHistoryEntry<BlockNumberFor<T>>)> =
+                SignalHistory::<T>::iter_prefix(mac_hash)
+                    .filter(|(block, _)| *block >= window_start)
+                    .take(T::MaxReadingsPerDevice::get() as usize)
+                    .filter(|(_, entry)| {
+                        Reporters::<T>::get(entry.reading.reporter_id)
+                            .is_some_and(|r| r.region == region)
+                    })
+                    .collect();
+            entries.sort_by_key(|(block, _)| core::cmp::Reverse(*block));
+
+            let mut seen: Vec<ReporterId> = Vec::new();
+            let mut observations = Vec::new();
+            for (block, entry) in entries {
+                if seen.contains(&entry.reading.reporter_id) {
+                    continue;
+                }
+                seen.push(entry.reading.reporter_id);
+                observations.push(SignalObservation {
+                    observer_position: entry.position_at_time.to_fusion(),
+                    rssi: entry.reading.rssi,
+                    frequency_mhz: Some(entry.reading.frequency),
+                    timestamp: block.try_into().unwrap_or(0u64),
+                });
+            }
+
+            let config = TriangulationConfig {
+                tx_power: T::ReferenceTxPower::get(),
+                path_loss_exponent_x100: T::PathLossExponentX100::get(),
+                ..Default::default()
+            };
+            multilateration(&observations, &config).map(|t| Position {
+                x: t.position.x as i64,
+                y: t.position.y as i64,
+                z: t.position.z as i64,
+            })
+        }
+
         pub fn get_device_history(
             mac_hash: H256,
         ) -> Vec<(BlockNumberFor<T>, SignalHistoryEntry<BlockNumberFor<T>>)> {
@@ -994,6 +2156,18 @@ pub mod pallet {
             TrackedDevices::<T>::get(mac_hash).map(|d| d.estimated_position)
         }
 
+        /// Estimated position of a device, if its confidence is at least `min_confidence`.
+        pub fn get_confident_position(mac_hash: H256, min_confidence: u8) -> Option<Position> {
+            TrackedDevices::<T>::get(mac_hash)
+                .filter(|d| d.confidence >= min_confidence)
+                .map(|d| d.estimated_position)
+        }
+
+        /// `get_confident_position` at `MinAuthoritativeConfidence`.
+        pub fn get_authoritative_position(mac_hash: H256) -> Option<Position> {
+            Self::get_confident_position(mac_hash, T::MinAuthoritativeConfidence::get())
+        }
+
         pub fn get_device_state(mac_hash: H256) -> Option<DeviceState> {
             TrackedDevices::<T>::get(mac_hash).map(|d| d.state)
         }
@@ -1,13 +1,18 @@
 #![allow(clippy::disallowed_macros)]
 
 use crate::{self as pallet_triangulation, DeviceState, Error, Position, ReporterId, SignalType};
-use frame_support::{assert_noop, assert_ok, derive_impl, parameter_types, traits::ConstU32};
+use frame_support::{
+    assert_noop, assert_ok, derive_impl, parameter_types,
+    traits::{ConstU32, Hooks},
+    BoundedVec,
+};
 use frame_system as system;
 use sp_core::H256;
 use sp_runtime::{
     traits::{BlakeTwo256, IdentityLookup},
-    BuildStorage,
+    BuildStorage, DispatchResult,
 };
+use std::cell::RefCell;
 
 type Block = frame_system::mocking::MockBlock<Test>;
 
@@ -52,6 +57,51 @@ parameter_types! {
     pub const LostTimeoutBlocks: u64 = 100;
     pub const MinReadingsForActive: u32 = 3;
     pub const SignalRetentionBlocks: u64 = 1000;
+    pub const MaxReporterMovementCm: u64 = 1000;
+    pub const StrongSignalRssi: i8 = -60;
+    pub const FraudCaseRetentionBlocks: u64 = 100;
+    pub static FraudResolutionTimeout: u64 = 0;
+    pub static AutoUpholdStrongEvidence: bool = false;
+    pub const InitialReporterReputation: u8 = 50;
+    pub const DeviceRootInterval: u64 = 10;
+    pub const MaxDeviceSetLeaves: u32 = 64;
+    pub const ReferenceTxPower: i8 = -59;
+    pub const PathLossExponentX100: u16 = 270;
+    pub const MaxBatchReadings: u32 = 10;
+    pub const GhostRetentionBlocks: u64 = 500;
+    pub static MaxHistoryPerReporter: u32 = 1000;
+    pub static AnomalyThreshold: u32 = 0;
+    pub static MinReportersForTrust: u32 = 1;
+    pub static SingleReporterConfidenceCap: u8 = 100;
+    pub static PositionConfirmationThreshold: u8 = 80;
+    pub static GhostWitnessQuorum: u32 = 0;
+    pub const UnwitnessedLostTimeoutBlocks: u64 = 300;
+    pub static MaxReadingsPerReporterPerBlock: u32 = 0;
+    pub const MinAuthoritativeConfidence: u8 = 60;
+    pub static AllowSignalTypeMigration: bool = false;
+    pub static MaxAccusationDistanceCm: u64 = 0;
+    pub static ReputationGainWindow: u64 = 0;
+    pub const MaxReputationGainPerWindow: u8 = 5;
+}
+
+thread_local! {
+    static CONFIRMED_POSITIONS: RefCell<Vec<(H256, Position, u8)>> =
+        const { RefCell::new(Vec::new()) };
+}
+
+/// Records every confirmation the pallet reports.
+pub struct MockPositionSink;
+impl seveny_primitives::traits::OnPositionConfirmed for MockPositionSink {
+    fn on_position_confirmed(device: H256, x: i64, y: i64, z: i64, confidence: u8) {
+        CONFIRMED_POSITIONS.with(|c| {
+            c.borrow_mut()
+                .push((device, Position { x, y, z }, confidence))
+        });
+    }
+}
+
+fn confirmed_positions() -> Vec<(H256, Position, u8)> {
+    CONFIRMED_POSITIONS.with(|c| c.borrow().clone())
 }
 
 impl pallet_triangulation::Config for Test {
@@ -63,6 +113,32 @@ impl pallet_triangulation::Config for Test {
     type LostTimeoutBlocks = LostTimeoutBlocks;
     type MinReadingsForActive = MinReadingsForActive;
     type SignalRetentionBlocks = SignalRetentionBlocks;
+    type MaxReporterMovementCm = MaxReporterMovementCm;
+    type StrongSignalRssi = StrongSignalRssi;
+    type FraudCaseRetentionBlocks = FraudCaseRetentionBlocks;
+    type FraudResolutionTimeout = FraudResolutionTimeout;
+    type AutoUpholdStrongEvidence = AutoUpholdStrongEvidence;
+    type InitialReporterReputation = InitialReporterReputation;
+    type DeviceRootInterval = DeviceRootInterval;
+    type MaxDeviceSetLeaves = MaxDeviceSetLeaves;
+    type ReferenceTxPower = ReferenceTxPower;
+    type PathLossExponentX100 = PathLossExponentX100;
+    type MaxBatchReadings = MaxBatchReadings;
+    type GhostRetentionBlocks = GhostRetentionBlocks;
+    type MaxHistoryPerReporter = MaxHistoryPerReporter;
+    type AnomalyThreshold = AnomalyThreshold;
+    type MinReportersForTrust = MinReportersForTrust;
+    type SingleReporterConfidenceCap = SingleReporterConfidenceCap;
+    type PositionConfirmed = MockPositionSink;
+    type PositionConfirmationThreshold = PositionConfirmationThreshold;
+    type GhostWitnessQuorum = GhostWitnessQuorum;
+    type UnwitnessedLostTimeoutBlocks = UnwitnessedLostTimeoutBlocks;
+    type MaxReadingsPerReporterPerBlock = MaxReadingsPerReporterPerBlock;
+    type MinAuthoritativeConfidence = MinAuthoritativeConfidence;
+    type AllowSignalTypeMigration = AllowSignalTypeMigration;
+    type MaxAccusationDistanceCm = MaxAccusationDistanceCm;
+    type ReputationGainWindow = ReputationGainWindow;
+    type MaxReputationGainPerWindow = MaxReputationGainPerWindow;
 }
 
 fn new_test_ext() -> sp_io::TestExternalities {
@@ -92,7 +168,8 @@ fn register_reporter_success() {
 
         assert_ok!(Triangulation::register_reporter(
             RuntimeOrigin::signed(1),
-            position.clone()
+            position.clone(),
+            None
         ));
 
         let reporter = Triangulation::reporters(ReporterId::new(0)).expect("reporter should exist");
@@ -113,7 +190,8 @@ fn register_reporter_sets_owner() {
 
         assert_ok!(Triangulation::register_reporter(
             RuntimeOrigin::signed(1),
-            position
+            position,
+            None
         ));
 
         let reporter_id = ReporterId::new(0);
@@ -133,7 +211,8 @@ fn non_owner_cannot_deregister_reporter() {
 
         assert_ok!(Triangulation::register_reporter(
             RuntimeOrigin::signed(1),
-            position
+            position,
+            None
         ));
 
         assert_noop!(
@@ -154,7 +233,8 @@ fn deregister_reporter_success() {
 
         assert_ok!(Triangulation::register_reporter(
             RuntimeOrigin::signed(1),
-            position
+            position,
+            None
         ));
 
         assert_ok!(Triangulation::deregister_reporter(
@@ -179,7 +259,8 @@ fn report_signal_creates_device() {
 
         assert_ok!(Triangulation::register_reporter(
             RuntimeOrigin::signed(1),
-            position
+            position,
+            None
         ));
 
         assert_ok!(Triangulation::report_signal(
@@ -210,7 +291,8 @@ fn invalid_rssi_rejected() {
 
         assert_ok!(Triangulation::register_reporter(
             RuntimeOrigin::signed(1),
-            position
+            position,
+            None
         ));
 
         assert_noop!(
@@ -239,7 +321,8 @@ fn inactive_reporter_cannot_report() {
 
         assert_ok!(Triangulation::register_reporter(
             RuntimeOrigin::signed(1),
-            position
+            position,
+            None
         ));
 
         assert_ok!(Triangulation::deregister_reporter(
@@ -273,7 +356,8 @@ fn signal_history_stored() {
 
         assert_ok!(Triangulation::register_reporter(
             RuntimeOrigin::signed(1),
-            position
+            position,
+            None
         ));
 
         assert_ok!(Triangulation::report_signal(
@@ -306,9 +390,37 @@ fn update_reporter_position_success() {
 
         assert_ok!(Triangulation::register_reporter(
             RuntimeOrigin::signed(1),
-            position
+            position,
+            None
+        ));
+
+        assert_ok!(Triangulation::update_reporter_position(
+            RuntimeOrigin::signed(1),
+            ReporterId::new(0),
+            new_position.clone()
+        ));
+
+        let reporter = Triangulation::reporters(ReporterId::new(0)).expect("reporter should exist");
+        assert_eq!(reporter.position, new_position);
+    });
+}
+
+#[test]
+fn plausible_reporter_movement_accepted_over_time() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(Triangulation::register_reporter(
+            RuntimeOrigin::signed(1),
+            Position { x: 0, y: 0, z: 0 },
+            None
         ));
 
+        // 3 blocks elapsed allows 3000cm of movement
+        System::set_block_number(4);
+        let new_position = Position {
+            x: 2500,
+            y: 0,
+            z: 0,
+        };
         assert_ok!(Triangulation::update_reporter_position(
             RuntimeOrigin::signed(1),
             ReporterId::new(0),
@@ -317,6 +429,93 @@ fn update_reporter_position_success() {
 
         let reporter = Triangulation::reporters(ReporterId::new(0)).expect("reporter should exist");
         assert_eq!(reporter.position, new_position);
+        assert_eq!(reporter.position_updated_at, 4);
+        assert_eq!(Triangulation::movement_violations(ReporterId::new(0)), 0);
+    });
+}
+
+#[test]
+fn implausible_reporter_teleport_rejected_and_flagged() {
+    new_test_ext().execute_with(|| {
+        let origin_position = Position { x: 0, y: 0, z: 0 };
+        assert_ok!(Triangulation::register_reporter(
+            RuntimeOrigin::signed(1),
+            origin_position.clone(),
+            None
+        ));
+
+        System::set_block_number(2);
+        let teleport = Position {
+            x: 1_000_000,
+            y: 0,
+            z: 0,
+        };
+        assert_ok!(Triangulation::update_reporter_position(
+            RuntimeOrigin::signed(1),
+            ReporterId::new(0),
+            teleport.clone()
+        ));
+
+        let reporter = Triangulation::reporters(ReporterId::new(0)).expect("reporter should exist");
+        assert_eq!(reporter.position, origin_position);
+        assert_eq!(reporter.position_updated_at, 1);
+        assert_eq!(Triangulation::movement_violations(ReporterId::new(0)), 1);
+
+        System::assert_last_event(RuntimeEvent::Triangulation(
+            pallet_triangulation::Event::ImplausibleMovementRejected {
+                reporter_id: ReporterId::new(0),
+                attempted_position: teleport,
+                max_allowed_cm: 1000,
+                violations: 1,
+            },
+        ));
+    });
+}
+
+#[test]
+fn same_block_moves_share_one_allowance() {
+    new_test_ext().execute_with(|| {
+        let reporter_id = ReporterId::new(0);
+        assert_ok!(Triangulation::register_reporter(
+            RuntimeOrigin::signed(1),
+            Position::default(),
+            None
+        ));
+        let at = |x: i64| Position { x, y: 0, z: 0 };
+
+        System::set_block_number(2);
+        assert_ok!(Triangulation::update_reporter_position(
+            RuntimeOrigin::signed(1),
+            reporter_id,
+            at(1000)
+        ));
+        // A second hop this block is measured from where the block started.
+        assert_ok!(Triangulation::update_reporter_position(
+            RuntimeOrigin::signed(1),
+            reporter_id,
+            at(2000)
+        ));
+        let reporter = Triangulation::reporters(reporter_id).expect("reporter should exist");
+        assert_eq!(reporter.position, at(1000));
+        assert_eq!(Triangulation::movement_violations(reporter_id), 1);
+
+        assert_ok!(Triangulation::update_reporter_position(
+            RuntimeOrigin::signed(1),
+            reporter_id,
+            at(500)
+        ));
+        let reporter = Triangulation::reporters(reporter_id).expect("reporter should exist");
+        assert_eq!(reporter.position, at(500));
+
+        System::set_block_number(3);
+        assert_ok!(Triangulation::update_reporter_position(
+            RuntimeOrigin::signed(1),
+            reporter_id,
+            at(1500)
+        ));
+        let reporter = Triangulation::reporters(reporter_id).expect("reporter should exist");
+        assert_eq!(reporter.position, at(1500));
+        assert_eq!(Triangulation::movement_violations(reporter_id), 1);
     });
 }
 
@@ -342,7 +541,8 @@ fn multiple_signals_improve_confidence() {
 
         assert_ok!(Triangulation::register_reporter(
             RuntimeOrigin::signed(1),
-            position
+            position,
+            None
         ));
 
         for _ in 0..5 {
@@ -373,7 +573,8 @@ fn all_signal_types() {
 
         assert_ok!(Triangulation::register_reporter(
             RuntimeOrigin::signed(1),
-            position
+            position,
+            None
         ));
 
         let signal_types = [
@@ -403,3 +604,1781 @@ fn all_signal_types() {
         assert_eq!(Triangulation::device_count(), 5);
     });
 }
+
+// ============================================================================
+// Ghost Classification Tests
+// ============================================================================
+
+fn report_readings(mac_hash: H256, rssi: i8, blocks: core::ops::RangeInclusive<u64>) {
+    for block in blocks {
+        System::set_block_number(block);
+        assert_ok!(Triangulation::report_signal(
+            RuntimeOrigin::signed(1),
+            ReporterId::new(0),
+            mac_hash,
+            rssi,
+            SignalType::NetworkLatency,
+            2400
+        ));
+    }
+}
+
+fn run_ghost_detection(block: u64) {
+    System::set_block_number(block);
+    Triangulation::on_initialize(block);
+}
+
+#[test]
+fn strong_signal_disappearance_flagged_suspicious() {
+    new_test_ext().execute_with(|| {
+        let strong = H256([1u8; 32]);
+        let weak = H256([2u8; 32]);
+        assert_ok!(Triangulation::register_reporter(
+            RuntimeOrigin::signed(1),
+            Position::default(),
+            None
+        ));
+
+        report_readings(strong, -40, 1..=3);
+        report_readings(weak, -90, 1..=3);
+        assert_eq!(Triangulation::recent_average_rssi(strong, 3), Some(-40));
+
+        run_ghost_detection(13);
+
+        let device = Triangulation::tracked_devices(strong).expect("device should exist");
+        assert_eq!(device.state, DeviceState::Suspicious);
+        assert!(Triangulation::ghost_events(strong).is_some());
+        System::assert_has_event(RuntimeEvent::Triangulation(
+            crate::Event::StrongSignalVanished {
+                mac_hash: strong,
+                average_rssi: -40,
+            },
+        ));
+
+        let device = Triangulation::tracked_devices(weak).expect("device should exist");
+        assert_eq!(device.state, DeviceState::Sleeping);
+        assert!(Triangulation::ghost_events(weak).is_none());
+        assert_eq!(Triangulation::ghost_count(), 1);
+    });
+}
+
+#[test]
+fn weak_signal_fade_out_goes_lost_normally() {
+    new_test_ext().execute_with(|| {
+        let strong = H256([1u8; 32]);
+        let weak = H256([2u8; 32]);
+        assert_ok!(Triangulation::register_reporter(
+            RuntimeOrigin::signed(1),
+            Position::default(),
+            None
+        ));
+
+        report_readings(strong, -40, 1..=3);
+        report_readings(weak, -90, 1..=3);
+
+        run_ghost_detection(13);
+        run_ghost_detection(103);
+
+        let device = Triangulation::tracked_devices(weak).expect("device should exist");
+        assert_eq!(device.state, DeviceState::Lost);
+        assert!(Triangulation::ghost_events(weak).is_some());
+
+        // The suspicious classification is not downgraded to Lost.
+        let device = Triangulation::tracked_devices(strong).expect("device should exist");
+        assert_eq!(device.state, DeviceState::Suspicious);
+        assert_eq!(Triangulation::ghost_count(), 2);
+    });
+}
+
+#[test]
+fn stale_ghosts_purged_after_retention() {
+    new_test_ext().execute_with(|| {
+        let strong = H256([1u8; 32]);
+        let weak = H256([2u8; 32]);
+        assert_ok!(Triangulation::register_reporter(
+            RuntimeOrigin::signed(1),
+            Position::default(),
+            None
+        ));
+
+        report_readings(strong, -40, 1..=3);
+        report_readings(weak, -90, 1..=3);
+        assert_eq!(Triangulation::device_count(), 2);
+
+        // Strong ghost raised at 13, weak (lost) ghost at 103.
+        run_ghost_detection(13);
+        run_ghost_detection(103);
+        assert_eq!(Triangulation::ghost_count(), 2);
+
+        run_ghost_detection(513);
+        System::assert_has_event(RuntimeEvent::Triangulation(crate::Event::GhostPurged {
+            mac_hash: strong,
+            device_removed: false,
+        }));
+        assert!(Triangulation::ghost_events(strong).is_none());
+        assert!(Triangulation::tracked_devices(strong).is_some());
+        assert!(Triangulation::ghost_events(weak).is_some());
+        assert_eq!(Triangulation::ghost_count(), 1);
+        assert_eq!(Triangulation::device_count(), 2);
+
+        run_ghost_detection(603);
+        System::assert_has_event(RuntimeEvent::Triangulation(crate::Event::GhostPurged {
+            mac_hash: weak,
+            device_removed: true,
+        }));
+        assert!(Triangulation::ghost_events(weak).is_none());
+        assert!(Triangulation::tracked_devices(weak).is_none());
+        assert_eq!(Triangulation::ghost_count(), 0);
+        assert_eq!(Triangulation::device_count(), 1);
+    });
+}
+
+#[test]
+fn recent_ghost_retained() {
+    new_test_ext().execute_with(|| {
+        let weak = H256([2u8; 32]);
+        assert_ok!(Triangulation::register_reporter(
+            RuntimeOrigin::signed(1),
+            Position::default(),
+            None
+        ));
+
+        report_readings(weak, -90, 1..=3);
+        run_ghost_detection(103);
+        run_ghost_detection(602);
+
+        assert!(Triangulation::ghost_events(weak).is_some());
+        assert!(Triangulation::tracked_devices(weak).is_some());
+        assert_eq!(Triangulation::ghost_count(), 1);
+        assert_eq!(Triangulation::device_count(), 1);
+    });
+}
+
+#[test]
+fn suspicious_device_recovers_only_after_clearing() {
+    new_test_ext().execute_with(|| {
+        let strong = H256([1u8; 32]);
+        assert_ok!(Triangulation::register_reporter(
+            RuntimeOrigin::signed(1),
+            Position::default(),
+            None
+        ));
+
+        report_readings(strong, -40, 1..=3);
+        run_ghost_detection(13);
+        assert_eq!(Triangulation::ghost_count(), 1);
+
+        report_readings(strong, -40, 14..=14);
+        let device = Triangulation::tracked_devices(strong).expect("device should exist");
+        assert_eq!(device.state, DeviceState::Suspicious);
+        assert_eq!(Triangulation::ghost_count(), 1);
+
+        assert_ok!(Triangulation::clear_suspicious_device(
+            RuntimeOrigin::root(),
+            strong
+        ));
+        let device = Triangulation::tracked_devices(strong).expect("device should exist");
+        assert_eq!(device.state, DeviceState::Unverifiable);
+
+        report_readings(strong, -40, 15..=15);
+
+        let device = Triangulation::tracked_devices(strong).expect("device should exist");
+        assert_eq!(device.state, DeviceState::Active);
+        assert!(Triangulation::ghost_events(strong).is_none());
+        assert_eq!(Triangulation::ghost_count(), 0);
+    });
+}
+
+fn report_weak(reporter: u64, mac_hash: H256, block: u64) {
+    System::set_block_number(block);
+    assert_ok!(Triangulation::report_signal(
+        RuntimeOrigin::signed(1),
+        ReporterId::new(reporter),
+        mac_hash,
+        -90,
+        SignalType::NetworkLatency,
+        2400
+    ));
+}
+
+fn register_two_reporters() {
+    for _ in 0..2 {
+        assert_ok!(Triangulation::register_reporter(
+            RuntimeOrigin::signed(1),
+            Position::default(),
+            None
+        ));
+    }
+}
+
+#[test]
+fn device_seen_by_another_reporter_not_ghosted() {
+    new_test_ext().execute_with(|| {
+        let roaming = H256([1u8; 32]);
+        let vanished = H256([2u8; 32]);
+        register_two_reporters();
+
+        for block in 1..=3 {
+            report_weak(0, roaming, block);
+            report_weak(1, vanished, block);
+        }
+        // Out of reporter 0's range, but reporter 1 still sees it.
+        report_weak(1, roaming, 50);
+
+        run_ghost_detection(103);
+
+        let device = Triangulation::tracked_devices(roaming).expect("device should exist");
+        assert_ne!(device.state, DeviceState::Lost);
+        assert!(Triangulation::ghost_events(roaming).is_none());
+        let device = Triangulation::tracked_devices(vanished).expect("device should exist");
+        assert_eq!(device.state, DeviceState::Lost);
+        assert_eq!(Triangulation::ghost_count(), 1);
+    });
+}
+
+#[test]
+fn ghost_requires_witness_quorum() {
+    new_test_ext().execute_with(|| {
+        MinReportersForTrust::set(2);
+        GhostWitnessQuorum::set(2);
+        let witnessed = H256([1u8; 32]);
+        let lone = H256([2u8; 32]);
+        register_two_reporters();
+
+        for block in 1..=3 {
+            report_weak(block % 2, witnessed, block);
+            report_weak(0, lone, block);
+        }
+
+        run_ghost_detection(103);
+
+        let device = Triangulation::tracked_devices(witnessed).expect("device should exist");
+        assert_eq!(device.state, DeviceState::Lost);
+        // A single reporter's blind spot is not enough to declare a ghost.
+        let device = Triangulation::tracked_devices(lone).expect("device should exist");
+        assert_ne!(device.state, DeviceState::Lost);
+        assert!(Triangulation::ghost_events(lone).is_none());
+
+        GhostWitnessQuorum::set(0);
+        MinReportersForTrust::set(1);
+    });
+}
+
+#[test]
+fn unwitnessed_device_lost_after_longer_timeout() {
+    new_test_ext().execute_with(|| {
+        MinReportersForTrust::set(2);
+        GhostWitnessQuorum::set(2);
+        let lone = H256([2u8; 32]);
+        register_two_reporters();
+        report_weak(0, lone, 1);
+
+        run_ghost_detection(1 + UnwitnessedLostTimeoutBlocks::get() - 1);
+        let device = Triangulation::tracked_devices(lone).expect("device should exist");
+        assert_ne!(device.state, DeviceState::Lost);
+
+        run_ghost_detection(1 + UnwitnessedLostTimeoutBlocks::get());
+        let device = Triangulation::tracked_devices(lone).expect("device should exist");
+        assert_eq!(device.state, DeviceState::Lost);
+        assert!(Triangulation::ghost_events(lone).is_some());
+
+        GhostWitnessQuorum::set(0);
+        MinReportersForTrust::set(1);
+    });
+}
+
+// ============================================================================
+// Fraud Case Lifecycle Tests
+// ============================================================================
+
+fn setup_fraud_reporters() {
+    assert_ok!(Triangulation::register_reporter(
+        RuntimeOrigin::signed(1),
+        Position::default(),
+        None
+    ));
+    assert_ok!(Triangulation::register_reporter(
+        RuntimeOrigin::signed(2),
+        Position::default(),
+        None
+    ));
+}
+
+fn fraud_proof_against(accused: ReporterId) -> crate::FraudProof {
+    let reading = crate::ConflictingReading {
+        device_hash: H256([9u8; 32]),
+        claimed_rssi: -20,
+        expected_rssi: -90,
+        distance_cm: 5000,
+        block_number: 1,
+    };
+    crate::FraudProof {
+        accused_reporter: accused,
+        conflicting_readings: vec![reading.clone(), reading.clone(), reading]
+            .try_into()
+            .expect("within bound"),
+        z_score_scaled: 400,
+        sample_size: 3,
+    }
+}
+
+#[test]
+fn pending_fraud_case_blocks_new_filing() {
+    new_test_ext().execute_with(|| {
+        setup_fraud_reporters();
+        let accused = ReporterId::new(1);
+
+        assert_ok!(Triangulation::submit_fraud_proof(
+            RuntimeOrigin::signed(1),
+            ReporterId::new(0),
+            fraud_proof_against(accused)
+        ));
+        assert_noop!(
+            Triangulation::submit_fraud_proof(
+                RuntimeOrigin::signed(1),
+                ReporterId::new(0),
+                fraud_proof_against(accused)
+            ),
+            Error::<Test>::FraudCaseAlreadyExists
+        );
+    });
+}
+
+#[test]
+fn dismissed_fraud_case_allows_new_filing() {
+    new_test_ext().execute_with(|| {
+        setup_fraud_reporters();
+        let accused = ReporterId::new(1);
+
+        assert_ok!(Triangulation::submit_fraud_proof(
+            RuntimeOrigin::signed(1),
+            ReporterId::new(0),
+            fraud_proof_against(accused)
+        ));
+        assert_ok!(Triangulation::resolve_fraud_case(
+            RuntimeOrigin::root(),
+            accused,
+            false
+        ));
+
+        System::set_block_number(5);
+        assert_ok!(Triangulation::submit_fraud_proof(
+            RuntimeOrigin::signed(1),
+            ReporterId::new(0),
+            fraud_proof_against(accused)
+        ));
+
+        let case = Triangulation::fraud_cases(accused).expect("case should exist");
+        assert_eq!(case.status, crate::FraudCaseStatus::Pending);
+        assert_eq!(case.submitted_at, 5);
+        assert_eq!(case.resolved_at, None);
+    });
+}
+
+#[test]
+fn resolved_fraud_cases_pruned_after_retention() {
+    new_test_ext().execute_with(|| {
+        setup_fraud_reporters();
+        let accused = ReporterId::new(1);
+
+        assert_ok!(Triangulation::submit_fraud_proof(
+            RuntimeOrigin::signed(1),
+            ReporterId::new(0),
+            fraud_proof_against(accused)
+        ));
+        assert_ok!(Triangulation::submit_fraud_proof(
+            RuntimeOrigin::signed(2),
+            ReporterId::new(1),
+            fraud_proof_against(ReporterId::new(0))
+        ));
+
+        System::set_block_number(10);
+        assert_ok!(Triangulation::resolve_fraud_case(
+            RuntimeOrigin::root(),
+            accused,
+            true
+        ));
+
+        Triangulation::on_initialize(109);
+        assert!(Triangulation::fraud_cases(accused).is_some());
+
+        Triangulation::on_initialize(110);
+        assert!(Triangulation::fraud_cases(accused).is_none());
+        System::assert_has_event(RuntimeEvent::Triangulation(crate::Event::FraudCasePruned {
+            reporter_id: accused,
+        }));
+
+        // Pending cases are never pruned.
+        assert!(Triangulation::fraud_cases(ReporterId::new(0)).is_some());
+    });
+}
+
+#[test]
+fn ghost_purge_resumes_across_blocks() {
+    new_test_ext().execute_with(|| {
+        let ghosts = 60u8;
+        for i in 0..ghosts {
+            let mac_hash = H256([i; 32]);
+            crate::GhostEvents::<Test>::insert(
+                mac_hash,
+                crate::GhostEvent {
+                    mac_hash,
+                    last_position: Position::default(),
+                    last_seen: 1,
+                    disappeared_at: 1,
+                    previous_state: DeviceState::Active,
+                },
+            );
+        }
+        crate::GhostCount::<Test>::put(u32::from(ghosts));
+
+        // Each block scans a bounded slice of the ghosts.
+        run_ghost_detection(1 + GhostRetentionBlocks::get());
+        assert_eq!(Triangulation::ghost_count(), 10);
+        assert!(crate::GhostPurgeCursor::<Test>::get().is_some());
+
+        run_ghost_detection(2 + GhostRetentionBlocks::get());
+        assert_eq!(Triangulation::ghost_count(), 0);
+        assert!(crate::GhostPurgeCursor::<Test>::get().is_none());
+    });
+}
+
+#[test]
+fn fraud_case_sweep_resumes_across_blocks() {
+    new_test_ext().execute_with(|| {
+        let cases = 51u64;
+        for _ in 0..=cases {
+            assert_ok!(Triangulation::register_reporter(
+                RuntimeOrigin::signed(1),
+                Position::default(),
+                None
+            ));
+        }
+        for accused in 1..=cases {
+            assert_ok!(Triangulation::submit_fraud_proof(
+                RuntimeOrigin::signed(1),
+                ReporterId::new(0),
+                fraud_proof_against(ReporterId::new(accused))
+            ));
+            assert_ok!(Triangulation::resolve_fraud_case(
+                RuntimeOrigin::root(),
+                ReporterId::new(accused),
+                false
+            ));
+        }
+        let remaining = || crate::FraudCases::<Test>::iter().count();
+
+        // Each block scans a bounded slice of the cases.
+        Triangulation::on_initialize(1 + FraudCaseRetentionBlocks::get());
+        assert_eq!(remaining(), 1);
+        assert!(crate::FraudSweepCursor::<Test>::get().is_some());
+
+        Triangulation::on_initialize(2 + FraudCaseRetentionBlocks::get());
+        assert_eq!(remaining(), 0);
+        assert!(crate::FraudSweepCursor::<Test>::get().is_none());
+    });
+}
+
+#[test]
+fn pending_fraud_expiry_resumes_across_blocks() {
+    FraudResolutionTimeout::set(20);
+    new_test_ext().execute_with(|| {
+        let cases = 51u64;
+        for _ in 0..=cases {
+            assert_ok!(Triangulation::register_reporter(
+                RuntimeOrigin::signed(1),
+                Position::default(),
+                None
+            ));
+        }
+        for accused in 1..=cases {
+            assert_ok!(Triangulation::submit_fraud_proof(
+                RuntimeOrigin::signed(1),
+                ReporterId::new(0),
+                fraud_proof_against(ReporterId::new(accused))
+            ));
+        }
+        let pending = || {
+            crate::FraudCases::<Test>::iter()
+                .filter(|(_, case)| case.status == crate::FraudCaseStatus::Pending)
+                .count()
+        };
+
+        Triangulation::on_initialize(21);
+        assert_eq!(pending(), 1);
+
+        Triangulation::on_initialize(22);
+        assert_eq!(pending(), 0);
+    });
+    FraudResolutionTimeout::set(0);
+}
+
+#[test]
+fn pending_fraud_case_auto_dismissed_after_timeout() {
+    FraudResolutionTimeout::set(20);
+    new_test_ext().execute_with(|| {
+        setup_fraud_reporters();
+        let accused = ReporterId::new(1);
+        assert_ok!(Triangulation::submit_fraud_proof(
+            RuntimeOrigin::signed(1),
+            ReporterId::new(0),
+            fraud_proof_against(accused)
+        ));
+
+        Triangulation::on_initialize(20);
+        let case = Triangulation::fraud_cases(accused).expect("case should exist");
+        assert_eq!(case.status, crate::FraudCaseStatus::Pending);
+
+        Triangulation::on_initialize(21);
+        let case = Triangulation::fraud_cases(accused).expect("case should exist");
+        assert_eq!(case.status, crate::FraudCaseStatus::Dismissed);
+        assert_eq!(case.resolved_at, Some(21));
+        System::assert_has_event(RuntimeEvent::Triangulation(
+            crate::Event::FraudCaseDismissed {
+                reporter_id: accused,
+            },
+        ));
+        let reporter = Triangulation::reporters(accused).expect("reporter should exist");
+        assert!(reporter.active);
+    });
+    FraudResolutionTimeout::set(0);
+}
+
+#[test]
+fn fraud_case_resolved_before_timeout_is_left_alone() {
+    FraudResolutionTimeout::set(20);
+    new_test_ext().execute_with(|| {
+        setup_fraud_reporters();
+        let accused = ReporterId::new(1);
+        assert_ok!(Triangulation::submit_fraud_proof(
+            RuntimeOrigin::signed(1),
+            ReporterId::new(0),
+            fraud_proof_against(accused)
+        ));
+
+        System::set_block_number(5);
+        assert_ok!(Triangulation::resolve_fraud_case(
+            RuntimeOrigin::root(),
+            accused,
+            true
+        ));
+
+        Triangulation::on_initialize(30);
+        let case = Triangulation::fraud_cases(accused).expect("case should exist");
+        assert_eq!(case.status, crate::FraudCaseStatus::Slashed);
+        assert_eq!(case.resolved_at, Some(5));
+    });
+    FraudResolutionTimeout::set(0);
+}
+
+#[test]
+fn timed_out_strong_evidence_upheld_when_configured() {
+    FraudResolutionTimeout::set(20);
+    AutoUpholdStrongEvidence::set(true);
+    new_test_ext().execute_with(|| {
+        setup_fraud_reporters();
+        let weak = ReporterId::new(1);
+        let strong = ReporterId::new(0);
+        assert_ok!(Triangulation::submit_fraud_proof(
+            RuntimeOrigin::signed(1),
+            ReporterId::new(0),
+            fraud_proof_against(weak)
+        ));
+        let mut proof = fraud_proof_against(strong);
+        proof.z_score_scaled = crate::FraudProof::required_z_score(100);
+        assert_ok!(Triangulation::submit_fraud_proof(
+            RuntimeOrigin::signed(2),
+            ReporterId::new(1),
+            proof
+        ));
+
+        Triangulation::on_initialize(21);
+        let weak_case = Triangulation::fraud_cases(weak).expect("case should exist");
+        assert_eq!(weak_case.status, crate::FraudCaseStatus::Dismissed);
+        let strong_case = Triangulation::fraud_cases(strong).expect("case should exist");
+        assert_eq!(strong_case.status, crate::FraudCaseStatus::Slashed);
+        let reporter = Triangulation::reporters(strong).expect("reporter should exist");
+        assert!(!reporter.active);
+    });
+    AutoUpholdStrongEvidence::set(false);
+    FraudResolutionTimeout::set(0);
+}
+
+// ============================================================================
+// Device State Transition Tests
+// ============================================================================
+
+const ALL_DEVICE_STATES: [DeviceState; 9] = [
+    DeviceState::Active,
+    DeviceState::LowPower,
+    DeviceState::Sleeping,
+    DeviceState::Unverifiable,
+    DeviceState::Offline,
+    DeviceState::Suspicious,
+    DeviceState::Lost,
+    DeviceState::Verified,
+    DeviceState::Disputed,
+];
+
+fn transition(
+    old: DeviceState,
+    new: DeviceState,
+) -> Result<DeviceState, sp_runtime::DispatchError> {
+    Triangulation::transition_device_state(old, new).map_err(Into::into)
+}
+
+#[test]
+fn legal_device_state_transitions_succeed() {
+    new_test_ext().execute_with(|| {
+        let live = [
+            DeviceState::Active,
+            DeviceState::LowPower,
+            DeviceState::Sleeping,
+            DeviceState::Verified,
+            DeviceState::Disputed,
+        ];
+        for old in live {
+            for new in ALL_DEVICE_STATES {
+                assert_eq!(transition(old, new), Ok(new));
+            }
+        }
+
+        for old in [
+            DeviceState::Unverifiable,
+            DeviceState::Offline,
+            DeviceState::Lost,
+        ] {
+            for new in [DeviceState::Active, DeviceState::Lost, old] {
+                assert_eq!(transition(old, new), Ok(new));
+            }
+        }
+
+        for new in [DeviceState::Unverifiable, DeviceState::Suspicious] {
+            assert_eq!(transition(DeviceState::Suspicious, new), Ok(new));
+        }
+    });
+}
+
+#[test]
+fn illegal_device_state_transitions_rejected() {
+    new_test_ext().execute_with(|| {
+        for old in [
+            DeviceState::Unverifiable,
+            DeviceState::Offline,
+            DeviceState::Lost,
+        ] {
+            for new in ALL_DEVICE_STATES
+                .into_iter()
+                .filter(|s| !matches!(s, DeviceState::Active | DeviceState::Lost) && *s != old)
+            {
+                assert_eq!(
+                    transition(old, new),
+                    Err(Error::<Test>::IllegalStateTransition.into())
+                );
+            }
+        }
+
+        for new in ALL_DEVICE_STATES
+            .into_iter()
+            .filter(|s| !matches!(s, DeviceState::Unverifiable | DeviceState::Suspicious))
+        {
+            assert_eq!(
+                transition(DeviceState::Suspicious, new),
+                Err(Error::<Test>::IllegalStateTransition.into())
+            );
+        }
+    });
+}
+
+#[test]
+fn clear_suspicious_device_requires_suspicious_state() {
+    new_test_ext().execute_with(|| {
+        let mac_hash = H256([1u8; 32]);
+        assert_ok!(Triangulation::register_reporter(
+            RuntimeOrigin::signed(1),
+            Position::default(),
+            None
+        ));
+        report_readings(mac_hash, -90, 1..=3);
+
+        assert_noop!(
+            Triangulation::clear_suspicious_device(RuntimeOrigin::signed(1), mac_hash),
+            sp_runtime::DispatchError::BadOrigin
+        );
+        assert_noop!(
+            Triangulation::clear_suspicious_device(RuntimeOrigin::root(), mac_hash),
+            Error::<Test>::IllegalStateTransition
+        );
+        assert_noop!(
+            Triangulation::clear_suspicious_device(RuntimeOrigin::root(), H256([9u8; 32])),
+            Error::<Test>::DeviceNotFound
+        );
+    });
+}
+
+// ============================================================================
+// Reputation-Adjusted Fraud Threshold Tests
+// ============================================================================
+
+#[test]
+fn fraud_threshold_scales_with_reputation() {
+    assert_eq!(crate::FraudProof::required_z_score(0), 250);
+    assert_eq!(crate::FraudProof::required_z_score(50), 350);
+    assert_eq!(crate::FraudProof::required_z_score(100), 450);
+    assert_eq!(crate::FraudProof::required_z_score(u8::MAX), 450);
+}
+
+#[test]
+fn same_proof_convicts_low_rep_but_not_high_rep_reporter() {
+    new_test_ext().execute_with(|| {
+        setup_fraud_reporters();
+        let veteran = ReporterId::new(0);
+        let newcomer = ReporterId::new(1);
+
+        // Reporter 0 builds a track record.
+        report_readings(H256([5u8; 32]), -50, 1..=30);
+        let reporter = Triangulation::reporters(veteran).expect("reporter should exist");
+        assert_eq!(reporter.reputation, 80);
+
+        assert_noop!(
+            Triangulation::submit_fraud_proof(
+                RuntimeOrigin::signed(2),
+                newcomer,
+                fraud_proof_against(veteran)
+            ),
+            Error::<Test>::InvalidFraudProof
+        );
+        assert_ok!(Triangulation::submit_fraud_proof(
+            RuntimeOrigin::signed(1),
+            veteran,
+            fraud_proof_against(newcomer)
+        ));
+    });
+}
+
+#[test]
+fn guilty_verdict_zeroes_reputation() {
+    new_test_ext().execute_with(|| {
+        setup_fraud_reporters();
+        let accused = ReporterId::new(1);
+
+        assert_ok!(Triangulation::submit_fraud_proof(
+            RuntimeOrigin::signed(1),
+            ReporterId::new(0),
+            fraud_proof_against(accused)
+        ));
+        assert_ok!(Triangulation::resolve_fraud_case(
+            RuntimeOrigin::root(),
+            accused,
+            true
+        ));
+
+        let reporter = Triangulation::reporters(accused).expect("reporter should exist");
+        assert_eq!(reporter.reputation, 0);
+    });
+}
+
+// ============================================================================
+// Device Set Root Tests
+// ============================================================================
+
+#[test]
+fn device_set_root_proves_tracked_devices() {
+    new_test_ext().execute_with(|| {
+        use seveny_primitives::traits::MerkleTree;
+
+        assert_ok!(Triangulation::register_reporter(
+            RuntimeOrigin::signed(1),
+            Position::default(),
+            None
+        ));
+        let devices: Vec<H256> = (1..=5u8).map(|b| H256([b; 32])).collect();
+        for mac_hash in &devices {
+            report_readings(*mac_hash, -50, 1..=1);
+        }
+        assert_eq!(
+            Triangulation::device_set_root(),
+            seveny_primitives::StateRoot::EMPTY
+        );
+
+        run_ghost_detection(10);
+        let root = Triangulation::device_set_root();
+        assert_ne!(root, seveny_primitives::StateRoot::EMPTY);
+        System::assert_has_event(RuntimeEvent::Triangulation(
+            crate::Event::DeviceSetRootUpdated {
+                root,
+                leaf_count: 5,
+            },
+        ));
+
+        let target = devices[2];
+        let device = Triangulation::tracked_devices(target).expect("device should exist");
+        let leaf =
+            Triangulation::device_set_leaf(target, &device.estimated_position, device.last_seen);
+        let proof = Triangulation::device_inclusion_proof(target).expect("proof should exist");
+        assert!(seveny_primitives::BinaryMerkleTree::verify_proof(
+            &root.0, &leaf, &proof
+        ));
+
+        // A stale or forged position does not verify.
+        let forged = Triangulation::device_set_leaf(
+            target,
+            &Position { x: 1, y: 2, z: 3 },
+            device.last_seen,
+        );
+        assert!(!proof.verify(&root.0, &forged));
+    });
+}
+
+#[test]
+fn device_set_root_rejects_non_members() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(Triangulation::register_reporter(
+            RuntimeOrigin::signed(1),
+            Position::default(),
+            None
+        ));
+        report_readings(H256([1u8; 32]), -50, 1..=1);
+        run_ghost_detection(10);
+
+        // Reported after the snapshot, so not yet committed.
+        let late = H256([7u8; 32]);
+        report_readings(late, -50, 11..=11);
+        assert!(Triangulation::device_inclusion_proof(late).is_none());
+
+        // A member's proof does not vouch for another device.
+        let member = H256([1u8; 32]);
+        let proof = Triangulation::device_inclusion_proof(member).expect("proof should exist");
+        let device = Triangulation::tracked_devices(late).expect("device should exist");
+        let leaf =
+            Triangulation::device_set_leaf(late, &device.estimated_position, device.last_seen);
+        assert!(!proof.verify(&Triangulation::device_set_root().0, &leaf));
+    });
+}
+
+#[test]
+fn device_set_root_commits_at_most_max_leaves() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(Triangulation::register_reporter(
+            RuntimeOrigin::signed(1),
+            Position::default(),
+            None
+        ));
+        report_readings(H256([1u8; 32]), -50, 1..=1);
+        let template =
+            Triangulation::tracked_devices(H256([1u8; 32])).expect("device should exist");
+        let tracked = MaxDeviceSetLeaves::get() + 6;
+        for i in 0..tracked {
+            let mut mac = [0u8; 32];
+            mac[..4].copy_from_slice(&i.to_le_bytes());
+            let mac_hash = H256(mac);
+            crate::TrackedDevices::<Test>::insert(
+                mac_hash,
+                crate::TrackedDevice {
+                    mac_hash,
+                    ..template.clone()
+                },
+            );
+        }
+
+        Triangulation::update_device_set_root();
+        assert_eq!(
+            Triangulation::device_set_leaves().len() as u32,
+            MaxDeviceSetLeaves::get()
+        );
+        System::assert_last_event(RuntimeEvent::Triangulation(
+            crate::Event::DeviceSetRootUpdated {
+                root: Triangulation::device_set_root(),
+                leaf_count: MaxDeviceSetLeaves::get(),
+            },
+        ));
+    });
+}
+
+// ============================================================================
+// Expected RSSI Tests
+// ============================================================================
+
+#[test]
+fn expected_rssi_follows_path_loss_model() {
+    new_test_ext().execute_with(|| {
+        let mac_hash = H256([9u8; 32]);
+        assert_ok!(Triangulation::register_reporter(
+            RuntimeOrigin::signed(1),
+            Position::default(),
+            None
+        ));
+        report_readings(mac_hash, -60, 1..=1);
+
+        for (distance_cm, expected) in [
+            (50, -59),
+            (100, -59),
+            (200, -67),
+            (1_000, -86),
+            (10_000, -113),
+        ] {
+            crate::TrackedDevices::<Test>::mutate(mac_hash, |d| {
+                d.as_mut().expect("device should exist").estimated_position = Position {
+                    x: 0,
+                    y: distance_cm,
+                    z: 0,
+                };
+            });
+            assert_eq!(
+                Triangulation::expected_rssi(ReporterId::new(0), mac_hash),
+                Some(expected)
+            );
+        }
+    });
+}
+
+#[test]
+fn expected_rssi_requires_known_reporter_and_device() {
+    new_test_ext().execute_with(|| {
+        let mac_hash = H256([9u8; 32]);
+        assert_eq!(
+            Triangulation::expected_rssi(ReporterId::new(0), mac_hash),
+            None
+        );
+
+        assert_ok!(Triangulation::register_reporter(
+            RuntimeOrigin::signed(1),
+            Position::default(),
+            None
+        ));
+        assert_eq!(
+            Triangulation::expected_rssi(ReporterId::new(0), mac_hash),
+            None
+        );
+
+        report_readings(mac_hash, -60, 1..=1);
+        assert_eq!(
+            Triangulation::expected_rssi(ReporterId::new(1), mac_hash),
+            None
+        );
+        assert!(Triangulation::expected_rssi(ReporterId::new(0), mac_hash).is_some());
+    });
+}
+
+// ============================================================================
+// Batch Signal Reporting Tests
+// ============================================================================
+
+#[test]
+fn batch_report_skips_out_of_range_readings() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(Triangulation::register_reporter(
+            RuntimeOrigin::signed(1),
+            Position::default(),
+            None
+        ));
+
+        let valid_a = H256([1u8; 32]);
+        let invalid = H256([2u8; 32]);
+        let valid_b = H256([3u8; 32]);
+        let too_strong = H256([4u8; 32]);
+        let readings = vec![
+            (valid_a, -50, SignalType::NetworkLatency, 2400),
+            (invalid, -121, SignalType::NetworkLatency, 2400),
+            (valid_b, -80, SignalType::PeerTopology, 2480),
+            (too_strong, 5, SignalType::NetworkLatency, 5000),
+        ];
+
+        assert_ok!(Triangulation::report_signals_batch(
+            RuntimeOrigin::signed(1),
+            ReporterId::new(0),
+            BoundedVec::try_from(readings).expect("batch within bound")
+        ));
+
+        assert!(Triangulation::tracked_devices(valid_a).is_some());
+        assert!(Triangulation::tracked_devices(valid_b).is_some());
+        assert!(Triangulation::tracked_devices(invalid).is_none());
+        assert!(Triangulation::tracked_devices(too_strong).is_none());
+        assert_eq!(Triangulation::device_count(), 2);
+
+        let reporter = Triangulation::reporters(ReporterId::new(0)).expect("reporter should exist");
+        assert_eq!(reporter.reading_count, 2);
+        assert_eq!(reporter.reputation, InitialReporterReputation::get() + 2);
+
+        System::assert_has_event(RuntimeEvent::Triangulation(
+            pallet_triangulation::Event::SignalDetected {
+                mac_hash: valid_b,
+                reporter_id: ReporterId::new(0),
+                rssi: -80,
+                signal_type: SignalType::PeerTopology,
+            },
+        ));
+        System::assert_last_event(RuntimeEvent::Triangulation(
+            pallet_triangulation::Event::SignalsBatchReported {
+                reporter_id: ReporterId::new(0),
+                accepted: 2,
+                skipped: 2,
+            },
+        ));
+    });
+}
+
+#[test]
+fn batch_report_requires_reporter_owner() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(Triangulation::register_reporter(
+            RuntimeOrigin::signed(1),
+            Position::default(),
+            None
+        ));
+        let readings = vec![(H256([1u8; 32]), -50, SignalType::NetworkLatency, 2400)];
+
+        assert_noop!(
+            Triangulation::report_signals_batch(
+                RuntimeOrigin::signed(2),
+                ReporterId::new(0),
+                BoundedVec::try_from(readings).expect("batch within bound")
+            ),
+            Error::<Test>::NotReporterOwner
+        );
+    });
+}
+
+// ============================================================================
+// Region Tests
+// ============================================================================
+
+fn register_in_region(x: i64, y: i64, region: Option<u16>) {
+    assert_ok!(Triangulation::register_reporter(
+        RuntimeOrigin::signed(1),
+        Position { x, y, z: 0 },
+        region
+    ));
+}
+
+fn report_at(block: u64, reporter: u64, mac_hash: H256, rssi: i8) {
+    System::set_block_number(block);
+    assert_ok!(Triangulation::report_signal(
+        RuntimeOrigin::signed(1),
+        ReporterId::new(reporter),
+        mac_hash,
+        rssi,
+        SignalType::NetworkLatency,
+        2400
+    ));
+}
+
+#[test]
+fn reporters_in_region_lists_active_members() {
+    new_test_ext().execute_with(|| {
+        register_in_region(0, 0, Some(1));
+        register_in_region(100, 0, Some(2));
+        register_in_region(0, 100, Some(1));
+        register_in_region(0, 0, None);
+
+        let mut region_one = Triangulation::reporters_in_region(1);
+        region_one.sort_by_key(|id| id.0);
+        assert_eq!(region_one, vec![ReporterId::new(0), ReporterId::new(2)]);
+
+        assert_ok!(Triangulation::deregister_reporter(
+            RuntimeOrigin::signed(1),
+            ReporterId::new(2)
+        ));
+        assert_eq!(
+            Triangulation::reporters_in_region(1),
+            vec![ReporterId::new(0)]
+        );
+        assert_eq!(
+            Triangulation::reporters_in_region(2),
+            vec![ReporterId::new(1)]
+        );
+    });
+}
+
+#[test]
+fn region_scoped_position_ignores_out_of_region_reporters() {
+    new_test_ext().execute_with(|| {
+        let mac_hash = H256([9u8; 32]);
+        // Site A triangle (region 1) and a distant site B reporter (region 2).
+        register_in_region(0, 0, Some(1));
+        register_in_region(1000, 0, Some(1));
+        register_in_region(0, 1000, Some(1));
+        register_in_region(5_000_000, 5_000_000, Some(2));
+
+        report_at(1, 3, mac_hash, -40);
+        report_at(2, 1, mac_hash, -70);
+        report_at(3, 2, mac_hash, -70);
+        report_at(4, 0, mac_hash, -70);
+
+        let position = Triangulation::region_scoped_position(mac_hash).expect("position");
+        assert!((0..=1000).contains(&position.x));
+        assert!((0..=1000).contains(&position.y));
+
+        // The same estimate results from the in-region readings alone.
+        let other = H256([8u8; 32]);
+        report_at(5, 1, other, -70);
+        report_at(6, 2, other, -70);
+        report_at(7, 0, other, -70);
+        assert_eq!(Triangulation::region_scoped_position(other), Some(position));
+
+        // A device last seen by the site B reporter resolves only against site B.
+        report_at(8, 3, other, -40);
+        assert_eq!(Triangulation::region_scoped_position(other), None);
+    });
+}
+
+// ============================================================================
+// Per-Reporter History Cap Tests
+// ============================================================================
+
+fn report_from(account: u64, reporter: u64, mac_hash: H256, block: u64) {
+    System::set_block_number(block);
+    assert_ok!(Triangulation::report_signal(
+        RuntimeOrigin::signed(account),
+        ReporterId::new(reporter),
+        mac_hash,
+        -50,
+        SignalType::NetworkLatency,
+        2400
+    ));
+}
+
+fn history_len(reporter: u64) -> usize {
+    crate::SignalHistory::<Test>::iter()
+        .filter(|(_, _, e)| e.reading.reporter_id == ReporterId::new(reporter))
+        .count()
+}
+
+#[test]
+fn chatty_reporter_history_bounded_to_cap() {
+    MaxHistoryPerReporter::set(3);
+    new_test_ext().execute_with(|| {
+        let position = Position { x: 0, y: 0, z: 0 };
+        for account in [1, 2] {
+            assert_ok!(Triangulation::register_reporter(
+                RuntimeOrigin::signed(account),
+                position.clone(),
+                None
+            ));
+        }
+
+        report_from(2, 1, H256([9u8; 32]), 1);
+        for block in 1..=6 {
+            report_from(1, 0, H256([block as u8; 32]), block);
+        }
+
+        assert_eq!(history_len(0), 3);
+        assert!(Triangulation::signal_history(H256([3u8; 32]), 3).is_none());
+        assert!(Triangulation::signal_history(H256([4u8; 32]), 4).is_some());
+        assert_eq!(
+            Triangulation::reporter_history(ReporterId::new(0)).into_inner(),
+            vec![
+                (H256([4u8; 32]), 4),
+                (H256([5u8; 32]), 5),
+                (H256([6u8; 32]), 6)
+            ]
+        );
+        // The sparse reporter keeps its entry.
+        assert_eq!(history_len(1), 1);
+    });
+    MaxHistoryPerReporter::set(1000);
+}
+
+#[test]
+fn capped_history_still_time_pruned() {
+    MaxHistoryPerReporter::set(3);
+    new_test_ext().execute_with(|| {
+        let mac_hash = H256([1u8; 32]);
+        assert_ok!(Triangulation::register_reporter(
+            RuntimeOrigin::signed(1),
+            Position { x: 0, y: 0, z: 0 },
+            None
+        ));
+        report_from(1, 0, mac_hash, 1);
+        report_from(1, 0, mac_hash, 2);
+
+        // Retention is 1000 blocks: both entries are below the cap but expire.
+        run_ghost_detection(1003);
+        assert_eq!(history_len(0), 0);
+
+        for block in 1004..=1006 {
+            report_from(1, 0, mac_hash, block);
+        }
+        assert_eq!(history_len(0), 3);
+        assert_eq!(Triangulation::reporter_history(ReporterId::new(0)).len(), 3);
+    });
+    MaxHistoryPerReporter::set(1000);
+}
+
+// ============================================================================
+// Signal Anomaly Score Tests
+// ============================================================================
+
+fn report_rssi(account: u64, reporter: u64, mac_hash: H256, rssi: i8, block: u64) {
+    System::set_block_number(block);
+    assert_ok!(Triangulation::report_signal(
+        RuntimeOrigin::signed(account),
+        ReporterId::new(reporter),
+        mac_hash,
+        rssi,
+        SignalType::NetworkLatency,
+        2400
+    ));
+}
+
+#[test]
+fn deviating_reporter_accumulates_anomaly_and_is_flagged() {
+    AnomalyThreshold::set(2000);
+    new_test_ext().execute_with(|| {
+        setup_fraud_reporters();
+        let mac_hash = H256([7u8; 32]);
+        let honest = ReporterId::new(0);
+        let deviant = ReporterId::new(1);
+
+        // Both sit on the device estimate, so the expected reading is ReferenceTxPower.
+        report_rssi(1, 0, mac_hash, -59, 1);
+        for block in 2..=3 {
+            report_rssi(1, 0, mac_hash, -59, block);
+            report_rssi(2, 1, mac_hash, -95, block);
+        }
+        // 36 dB off: 900, then 1575 — still below the threshold.
+        assert_eq!(Triangulation::reporter_anomaly(deviant), 1575);
+        assert!(Triangulation::anomaly_flagged(deviant).is_none());
+
+        report_rssi(2, 1, mac_hash, -95, 4);
+        assert_eq!(Triangulation::reporter_anomaly(deviant), 2081);
+        assert_eq!(Triangulation::anomaly_flagged(deviant), Some(4));
+        System::assert_has_event(RuntimeEvent::Triangulation(
+            crate::Event::ReporterAnomalyFlagged {
+                reporter_id: deviant,
+                score: 2081,
+            },
+        ));
+
+        assert_eq!(Triangulation::reporter_anomaly(honest), 0);
+        assert!(Triangulation::anomaly_flagged(honest).is_none());
+
+        // Readings back in line decay the score and clear the flag.
+        for block in 5..=6 {
+            report_rssi(2, 1, mac_hash, -59, block);
+        }
+        assert!(Triangulation::reporter_anomaly(deviant) < 2000);
+        assert!(Triangulation::anomaly_flagged(deviant).is_none());
+    });
+    AnomalyThreshold::set(0);
+}
+
+#[test]
+fn anomaly_flag_fast_tracks_fraud_proof() {
+    AnomalyThreshold::set(2000);
+    new_test_ext().execute_with(|| {
+        setup_fraud_reporters();
+        let mac_hash = H256([7u8; 32]);
+        let veteran = ReporterId::new(0);
+
+        // Reporter 0 earns reputation 80 (4.1 sigma required) while reading 36 dB off.
+        report_rssi(2, 1, mac_hash, -59, 1);
+        report_readings(H256([5u8; 32]), -50, 1..=30);
+        let mut proof = fraud_proof_against(veteran);
+        proof.z_score_scaled = 300;
+        assert_noop!(
+            Triangulation::submit_fraud_proof(
+                RuntimeOrigin::signed(2),
+                ReporterId::new(1),
+                proof.clone()
+            ),
+            Error::<Test>::InvalidFraudProof
+        );
+
+        for block in 31..=34 {
+            report_rssi(1, 0, mac_hash, -95, block);
+        }
+        assert!(Triangulation::anomaly_flagged(veteran).is_some());
+
+        // Flagged: held to the 2.5 sigma floor regardless of reputation.
+        assert_ok!(Triangulation::submit_fraud_proof(
+            RuntimeOrigin::signed(2),
+            ReporterId::new(1),
+            proof
+        ));
+    });
+    AnomalyThreshold::set(0);
+}
+
+// ============================================================================
+// Single-Reporter Confidence Cap Tests
+// ============================================================================
+
+#[test]
+fn single_reporter_device_confidence_capped() {
+    MinReportersForTrust::set(2);
+    SingleReporterConfidenceCap::set(50);
+    new_test_ext().execute_with(|| {
+        setup_fraud_reporters();
+        let mac_hash = H256([3u8; 32]);
+
+        for block in 1..=20 {
+            report_from(1, 0, mac_hash, block);
+        }
+
+        let device = Triangulation::tracked_devices(mac_hash).expect("device should exist");
+        assert_eq!(device.reading_count, 20);
+        assert_eq!(device.confidence, 50);
+        assert_eq!(Triangulation::device_reporter_count(mac_hash), 1);
+    });
+    MinReportersForTrust::set(1);
+    SingleReporterConfidenceCap::set(100);
+}
+
+#[test]
+fn minimum_distinct_reporters_lift_confidence_cap() {
+    MinReportersForTrust::set(2);
+    SingleReporterConfidenceCap::set(50);
+    new_test_ext().execute_with(|| {
+        setup_fraud_reporters();
+        let mac_hash = H256([3u8; 32]);
+
+        for block in 1..=10 {
+            report_from(1, 0, mac_hash, block);
+        }
+        assert_eq!(
+            Triangulation::tracked_devices(mac_hash)
+                .expect("device should exist")
+                .confidence,
+            50
+        );
+
+        report_from(2, 1, mac_hash, 11);
+        for block in 12..=14 {
+            report_from(1, 0, mac_hash, block);
+        }
+
+        let device = Triangulation::tracked_devices(mac_hash).expect("device should exist");
+        assert_eq!(device.confidence, 70);
+        assert_eq!(Triangulation::device_reporter_count(mac_hash), 2);
+    });
+    MinReportersForTrust::set(1);
+    SingleReporterConfidenceCap::set(100);
+}
+
+// ============================================================================
+// Position Confirmation Hook Tests
+// ============================================================================
+
+#[test]
+fn confident_position_update_notifies_hook() {
+    new_test_ext().execute_with(|| {
+        setup_fraud_reporters();
+        let mac_hash = H256([5u8; 32]);
+
+        // Confidence starts at 30 and gains 5 per reading: 75 after ten readings.
+        for block in 1..=10 {
+            report_from(1, 0, mac_hash, block);
+        }
+        assert!(confirmed_positions().is_empty());
+
+        report_from(1, 0, mac_hash, 11);
+        let device = Triangulation::tracked_devices(mac_hash).expect("device should exist");
+        assert_eq!(device.confidence, 80);
+        assert_eq!(
+            confirmed_positions(),
+            vec![(mac_hash, device.estimated_position, 80)]
+        );
+
+        report_from(1, 0, mac_hash, 12);
+        assert_eq!(confirmed_positions().len(), 2);
+    });
+}
+
+#[test]
+fn suspicious_or_low_confidence_device_not_reported() {
+    PositionConfirmationThreshold::set(50);
+    new_test_ext().execute_with(|| {
+        setup_fraud_reporters();
+        let mac_hash = H256([5u8; 32]);
+
+        for block in 1..=4 {
+            report_from(1, 0, mac_hash, block);
+        }
+        assert_eq!(confirmed_positions().len(), 0);
+
+        crate::TrackedDevices::<Test>::mutate(mac_hash, |d| {
+            if let Some(d) = d {
+                d.state = DeviceState::Suspicious;
+            }
+        });
+        for block in 5..=8 {
+            report_from(1, 0, mac_hash, block);
+        }
+        let device = Triangulation::tracked_devices(mac_hash).expect("device should exist");
+        assert!(device.confidence >= 50);
+        assert!(confirmed_positions().is_empty());
+    });
+    PositionConfirmationThreshold::set(80);
+}
+
+// ============================================================================
+// Reporter Rate Limit Tests
+// ============================================================================
+
+fn report_device(mac_seed: u8) -> DispatchResult {
+    Triangulation::report_signal(
+        RuntimeOrigin::signed(1),
+        ReporterId::new(0),
+        H256([mac_seed; 32]),
+        -50,
+        SignalType::NetworkLatency,
+        2400,
+    )
+}
+
+#[test]
+fn reporter_rate_limited_within_block() {
+    MaxReadingsPerReporterPerBlock::set(3);
+    new_test_ext().execute_with(|| {
+        assert_ok!(Triangulation::register_reporter(
+            RuntimeOrigin::signed(1),
+            Position::default(),
+            None
+        ));
+
+        for seed in 1..=3 {
+            assert_ok!(report_device(seed));
+        }
+        assert_noop!(report_device(4), Error::<Test>::ReporterRateLimited);
+        assert_eq!(
+            Triangulation::reporter_block_readings(ReporterId::new(0)),
+            Some((1, 3))
+        );
+
+        System::set_block_number(2);
+        for seed in 4..=6 {
+            assert_ok!(report_device(seed));
+        }
+        assert_noop!(report_device(7), Error::<Test>::ReporterRateLimited);
+        assert_eq!(Triangulation::device_count(), 6);
+    });
+    MaxReadingsPerReporterPerBlock::set(0);
+}
+
+#[test]
+fn batch_readings_beyond_block_cap_skipped() {
+    MaxReadingsPerReporterPerBlock::set(3);
+    new_test_ext().execute_with(|| {
+        assert_ok!(Triangulation::register_reporter(
+            RuntimeOrigin::signed(1),
+            Position::default(),
+            None
+        ));
+        assert_ok!(report_device(1));
+
+        let readings: Vec<_> = (2..=5u8)
+            .map(|seed| (H256([seed; 32]), -50, SignalType::NetworkLatency, 2400))
+            .collect();
+        assert_ok!(Triangulation::report_signals_batch(
+            RuntimeOrigin::signed(1),
+            ReporterId::new(0),
+            BoundedVec::try_from(readings).expect("batch within bound")
+        ));
+
+        System::assert_last_event(RuntimeEvent::Triangulation(
+            pallet_triangulation::Event::SignalsBatchReported {
+                reporter_id: ReporterId::new(0),
+                accepted: 2,
+                skipped: 2,
+            },
+        ));
+        assert!(Triangulation::tracked_devices(H256([3u8; 32])).is_some());
+        assert!(Triangulation::tracked_devices(H256([4u8; 32])).is_none());
+        assert_noop!(report_device(6), Error::<Test>::ReporterRateLimited);
+
+        System::set_block_number(2);
+        assert_ok!(report_device(6));
+    });
+    MaxReadingsPerReporterPerBlock::set(0);
+}
+
+// ============================================================================
+// Confident Position Query Tests
+// ============================================================================
+
+fn set_device_confidence(mac_hash: H256, confidence: u8) {
+    crate::TrackedDevices::<Test>::mutate(mac_hash, |d| {
+        if let Some(d) = d {
+            d.confidence = confidence;
+        }
+    });
+}
+
+#[test]
+fn low_confidence_position_not_returned() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(Triangulation::register_reporter(
+            RuntimeOrigin::signed(1),
+            Position::default(),
+            None
+        ));
+        let mac_hash = H256([1u8; 32]);
+        assert_ok!(report_device(1));
+        set_device_confidence(mac_hash, 40);
+
+        assert!(Triangulation::get_last_known_position(mac_hash).is_some());
+        assert_eq!(Triangulation::get_confident_position(mac_hash, 50), None);
+        assert_eq!(Triangulation::get_authoritative_position(mac_hash), None);
+        assert_eq!(
+            Triangulation::get_confident_position(H256([2u8; 32]), 0),
+            None
+        );
+    });
+}
+
+#[test]
+fn high_confidence_position_returned() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(Triangulation::register_reporter(
+            RuntimeOrigin::signed(1),
+            Position::default(),
+            None
+        ));
+        let mac_hash = H256([1u8; 32]);
+        assert_ok!(report_device(1));
+        set_device_confidence(mac_hash, MinAuthoritativeConfidence::get());
+
+        let position = Triangulation::get_last_known_position(mac_hash);
+        assert!(position.is_some());
+        assert_eq!(
+            Triangulation::get_confident_position(mac_hash, MinAuthoritativeConfidence::get()),
+            position
+        );
+        assert_eq!(
+            Triangulation::get_authoritative_position(mac_hash),
+            position
+        );
+        assert_eq!(
+            Triangulation::get_confident_position(mac_hash, MinAuthoritativeConfidence::get() + 1),
+            None
+        );
+    });
+}
+
+// ============================================================================
+// Signal Type Migration Tests
+// ============================================================================
+
+fn report_as(mac_hash: H256, signal_type: SignalType) {
+    assert_ok!(Triangulation::report_signal(
+        RuntimeOrigin::signed(1),
+        ReporterId::new(0),
+        mac_hash,
+        -50,
+        signal_type,
+        2400
+    ));
+}
+
+#[test]
+fn signal_type_change_flags_device_under_strict_config() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(Triangulation::register_reporter(
+            RuntimeOrigin::signed(1),
+            Position::default(),
+            None
+        ));
+        let mac_hash = H256([1u8; 32]);
+        report_as(mac_hash, SignalType::NetworkLatency);
+        report_as(mac_hash, SignalType::PeerTopology);
+
+        let device = Triangulation::tracked_devices(mac_hash).expect("device should exist");
+        assert_eq!(device.state, DeviceState::Suspicious);
+        assert_eq!(device.signal_type, SignalType::NetworkLatency);
+        System::assert_has_event(RuntimeEvent::Triangulation(
+            pallet_triangulation::Event::SignalTypeChanged {
+                mac_hash,
+                reporter_id: ReporterId::new(0),
+                old_type: SignalType::NetworkLatency,
+                new_type: SignalType::PeerTopology,
+                migrated: false,
+            },
+        ));
+
+        // Further readings keep the device flagged.
+        report_as(mac_hash, SignalType::NetworkLatency);
+        let device = Triangulation::tracked_devices(mac_hash).expect("device should exist");
+        assert_eq!(device.state, DeviceState::Suspicious);
+    });
+}
+
+#[test]
+fn signal_type_change_migrates_under_lenient_config() {
+    AllowSignalTypeMigration::set(true);
+    new_test_ext().execute_with(|| {
+        assert_ok!(Triangulation::register_reporter(
+            RuntimeOrigin::signed(1),
+            Position::default(),
+            None
+        ));
+        let mac_hash = H256([1u8; 32]);
+        report_as(mac_hash, SignalType::NetworkLatency);
+        report_as(mac_hash, SignalType::PeerTopology);
+
+        let device = Triangulation::tracked_devices(mac_hash).expect("device should exist");
+        assert_ne!(device.state, DeviceState::Suspicious);
+        assert_eq!(device.signal_type, SignalType::PeerTopology);
+        System::assert_has_event(RuntimeEvent::Triangulation(
+            pallet_triangulation::Event::SignalTypeChanged {
+                mac_hash,
+                reporter_id: ReporterId::new(0),
+                old_type: SignalType::NetworkLatency,
+                new_type: SignalType::PeerTopology,
+                migrated: true,
+            },
+        ));
+    });
+    AllowSignalTypeMigration::set(false);
+}
+
+// ============================================================================
+// Fraud Proof Region Isolation Tests
+// ============================================================================
+
+fn register_at(account: u64, position: Position, region: Option<u16>) {
+    assert_ok!(Triangulation::register_reporter(
+        RuntimeOrigin::signed(account),
+        position,
+        region
+    ));
+}
+
+#[test]
+fn in_region_fraud_proof_is_accepted() {
+    new_test_ext().execute_with(|| {
+        register_at(1, Position::default(), Some(7));
+        register_at(
+            2,
+            Position {
+                x: 900_000,
+                y: 0,
+                z: 0,
+            },
+            Some(7),
+        );
+
+        assert_ok!(Triangulation::submit_fraud_proof(
+            RuntimeOrigin::signed(1),
+            ReporterId::new(0),
+            fraud_proof_against(ReporterId::new(1))
+        ));
+        assert!(Triangulation::fraud_cases(ReporterId::new(1)).is_some());
+    });
+}
+
+#[test]
+fn cross_region_fraud_proof_is_rejected() {
+    new_test_ext().execute_with(|| {
+        register_at(1, Position::default(), Some(7));
+        register_at(2, Position::default(), Some(8));
+
+        assert_noop!(
+            Triangulation::submit_fraud_proof(
+                RuntimeOrigin::signed(1),
+                ReporterId::new(0),
+                fraud_proof_against(ReporterId::new(1))
+            ),
+            Error::<Test>::CrossRegionAccusation
+        );
+    });
+}
+
+#[test]
+fn untagged_fraud_proof_limited_by_distance() {
+    MaxAccusationDistanceCm::set(10_000);
+    new_test_ext().execute_with(|| {
+        register_at(1, Position::default(), Some(7));
+        register_at(
+            2,
+            Position {
+                x: 6_000,
+                y: 8_000,
+                z: 0,
+            },
+            None,
+        );
+        register_at(
+            3,
+            Position {
+                x: 6_000,
+                y: 8_001,
+                z: 0,
+            },
+            None,
+        );
+
+        assert_noop!(
+            Triangulation::submit_fraud_proof(
+                RuntimeOrigin::signed(1),
+                ReporterId::new(0),
+                fraud_proof_against(ReporterId::new(2))
+            ),
+            Error::<Test>::CrossRegionAccusation
+        );
+        assert_ok!(Triangulation::submit_fraud_proof(
+            RuntimeOrigin::signed(1),
+            ReporterId::new(0),
+            fraud_proof_against(ReporterId::new(1))
+        ));
+    });
+    MaxAccusationDistanceCm::set(0);
+}
+
+// ============================================================================
+// Reputation Gain Window Tests
+// ============================================================================
+
+#[test]
+fn reputation_gain_capped_per_window() {
+    ReputationGainWindow::set(10);
+    new_test_ext().execute_with(|| {
+        setup_fraud_reporters();
+        let reporter_id = ReporterId::new(0);
+        let initial = InitialReporterReputation::get();
+
+        report_readings(H256([5u8; 32]), -50, 1..=10);
+        let reporter = Triangulation::reporters(reporter_id).expect("reporter should exist");
+        assert_eq!(reporter.reading_count, 10);
+        assert_eq!(
+            reporter.reputation,
+            initial + MaxReputationGainPerWindow::get()
+        );
+
+        // A new window earns again.
+        report_readings(H256([5u8; 32]), -50, 11..=11);
+        let reporter = Triangulation::reporters(reporter_id).expect("reporter should exist");
+        assert_eq!(
+            reporter.reputation,
+            initial + MaxReputationGainPerWindow::get() + 1
+        );
+    });
+    ReputationGainWindow::set(0);
+}
+
+#[test]
+fn anomaly_flagged_reporter_earns_no_reputation() {
+    AnomalyThreshold::set(2000);
+    new_test_ext().execute_with(|| {
+        setup_fraud_reporters();
+        let mac_hash = H256([7u8; 32]);
+        let reporter_id = ReporterId::new(0);
+
+        report_rssi(2, 1, mac_hash, -59, 1);
+        for block in 2..=5 {
+            report_rssi(1, 0, mac_hash, -95, block);
+        }
+        assert!(Triangulation::anomaly_flagged(reporter_id).is_some());
+        let flagged_at = Triangulation::reporters(reporter_id)
+            .expect("reporter should exist")
+            .reputation;
+
+        for block in 6..=8 {
+            report_rssi(1, 0, mac_hash, -95, block);
+        }
+        let reporter = Triangulation::reporters(reporter_id).expect("reporter should exist");
+        assert_eq!(reporter.reputation, flagged_at);
+        assert!(reporter.reputation < InitialReporterReputation::get() + 7);
+    });
+    AnomalyThreshold::set(0);
+}
@@ -13,6 +13,7 @@ pub trait WeightInfo {
     fn deregister_reporter() -> Weight;
     fn report_signal() -> Weight;
     fn update_reporter_position() -> Weight;
+    fn report_signals_batch(n: u32) -> Weight;
 }
 
 pub struct SubstrateWeight<T>(PhantomData<T>);
@@ -32,14 +33,31 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 
     fn report_signal() -> Weight {
         Weight::from_parts(40_000_000, 0)
-            .saturating_add(T::DbWeight::get().reads(3))
-            .saturating_add(T::DbWeight::get().writes(4))
+            .saturating_add(T::DbWeight::get().reads(13))
+            .saturating_add(T::DbWeight::get().writes(12))
     }
 
     fn update_reporter_position() -> Weight {
-        Weight::from_parts(20_000_000, 0)
-            .saturating_add(T::DbWeight::get().reads(1))
-            .saturating_add(T::DbWeight::get().writes(1))
+        // Reads: ReporterOwner, Reporters, PositionAnchors, MovementViolations
+        // Writes: Reporters and PositionAnchors, or MovementViolations
+        Weight::from_parts(25_000_000, 0)
+            .saturating_add(T::DbWeight::get().reads(4))
+            .saturating_add(T::DbWeight::get().writes(2))
+    }
+
+    fn report_signals_batch(n: u32) -> Weight {
+        // Reads: ReporterOwner, Reporters, ReporterBlockReadings, then TrackedDevices,
+        // SignalHistory, ReporterHistory, ReporterAnomaly, AnomalyFlagged,
+        // DeviceReporterCount and DeviceReporters per reading
+        // Writes: Reporters, ReporterBlockReadings, then TrackedDevices, SignalHistory,
+        // ReporterHistory, ReporterAnomaly, AnomalyFlagged, DeviceReporters, DeviceReporterCount,
+        // evictions and counters per reading
+        Weight::from_parts(15_000_000, 0)
+            .saturating_add(Weight::from_parts(30_000_000, 0).saturating_mul(n as u64))
+            .saturating_add(T::DbWeight::get().reads(5))
+            .saturating_add(T::DbWeight::get().reads(n as u64 * 8))
+            .saturating_add(T::DbWeight::get().writes(3))
+            .saturating_add(T::DbWeight::get().writes(n as u64 * 10))
     }
 }
 
@@ -58,13 +76,22 @@ impl WeightInfo for () {
 
     fn report_signal() -> Weight {
         Weight::from_parts(40_000_000, 0)
-            .saturating_add(RocksDbWeight::get().reads(3))
-            .saturating_add(RocksDbWeight::get().writes(4))
+            .saturating_add(RocksDbWeight::get().reads(13))
+            .saturating_add(RocksDbWeight::get().writes(12))
     }
 
     fn update_reporter_position() -> Weight {
-        Weight::from_parts(20_000_000, 0)
-            .saturating_add(RocksDbWeight::get().reads(1))
-            .saturating_add(RocksDbWeight::get().writes(1))
+        Weight::from_parts(25_000_000, 0)
+            .saturating_add(RocksDbWeight::get().reads(4))
+            .saturating_add(RocksDbWeight::get().writes(2))
+    }
+
+    fn report_signals_batch(n: u32) -> Weight {
+        Weight::from_parts(15_000_000, 0)
+            .saturating_add(Weight::from_parts(30_000_000, 0).saturating_mul(n as u64))
+            .saturating_add(RocksDbWeight::get().reads(5))
+            .saturating_add(RocksDbWeight::get().reads(n as u64 * 8))
+            .saturating_add(RocksDbWeight::get().writes(3))
+            .saturating_add(RocksDbWeight::get().writes(n as u64 * 10))
     }
 }
@@ -15,7 +15,10 @@ pub mod pallet {
     use alloc::vec::Vec;
     use frame_support::{
         pallet_prelude::*,
-        traits::{Currency, Get, Imbalance, ReservableCurrency, StorageVersion},
+        traits::{
+            BalanceStatus, Currency, Get, Imbalance, OnUnbalanced, ReservableCurrency,
+            StorageVersion,
+        },
     };
     use frame_system::pallet_prelude::*;
     use seveny_primitives::{
@@ -23,10 +26,15 @@ pub mod pallet {
             EVIDENCE_REWARD_MAX, MAX_STAKE_RATIO, SLASH_CRITICAL, SLASH_MINOR, SLASH_MODERATE,
             SLASH_SEVERE,
         },
+        traits::KeyRotationProvider,
         types::{ValidatorId, ViolationType},
     };
-    use sp_arithmetic::Perbill;
-    use sp_runtime::{traits::Zero, Saturating};
+    use sp_arithmetic::{helpers_128bit::multiply_by_rational_with_rounding, Perbill, Rounding};
+    use sp_core::H256;
+    use sp_runtime::{
+        traits::{SaturatedConversion, Zero},
+        Saturating,
+    };
 
     use crate::WeightInfo;
 
@@ -35,6 +43,16 @@ pub mod pallet {
     pub type BalanceOf<T> =
         <<T as Config>::Currency as Currency<<T as frame_system::Config>::AccountId>>::Balance;
 
+    pub type NegativeImbalanceOf<T> = <<T as Config>::Currency as Currency<
+        <T as frame_system::Config>::AccountId,
+    >>::NegativeImbalance;
+
+    /// Validator payout and each nominator's payout from a reward split.
+    pub type RewardSplit<T> = (
+        BalanceOf<T>,
+        Vec<(<T as frame_system::Config>::AccountId, BalanceOf<T>)>,
+    );
+
     #[pallet::pallet]
     #[pallet::storage_version(STORAGE_VERSION)]
     pub struct Pallet<T>(_);
@@ -44,6 +62,9 @@ pub mod pallet {
         type WeightInfo: WeightInfo;
         type Currency: ReservableCurrency<Self::AccountId>;
 
+        /// Destination for slashed funds left after evidence rewards. `()` burns them.
+        type OnSlash: OnUnbalanced<NegativeImbalanceOf<Self>>;
+
         #[pallet::constant]
         type MinStake: Get<BalanceOf<Self>>;
 
@@ -58,6 +79,18 @@ pub mod pallet {
 
         #[pallet::constant]
         type SlashDeferDuration: Get<BlockNumberFor<Self>>;
+
+        /// Apply `Critical` slashes from `slash_validator` at once instead of
+        /// deferring them by `SlashDeferDuration`.
+        #[pallet::constant]
+        type ImmediateCriticalSlash: Get<bool>;
+
+        /// Highest commission a validator may take from rewards.
+        #[pallet::constant]
+        type MaxCommission: Get<Perbill>;
+
+        /// Lifecycle key rotations that `rotate_validator_key` must match.
+        type KeyRotation: KeyRotationProvider;
     }
 
     #[derive(
@@ -97,6 +130,8 @@ pub mod pallet {
         pub status: ValidatorStatus,
         pub registered_at: BlockNumberFor<T>,
         pub unbonding_at: Option<BlockNumberFor<T>>,
+        /// Share of rewards kept by the validator before the rest is split by stake
+        pub commission: Perbill,
     }
 
     #[derive(
@@ -210,6 +245,9 @@ pub mod pallet {
             validator: ValidatorId,
             unbond_at: BlockNumberFor<T>,
         },
+        ValidatorRebonded {
+            validator: ValidatorId,
+        },
         ValidatorSlashed {
             validator: ValidatorId,
             amount: BalanceOf<T>,
@@ -228,6 +266,15 @@ pub mod pallet {
             reporter: T::AccountId,
             amount: BalanceOf<T>,
         },
+        CommissionSet {
+            validator: ValidatorId,
+            commission: Perbill,
+        },
+        ValidatorKeyRotated {
+            validator: ValidatorId,
+            old_controller: T::AccountId,
+            new_controller: T::AccountId,
+        },
     }
 
     #[pallet::error]
@@ -254,11 +301,19 @@ pub mod pallet {
         /// Evidence report rate limit exceeded
         EvidenceRateLimitExceeded,
         DuplicateSlash,
+        /// Validator is not in the unbonding window
+        NotUnbonding,
+        /// Commission exceeds `MaxCommission`
+        CommissionTooHigh,
+        /// The controller's actor has no pending lifecycle rotation to the new controller
+        KeyRotationNotPending,
     }
 
     #[pallet::genesis_config]
     #[derive(frame_support::DefaultNoBound)]
     pub struct GenesisConfig<T: Config> {
+        /// Controllers and their stakes. Each controller must be endowed with at
+        /// least its stake in the balances genesis, which is reserved at build.
         pub initial_validators: Vec<(T::AccountId, BalanceOf<T>)>,
         #[serde(skip)]
         pub _phantom: PhantomData<T>,
@@ -270,6 +325,14 @@ pub mod pallet {
             for (controller, stake) in &self.initial_validators {
                 let validator_id =
                     seveny_primitives::crypto::derive_validator_id(&controller.encode());
+                // Back the stake like `register_validator` so slashes seize real funds.
+                assert!(
+                    T::Currency::can_reserve(controller, *stake),
+                    "genesis validator {controller:?} cannot reserve its stake of {stake:?}; \
+                     endow it in the balances genesis",
+                );
+                T::Currency::reserve(controller, *stake)
+                    .expect("genesis validator must hold its stake");
 
                 let info = ValidatorInfo {
                     id: validator_id,
@@ -278,6 +341,7 @@ pub mod pallet {
                     status: ValidatorStatus::Active,
                     registered_at: BlockNumberFor::<T>::zero(),
                     unbonding_at: None,
+                    commission: Perbill::zero(),
                 };
 
                 Validators::<T>::insert(validator_id, info);
@@ -326,6 +390,7 @@ pub mod pallet {
                 status: ValidatorStatus::Bonding,
                 registered_at: block_number,
                 unbonding_at: None,
+                commission: Perbill::zero(),
             };
 
             Validators::<T>::insert(validator_id, info);
@@ -499,7 +564,11 @@ pub mod pallet {
         }
 
         #[pallet::call_index(5)]
-        #[pallet::weight(T::WeightInfo::slash_validator())]
+        #[pallet::weight(if *violation == ViolationType::Critical && T::ImmediateCriticalSlash::get() {
+            T::WeightInfo::slash_validator().saturating_add(T::WeightInfo::apply_slash())
+        } else {
+            T::WeightInfo::slash_validator()
+        })]
         pub fn slash_validator(
             origin: OriginFor<T>,
             validator: ValidatorId,
@@ -523,6 +592,8 @@ pub mod pallet {
             SlashCount::<T>::put(slash_id.saturating_add(1));
 
             let defer_until = block_number.saturating_add(T::SlashDeferDuration::get());
+            let immediate =
+                violation == ViolationType::Critical && T::ImmediateCriticalSlash::get();
 
             let slash_record = SlashRecord {
                 validator,
@@ -533,14 +604,16 @@ pub mod pallet {
                 reporter: None,
             };
 
-            PendingSlashes::<T>::insert(slash_id, slash_record);
+            PendingSlashes::<T>::insert(slash_id, slash_record.clone());
             SlashDedup::<T>::insert(validator, violation, block_number);
 
-            Self::deposit_event(Event::SlashDeferred {
-                validator,
-                amount: slash_amount,
-                defer_until,
-            });
+            if !immediate {
+                Self::deposit_event(Event::SlashDeferred {
+                    validator,
+                    amount: slash_amount,
+                    defer_until,
+                });
+            }
 
             if violation == ViolationType::Critical && info.status != ValidatorStatus::Slashed {
                 // H04: only decrement ActiveValidatorCount for Active validators
@@ -562,6 +635,10 @@ pub mod pallet {
                 violation,
             });
 
+            if immediate {
+                Self::execute_slash(slash_id, slash_record)?;
+            }
+
             Ok(())
         }
 
@@ -571,7 +648,7 @@ pub mod pallet {
             ensure_root(origin)?;
             let block_number = frame_system::Pallet::<T>::block_number();
 
-            let mut slash_record =
+            let slash_record =
                 PendingSlashes::<T>::get(slash_id).ok_or(Error::<T>::SlashNotFound)?;
 
             ensure!(!slash_record.applied, Error::<T>::SlashAlreadyApplied);
@@ -584,53 +661,7 @@ pub mod pallet {
                 Error::<T>::UnbondingPeriodNotElapsed
             );
 
-            let info = Validators::<T>::get(slash_record.validator)
-                .ok_or(Error::<T>::ValidatorNotFound)?;
-
-            let (slash_imbalance, _remainder) =
-                T::Currency::slash_reserved(&info.controller, slash_record.amount);
-
-            let new_stake = info.stake.saturating_sub(slash_record.amount);
-            TotalStake::<T>::mutate(|total| {
-                *total = total.saturating_sub(slash_record.amount);
-            });
-
-            let mut info_mut = info;
-            info_mut.stake = new_stake;
-            Validators::<T>::insert(slash_record.validator, info_mut);
-
-            slash_record.applied = true;
-            PendingSlashes::<T>::insert(slash_id, slash_record.clone());
-
-            // Clear dedup entry so a new slash can be created for this violation type
-            SlashDedup::<T>::remove(slash_record.validator, slash_record.violation);
-
-            Self::deposit_event(Event::SlashApplied {
-                validator: slash_record.validator,
-                amount: slash_record.amount,
-            });
-
-            // C04: pay evidence reward from slash imbalance, not by minting
-            // new tokens. Split the slashed amount into reward + burn.
-            if let Some(ref reporter) = slash_record.reporter {
-                let reward = Self::calculate_evidence_reward(slash_record.amount);
-                if reward > BalanceOf::<T>::zero() {
-                    let (reward_imbalance, burn_imbalance) = slash_imbalance.split(reward);
-                    T::Currency::resolve_creating(reporter, reward_imbalance);
-                    drop(burn_imbalance);
-
-                    Self::deposit_event(Event::EvidenceRewardPaid {
-                        reporter: reporter.clone(),
-                        amount: reward,
-                    });
-                } else {
-                    drop(slash_imbalance);
-                }
-            } else {
-                drop(slash_imbalance);
-            }
-
-            Ok(())
+            Self::execute_slash(slash_id, slash_record)
         }
 
         /// Report evidence of a validator violation.
@@ -747,6 +778,132 @@ pub mod pallet {
 
             Ok(())
         }
+
+        /// Cancel a pending unbonding and return to the active set without
+        /// re-registering. The remaining stake must still meet `MinStake` and
+        /// the stake ratio cap.
+        #[pallet::call_index(9)]
+        #[pallet::weight(T::WeightInfo::rebond())]
+        pub fn rebond(origin: OriginFor<T>) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+
+            let validator_id =
+                ValidatorByController::<T>::get(&who).ok_or(Error::<T>::ValidatorNotFound)?;
+            let mut info =
+                Validators::<T>::get(validator_id).ok_or(Error::<T>::ValidatorNotFound)?;
+
+            ensure!(
+                info.status == ValidatorStatus::Unbonding,
+                Error::<T>::NotUnbonding
+            );
+            ensure!(
+                info.stake >= T::MinStake::get(),
+                Error::<T>::InsufficientStake
+            );
+            // The stake is still counted in TotalStake while unbonding.
+            Self::ensure_stake_ratio_valid(info.stake, BalanceOf::<T>::zero())?;
+
+            info.status = ValidatorStatus::Active;
+            info.unbonding_at = None;
+            Validators::<T>::insert(validator_id, info);
+
+            ActiveValidatorCount::<T>::mutate(|count| {
+                *count = count.saturating_add(1);
+            });
+
+            Self::deposit_event(Event::ValidatorRebonded {
+                validator: validator_id,
+            });
+
+            Ok(())
+        }
+
+        /// Set the share of rewards the caller's validator keeps before the
+        /// remainder is shared with its nominators.
+        #[pallet::call_index(10)]
+        #[pallet::weight(T::WeightInfo::set_commission())]
+        pub fn set_commission(origin: OriginFor<T>, commission: Perbill) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+
+            ensure!(
+                commission <= T::MaxCommission::get(),
+                Error::<T>::CommissionTooHigh
+            );
+            let validator_id =
+                ValidatorByController::<T>::get(&who).ok_or(Error::<T>::ValidatorNotFound)?;
+            Validators::<T>::try_mutate(validator_id, |info| -> DispatchResult {
+                let info = info.as_mut().ok_or(Error::<T>::ValidatorNotFound)?;
+                info.commission = commission;
+                Ok(())
+            })?;
+
+            Self::deposit_event(Event::CommissionSet {
+                validator: validator_id,
+                commission,
+            });
+
+            Ok(())
+        }
+
+        /// Move the caller's validator to `new_controller`, keeping its id, stake,
+        /// status and history. The caller's actor must have a pending lifecycle key
+        /// rotation whose new key hash is the blake2-256 of the encoded
+        /// `new_controller`, and the rotation is completed here. The reserved stake
+        /// moves to `new_controller`, which must already exist; the recorded stake
+        /// becomes whatever reserve actually moved.
+        #[pallet::call_index(11)]
+        #[pallet::weight(T::WeightInfo::rotate_validator_key())]
+        pub fn rotate_validator_key(
+            origin: OriginFor<T>,
+            new_controller: T::AccountId,
+        ) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+
+            let validator_id =
+                ValidatorByController::<T>::get(&who).ok_or(Error::<T>::ValidatorNotFound)?;
+            ensure!(
+                ValidatorByController::<T>::get(&new_controller).is_none(),
+                Error::<T>::ControllerAlreadyUsed
+            );
+            let mut info =
+                Validators::<T>::get(validator_id).ok_or(Error::<T>::ValidatorNotFound)?;
+
+            let actor = seveny_primitives::crypto::derive_actor_id(&who.encode());
+            let new_key_hash = H256(sp_io::hashing::blake2_256(&new_controller.encode()));
+            ensure!(
+                T::KeyRotation::is_rotation_pending(actor, new_key_hash),
+                Error::<T>::KeyRotationNotPending
+            );
+
+            // Move the reserved stake, net of any applied slashes, to the new controller.
+            let remainder = T::Currency::repatriate_reserved(
+                &who,
+                &new_controller,
+                info.stake,
+                BalanceStatus::Reserved,
+            )?;
+            if !remainder.is_zero() {
+                info.stake = info.stake.saturating_sub(remainder);
+                TotalStake::<T>::mutate(|total| *total = total.saturating_sub(remainder));
+            }
+            ensure!(
+                T::KeyRotation::complete_rotation(actor, new_key_hash),
+                Error::<T>::KeyRotationNotPending
+            );
+
+            info.controller = new_controller.clone();
+            Validators::<T>::insert(validator_id, info);
+            ValidatorByController::<T>::remove(&who);
+            ValidatorByController::<T>::insert(&new_controller, validator_id);
+
+            Self::deposit_event(Event::ValidatorKeyRotated {
+                validator: validator_id,
+                old_controller: who,
+                new_controller,
+            });
+
+            Ok(())
+        }
     }
 
     impl<T: Config> Pallet<T> {
@@ -781,6 +938,56 @@ pub mod pallet {
             Ok(())
         }
 
+        /// Seize a recorded slash from the validator's reserved stake, mark it
+        /// applied and pay any evidence reward from the slashed funds.
+        fn execute_slash(slash_id: u64, mut slash_record: SlashRecord<T>) -> DispatchResult {
+            let info = Validators::<T>::get(slash_record.validator)
+                .ok_or(Error::<T>::ValidatorNotFound)?;
+
+            let (slash_imbalance, _remainder) =
+                T::Currency::slash_reserved(&info.controller, slash_record.amount);
+
+            let new_stake = info.stake.saturating_sub(slash_record.amount);
+            TotalStake::<T>::mutate(|total| {
+                *total = total.saturating_sub(slash_record.amount);
+            });
+
+            let mut info_mut = info;
+            info_mut.stake = new_stake;
+            Validators::<T>::insert(slash_record.validator, info_mut);
+
+            slash_record.applied = true;
+            PendingSlashes::<T>::insert(slash_id, slash_record.clone());
+
+            // Clear dedup entry so a new slash can be created for this violation type
+            SlashDedup::<T>::remove(slash_record.validator, slash_record.violation);
+
+            Self::deposit_event(Event::SlashApplied {
+                validator: slash_record.validator,
+                amount: slash_record.amount,
+            });
+
+            // C04: pay evidence reward from slash imbalance, not by minting
+            // new tokens. The remainder goes to the configured slash destination.
+            let mut remainder = slash_imbalance;
+            if let Some(ref reporter) = slash_record.reporter {
+                let reward = Self::calculate_evidence_reward(slash_record.amount);
+                if reward > BalanceOf::<T>::zero() {
+                    let (reward_imbalance, rest) = remainder.split(reward);
+                    T::Currency::resolve_creating(reporter, reward_imbalance);
+                    remainder = rest;
+
+                    Self::deposit_event(Event::EvidenceRewardPaid {
+                        reporter: reporter.clone(),
+                        amount: reward,
+                    });
+                }
+            }
+            T::OnSlash::on_unbalanced(remainder);
+
+            Ok(())
+        }
+
         fn get_slash_percentage(violation: &ViolationType) -> Perbill {
             match violation {
                 ViolationType::Minor => SLASH_MINOR,
@@ -829,6 +1036,52 @@ pub mod pallet {
                 .collect()
         }
 
+        /// Split `reward` earned by a validator: its commission comes off the top
+        /// and the remainder is shared between the validator's own stake and each
+        /// nominator's backing, pro rata. Rounding dust goes to the validator.
+        ///
+        /// Returns the validator's payout and each nominator's, in input order,
+        /// or `None` for an unknown validator. Nothing is transferred: the caller
+        /// supplies the nominators' backing and pays out the returned amounts.
+        pub fn split_reward(
+            validator: ValidatorId,
+            reward: BalanceOf<T>,
+            nominators: &[(T::AccountId, BalanceOf<T>)],
+        ) -> Option<RewardSplit<T>> {
+            let info = Validators::<T>::get(validator)?;
+            let commission = info.commission.mul_floor(reward);
+            let shared: u128 = reward.saturating_sub(commission).saturated_into();
+            let backing: u128 = nominators
+                .iter()
+                .fold(info.stake, |total, (_, stake)| total.saturating_add(*stake))
+                .saturated_into();
+
+            let share_of = |stake: BalanceOf<T>| -> BalanceOf<T> {
+                if backing == 0 {
+                    return BalanceOf::<T>::zero();
+                }
+                multiply_by_rational_with_rounding(
+                    shared,
+                    stake.saturated_into(),
+                    backing,
+                    Rounding::Down,
+                )
+                .unwrap_or(0)
+                .saturated_into()
+            };
+            let payouts: Vec<(T::AccountId, BalanceOf<T>)> = nominators
+                .iter()
+                .map(|(who, stake)| (who.clone(), share_of(*stake)))
+                .collect();
+            let paid = payouts
+                .iter()
+                .fold(BalanceOf::<T>::zero(), |total, (_, amount)| {
+                    total.saturating_add(*amount)
+                });
+
+            Some((reward.saturating_sub(paid), payouts))
+        }
+
         pub fn get_stake_ratio(validator: ValidatorId) -> Option<(BalanceOf<T>, BalanceOf<T>)> {
             let info = Validators::<T>::get(validator)?;
             let total = TotalStake::<T>::get();
@@ -844,5 +1097,13 @@ pub mod pallet {
         fn is_validator_active(validator_id: ValidatorId) -> bool {
             Self::is_validator_active(validator_id)
         }
+
+        fn active_validator_count() -> u32 {
+            ActiveValidatorCount::<T>::get()
+        }
+
+        fn active_validators() -> Vec<ValidatorId> {
+            Self::get_active_validators()
+        }
     }
 }
@@ -3,17 +3,20 @@
 use crate::{self as pallet_validator, Error, Event, ValidatorStatus};
 use frame_support::{
     assert_noop, assert_ok, derive_impl, parameter_types,
-    traits::{ConstU32, ConstU64},
+    traits::{ConstU32, ConstU64, Imbalance, OnUnbalanced, ReservableCurrency},
 };
 use frame_system as system;
 use parity_scale_codec::Encode;
-use seveny_primitives::types::{ValidatorId, ViolationType};
+use seveny_primitives::constants::{SLASH_CRITICAL, SLASH_MODERATE};
+use seveny_primitives::traits::KeyRotationProvider;
+use seveny_primitives::types::{ActorId, ValidatorId, ViolationType};
 use sp_arithmetic::Perbill;
 use sp_core::H256;
 use sp_runtime::{
     traits::{BlakeTwo256, IdentityLookup},
     BuildStorage,
 };
+use std::cell::{Cell, RefCell};
 
 type Block = frame_system::mocking::MockBlock<Test>;
 
@@ -74,16 +77,63 @@ parameter_types! {
     pub const MinValidators: u32 = 3;
     pub const BondingDuration: u64 = 10;
     pub const SlashDeferDuration: u64 = 5;
+    pub static ImmediateCriticalSlash: bool = false;
+    pub const MaxCommission: Perbill = Perbill::from_percent(50);
+}
+
+thread_local! {
+    static SLASH_POT: Cell<u64> = const { Cell::new(0) };
+    static PENDING_ROTATIONS: RefCell<Vec<(ActorId, H256)>> = const { RefCell::new(Vec::new()) };
+}
+
+/// Records the amount of every slashed imbalance it receives.
+pub struct MockSlashPot;
+impl OnUnbalanced<pallet_validator::NegativeImbalanceOf<Test>> for MockSlashPot {
+    fn on_nonzero_unbalanced(amount: pallet_validator::NegativeImbalanceOf<Test>) {
+        SLASH_POT.with(|pot| pot.set(pot.get() + amount.peek()));
+    }
+}
+
+fn slash_pot() -> u64 {
+    SLASH_POT.with(|pot| pot.get())
+}
+
+/// Treats the rotations registered with `start_rotation` as pending.
+pub struct MockKeyRotation;
+impl KeyRotationProvider for MockKeyRotation {
+    fn is_rotation_pending(actor: ActorId, new_key_hash: H256) -> bool {
+        PENDING_ROTATIONS.with(|p| p.borrow().contains(&(actor, new_key_hash)))
+    }
+
+    fn complete_rotation(actor: ActorId, new_key_hash: H256) -> bool {
+        PENDING_ROTATIONS.with(|p| {
+            let mut pending = p.borrow_mut();
+            let before = pending.len();
+            pending.retain(|r| *r != (actor, new_key_hash));
+            pending.len() < before
+        })
+    }
+}
+
+/// Start a lifecycle rotation from `old_controller`'s key to `new_controller`.
+fn start_rotation(old_controller: u64, new_controller: u64) {
+    let actor = seveny_primitives::crypto::derive_actor_id(&old_controller.encode());
+    let new_key_hash = H256(sp_io::hashing::blake2_256(&new_controller.encode()));
+    PENDING_ROTATIONS.with(|p| p.borrow_mut().push((actor, new_key_hash)));
 }
 
 impl pallet_validator::Config for Test {
     type WeightInfo = ();
     type Currency = Balances;
+    type OnSlash = MockSlashPot;
     type MinStake = MinStake;
     type MaxValidators = MaxValidators;
     type MinValidators = MinValidators;
     type BondingDuration = BondingDuration;
     type SlashDeferDuration = SlashDeferDuration;
+    type ImmediateCriticalSlash = ImmediateCriticalSlash;
+    type MaxCommission = MaxCommission;
+    type KeyRotation = MockKeyRotation;
 }
 
 fn new_test_ext() -> sp_io::TestExternalities {
@@ -547,6 +597,46 @@ fn apply_slash_success() {
     });
 }
 
+#[test]
+fn apply_slash_routes_funds_to_slash_destination() {
+    new_test_ext_with_validators().execute_with(|| {
+        let validator_id = account_to_validator(1);
+        let initial_stake = Validator::validator_stake(validator_id);
+        assert_ok!(Validator::slash_validator(
+            RuntimeOrigin::root(),
+            validator_id,
+            ViolationType::Minor
+        ));
+        run_to_block(7);
+        assert_ok!(Validator::apply_slash(RuntimeOrigin::root(), 0));
+
+        let slash_amount = Perbill::from_percent(5).mul_floor(initial_stake);
+        assert_eq!(slash_pot(), slash_amount);
+        assert_eq!(Balances::reserved_balance(1), initial_stake - slash_amount);
+    });
+}
+
+#[test]
+fn slash_destination_receives_remainder_after_evidence_reward() {
+    new_test_ext_with_validators().execute_with(|| {
+        let validator_id = account_to_validator(1);
+        let initial_stake = Validator::validator_stake(validator_id);
+
+        assert_ok!(Validator::report_evidence(
+            RuntimeOrigin::signed(7),
+            validator_id,
+            ViolationType::Critical
+        ));
+        run_to_block(7);
+        let slash_id = Validator::slash_count().saturating_sub(1);
+        assert_ok!(Validator::apply_slash(RuntimeOrigin::root(), slash_id));
+
+        let slash_amount = Perbill::from_percent(100).mul_floor(initial_stake);
+        let reward = core::cmp::min(slash_amount / 10, 1000);
+        assert_eq!(slash_pot(), slash_amount - reward);
+    });
+}
+
 #[test]
 fn apply_slash_defer_not_elapsed() {
     new_test_ext_with_validators().execute_with(|| {
@@ -977,3 +1067,397 @@ fn stake_ratio_enforced_at_3_plus_validators() {
         );
     });
 }
+
+// ===================================================================
+// Rebond Tests
+// ===================================================================
+
+#[test]
+fn rebond_within_unbonding_window_restores_active() {
+    new_test_ext_with_validators().execute_with(|| {
+        assert_ok!(Validator::register_validator(
+            RuntimeOrigin::signed(7),
+            2000
+        ));
+        run_to_block(12);
+        assert_ok!(Validator::activate_validator(RuntimeOrigin::signed(7)));
+        let active_count = Validator::active_validator_count();
+
+        assert_ok!(Validator::deactivate_validator(RuntimeOrigin::signed(7)));
+        assert_eq!(Validator::active_validator_count(), active_count - 1);
+
+        run_to_block(15);
+        assert_ok!(Validator::rebond(RuntimeOrigin::signed(7)));
+
+        let validator_id = account_to_validator(7);
+        let info = Validator::validators(validator_id).expect("validator should exist");
+        assert_eq!(info.status, ValidatorStatus::Active);
+        assert_eq!(info.unbonding_at, None);
+        assert_eq!(Validator::active_validator_count(), active_count);
+        System::assert_last_event(RuntimeEvent::Validator(Event::ValidatorRebonded {
+            validator: validator_id,
+        }));
+
+        assert_noop!(
+            Validator::rebond(RuntimeOrigin::signed(7)),
+            Error::<Test>::NotUnbonding
+        );
+    });
+}
+
+#[test]
+fn rebond_rejected_after_stake_withdrawn() {
+    new_test_ext_with_validators().execute_with(|| {
+        assert_ok!(Validator::register_validator(
+            RuntimeOrigin::signed(7),
+            2000
+        ));
+        run_to_block(12);
+        assert_ok!(Validator::activate_validator(RuntimeOrigin::signed(7)));
+        assert_ok!(Validator::deactivate_validator(RuntimeOrigin::signed(7)));
+
+        run_to_block(23);
+        assert_ok!(Validator::withdraw_stake(RuntimeOrigin::signed(7)));
+
+        assert_noop!(
+            Validator::rebond(RuntimeOrigin::signed(7)),
+            Error::<Test>::ValidatorNotFound
+        );
+    });
+}
+
+// ===================================================================
+// Immediate Critical Slash Tests
+// ===================================================================
+
+#[test]
+fn critical_slash_applied_immediately_under_flag() {
+    ImmediateCriticalSlash::set(true);
+    new_test_ext_with_validators().execute_with(|| {
+        let validator_id = account_to_validator(1);
+        let initial_stake = Validator::validator_stake(validator_id);
+        let initial_reserved = Balances::reserved_balance(1);
+        let initial_total = Validator::total_stake();
+        assert_eq!(initial_reserved, initial_stake);
+
+        assert_ok!(Validator::slash_validator(
+            RuntimeOrigin::root(),
+            validator_id,
+            ViolationType::Critical
+        ));
+
+        let slash_amount = SLASH_CRITICAL.mul_floor(initial_stake);
+        let pending = Validator::pending_slashes(0).expect("slash should exist");
+        assert!(pending.applied);
+        assert_eq!(
+            Validator::validator_stake(validator_id),
+            initial_stake - slash_amount
+        );
+        assert_eq!(Validator::total_stake(), initial_total - slash_amount);
+        assert_eq!(
+            Balances::reserved_balance(1),
+            initial_reserved - slash_amount
+        );
+        assert_eq!(slash_pot(), slash_amount);
+        assert_eq!(
+            Validator::validators(validator_id)
+                .expect("validator should exist")
+                .status,
+            ValidatorStatus::Slashed
+        );
+        System::assert_has_event(RuntimeEvent::Validator(Event::SlashApplied {
+            validator: validator_id,
+            amount: slash_amount,
+        }));
+        assert!(!System::events().iter().any(|r| matches!(
+            r.event,
+            RuntimeEvent::Validator(Event::SlashDeferred { .. })
+        )));
+
+        assert_noop!(
+            Validator::apply_slash(RuntimeOrigin::root(), 0),
+            Error::<Test>::SlashAlreadyApplied
+        );
+    });
+    ImmediateCriticalSlash::set(false);
+}
+
+#[test]
+fn lesser_slash_still_deferred_under_flag() {
+    ImmediateCriticalSlash::set(true);
+    new_test_ext_with_validators().execute_with(|| {
+        let validator_id = account_to_validator(1);
+        let initial_stake = Validator::validator_stake(validator_id);
+
+        assert_ok!(Validator::slash_validator(
+            RuntimeOrigin::root(),
+            validator_id,
+            ViolationType::Moderate
+        ));
+
+        let pending = Validator::pending_slashes(0).expect("slash should exist");
+        assert!(!pending.applied);
+        assert_eq!(Validator::validator_stake(validator_id), initial_stake);
+        assert_eq!(slash_pot(), 0);
+
+        run_to_block(7);
+        assert_ok!(Validator::apply_slash(RuntimeOrigin::root(), 0));
+        assert_eq!(
+            Validator::validator_stake(validator_id),
+            initial_stake - SLASH_MODERATE.mul_floor(initial_stake)
+        );
+    });
+    ImmediateCriticalSlash::set(false);
+}
+
+#[test]
+fn critical_slash_deferred_without_flag() {
+    new_test_ext_with_validators().execute_with(|| {
+        let validator_id = account_to_validator(1);
+        let initial_stake = Validator::validator_stake(validator_id);
+
+        assert_ok!(Validator::slash_validator(
+            RuntimeOrigin::root(),
+            validator_id,
+            ViolationType::Critical
+        ));
+
+        assert!(
+            !Validator::pending_slashes(0)
+                .expect("slash should exist")
+                .applied
+        );
+        assert_eq!(Validator::validator_stake(validator_id), initial_stake);
+    });
+}
+
+// ===================================================================
+// Commission Tests
+// ===================================================================
+
+#[test]
+fn set_commission_bounded_by_max() {
+    new_test_ext_with_validators().execute_with(|| {
+        let validator_id = account_to_validator(1);
+
+        assert_noop!(
+            Validator::set_commission(RuntimeOrigin::signed(1), Perbill::from_percent(51)),
+            Error::<Test>::CommissionTooHigh
+        );
+        assert_noop!(
+            Validator::set_commission(RuntimeOrigin::signed(9), Perbill::from_percent(20)),
+            Error::<Test>::ValidatorNotFound
+        );
+        assert_ok!(Validator::set_commission(
+            RuntimeOrigin::signed(1),
+            Perbill::from_percent(20)
+        ));
+
+        let info = Validator::validators(validator_id).expect("validator should exist");
+        assert_eq!(info.commission, Perbill::from_percent(20));
+        System::assert_last_event(
+            Event::CommissionSet {
+                validator: validator_id,
+                commission: Perbill::from_percent(20),
+            }
+            .into(),
+        );
+    });
+}
+
+#[test]
+fn reward_split_takes_commission_then_shares_by_stake() {
+    new_test_ext_with_validators().execute_with(|| {
+        let validator_id = account_to_validator(1);
+        assert_ok!(Validator::set_commission(
+            RuntimeOrigin::signed(1),
+            Perbill::from_percent(20)
+        ));
+
+        // 200 commission; the other 800 over 10_000 own + 40_000 nominated stake.
+        let (validator_payout, nominator_payouts) =
+            Validator::split_reward(validator_id, 1_000, &[(20, 30_000), (21, 10_000)])
+                .expect("validator should exist");
+
+        assert_eq!(validator_payout, 200 + 160);
+        assert_eq!(nominator_payouts, vec![(20, 480), (21, 160)]);
+
+        assert_eq!(
+            Validator::split_reward(account_to_validator(9), 1_000, &[]),
+            None
+        );
+    });
+}
+
+#[test]
+fn reward_split_without_commission_or_nominators() {
+    new_test_ext_with_validators().execute_with(|| {
+        let validator_id = account_to_validator(1);
+
+        let (validator_payout, nominator_payouts) =
+            Validator::split_reward(validator_id, 1_000, &[]).expect("validator should exist");
+        assert_eq!(validator_payout, 1_000);
+        assert!(nominator_payouts.is_empty());
+
+        // Rounding dust stays with the validator.
+        let (validator_payout, nominator_payouts) =
+            Validator::split_reward(validator_id, 100, &[(20, 20_000)])
+                .expect("validator should exist");
+        assert_eq!(nominator_payouts, vec![(20, 66)]);
+        assert_eq!(validator_payout, 34);
+    });
+}
+
+// ===================================================================
+// Key Rotation Tests
+// ===================================================================
+
+#[test]
+fn key_rotation_moves_validator_to_new_controller() {
+    new_test_ext_with_validators().execute_with(|| {
+        assert_ok!(Validator::register_validator(
+            RuntimeOrigin::signed(7),
+            5_000
+        ));
+        assert_ok!(Validator::force_activate_validator(
+            RuntimeOrigin::root(),
+            7
+        ));
+        let validator_id = account_to_validator(7);
+        let total_stake = Validator::total_stake();
+
+        start_rotation(7, 8);
+        assert_ok!(Validator::rotate_validator_key(RuntimeOrigin::signed(7), 8));
+
+        let info = Validator::validators(validator_id).expect("validator should exist");
+        assert_eq!(info.controller, 8);
+        assert_eq!(info.stake, 5_000);
+        assert_eq!(info.status, ValidatorStatus::Active);
+        assert!(Validator::is_validator_active(validator_id));
+        assert_eq!(Validator::total_stake(), total_stake);
+        assert_eq!(Validator::validator_by_controller(8), Some(validator_id));
+        assert_eq!(Validator::validator_by_controller(7), None);
+
+        assert_eq!(Balances::reserved_balance(7), 0);
+        assert_eq!(Balances::reserved_balance(8), 5_000);
+        assert_eq!(Balances::free_balance(8), 100_000);
+
+        System::assert_last_event(
+            Event::ValidatorKeyRotated {
+                validator: validator_id,
+                old_controller: 7,
+                new_controller: 8,
+            }
+            .into(),
+        );
+
+        // The old controller no longer controls the validator; the new one does.
+        assert_noop!(
+            Validator::deactivate_validator(RuntimeOrigin::signed(7)),
+            Error::<Test>::ValidatorNotFound
+        );
+        assert_ok!(Validator::set_commission(
+            RuntimeOrigin::signed(8),
+            Perbill::from_percent(10)
+        ));
+    });
+}
+
+#[test]
+fn key_rotation_consumes_lifecycle_rotation() {
+    new_test_ext_with_validators().execute_with(|| {
+        assert_ok!(Validator::register_validator(
+            RuntimeOrigin::signed(7),
+            5_000
+        ));
+        start_rotation(7, 8);
+        assert_ok!(Validator::rotate_validator_key(RuntimeOrigin::signed(7), 8));
+
+        // 8 moves on, and 7 registers again: the spent rotation cannot be replayed.
+        start_rotation(8, 10);
+        assert_ok!(Validator::rotate_validator_key(
+            RuntimeOrigin::signed(8),
+            10
+        ));
+        assert_ok!(Validator::register_validator(
+            RuntimeOrigin::signed(7),
+            5_000
+        ));
+        assert_noop!(
+            Validator::rotate_validator_key(RuntimeOrigin::signed(7), 8),
+            Error::<Test>::KeyRotationNotPending
+        );
+    });
+}
+
+#[test]
+fn key_rotation_records_stake_actually_moved() {
+    new_test_ext_with_validators().execute_with(|| {
+        assert_ok!(Validator::register_validator(
+            RuntimeOrigin::signed(7),
+            5_000
+        ));
+        let validator_id = account_to_validator(7);
+        let total_stake = Validator::total_stake();
+        Balances::unreserve(&7, 1_000);
+
+        start_rotation(7, 8);
+        assert_ok!(Validator::rotate_validator_key(RuntimeOrigin::signed(7), 8));
+
+        let info = Validator::validators(validator_id).expect("validator should exist");
+        assert_eq!(info.stake, 4_000);
+        assert_eq!(Balances::reserved_balance(8), 4_000);
+        assert_eq!(Validator::total_stake(), total_stake - 1_000);
+    });
+}
+
+#[test]
+fn key_rotation_requires_pending_lifecycle_rotation() {
+    new_test_ext_with_validators().execute_with(|| {
+        assert_noop!(
+            Validator::rotate_validator_key(RuntimeOrigin::signed(1), 8),
+            Error::<Test>::KeyRotationNotPending
+        );
+
+        // A rotation to a different key does not authorize this controller.
+        start_rotation(1, 9);
+        assert_noop!(
+            Validator::rotate_validator_key(RuntimeOrigin::signed(1), 8),
+            Error::<Test>::KeyRotationNotPending
+        );
+
+        assert_noop!(
+            Validator::rotate_validator_key(RuntimeOrigin::signed(1), 2),
+            Error::<Test>::ControllerAlreadyUsed
+        );
+        assert_noop!(
+            Validator::rotate_validator_key(RuntimeOrigin::signed(9), 8),
+            Error::<Test>::ValidatorNotFound
+        );
+    });
+}
+
+// ===================================================================
+// Genesis tests
+// ===================================================================
+
+#[test]
+#[should_panic(expected = "genesis validator 1 cannot reserve its stake of 10000")]
+fn genesis_rejects_under_endowed_validator() {
+    let mut t = system::GenesisConfig::<Test>::default()
+        .build_storage()
+        .expect("storage build failed");
+
+    pallet_balances::GenesisConfig::<Test> {
+        balances: vec![(1, 100)],
+        dev_accounts: None,
+    }
+    .assimilate_storage(&mut t)
+    .expect("balances genesis build failed");
+
+    let _ = pallet_validator::GenesisConfig::<Test> {
+        initial_validators: vec![(1, 10_000)],
+        _phantom: Default::default(),
+    }
+    .assimilate_storage(&mut t);
+}
@@ -18,6 +18,9 @@ pub trait WeightInfo {
     fn slash_validator() -> Weight;
     fn apply_slash() -> Weight;
     fn report_evidence() -> Weight;
+    fn rebond() -> Weight;
+    fn set_commission() -> Weight;
+    fn rotate_validator_key() -> Weight;
 }
 
 pub struct SubstrateWeight<T>(PhantomData<T>);
@@ -76,6 +79,28 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
             .saturating_add(T::DbWeight::get().reads(3))
             .saturating_add(T::DbWeight::get().writes(4))
     }
+
+    fn rebond() -> Weight {
+        Weight::from_parts(30_000_000, 0)
+            .saturating_add(T::DbWeight::get().reads(4))
+            .saturating_add(T::DbWeight::get().writes(2))
+    }
+
+    fn set_commission() -> Weight {
+        Weight::from_parts(20_000_000, 0)
+            .saturating_add(T::DbWeight::get().reads(2))
+            .saturating_add(T::DbWeight::get().writes(1))
+    }
+
+    fn rotate_validator_key() -> Weight {
+        // Reads: ValidatorByController x2, Validators, KeyRotations, both accounts,
+        //        TotalStake, Actors
+        // Writes: Validators, ValidatorByController x2, both accounts, TotalStake,
+        //         KeyRotations, LastRotationAt, Actors
+        Weight::from_parts(45_000_000, 0)
+            .saturating_add(T::DbWeight::get().reads(8))
+            .saturating_add(T::DbWeight::get().writes(9))
+    }
 }
 
 impl WeightInfo for () {
@@ -132,4 +157,26 @@ impl WeightInfo for () {
             .saturating_add(RocksDbWeight::get().reads(3))
             .saturating_add(RocksDbWeight::get().writes(4))
     }
+
+    fn rebond() -> Weight {
+        Weight::from_parts(30_000_000, 0)
+            .saturating_add(RocksDbWeight::get().reads(4))
+            .saturating_add(RocksDbWeight::get().writes(2))
+    }
+
+    fn set_commission() -> Weight {
+        Weight::from_parts(20_000_000, 0)
+            .saturating_add(RocksDbWeight::get().reads(2))
+            .saturating_add(RocksDbWeight::get().writes(1))
+    }
+
+    fn rotate_validator_key() -> Weight {
+        // Reads: ValidatorByController x2, Validators, KeyRotations, both accounts,
+        //        TotalStake, Actors
+        // Writes: Validators, ValidatorByController x2, both accounts, TotalStake,
+        //         KeyRotations, LastRotationAt, Actors
+        Weight::from_parts(45_000_000, 0)
+            .saturating_add(RocksDbWeight::get().reads(8))
+            .saturating_add(RocksDbWeight::get().writes(9))
+    }
 }
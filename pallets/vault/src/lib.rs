@@ -123,6 +123,25 @@ pub enum ShareStatus {
     Invalidated,
 }
 
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    PartialEq,
+    Eq,
+    Encode,
+    Decode,
+    parity_scale_codec::DecodeWithMemTracking,
+    TypeInfo,
+    MaxEncodedLen,
+)]
+pub enum RecoveryAbortReason {
+    /// Revealed plus still-distributed shares can no longer reach the threshold.
+    InsufficientLiveShares,
+    /// The recovery period ended before enough shares were revealed.
+    Expired,
+}
+
 #[derive(
     Clone,
     Debug,
@@ -277,6 +296,44 @@ pub struct UnlockRequest<T: Config> {
     pub completed: bool,
 }
 
+#[derive(
+    Clone,
+    Debug,
+    PartialEq,
+    Eq,
+    Encode,
+    Decode,
+    parity_scale_codec::DecodeWithMemTracking,
+    TypeInfo,
+    MaxEncodedLen,
+)]
+#[scale_info(skip_type_params(T))]
+pub struct DissolutionRequest<T: Config> {
+    pub vault: VaultId,
+    pub requester: ActorId,
+    pub approvals: u32,
+    pub requested_at: BlockNumberFor<T>,
+}
+
+/// One change of a vault's committed secret hash.
+#[derive(
+    Clone,
+    Debug,
+    PartialEq,
+    Eq,
+    Encode,
+    Decode,
+    parity_scale_codec::DecodeWithMemTracking,
+    TypeInfo,
+    MaxEncodedLen,
+)]
+#[scale_info(skip_type_params(T))]
+pub struct SecretHashRotation<T: Config> {
+    pub old_hash: H256,
+    pub new_hash: H256,
+    pub rotated_at: BlockNumberFor<T>,
+}
+
 #[allow(clippy::expect_used)] // pallet::storage macros emit expect()
 #[frame_support::pallet]
 pub mod pallet {
@@ -310,6 +367,21 @@ pub mod pallet {
 
         #[pallet::constant]
         type UnlockPeriodBlocks: Get<BlockNumberFor<Self>>;
+
+        /// Number of guardian approvals required to dissolve a vault
+        /// without root.
+        #[pallet::constant]
+        type GuardianApprovalThreshold: Get<u32>;
+
+        /// Require recoveries to be completed by a block-hash-seeded random
+        /// subset of share indices rather than any `threshold` holders.
+        #[pallet::constant]
+        type RecoveryChallenge: Get<bool>;
+
+        /// Recoveries one actor may have in flight at once. An expired recovery
+        /// keeps its slot until it is replaced on the same vault.
+        #[pallet::constant]
+        type MaxConcurrentRecoveries: Get<u32>;
     }
 
     #[pallet::storage]
@@ -390,6 +462,54 @@ pub mod pallet {
     pub type ActiveUnlocks<T: Config> =
         StorageDoubleMap<_, Blake2_128Concat, VaultId, Blake2_128Concat, H256, UnlockRequestId>;
 
+    #[pallet::storage]
+    #[pallet::getter(fn dissolution_requests)]
+    pub type DissolutionRequests<T: Config> =
+        StorageMap<_, Blake2_128Concat, VaultId, DissolutionRequest<T>>;
+
+    #[pallet::storage]
+    #[pallet::getter(fn dissolution_approvals)]
+    pub type DissolutionApprovals<T: Config> =
+        StorageDoubleMap<_, Blake2_128Concat, VaultId, Blake2_128Concat, ActorId, ()>;
+
+    /// Next share index to hand out per vault. Only ever increases, so indices
+    /// stay unique and stable when members are removed. Vaults created before
+    /// this existed fall back to `member_count`, which was contiguous then.
+    #[pallet::storage]
+    #[pallet::getter(fn next_share_index)]
+    pub type NextShareIndex<T: Config> = StorageMap<_, Blake2_128Concat, VaultId, u32>;
+
+    /// Secret hash rotations per vault, keyed by rotation sequence number.
+    #[pallet::storage]
+    #[pallet::getter(fn secret_hash_history)]
+    pub type SecretHashHistory<T: Config> =
+        StorageDoubleMap<_, Blake2_128Concat, VaultId, Twox64Concat, u32, SecretHashRotation<T>>;
+
+    /// Number of secret hash rotations recorded per vault.
+    #[pallet::storage]
+    #[pallet::getter(fn secret_rotation_count)]
+    pub type SecretRotationCount<T: Config> =
+        StorageMap<_, Blake2_128Concat, VaultId, u32, ValueQuery>;
+
+    /// Share indices challenged to reveal for a vault's active recovery, sorted.
+    /// Only present while `RecoveryChallenge` is enabled.
+    #[pallet::storage]
+    #[pallet::getter(fn recovery_challenges)]
+    pub type RecoveryChallenges<T: Config> =
+        StorageMap<_, Blake2_128Concat, VaultId, BoundedVec<u32, T::MaxRingSize>>;
+
+    /// Recovery requests currently open per requesting actor. A slot is released
+    /// when its request completes, aborts, or is swept after expiring.
+    #[pallet::storage]
+    #[pallet::getter(fn actor_active_recoveries)]
+    pub type ActorActiveRecoveries<T: Config> =
+        StorageMap<_, Blake2_128Concat, ActorId, u32, ValueQuery>;
+
+    /// Raw `RecoveryRequests` key where the next recovery sweep resumes.
+    #[pallet::storage]
+    pub type RecoverySweepCursor<T: Config> =
+        StorageValue<_, BoundedVec<u8, ConstU32<128>>, OptionQuery>;
+
     #[pallet::genesis_config]
     #[derive(frame_support::DefaultNoBound)]
     pub struct GenesisConfig<T: Config> {
@@ -407,6 +527,50 @@ pub mod pallet {
         }
     }
 
+    #[pallet::hooks]
+    impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
+        fn on_initialize(n: BlockNumberFor<T>) -> Weight {
+            const MAX_RECOVERY_CHECKS_PER_BLOCK: usize = 20;
+
+            let mut iter = match RecoverySweepCursor::<T>::get() {
+                Some(key) => RecoveryRequests::<T>::iter_from(key.into_inner()),
+                None => RecoveryRequests::<T>::iter(),
+            };
+            let recovering: Vec<(VaultId, RecoveryRequest<T>)> =
+                iter.by_ref().take(MAX_RECOVERY_CHECKS_PER_BLOCK).collect();
+            if recovering.len() < MAX_RECOVERY_CHECKS_PER_BLOCK {
+                RecoverySweepCursor::<T>::kill();
+            } else {
+                RecoverySweepCursor::<T>::put(BoundedVec::truncate_from(
+                    iter.last_raw_key().to_vec(),
+                ));
+            }
+
+            let mut aborted = 0u64;
+            for (vault_id, request) in recovering.iter() {
+                let ended = if n > request.expires_at {
+                    Self::abort_recovery(*vault_id, RecoveryAbortReason::Expired)
+                } else {
+                    Self::abort_if_unrecoverable(*vault_id, request.shares_revealed)
+                };
+                if ended {
+                    aborted = aborted.saturating_add(1);
+                }
+            }
+
+            let checked = recovering.len() as u64;
+            T::DbWeight::get()
+                .reads(
+                    checked
+                        .saturating_mul(2u64.saturating_add(T::MaxRingSize::get().into()))
+                        .saturating_add(1),
+                )
+                .saturating_add(
+                    T::DbWeight::get().writes(aborted.saturating_mul(4).saturating_add(1)),
+                )
+        }
+    }
+
     #[pallet::event]
     #[pallet::generate_deposit(pub(super) fn deposit_event)]
     pub enum Event<T: Config> {
@@ -421,6 +585,28 @@ pub mod pallet {
             member: ActorId,
             role: MemberRole,
         },
+        MemberRemoved {
+            vault_id: VaultId,
+            member: ActorId,
+        },
+        MemberRoleChanged {
+            vault_id: VaultId,
+            member: ActorId,
+            old_role: MemberRole,
+            new_role: MemberRole,
+        },
+        /// A lost member was replaced; the new member must commit a fresh share
+        MemberReplaced {
+            vault_id: VaultId,
+            old_member: ActorId,
+            new_member: ActorId,
+            share_index: u32,
+        },
+        SecretHashRotated {
+            vault_id: VaultId,
+            old_hash: H256,
+            new_hash: H256,
+        },
         VaultActivated {
             vault_id: VaultId,
         },
@@ -440,6 +626,15 @@ pub mod pallet {
         RecoveryCompleted {
             vault_id: VaultId,
         },
+        RecoveryAborted {
+            vault_id: VaultId,
+            reason: RecoveryAbortReason,
+        },
+        /// Share left unrevealed because its recovery was aborted first
+        ShareNotRevealed {
+            vault_id: VaultId,
+            share_id: ShareId,
+        },
         VaultLocked {
             vault_id: VaultId,
         },
@@ -469,6 +664,18 @@ pub mod pallet {
             request_id: UnlockRequestId,
             file_enc_hash: H256,
         },
+        DissolutionRequested {
+            vault_id: VaultId,
+            requester: ActorId,
+        },
+        DissolutionApproved {
+            vault_id: VaultId,
+            guardian: ActorId,
+            approvals_so_far: u32,
+        },
+        DissolutionCancelled {
+            vault_id: VaultId,
+        },
     }
 
     #[pallet::error]
@@ -502,6 +709,19 @@ pub mod pallet {
         MaxFilesReached,
         UnlockNotFound,
         UnlockAlreadyCompleted,
+        DissolutionAlreadyRequested,
+        DissolutionNotRequested,
+        NotGuardian,
+        /// Another member already holds the share index being assigned
+        ShareIndexCollision,
+        /// The vault owner cannot be removed
+        CannotRemoveOwner,
+        /// The share's index is not in the recovery's challenge set
+        ShareNotChallenged,
+        /// The owner's role cannot change, and no member can become owner
+        OwnerRoleImmutable,
+        /// Requester already has `MaxConcurrentRecoveries` recoveries open
+        TooManyActiveRecoveries,
     }
 
     #[pallet::call]
@@ -561,6 +781,7 @@ pub mod pallet {
 
             Vaults::<T>::insert(vault_id, vault);
             VaultMembers::<T>::insert(vault_id, owner, owner_member);
+            NextShareIndex::<T>::insert(vault_id, 1);
             ActorVaults::<T>::insert(owner, vault_id, ());
             VaultCountPerActor::<T>::mutate(owner, |count| *count = count.saturating_add(1));
 
@@ -613,11 +834,18 @@ pub mod pallet {
 
             let block_number = frame_system::Pallet::<T>::block_number();
 
+            let share_index = NextShareIndex::<T>::get(vault_id).unwrap_or(vault.member_count);
+            ensure!(
+                !VaultMembers::<T>::iter_prefix_values(vault_id)
+                    .any(|m| m.share_index == share_index),
+                Error::<T>::ShareIndexCollision
+            );
+
             let vault_member = VaultMember {
                 vault: vault_id,
                 actor: member,
                 role,
-                share_index: vault.member_count,
+                share_index,
                 joined_at: block_number,
                 share_committed: false,
             };
@@ -634,6 +862,7 @@ pub mod pallet {
 
             Vaults::<T>::insert(vault_id, vault);
             VaultMembers::<T>::insert(vault_id, member, vault_member);
+            NextShareIndex::<T>::insert(vault_id, share_index.saturating_add(1));
             ActorVaults::<T>::insert(member, vault_id, ());
             // H16: increment VaultCountPerActor for new member
             VaultCountPerActor::<T>::mutate(member, |c| *c = c.saturating_add(1));
@@ -735,7 +964,8 @@ pub mod pallet {
         }
 
         #[pallet::call_index(4)]
-        #[pallet::weight(T::WeightInfo::initiate_recovery())]
+        #[pallet::weight(T::WeightInfo::initiate_recovery()
+            .saturating_add(T::DbWeight::get().reads(T::MaxRingSize::get().saturating_mul(2).into())))]
         pub fn initiate_recovery(origin: OriginFor<T>, vault_id: VaultId) -> DispatchResult {
             let who = ensure_signed(origin)?;
 
@@ -747,7 +977,7 @@ pub mod pallet {
                 if block_number <= existing.expires_at {
                     return Err(Error::<T>::RecoveryAlreadyActive.into());
                 }
-                RecoveryRequests::<T>::remove(vault_id);
+                Self::clear_recovery(vault_id);
                 if vault.status == VaultStatus::Recovering {
                     vault.status = VaultStatus::Active;
                 }
@@ -766,6 +996,10 @@ pub mod pallet {
                 Error::<T>::NotVaultOwner
             );
             ensure!(member.share_committed, Error::<T>::InsufficientShares);
+            ensure!(
+                ActorActiveRecoveries::<T>::get(actor) < T::MaxConcurrentRecoveries::get(),
+                Error::<T>::TooManyActiveRecoveries
+            );
 
             let expires_at = block_number.saturating_add(T::RecoveryPeriodBlocks::get());
 
@@ -778,6 +1012,11 @@ pub mod pallet {
             };
 
             RecoveryRequests::<T>::insert(vault_id, request);
+            ActorActiveRecoveries::<T>::mutate(actor, |count| *count = count.saturating_add(1));
+            if T::RecoveryChallenge::get() {
+                let challenge = Self::derive_recovery_challenge(vault_id, vault.threshold);
+                RecoveryChallenges::<T>::insert(vault_id, challenge);
+            }
 
             vault.status = VaultStatus::Recovering;
             vault.last_activity = block_number;
@@ -792,7 +1031,8 @@ pub mod pallet {
         }
 
         #[pallet::call_index(5)]
-        #[pallet::weight(T::WeightInfo::reveal_share())]
+        #[pallet::weight(T::WeightInfo::reveal_share()
+            .saturating_add(T::DbWeight::get().reads(T::MaxRingSize::get().into())))]
         pub fn reveal_share(origin: OriginFor<T>, share_id: ShareId) -> DispatchResult {
             let who = ensure_signed(origin)?;
             let caller_actor = Self::account_to_actor(who);
@@ -807,10 +1047,21 @@ pub mod pallet {
                 vault.status == VaultStatus::Recovering,
                 Error::<T>::RecoveryNotActive
             );
+
+            let shares_revealed = Self::get_revealed_shares_count(vault_id);
+            if Self::abort_if_unrecoverable(vault_id, shares_revealed) {
+                Self::deposit_event(Event::ShareNotRevealed { vault_id, share_id });
+                return Ok(());
+            }
+
             ensure!(
                 share.status == ShareStatus::Distributed,
                 Error::<T>::ShareNotDistributed
             );
+            ensure!(
+                Self::is_challenged(vault_id, share.index),
+                Error::<T>::ShareNotChallenged
+            );
 
             let block_number = frame_system::Pallet::<T>::block_number();
             let recovery_complete = RecoveryRequests::<T>::try_mutate(
@@ -833,7 +1084,7 @@ pub mod pallet {
             });
 
             if recovery_complete {
-                RecoveryRequests::<T>::remove(vault_id);
+                Self::clear_recovery(vault_id);
                 Vaults::<T>::mutate(vault_id, |v| {
                     if let Some(ref mut vault) = v {
                         vault.status = VaultStatus::Active;
@@ -878,57 +1129,7 @@ pub mod pallet {
         #[pallet::weight(T::WeightInfo::dissolve_vault())]
         pub fn dissolve_vault(origin: OriginFor<T>, vault_id: VaultId) -> DispatchResult {
             ensure_root(origin)?;
-
-            Vaults::<T>::try_mutate(vault_id, |vault| -> DispatchResult {
-                let v = vault.as_mut().ok_or(Error::<T>::VaultNotFound)?;
-
-                ensure!(
-                    matches!(
-                        v.status,
-                        VaultStatus::Creating | VaultStatus::Locked | VaultStatus::Recovering
-                    ),
-                    Error::<T>::CannotDissolvActiveVault
-                );
-
-                // Decrement ActiveVaultCount when dissolving from
-                // Recovering (was incremented at activation).
-                if v.status == VaultStatus::Recovering {
-                    ActiveVaultCount::<T>::mutate(|c| *c = c.saturating_sub(1));
-                }
-
-                v.status = VaultStatus::Dissolved;
-                v.last_activity = frame_system::Pallet::<T>::block_number();
-
-                // Clean up members and decrement their VaultCountPerActor
-                for (actor, _) in VaultMembers::<T>::drain_prefix(vault_id) {
-                    ActorVaults::<T>::remove(actor, vault_id);
-                    VaultCountPerActor::<T>::mutate(actor, |c| *c = c.saturating_sub(1));
-                }
-
-                // Clean up shares
-                for (share_id, _) in VaultShares::<T>::drain_prefix(vault_id) {
-                    if let Some(share) = Shares::<T>::take(share_id) {
-                        ActorShares::<T>::remove(share.holder, share_id);
-                    }
-                }
-
-                // Clean up files
-                let _ = VaultFiles::<T>::clear_prefix(vault_id, u32::MAX, None);
-                VaultFileCount::<T>::remove(vault_id);
-
-                // Clean up unlock state
-                for (_enc_hash, request_id) in ActiveUnlocks::<T>::drain_prefix(vault_id) {
-                    UnlockRequests::<T>::remove(request_id);
-                    let _ = UnlockApprovals::<T>::clear_prefix(request_id, u32::MAX, None);
-                }
-
-                // Clean up recovery requests
-                RecoveryRequests::<T>::remove(vault_id);
-
-                Self::deposit_event(Event::VaultDissolved { vault_id });
-
-                Ok(())
-            })
+            Self::do_dissolve_vault(vault_id)
         }
 
         /// Register an encrypted file reference in a vault.
@@ -1149,6 +1350,332 @@ pub mod pallet {
 
             Ok(())
         }
+
+        /// Request guardian-approved dissolution of a vault.
+        ///
+        /// Guards: caller is the vault owner, vault is not Active
+        /// or already Dissolved, no dissolution request pending.
+        /// Guardians approve via `approve_dissolution`; the owner may withdraw
+        /// the request with `cancel_dissolution`.
+        #[pallet::call_index(11)]
+        #[pallet::weight(T::WeightInfo::request_dissolution())]
+        pub fn request_dissolution(origin: OriginFor<T>, vault_id: VaultId) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+            let caller_actor = Self::account_to_actor(who);
+
+            let vault = Vaults::<T>::get(vault_id).ok_or(Error::<T>::VaultNotFound)?;
+            ensure!(vault.owner == caller_actor, Error::<T>::NotVaultOwner);
+            ensure!(
+                matches!(
+                    vault.status,
+                    VaultStatus::Creating | VaultStatus::Locked | VaultStatus::Recovering
+                ),
+                Error::<T>::CannotDissolvActiveVault
+            );
+            ensure!(
+                !DissolutionRequests::<T>::contains_key(vault_id),
+                Error::<T>::DissolutionAlreadyRequested
+            );
+
+            let request = DissolutionRequest {
+                vault: vault_id,
+                requester: caller_actor,
+                approvals: 0,
+                requested_at: frame_system::Pallet::<T>::block_number(),
+            };
+
+            DissolutionRequests::<T>::insert(vault_id, request);
+
+            Self::deposit_event(Event::DissolutionRequested {
+                vault_id,
+                requester: caller_actor,
+            });
+
+            Ok(())
+        }
+
+        /// Approve a pending dissolution request as a guardian.
+        ///
+        /// Guards: request exists, caller is a Guardian of the vault,
+        /// caller has not already approved. Once approvals reach
+        /// `GuardianApprovalThreshold` the vault is dissolved.
+        #[pallet::call_index(12)]
+        #[pallet::weight(T::WeightInfo::approve_dissolution())]
+        pub fn approve_dissolution(origin: OriginFor<T>, vault_id: VaultId) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+            let actor = Self::account_to_actor(who);
+
+            ensure!(
+                DissolutionRequests::<T>::contains_key(vault_id),
+                Error::<T>::DissolutionNotRequested
+            );
+
+            let member =
+                VaultMembers::<T>::get(vault_id, actor).ok_or(Error::<T>::NotVaultMember)?;
+            ensure!(member.role == MemberRole::Guardian, Error::<T>::NotGuardian);
+
+            ensure!(
+                !DissolutionApprovals::<T>::contains_key(vault_id, actor),
+                Error::<T>::AlreadyApproved
+            );
+
+            DissolutionApprovals::<T>::insert(vault_id, actor, ());
+
+            let new_approvals = DissolutionRequests::<T>::try_mutate(
+                vault_id,
+                |req| -> Result<u32, DispatchError> {
+                    let r = req.as_mut().ok_or(Error::<T>::DissolutionNotRequested)?;
+                    r.approvals = r.approvals.saturating_add(1);
+                    Ok(r.approvals)
+                },
+            )?;
+
+            Self::deposit_event(Event::DissolutionApproved {
+                vault_id,
+                guardian: actor,
+                approvals_so_far: new_approvals,
+            });
+
+            if new_approvals >= T::GuardianApprovalThreshold::get() {
+                Self::do_dissolve_vault(vault_id)?;
+            }
+
+            Ok(())
+        }
+
+        /// Remove a non-owner member while the vault is still being assembled.
+        ///
+        /// The removed member's share index is retired rather than reused.
+        #[pallet::call_index(13)]
+        #[pallet::weight(T::WeightInfo::remove_member())]
+        pub fn remove_member(
+            origin: OriginFor<T>,
+            vault_id: VaultId,
+            member: ActorId,
+        ) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+            let caller_actor = Self::account_to_actor(who);
+
+            let mut vault = Vaults::<T>::get(vault_id).ok_or(Error::<T>::VaultNotFound)?;
+
+            ensure!(vault.owner == caller_actor, Error::<T>::NotVaultOwner);
+            ensure!(
+                vault.status == VaultStatus::Creating,
+                Error::<T>::VaultAlreadyActive
+            );
+            ensure!(member != vault.owner, Error::<T>::CannotRemoveOwner);
+            ensure!(
+                VaultMembers::<T>::contains_key(vault_id, member),
+                Error::<T>::NotVaultMember
+            );
+
+            vault.member_count = vault.member_count.saturating_sub(1);
+            vault.last_activity = frame_system::Pallet::<T>::block_number();
+
+            Vaults::<T>::insert(vault_id, vault);
+            VaultMembers::<T>::remove(vault_id, member);
+            ActorVaults::<T>::remove(member, vault_id);
+            VaultCountPerActor::<T>::mutate(member, |c| *c = c.saturating_sub(1));
+
+            Self::deposit_event(Event::MemberRemoved { vault_id, member });
+
+            Ok(())
+        }
+
+        /// Replace the committed secret hash of an active vault. Shares and
+        /// membership are left untouched; the change is kept in `SecretHashHistory`.
+        #[pallet::call_index(14)]
+        #[pallet::weight(T::WeightInfo::rotate_secret_hash())]
+        pub fn rotate_secret_hash(
+            origin: OriginFor<T>,
+            vault_id: VaultId,
+            new_hash: H256,
+        ) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+            let caller_actor = Self::account_to_actor(who);
+
+            let mut vault = Vaults::<T>::get(vault_id).ok_or(Error::<T>::VaultNotFound)?;
+
+            ensure!(vault.owner == caller_actor, Error::<T>::NotVaultOwner);
+            ensure!(
+                vault.status != VaultStatus::Recovering,
+                Error::<T>::RecoveryAlreadyActive
+            );
+            ensure!(
+                vault.status == VaultStatus::Active,
+                Error::<T>::VaultNotActive
+            );
+
+            let block_number = frame_system::Pallet::<T>::block_number();
+            let old_hash = vault.secret_hash;
+            vault.secret_hash = new_hash;
+            vault.last_activity = block_number;
+            Vaults::<T>::insert(vault_id, vault);
+
+            let sequence = SecretRotationCount::<T>::get(vault_id);
+            SecretHashHistory::<T>::insert(
+                vault_id,
+                sequence,
+                SecretHashRotation {
+                    old_hash,
+                    new_hash,
+                    rotated_at: block_number,
+                },
+            );
+            SecretRotationCount::<T>::insert(vault_id, sequence.saturating_add(1));
+
+            Self::deposit_event(Event::SecretHashRotated {
+                vault_id,
+                old_hash,
+                new_hash,
+            });
+
+            Ok(())
+        }
+
+        /// Change a member's role while the vault is still `Creating`. The share
+        /// index is kept.
+        #[pallet::call_index(15)]
+        #[pallet::weight(T::WeightInfo::set_member_role())]
+        pub fn set_member_role(
+            origin: OriginFor<T>,
+            vault_id: VaultId,
+            member: ActorId,
+            new_role: MemberRole,
+        ) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+            let caller_actor = Self::account_to_actor(who);
+
+            let mut vault = Vaults::<T>::get(vault_id).ok_or(Error::<T>::VaultNotFound)?;
+
+            ensure!(vault.owner == caller_actor, Error::<T>::NotVaultOwner);
+            ensure!(
+                vault.status == VaultStatus::Creating,
+                Error::<T>::VaultAlreadyActive
+            );
+
+            let mut vault_member =
+                VaultMembers::<T>::get(vault_id, member).ok_or(Error::<T>::MemberNotFound)?;
+            ensure!(
+                vault_member.role != MemberRole::Owner && new_role != MemberRole::Owner,
+                Error::<T>::OwnerRoleImmutable
+            );
+
+            let old_role = vault_member.role;
+            vault_member.role = new_role;
+            VaultMembers::<T>::insert(vault_id, member, vault_member);
+
+            vault.last_activity = frame_system::Pallet::<T>::block_number();
+            Vaults::<T>::insert(vault_id, vault);
+
+            Self::deposit_event(Event::MemberRoleChanged {
+                vault_id,
+                member,
+                old_role,
+                new_role,
+            });
+
+            Ok(())
+        }
+
+        /// Hand a lost member's share index and role to `new_member` on an active
+        /// vault. The old member's share is invalidated and the new member's only
+        /// counts once they `commit_share`.
+        #[pallet::call_index(16)]
+        #[pallet::weight(T::WeightInfo::replace_member()
+            .saturating_add(T::DbWeight::get().reads(T::MaxVaultsPerActor::get().into())))]
+        pub fn replace_member(
+            origin: OriginFor<T>,
+            vault_id: VaultId,
+            old_member: ActorId,
+            new_member: ActorId,
+        ) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+            let caller_actor = Self::account_to_actor(who);
+
+            let mut vault = Vaults::<T>::get(vault_id).ok_or(Error::<T>::VaultNotFound)?;
+
+            ensure!(vault.owner == caller_actor, Error::<T>::NotVaultOwner);
+            ensure!(
+                vault.status == VaultStatus::Active,
+                Error::<T>::VaultNotActive
+            );
+            ensure!(old_member != vault.owner, Error::<T>::CannotRemoveOwner);
+            let old =
+                VaultMembers::<T>::get(vault_id, old_member).ok_or(Error::<T>::NotVaultMember)?;
+            ensure!(
+                !VaultMembers::<T>::contains_key(vault_id, new_member),
+                Error::<T>::MemberAlreadyExists
+            );
+            ensure!(
+                VaultCountPerActor::<T>::get(new_member) < T::MaxVaultsPerActor::get(),
+                Error::<T>::MaxVaultsReached
+            );
+
+            for (share_id, _) in ActorShares::<T>::iter_prefix(old_member) {
+                Shares::<T>::mutate(share_id, |share| {
+                    if let Some(ref mut sh) = share {
+                        if sh.vault == vault_id {
+                            sh.status = ShareStatus::Invalidated;
+                        }
+                    }
+                });
+            }
+
+            let block_number = frame_system::Pallet::<T>::block_number();
+            let share_index = old.share_index;
+            let replacement = VaultMember {
+                vault: vault_id,
+                actor: new_member,
+                role: old.role,
+                share_index,
+                joined_at: block_number,
+                share_committed: false,
+            };
+
+            VaultMembers::<T>::remove(vault_id, old_member);
+            ActorVaults::<T>::remove(old_member, vault_id);
+            VaultCountPerActor::<T>::mutate(old_member, |c| *c = c.saturating_sub(1));
+
+            VaultMembers::<T>::insert(vault_id, new_member, replacement);
+            ActorVaults::<T>::insert(new_member, vault_id, ());
+            VaultCountPerActor::<T>::mutate(new_member, |c| *c = c.saturating_add(1));
+
+            vault.last_activity = block_number;
+            Vaults::<T>::insert(vault_id, vault);
+
+            Self::deposit_event(Event::MemberReplaced {
+                vault_id,
+                old_member,
+                new_member,
+                share_index,
+            });
+
+            Ok(())
+        }
+
+        /// Withdraw a pending dissolution request (owner only), clearing any
+        /// guardian approvals gathered so far. Lets the owner drop a request that
+        /// can no longer pass, such as one left behind when the vault returned to
+        /// `Active`.
+        #[pallet::call_index(17)]
+        #[pallet::weight(T::WeightInfo::cancel_dissolution())]
+        pub fn cancel_dissolution(origin: OriginFor<T>, vault_id: VaultId) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+            let caller_actor = Self::account_to_actor(who);
+
+            let vault = Vaults::<T>::get(vault_id).ok_or(Error::<T>::VaultNotFound)?;
+            ensure!(vault.owner == caller_actor, Error::<T>::NotVaultOwner);
+            ensure!(
+                DissolutionRequests::<T>::take(vault_id).is_some(),
+                Error::<T>::DissolutionNotRequested
+            );
+            let _ = DissolutionApprovals::<T>::clear_prefix(vault_id, u32::MAX, None);
+
+            Self::deposit_event(Event::DissolutionCancelled { vault_id });
+
+            Ok(())
+        }
     }
 
     impl<T: Config> Pallet<T> {
@@ -1197,6 +1724,64 @@ pub mod pallet {
             Ok(())
         }
 
+        fn do_dissolve_vault(vault_id: VaultId) -> DispatchResult {
+            let mut vault = Vaults::<T>::get(vault_id).ok_or(Error::<T>::VaultNotFound)?;
+
+            ensure!(
+                matches!(
+                    vault.status,
+                    VaultStatus::Creating | VaultStatus::Locked | VaultStatus::Recovering
+                ),
+                Error::<T>::CannotDissolvActiveVault
+            );
+
+            // Decrement ActiveVaultCount when dissolving from
+            // Recovering (was incremented at activation).
+            if vault.status == VaultStatus::Recovering {
+                ActiveVaultCount::<T>::mutate(|c| *c = c.saturating_sub(1));
+            }
+
+            vault.status = VaultStatus::Dissolved;
+            vault.last_activity = frame_system::Pallet::<T>::block_number();
+            Vaults::<T>::insert(vault_id, vault);
+
+            // Clean up members and decrement their VaultCountPerActor
+            for (actor, _) in VaultMembers::<T>::drain_prefix(vault_id) {
+                ActorVaults::<T>::remove(actor, vault_id);
+                VaultCountPerActor::<T>::mutate(actor, |c| *c = c.saturating_sub(1));
+            }
+
+            // Clean up shares
+            for (share_id, _) in VaultShares::<T>::drain_prefix(vault_id) {
+                if let Some(share) = Shares::<T>::take(share_id) {
+                    ActorShares::<T>::remove(share.holder, share_id);
+                }
+            }
+
+            NextShareIndex::<T>::remove(vault_id);
+            let _ = SecretHashHistory::<T>::clear_prefix(vault_id, u32::MAX, None);
+            SecretRotationCount::<T>::remove(vault_id);
+
+            // Clean up files
+            let _ = VaultFiles::<T>::clear_prefix(vault_id, u32::MAX, None);
+            VaultFileCount::<T>::remove(vault_id);
+
+            // Clean up unlock state
+            for (_enc_hash, request_id) in ActiveUnlocks::<T>::drain_prefix(vault_id) {
+                UnlockRequests::<T>::remove(request_id);
+                let _ = UnlockApprovals::<T>::clear_prefix(request_id, u32::MAX, None);
+            }
+
+            // Clean up recovery and dissolution requests
+            Self::clear_recovery(vault_id);
+            DissolutionRequests::<T>::remove(vault_id);
+            let _ = DissolutionApprovals::<T>::clear_prefix(vault_id, u32::MAX, None);
+
+            Self::deposit_event(Event::VaultDissolved { vault_id });
+
+            Ok(())
+        }
+
         fn account_to_actor(account: T::AccountId) -> ActorId {
             seveny_primitives::crypto::derive_actor_id(&account.encode())
         }
@@ -1230,5 +1815,103 @@ pub mod pallet {
         pub fn is_recovery_active(vault_id: VaultId) -> bool {
             Vaults::<T>::get(vault_id).is_some_and(|v| v.status == VaultStatus::Recovering)
         }
+
+        /// Shares that can still count towards the active recovery: those already
+        /// revealed in it plus those still distributed and challenged.
+        pub fn live_share_count(vault_id: VaultId, shares_revealed: u32) -> u32 {
+            let challenge = RecoveryChallenges::<T>::get(vault_id);
+            let distributed = VaultShares::<T>::iter_prefix(vault_id)
+                .filter_map(|(share_id, _)| Shares::<T>::get(share_id))
+                .filter(|share| share.status == ShareStatus::Distributed)
+                .filter(|share| {
+                    challenge
+                        .as_ref()
+                        .is_none_or(|c| c.binary_search(&share.index).is_ok())
+                })
+                .count() as u32;
+            distributed.saturating_add(shares_revealed)
+        }
+
+        /// Whether a share index may be revealed in the vault's recovery. Always
+        /// true when the recovery has no challenge set.
+        pub fn is_challenged(vault_id: VaultId, share_index: u32) -> bool {
+            RecoveryChallenges::<T>::get(vault_id)
+                .is_none_or(|challenge| challenge.binary_search(&share_index).is_ok())
+        }
+
+        /// Pick `threshold` of the vault's distributed share indices, seeded by
+        /// the parent block hash so the subset is unknown until initiation.
+        fn derive_recovery_challenge(
+            vault_id: VaultId,
+            threshold: u32,
+        ) -> BoundedVec<u32, T::MaxRingSize> {
+            let block_number = frame_system::Pallet::<T>::block_number();
+            let parent_hash =
+                frame_system::Pallet::<T>::block_hash(block_number.saturating_sub(1u32.into()));
+            let seed = sp_core::blake2_256(&(parent_hash, vault_id, block_number).encode());
+
+            let mut indices: Vec<u32> = VaultShares::<T>::iter_prefix(vault_id)
+                .filter_map(|(share_id, _)| Shares::<T>::get(share_id))
+                .filter(|share| share.status == ShareStatus::Distributed)
+                .map(|share| share.index)
+                .collect();
+            indices.sort_unstable();
+
+            // Partial Fisher-Yates: the first `threshold` slots are the challenge.
+            let picks = (threshold as usize).min(indices.len());
+            for i in 0..picks {
+                let draw = sp_core::blake2_256(&(seed, i as u32).encode());
+                let word = u32::from_le_bytes([draw[0], draw[1], draw[2], draw[3]]);
+                let j = i.saturating_add(word as usize % indices.len().saturating_sub(i));
+                indices.swap(i, j);
+            }
+            indices.truncate(picks);
+            indices.sort_unstable();
+
+            BoundedVec::truncate_from(indices)
+        }
+
+        /// Abort the vault's recovery and return it to `Active` if the live
+        /// shares can no longer reach the threshold. Returns whether it aborted.
+        fn abort_if_unrecoverable(vault_id: VaultId, shares_revealed: u32) -> bool {
+            let Some(vault) = Vaults::<T>::get(vault_id) else {
+                return false;
+            };
+            if vault.status != VaultStatus::Recovering
+                || Self::live_share_count(vault_id, shares_revealed) >= vault.threshold
+            {
+                return false;
+            }
+            Self::abort_recovery(vault_id, RecoveryAbortReason::InsufficientLiveShares)
+        }
+
+        /// Drop the vault's recovery and return a recovering vault to `Active`.
+        /// Returns whether there was a recovery to abort.
+        fn abort_recovery(vault_id: VaultId, reason: RecoveryAbortReason) -> bool {
+            if !RecoveryRequests::<T>::contains_key(vault_id) {
+                return false;
+            }
+            Self::clear_recovery(vault_id);
+            Vaults::<T>::mutate(vault_id, |v| {
+                if let Some(vault) = v.as_mut().filter(|v| v.status == VaultStatus::Recovering) {
+                    vault.status = VaultStatus::Active;
+                    vault.last_activity = frame_system::Pallet::<T>::block_number();
+                }
+            });
+
+            Self::deposit_event(Event::RecoveryAborted { vault_id, reason });
+            true
+        }
+
+        /// Drop the vault's recovery request and challenge, releasing the
+        /// requester's slot in `ActorActiveRecoveries`.
+        fn clear_recovery(vault_id: VaultId) {
+            if let Some(request) = RecoveryRequests::<T>::take(vault_id) {
+                ActorActiveRecoveries::<T>::mutate_exists(request.requester, |count| {
+                    *count = count.map(|c| c.saturating_sub(1)).filter(|c| *c > 0);
+                });
+            }
+            RecoveryChallenges::<T>::remove(vault_id);
+        }
     }
 }
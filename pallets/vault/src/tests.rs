@@ -1,10 +1,13 @@
 #![allow(clippy::disallowed_macros, clippy::expect_used, clippy::unwrap_used)]
 
 use crate::{
-    self as pallet_vault, Error, Event, MemberRole, ShareId, ShareStatus, UnlockRequestId, VaultId,
-    VaultStatus,
+    self as pallet_vault, Error, Event, MemberRole, RecoveryAbortReason, ShareId, ShareStatus,
+    UnlockRequestId, VaultId, VaultStatus,
+};
+use frame_support::{
+    assert_noop, assert_ok, derive_impl, parameter_types,
+    traits::{ConstU32, Hooks},
 };
-use frame_support::{assert_noop, assert_ok, derive_impl, parameter_types, traits::ConstU32};
 use frame_system as system;
 use seveny_primitives::types::ActorId;
 use sp_core::H256;
@@ -56,6 +59,9 @@ parameter_types! {
     pub const MaxVaultsPerActor: u32 = 5;
     pub const MaxFilesPerVault: u32 = 3;
     pub const UnlockPeriodBlocks: u64 = 50;
+    pub const GuardianApprovalThreshold: u32 = 2;
+    pub static RecoveryChallenge: bool = false;
+    pub const MaxConcurrentRecoveries: u32 = 2;
 }
 
 impl pallet_vault::Config for Test {
@@ -67,6 +73,9 @@ impl pallet_vault::Config for Test {
     type MaxVaultsPerActor = MaxVaultsPerActor;
     type MaxFilesPerVault = MaxFilesPerVault;
     type UnlockPeriodBlocks = UnlockPeriodBlocks;
+    type GuardianApprovalThreshold = GuardianApprovalThreshold;
+    type RecoveryChallenge = RecoveryChallenge;
+    type MaxConcurrentRecoveries = MaxConcurrentRecoveries;
 }
 
 fn new_test_ext() -> sp_io::TestExternalities {
@@ -354,15 +363,19 @@ fn cannot_initiate_recovery_twice() {
 #[test]
 fn reveal_share_success() {
     new_test_ext().execute_with(|| {
-        // Owner must have committed share to initiate recovery
-        let vault_id = create_active_vault_with_shares(1, &[1]);
+        // Owner must have committed share to initiate recovery, and enough
+        // shares must be live to reach the threshold.
+        let vault_id = create_active_vault_with_shares(1, &[1, 2]);
 
         assert_ok!(Vault::initiate_recovery(RuntimeOrigin::signed(1), vault_id));
 
-        let shares = Vault::get_vault_shares(vault_id);
-        assert_ok!(Vault::reveal_share(RuntimeOrigin::signed(1), shares[0]));
+        // Share 0 was committed by account 1
+        assert_ok!(Vault::reveal_share(
+            RuntimeOrigin::signed(1),
+            ShareId::new(0)
+        ));
 
-        let share = Vault::shares(shares[0]).expect("share should exist");
+        let share = Vault::shares(ShareId::new(0)).expect("share should exist");
         assert_eq!(share.status, ShareStatus::Revealed);
         assert_eq!(Vault::get_revealed_shares_count(vault_id), 1);
     });
@@ -1586,3 +1599,952 @@ fn recovery_expiration_cleanup() {
         assert!(Vault::is_recovery_active(vault_id));
     });
 }
+
+// ===========================================================================
+// Guardian-approved dissolution tests
+// ===========================================================================
+
+/// Create a locked vault whose members are owner, owner+1 and owner+2
+/// as guardians, and owner+3 as participant.
+fn create_locked_vault_with_guardians(owner: u64) -> VaultId {
+    let owner_actor = account_to_actor(owner);
+    assert_ok!(Vault::create_vault(
+        RuntimeOrigin::signed(owner),
+        owner_actor,
+        2,
+        4,
+        H256([1u8; 32]),
+    ));
+    let vault_id = VaultId::new(0);
+
+    for (offset, role) in [
+        (1, MemberRole::Guardian),
+        (2, MemberRole::Guardian),
+        (3, MemberRole::Participant),
+    ] {
+        assert_ok!(Vault::add_member(
+            RuntimeOrigin::signed(owner),
+            vault_id,
+            account_to_actor(owner + offset),
+            role,
+        ));
+    }
+
+    assert_ok!(Vault::activate_vault(
+        RuntimeOrigin::signed(owner),
+        vault_id
+    ));
+    assert_ok!(Vault::lock_vault(RuntimeOrigin::signed(owner), vault_id));
+    vault_id
+}
+
+#[test]
+fn dissolution_succeeds_after_guardian_threshold() {
+    new_test_ext().execute_with(|| {
+        let vault_id = create_locked_vault_with_guardians(1);
+
+        assert_ok!(Vault::request_dissolution(
+            RuntimeOrigin::signed(1),
+            vault_id
+        ));
+        assert_ok!(Vault::approve_dissolution(
+            RuntimeOrigin::signed(2),
+            vault_id
+        ));
+        assert_eq!(
+            Vault::vaults(vault_id).expect("vault should exist").status,
+            VaultStatus::Locked
+        );
+
+        assert_ok!(Vault::approve_dissolution(
+            RuntimeOrigin::signed(3),
+            vault_id
+        ));
+
+        let vault = Vault::vaults(vault_id).expect("vault should exist");
+        assert_eq!(vault.status, VaultStatus::Dissolved);
+        assert!(Vault::dissolution_requests(vault_id).is_none());
+        assert!(Vault::dissolution_approvals(vault_id, account_to_actor(2)).is_none());
+        assert_eq!(Vault::vault_count_per_actor(account_to_actor(1)), 0);
+
+        System::assert_has_event(RuntimeEvent::Vault(Event::VaultDissolved { vault_id }));
+    });
+}
+
+#[test]
+fn dissolution_not_applied_with_too_few_approvals() {
+    new_test_ext().execute_with(|| {
+        let vault_id = create_locked_vault_with_guardians(1);
+
+        assert_ok!(Vault::request_dissolution(
+            RuntimeOrigin::signed(1),
+            vault_id
+        ));
+        assert_ok!(Vault::approve_dissolution(
+            RuntimeOrigin::signed(2),
+            vault_id
+        ));
+
+        // Same guardian cannot approve twice
+        assert_noop!(
+            Vault::approve_dissolution(RuntimeOrigin::signed(2), vault_id),
+            Error::<Test>::AlreadyApproved
+        );
+
+        // Participants and the owner do not count toward guardian consensus
+        assert_noop!(
+            Vault::approve_dissolution(RuntimeOrigin::signed(4), vault_id),
+            Error::<Test>::NotGuardian
+        );
+        assert_noop!(
+            Vault::approve_dissolution(RuntimeOrigin::signed(1), vault_id),
+            Error::<Test>::NotGuardian
+        );
+
+        let vault = Vault::vaults(vault_id).expect("vault should exist");
+        assert_eq!(vault.status, VaultStatus::Locked);
+        assert_eq!(
+            Vault::dissolution_requests(vault_id)
+                .expect("request should exist")
+                .approvals,
+            1
+        );
+    });
+}
+
+#[test]
+fn request_dissolution_guards() {
+    new_test_ext().execute_with(|| {
+        let vault_id = create_vault_with_members(1, 3);
+        assert_ok!(Vault::activate_vault(RuntimeOrigin::signed(1), vault_id));
+
+        assert_noop!(
+            Vault::request_dissolution(RuntimeOrigin::signed(1), vault_id),
+            Error::<Test>::CannotDissolvActiveVault
+        );
+
+        assert_ok!(Vault::lock_vault(RuntimeOrigin::signed(1), vault_id));
+
+        assert_noop!(
+            Vault::request_dissolution(RuntimeOrigin::signed(2), vault_id),
+            Error::<Test>::NotVaultOwner
+        );
+
+        assert_ok!(Vault::request_dissolution(
+            RuntimeOrigin::signed(1),
+            vault_id
+        ));
+        assert_noop!(
+            Vault::request_dissolution(RuntimeOrigin::signed(1), vault_id),
+            Error::<Test>::DissolutionAlreadyRequested
+        );
+    });
+}
+
+#[test]
+fn approve_dissolution_requires_request() {
+    new_test_ext().execute_with(|| {
+        let vault_id = create_locked_vault_with_guardians(1);
+
+        assert_noop!(
+            Vault::approve_dissolution(RuntimeOrigin::signed(2), vault_id),
+            Error::<Test>::DissolutionNotRequested
+        );
+    });
+}
+
+#[test]
+fn owner_cancels_dissolution_request() {
+    new_test_ext().execute_with(|| {
+        let vault_id = create_locked_vault_with_guardians(1);
+        assert_noop!(
+            Vault::cancel_dissolution(RuntimeOrigin::signed(1), vault_id),
+            Error::<Test>::DissolutionNotRequested
+        );
+
+        assert_ok!(Vault::request_dissolution(
+            RuntimeOrigin::signed(1),
+            vault_id
+        ));
+        assert_ok!(Vault::approve_dissolution(
+            RuntimeOrigin::signed(2),
+            vault_id
+        ));
+        assert_noop!(
+            Vault::cancel_dissolution(RuntimeOrigin::signed(2), vault_id),
+            Error::<Test>::NotVaultOwner
+        );
+
+        assert_ok!(Vault::cancel_dissolution(
+            RuntimeOrigin::signed(1),
+            vault_id
+        ));
+        assert!(Vault::dissolution_requests(vault_id).is_none());
+        System::assert_last_event(RuntimeEvent::Vault(Event::DissolutionCancelled {
+            vault_id,
+        }));
+
+        // A fresh request starts from zero approvals.
+        assert_ok!(Vault::request_dissolution(
+            RuntimeOrigin::signed(1),
+            vault_id
+        ));
+        assert_ok!(Vault::approve_dissolution(
+            RuntimeOrigin::signed(2),
+            vault_id
+        ));
+        assert_eq!(
+            Vault::dissolution_requests(vault_id)
+                .expect("request should exist")
+                .approvals,
+            1
+        );
+    });
+}
+
+// ===========================================================================
+// Recovery Abort Tests
+// ===========================================================================
+
+fn invalidate_share(share_id: ShareId) {
+    pallet_vault::Shares::<Test>::mutate(share_id, |s| {
+        s.as_mut().expect("share should exist").status = ShareStatus::Invalidated;
+    });
+}
+
+#[test]
+fn reveal_aborts_recovery_when_live_shares_below_threshold() {
+    new_test_ext().execute_with(|| {
+        let vault_id = create_active_vault_with_shares(1, &[1, 2]);
+        assert_ok!(Vault::initiate_recovery(RuntimeOrigin::signed(1), vault_id));
+
+        invalidate_share(ShareId::new(1));
+        assert_eq!(Vault::live_share_count(vault_id, 0), 1);
+
+        assert_ok!(Vault::reveal_share(
+            RuntimeOrigin::signed(1),
+            ShareId::new(0)
+        ));
+
+        System::assert_has_event(RuntimeEvent::Vault(Event::RecoveryAborted {
+            vault_id,
+            reason: RecoveryAbortReason::InsufficientLiveShares,
+        }));
+        System::assert_last_event(RuntimeEvent::Vault(Event::ShareNotRevealed {
+            vault_id,
+            share_id: ShareId::new(0),
+        }));
+        let vault = Vault::vaults(vault_id).expect("vault should exist");
+        assert_eq!(vault.status, VaultStatus::Active);
+        assert!(Vault::recovery_requests(vault_id).is_none());
+        let share = Vault::shares(ShareId::new(0)).expect("share should exist");
+        assert_eq!(share.status, ShareStatus::Distributed);
+    });
+}
+
+#[test]
+fn periodic_check_aborts_stalled_recovery() {
+    new_test_ext().execute_with(|| {
+        let vault_id = create_active_vault_with_shares(1, &[1, 2, 3]);
+        assert_ok!(Vault::initiate_recovery(RuntimeOrigin::signed(1), vault_id));
+        assert_ok!(Vault::reveal_share(
+            RuntimeOrigin::signed(1),
+            ShareId::new(0)
+        ));
+
+        // One revealed plus two distributed still reaches the threshold.
+        Vault::on_initialize(2);
+        assert!(Vault::is_recovery_active(vault_id));
+
+        invalidate_share(ShareId::new(1));
+        invalidate_share(ShareId::new(2));
+        Vault::on_initialize(3);
+
+        assert!(!Vault::is_recovery_active(vault_id));
+        assert!(Vault::recovery_requests(vault_id).is_none());
+        System::assert_has_event(RuntimeEvent::Vault(Event::RecoveryAborted {
+            vault_id,
+            reason: RecoveryAbortReason::InsufficientLiveShares,
+        }));
+    });
+}
+
+#[test]
+fn recovery_sweep_resumes_and_expires_requests() {
+    new_test_ext().execute_with(|| {
+        let template = Vault::vaults(create_active_vault(1)).expect("vault should exist");
+        let vaults: Vec<VaultId> = (1..=25)
+            .map(|id| {
+                let vault_id = VaultId::new(id);
+                // No shares and no threshold, so only expiry can end the recovery.
+                let mut vault = template.clone();
+                vault.status = VaultStatus::Recovering;
+                vault.threshold = 0;
+                crate::Vaults::<Test>::insert(vault_id, vault);
+                crate::RecoveryRequests::<Test>::insert(
+                    vault_id,
+                    crate::RecoveryRequest {
+                        vault: vault_id,
+                        requester: account_to_actor(1),
+                        shares_revealed: 0,
+                        initiated_at: 1,
+                        expires_at: 5,
+                    },
+                );
+                vault_id
+            })
+            .collect();
+        let open = || {
+            vaults
+                .iter()
+                .filter(|v| Vault::recovery_requests(**v).is_some())
+                .count()
+        };
+
+        // Unexpired requests stay open; the first 20 are checked.
+        Vault::on_initialize(5);
+        assert_eq!(open(), 25);
+
+        // The sweep resumes with the remaining 5, then wraps to the start.
+        Vault::on_initialize(6);
+        assert_eq!(open(), 20);
+        Vault::on_initialize(7);
+        assert_eq!(open(), 0);
+        assert!(vaults
+            .iter()
+            .all(|v| Vault::vaults(*v).expect("vault should exist").status == VaultStatus::Active));
+        System::assert_has_event(RuntimeEvent::Vault(Event::RecoveryAborted {
+            vault_id: vaults[0],
+            reason: RecoveryAbortReason::Expired,
+        }));
+    });
+}
+
+#[test]
+fn expired_recoveries_release_requester_slots() {
+    new_test_ext().execute_with(|| {
+        let vault_id = create_active_vault_with_shares(1, &[1, 2]);
+        let requester = account_to_actor(1);
+        let template = Vault::vaults(vault_id).expect("vault should exist");
+        for id in 1..=MaxConcurrentRecoveries::get() as u64 {
+            let abandoned = VaultId::new(id);
+            // Zero threshold keeps the recovery viable, so only expiry ends it.
+            let mut vault = template.clone();
+            vault.status = VaultStatus::Recovering;
+            vault.threshold = 0;
+            crate::Vaults::<Test>::insert(abandoned, vault);
+            crate::RecoveryRequests::<Test>::insert(
+                abandoned,
+                crate::RecoveryRequest {
+                    vault: abandoned,
+                    requester,
+                    shares_revealed: 0,
+                    initiated_at: 1,
+                    expires_at: 5,
+                },
+            );
+        }
+        crate::ActorActiveRecoveries::<Test>::insert(requester, MaxConcurrentRecoveries::get());
+
+        assert_noop!(
+            Vault::initiate_recovery(RuntimeOrigin::signed(1), vault_id),
+            Error::<Test>::TooManyActiveRecoveries
+        );
+
+        System::set_block_number(6);
+        Vault::on_initialize(6);
+        assert_eq!(Vault::actor_active_recoveries(requester), 0);
+        assert_ok!(Vault::initiate_recovery(RuntimeOrigin::signed(1), vault_id));
+    });
+}
+
+// ===========================================================================
+// Share Index Allocation
+// ===========================================================================
+
+fn share_indices(vault_id: VaultId) -> Vec<u32> {
+    let mut indices: Vec<u32> = crate::VaultMembers::<Test>::iter_prefix_values(vault_id)
+        .map(|m| m.share_index)
+        .collect();
+    indices.sort();
+    indices
+}
+
+#[test]
+fn share_indices_never_reused_after_removal() {
+    new_test_ext().execute_with(|| {
+        let vault_id = create_vault_with_members(1, 3);
+        let removed = account_to_actor(2);
+        let kept = account_to_actor(3);
+        assert_eq!(share_indices(vault_id), vec![0, 1, 2]);
+
+        assert_ok!(Vault::remove_member(
+            RuntimeOrigin::signed(1),
+            vault_id,
+            removed
+        ));
+        assert!(Vault::vault_members(vault_id, removed).is_none());
+        assert_eq!(
+            Vault::vaults(vault_id)
+                .expect("vault should exist")
+                .member_count,
+            2
+        );
+        assert_eq!(Vault::vault_count_per_actor(removed), 0);
+
+        let newcomer = account_to_actor(4);
+        assert_ok!(Vault::add_member(
+            RuntimeOrigin::signed(1),
+            vault_id,
+            newcomer,
+            MemberRole::Participant
+        ));
+
+        // Index 1 is retired; the kept member's index is unchanged
+        assert_eq!(share_indices(vault_id), vec![0, 2, 3]);
+        assert_eq!(
+            Vault::vault_members(vault_id, kept)
+                .expect("member should exist")
+                .share_index,
+            2
+        );
+        assert_eq!(Vault::next_share_index(vault_id), Some(4));
+    });
+}
+
+#[test]
+fn readding_removed_member_gets_fresh_index() {
+    new_test_ext().execute_with(|| {
+        let vault_id = create_vault_with_members(1, 3);
+        let member = account_to_actor(3);
+
+        for expected in [3, 4] {
+            assert_ok!(Vault::remove_member(
+                RuntimeOrigin::signed(1),
+                vault_id,
+                member
+            ));
+            assert_ok!(Vault::add_member(
+                RuntimeOrigin::signed(1),
+                vault_id,
+                member,
+                MemberRole::Guardian
+            ));
+            assert_eq!(
+                Vault::vault_members(vault_id, member)
+                    .expect("member should exist")
+                    .share_index,
+                expected
+            );
+        }
+        assert_eq!(share_indices(vault_id), vec![0, 1, 4]);
+    });
+}
+
+#[test]
+fn add_member_rejects_share_index_collision() {
+    new_test_ext().execute_with(|| {
+        let vault_id = create_vault_with_members(1, 3);
+        assert_ok!(Vault::remove_member(
+            RuntimeOrigin::signed(1),
+            vault_id,
+            account_to_actor(3)
+        ));
+
+        // Corrupt the counter so it points at an index already held
+        crate::NextShareIndex::<Test>::insert(vault_id, 1);
+        assert_noop!(
+            Vault::add_member(
+                RuntimeOrigin::signed(1),
+                vault_id,
+                account_to_actor(5),
+                MemberRole::Participant
+            ),
+            Error::<Test>::ShareIndexCollision
+        );
+    });
+}
+
+#[test]
+fn remove_member_guards() {
+    new_test_ext().execute_with(|| {
+        let vault_id = create_vault_with_members(1, 3);
+
+        assert_noop!(
+            Vault::remove_member(RuntimeOrigin::signed(1), vault_id, account_to_actor(1)),
+            Error::<Test>::CannotRemoveOwner
+        );
+        assert_noop!(
+            Vault::remove_member(RuntimeOrigin::signed(2), vault_id, account_to_actor(3)),
+            Error::<Test>::NotVaultOwner
+        );
+        assert_noop!(
+            Vault::remove_member(RuntimeOrigin::signed(1), vault_id, account_to_actor(9)),
+            Error::<Test>::NotVaultMember
+        );
+
+        assert_ok!(Vault::activate_vault(RuntimeOrigin::signed(1), vault_id));
+        assert_noop!(
+            Vault::remove_member(RuntimeOrigin::signed(1), vault_id, account_to_actor(3)),
+            Error::<Test>::VaultAlreadyActive
+        );
+    });
+}
+
+// ===========================================================================
+// Secret hash rotation tests
+// ===========================================================================
+
+#[test]
+fn rotate_secret_hash_on_active_vault() {
+    new_test_ext().execute_with(|| {
+        let vault_id = create_active_vault_with_shares(1, &[1, 2]);
+        let old_hash = Vault::vaults(vault_id).expect("vault").secret_hash;
+        let new_hash = H256([42u8; 32]);
+        let shares_before = Vault::get_vault_shares(vault_id);
+
+        System::set_block_number(7);
+        assert_noop!(
+            Vault::rotate_secret_hash(RuntimeOrigin::signed(2), vault_id, new_hash),
+            Error::<Test>::NotVaultOwner
+        );
+        assert_ok!(Vault::rotate_secret_hash(
+            RuntimeOrigin::signed(1),
+            vault_id,
+            new_hash
+        ));
+
+        let vault = Vault::vaults(vault_id).expect("vault");
+        assert_eq!(vault.secret_hash, new_hash);
+        assert_eq!(vault.status, VaultStatus::Active);
+        assert_eq!(Vault::get_vault_shares(vault_id), shares_before);
+
+        let entry = Vault::secret_hash_history(vault_id, 0).expect("history entry");
+        assert_eq!(entry.old_hash, old_hash);
+        assert_eq!(entry.new_hash, new_hash);
+        assert_eq!(entry.rotated_at, 7);
+        assert_eq!(Vault::secret_rotation_count(vault_id), 1);
+
+        System::assert_has_event(RuntimeEvent::Vault(Event::SecretHashRotated {
+            vault_id,
+            old_hash,
+            new_hash,
+        }));
+    });
+}
+
+#[test]
+fn rotate_secret_hash_rejected_during_recovery() {
+    new_test_ext().execute_with(|| {
+        let vault_id = create_active_vault_with_shares(1, &[1]);
+        assert_ok!(Vault::initiate_recovery(RuntimeOrigin::signed(1), vault_id));
+
+        assert_noop!(
+            Vault::rotate_secret_hash(RuntimeOrigin::signed(1), vault_id, H256([42u8; 32])),
+            Error::<Test>::RecoveryAlreadyActive
+        );
+        assert_eq!(Vault::secret_rotation_count(vault_id), 0);
+    });
+}
+
+// ===========================================================================
+// Recovery challenge tests
+// ===========================================================================
+
+type ShareHolders = Vec<(u64, ShareId)>;
+
+/// Split the vault's shares into (challenged, unchallenged) `(account, share_id)` pairs.
+fn partition_by_challenge(vault_id: VaultId) -> (ShareHolders, ShareHolders) {
+    (1..=3u64)
+        .map(|account| (account, ShareId::new(account - 1)))
+        .partition(|(_, share_id)| {
+            let share = Vault::shares(share_id).expect("share should exist");
+            Vault::is_challenged(vault_id, share.index)
+        })
+}
+
+#[test]
+fn only_challenged_shares_count_toward_recovery() {
+    RecoveryChallenge::set(true);
+    new_test_ext().execute_with(|| {
+        let vault_id = create_active_vault_with_shares(1, &[1, 2, 3]);
+        assert_ok!(Vault::initiate_recovery(RuntimeOrigin::signed(1), vault_id));
+
+        let (challenged, unchallenged) = partition_by_challenge(vault_id);
+        assert_eq!(challenged.len(), 2);
+        let (outsider, outsider_share) = unchallenged[0];
+
+        assert_noop!(
+            Vault::reveal_share(RuntimeOrigin::signed(outsider), outsider_share),
+            Error::<Test>::ShareNotChallenged
+        );
+        assert_eq!(Vault::get_revealed_shares_count(vault_id), 0);
+
+        let (account, share_id) = challenged[0];
+        assert_ok!(Vault::reveal_share(
+            RuntimeOrigin::signed(account),
+            share_id
+        ));
+        assert_eq!(Vault::get_revealed_shares_count(vault_id), 1);
+
+        let (account, share_id) = challenged[1];
+        assert_ok!(Vault::reveal_share(
+            RuntimeOrigin::signed(account),
+            share_id
+        ));
+        let vault = Vault::vaults(vault_id).expect("vault should exist");
+        assert_eq!(vault.status, VaultStatus::Active);
+        assert!(Vault::recovery_challenges(vault_id).is_none());
+    });
+    RecoveryChallenge::set(false);
+}
+
+#[test]
+fn challenge_is_threshold_sized_subset_of_distributed_indices() {
+    RecoveryChallenge::set(true);
+    new_test_ext().execute_with(|| {
+        let vault_id = create_active_vault_with_shares(1, &[1, 2, 3]);
+        assert_ok!(Vault::initiate_recovery(RuntimeOrigin::signed(1), vault_id));
+
+        let challenge = Vault::recovery_challenges(vault_id).expect("challenge should exist");
+        let mut indices: Vec<u32> = (0..3u64)
+            .map(|id| Vault::shares(ShareId::new(id)).expect("share").index)
+            .collect();
+        indices.sort();
+        assert_eq!(challenge.len(), 2);
+        assert!(challenge.windows(2).all(|w| w[0] < w[1]));
+        assert!(challenge.iter().all(|i| indices.contains(i)));
+    });
+    RecoveryChallenge::set(false);
+
+    new_test_ext().execute_with(|| {
+        let vault_id = create_active_vault_with_shares(1, &[1, 2, 3]);
+        assert_ok!(Vault::initiate_recovery(RuntimeOrigin::signed(1), vault_id));
+        // Disabled: no challenge, every index may reveal.
+        assert!(Vault::recovery_challenges(vault_id).is_none());
+        assert!(Vault::is_challenged(vault_id, 2));
+    });
+}
+
+// ===========================================================================
+// Member Role Change Tests
+// ===========================================================================
+
+#[test]
+fn promote_participant_to_guardian_keeps_share_index() {
+    new_test_ext().execute_with(|| {
+        let vault_id = create_vault_with_members(1, 3);
+        let member = account_to_actor(3);
+        let share_index = Vault::vault_members(vault_id, member)
+            .expect("member should exist")
+            .share_index;
+
+        assert_ok!(Vault::set_member_role(
+            RuntimeOrigin::signed(1),
+            vault_id,
+            member,
+            MemberRole::Guardian
+        ));
+
+        let updated = Vault::vault_members(vault_id, member).expect("member should exist");
+        assert_eq!(updated.role, MemberRole::Guardian);
+        assert_eq!(updated.share_index, share_index);
+        System::assert_last_event(RuntimeEvent::Vault(Event::MemberRoleChanged {
+            vault_id,
+            member,
+            old_role: MemberRole::Participant,
+            new_role: MemberRole::Guardian,
+        }));
+    });
+}
+
+#[test]
+fn owner_role_cannot_change() {
+    new_test_ext().execute_with(|| {
+        let vault_id = create_vault_with_members(1, 3);
+
+        assert_noop!(
+            Vault::set_member_role(
+                RuntimeOrigin::signed(1),
+                vault_id,
+                account_to_actor(1),
+                MemberRole::Guardian
+            ),
+            Error::<Test>::OwnerRoleImmutable
+        );
+        assert_noop!(
+            Vault::set_member_role(
+                RuntimeOrigin::signed(1),
+                vault_id,
+                account_to_actor(2),
+                MemberRole::Owner
+            ),
+            Error::<Test>::OwnerRoleImmutable
+        );
+    });
+}
+
+#[test]
+fn member_role_change_restricted_to_owner_before_activation() {
+    new_test_ext().execute_with(|| {
+        let vault_id = create_vault_with_members(1, 3);
+        let member = account_to_actor(2);
+
+        assert_noop!(
+            Vault::set_member_role(
+                RuntimeOrigin::signed(2),
+                vault_id,
+                member,
+                MemberRole::Guardian
+            ),
+            Error::<Test>::NotVaultOwner
+        );
+        assert_noop!(
+            Vault::set_member_role(
+                RuntimeOrigin::signed(1),
+                vault_id,
+                account_to_actor(9),
+                MemberRole::Guardian
+            ),
+            Error::<Test>::MemberNotFound
+        );
+
+        assert_ok!(Vault::activate_vault(RuntimeOrigin::signed(1), vault_id));
+        assert_noop!(
+            Vault::set_member_role(
+                RuntimeOrigin::signed(1),
+                vault_id,
+                member,
+                MemberRole::Guardian
+            ),
+            Error::<Test>::VaultAlreadyActive
+        );
+    });
+}
+
+// ===========================================================================
+// Concurrent recovery limit tests
+// ===========================================================================
+
+/// Active 2-of-3 vault owned by `owner` with members 2 and 3, where the owner
+/// and member 2 have committed shares. Returns the vault and those share ids.
+fn create_recoverable_vault(owner: u64) -> (VaultId, [ShareId; 2]) {
+    assert_ok!(Vault::create_vault(
+        RuntimeOrigin::signed(owner),
+        account_to_actor(owner),
+        2,
+        3,
+        H256([1u8; 32]),
+    ));
+    let vault_id = VaultId::new(Vault::vault_count() - 1);
+    for member in [2, 3] {
+        assert_ok!(Vault::add_member(
+            RuntimeOrigin::signed(owner),
+            vault_id,
+            account_to_actor(member),
+            MemberRole::Participant,
+        ));
+    }
+    assert_ok!(Vault::activate_vault(
+        RuntimeOrigin::signed(owner),
+        vault_id
+    ));
+
+    let first_share = ShareId::new(Vault::share_count());
+    for account in [owner, 2] {
+        assert_ok!(Vault::commit_share(
+            RuntimeOrigin::signed(account),
+            vault_id,
+            H256([account as u8; 32]),
+        ));
+    }
+    (vault_id, [first_share, ShareId::new(first_share.0 + 1)])
+}
+
+#[test]
+fn concurrent_recovery_cap_blocks_until_one_completes() {
+    new_test_ext().execute_with(|| {
+        let owner = account_to_actor(1);
+        let (first, first_shares) = create_recoverable_vault(1);
+        let (second, _) = create_recoverable_vault(1);
+        let (third, _) = create_recoverable_vault(1);
+
+        assert_ok!(Vault::initiate_recovery(RuntimeOrigin::signed(1), first));
+        assert_ok!(Vault::initiate_recovery(RuntimeOrigin::signed(1), second));
+        assert_eq!(Vault::actor_active_recoveries(owner), 2);
+
+        assert_noop!(
+            Vault::initiate_recovery(RuntimeOrigin::signed(1), third),
+            Error::<Test>::TooManyActiveRecoveries
+        );
+
+        for (account, share_id) in [1, 2].into_iter().zip(first_shares) {
+            assert_ok!(Vault::reveal_share(
+                RuntimeOrigin::signed(account),
+                share_id
+            ));
+        }
+        assert!(!Vault::is_recovery_active(first));
+        assert_eq!(Vault::actor_active_recoveries(owner), 1);
+
+        assert_ok!(Vault::initiate_recovery(RuntimeOrigin::signed(1), third));
+        assert_eq!(Vault::actor_active_recoveries(owner), 2);
+    });
+}
+
+#[test]
+fn replacing_expired_recovery_keeps_one_slot() {
+    new_test_ext().execute_with(|| {
+        let owner = account_to_actor(1);
+        let (vault_id, _) = create_recoverable_vault(1);
+
+        assert_ok!(Vault::initiate_recovery(RuntimeOrigin::signed(1), vault_id));
+        System::set_block_number(1 + RecoveryPeriodBlocks::get() + 1);
+        assert_ok!(Vault::initiate_recovery(RuntimeOrigin::signed(1), vault_id));
+        assert_eq!(Vault::actor_active_recoveries(owner), 1);
+
+        assert_ok!(Vault::dissolve_vault(RuntimeOrigin::root(), vault_id));
+        assert_eq!(Vault::actor_active_recoveries(owner), 0);
+    });
+}
+
+// ===========================================================================
+// Member replacement tests
+// ===========================================================================
+
+#[test]
+fn replace_member_preserves_share_index() {
+    new_test_ext().execute_with(|| {
+        let vault_id = create_active_vault_with_shares(1, &[1, 3]);
+        let old_member = account_to_actor(3);
+        let new_member = account_to_actor(10);
+        let old_index = Vault::vault_members(vault_id, old_member)
+            .expect("member should exist")
+            .share_index;
+
+        assert_ok!(Vault::replace_member(
+            RuntimeOrigin::signed(1),
+            vault_id,
+            old_member,
+            new_member
+        ));
+
+        assert!(Vault::vault_members(vault_id, old_member).is_none());
+        let replacement =
+            Vault::vault_members(vault_id, new_member).expect("replacement should exist");
+        assert_eq!(replacement.share_index, old_index);
+        assert_eq!(replacement.role, MemberRole::Participant);
+        assert!(!replacement.share_committed);
+
+        let vault = Vault::vaults(vault_id).expect("vault should exist");
+        assert_eq!(vault.member_count, 3);
+        assert_eq!(Vault::vault_count_per_actor(old_member), 0);
+        assert_eq!(Vault::vault_count_per_actor(new_member), 1);
+        System::assert_has_event(RuntimeEvent::Vault(Event::MemberReplaced {
+            vault_id,
+            old_member,
+            new_member,
+            share_index: old_index,
+        }));
+    });
+}
+
+#[test]
+fn replace_member_invalidates_old_share() {
+    new_test_ext().execute_with(|| {
+        let vault_id = create_active_vault_with_shares(1, &[1, 3]);
+        let old_share = ShareId::new(1);
+
+        assert_ok!(Vault::replace_member(
+            RuntimeOrigin::signed(1),
+            vault_id,
+            account_to_actor(3),
+            account_to_actor(10)
+        ));
+
+        let share = Vault::shares(old_share).expect("share should exist");
+        assert_eq!(share.status, ShareStatus::Invalidated);
+        assert_eq!(Vault::live_share_count(vault_id, 0), 1);
+    });
+}
+
+#[test]
+fn replace_member_requires_recommitment_before_recovery() {
+    new_test_ext().execute_with(|| {
+        let vault_id = create_active_vault_with_shares(1, &[1, 3]);
+        let new_member = account_to_actor(10);
+
+        assert_ok!(Vault::replace_member(
+            RuntimeOrigin::signed(1),
+            vault_id,
+            account_to_actor(3),
+            new_member
+        ));
+
+        // Only the owner's share is live, so recovery cannot reach the threshold.
+        assert_ok!(Vault::initiate_recovery(RuntimeOrigin::signed(1), vault_id));
+        assert_ok!(Vault::reveal_share(
+            RuntimeOrigin::signed(1),
+            ShareId::new(0)
+        ));
+        assert!(!Vault::is_recovery_active(vault_id));
+        System::assert_has_event(RuntimeEvent::Vault(Event::RecoveryAborted {
+            vault_id,
+            reason: RecoveryAbortReason::InsufficientLiveShares,
+        }));
+
+        assert_ok!(Vault::commit_share(
+            RuntimeOrigin::signed(10),
+            vault_id,
+            H256([110u8; 32])
+        ));
+        let new_share = ShareId::new(2);
+        let share = Vault::shares(new_share).expect("share should exist");
+        assert_eq!(share.holder, new_member);
+        assert_eq!(share.index, 2);
+
+        assert_ok!(Vault::initiate_recovery(RuntimeOrigin::signed(1), vault_id));
+        assert_ok!(Vault::reveal_share(
+            RuntimeOrigin::signed(1),
+            ShareId::new(0)
+        ));
+        assert_ok!(Vault::reveal_share(RuntimeOrigin::signed(10), new_share));
+        System::assert_has_event(RuntimeEvent::Vault(Event::RecoveryCompleted { vault_id }));
+    });
+}
+
+#[test]
+fn replace_member_rejects_owner_and_inactive_vault() {
+    new_test_ext().execute_with(|| {
+        let vault_id = create_vault_with_members(1, 3);
+        assert_noop!(
+            Vault::replace_member(
+                RuntimeOrigin::signed(1),
+                vault_id,
+                account_to_actor(2),
+                account_to_actor(10)
+            ),
+            Error::<Test>::VaultNotActive
+        );
+
+        assert_ok!(Vault::activate_vault(RuntimeOrigin::signed(1), vault_id));
+        assert_noop!(
+            Vault::replace_member(
+                RuntimeOrigin::signed(1),
+                vault_id,
+                account_to_actor(1),
+                account_to_actor(10)
+            ),
+            Error::<Test>::CannotRemoveOwner
+        );
+        assert_noop!(
+            Vault::replace_member(
+                RuntimeOrigin::signed(2),
+                vault_id,
+                account_to_actor(3),
+                account_to_actor(10)
+            ),
+            Error::<Test>::NotVaultOwner
+        );
+    });
+}
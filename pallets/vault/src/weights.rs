@@ -20,6 +20,13 @@ pub trait WeightInfo {
     fn register_file() -> Weight;
     fn request_unlock() -> Weight;
     fn authorize_unlock() -> Weight;
+    fn request_dissolution() -> Weight;
+    fn approve_dissolution() -> Weight;
+    fn remove_member() -> Weight;
+    fn rotate_secret_hash() -> Weight;
+    fn set_member_role() -> Weight;
+    fn replace_member() -> Weight;
+    fn cancel_dissolution() -> Weight;
 }
 
 pub struct SubstrateWeight<T>(PhantomData<T>);
@@ -32,11 +39,12 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
     }
 
     fn add_member() -> Weight {
-        // Reads: Vaults, VaultMembers(exists_check), VaultCountPerActor
-        // Writes: Vaults, VaultMembers, ActorVaults, VaultCountPerActor
+        // Reads: Vaults, VaultMembers(exists_check), VaultCountPerActor,
+        //        NextShareIndex, VaultMembers(index scan, up to MaxRingSize)
+        // Writes: Vaults, VaultMembers, ActorVaults, VaultCountPerActor, NextShareIndex
         Weight::from_parts(30_000_000, 0)
-            .saturating_add(T::DbWeight::get().reads(3))
-            .saturating_add(T::DbWeight::get().writes(4))
+            .saturating_add(T::DbWeight::get().reads(14))
+            .saturating_add(T::DbWeight::get().writes(5))
     }
 
     fn activate_vault() -> Weight {
@@ -52,16 +60,16 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
     }
 
     fn initiate_recovery() -> Weight {
-        // Reads: Vaults, RecoveryRequests, VaultMembers
-        // Writes: RecoveryRequests, Vaults
+        // Reads: Vaults, RecoveryRequests, VaultMembers, ActorActiveRecoveries
+        // Writes: RecoveryRequests, RecoveryChallenges, Vaults, ActorActiveRecoveries
         Weight::from_parts(30_000_000, 0)
-            .saturating_add(T::DbWeight::get().reads(3))
-            .saturating_add(T::DbWeight::get().writes(2))
+            .saturating_add(T::DbWeight::get().reads(4))
+            .saturating_add(T::DbWeight::get().writes(4))
     }
 
     fn reveal_share() -> Weight {
         Weight::from_parts(35_000_000, 0)
-            .saturating_add(T::DbWeight::get().reads(3))
+            .saturating_add(T::DbWeight::get().reads(4))
             .saturating_add(T::DbWeight::get().writes(2))
     }
 
@@ -100,6 +108,65 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
             .saturating_add(T::DbWeight::get().reads(4))
             .saturating_add(T::DbWeight::get().writes(5))
     }
+
+    fn request_dissolution() -> Weight {
+        // Reads: Vaults, DissolutionRequests
+        // Writes: DissolutionRequests
+        Weight::from_parts(25_000_000, 0)
+            .saturating_add(T::DbWeight::get().reads(2))
+            .saturating_add(T::DbWeight::get().writes(1))
+    }
+
+    fn approve_dissolution() -> Weight {
+        // Reads: DissolutionRequests, VaultMembers, DissolutionApprovals
+        // Writes: DissolutionApprovals, DissolutionRequests
+        // (threshold reached: full dissolve_vault cleanup)
+        Weight::from_parts(130_000_000, 0)
+            .saturating_add(T::DbWeight::get().reads(13))
+            .saturating_add(T::DbWeight::get().writes(32))
+    }
+
+    fn remove_member() -> Weight {
+        // Reads: Vaults, VaultMembers
+        // Writes: Vaults, VaultMembers, ActorVaults, VaultCountPerActor
+        Weight::from_parts(30_000_000, 0)
+            .saturating_add(T::DbWeight::get().reads(2))
+            .saturating_add(T::DbWeight::get().writes(4))
+    }
+
+    fn rotate_secret_hash() -> Weight {
+        // Reads: Vaults, SecretRotationCount
+        // Writes: Vaults, SecretHashHistory, SecretRotationCount
+        Weight::from_parts(25_000_000, 0)
+            .saturating_add(T::DbWeight::get().reads(2))
+            .saturating_add(T::DbWeight::get().writes(3))
+    }
+
+    fn set_member_role() -> Weight {
+        // Reads: Vaults, VaultMembers
+        // Writes: Vaults, VaultMembers
+        Weight::from_parts(20_000_000, 0)
+            .saturating_add(T::DbWeight::get().reads(2))
+            .saturating_add(T::DbWeight::get().writes(2))
+    }
+
+    fn replace_member() -> Weight {
+        // Reads: Vaults, VaultMembers (old, new), VaultCountPerActor
+        // Writes: Vaults, VaultMembers (old, new), ActorVaults (old, new),
+        //         VaultCountPerActor (old, new), Shares
+        // The call adds a read per vault the old member may hold shares in.
+        Weight::from_parts(30_000_000, 0)
+            .saturating_add(T::DbWeight::get().reads(4))
+            .saturating_add(T::DbWeight::get().writes(8))
+    }
+
+    fn cancel_dissolution() -> Weight {
+        // Reads: Vaults, DissolutionRequests
+        // Writes: DissolutionRequests, DissolutionApprovals (below GuardianApprovalThreshold)
+        Weight::from_parts(25_000_000, 0)
+            .saturating_add(T::DbWeight::get().reads(2))
+            .saturating_add(T::DbWeight::get().writes(4))
+    }
 }
 
 impl WeightInfo for () {
@@ -111,8 +178,8 @@ impl WeightInfo for () {
 
     fn add_member() -> Weight {
         Weight::from_parts(30_000_000, 0)
-            .saturating_add(RocksDbWeight::get().reads(3))
-            .saturating_add(RocksDbWeight::get().writes(4))
+            .saturating_add(RocksDbWeight::get().reads(14))
+            .saturating_add(RocksDbWeight::get().writes(5))
     }
 
     fn activate_vault() -> Weight {
@@ -129,13 +196,13 @@ impl WeightInfo for () {
 
     fn initiate_recovery() -> Weight {
         Weight::from_parts(30_000_000, 0)
-            .saturating_add(RocksDbWeight::get().reads(3))
-            .saturating_add(RocksDbWeight::get().writes(2))
+            .saturating_add(RocksDbWeight::get().reads(4))
+            .saturating_add(RocksDbWeight::get().writes(4))
     }
 
     fn reveal_share() -> Weight {
         Weight::from_parts(35_000_000, 0)
-            .saturating_add(RocksDbWeight::get().reads(3))
+            .saturating_add(RocksDbWeight::get().reads(4))
             .saturating_add(RocksDbWeight::get().writes(2))
     }
 
@@ -168,4 +235,56 @@ impl WeightInfo for () {
             .saturating_add(RocksDbWeight::get().reads(4))
             .saturating_add(RocksDbWeight::get().writes(5))
     }
+
+    fn request_dissolution() -> Weight {
+        Weight::from_parts(25_000_000, 0)
+            .saturating_add(RocksDbWeight::get().reads(2))
+            .saturating_add(RocksDbWeight::get().writes(1))
+    }
+
+    fn approve_dissolution() -> Weight {
+        Weight::from_parts(130_000_000, 0)
+            .saturating_add(RocksDbWeight::get().reads(13))
+            .saturating_add(RocksDbWeight::get().writes(32))
+    }
+
+    fn remove_member() -> Weight {
+        Weight::from_parts(30_000_000, 0)
+            .saturating_add(RocksDbWeight::get().reads(2))
+            .saturating_add(RocksDbWeight::get().writes(4))
+    }
+
+    fn rotate_secret_hash() -> Weight {
+        // Reads: Vaults, SecretRotationCount
+        // Writes: Vaults, SecretHashHistory, SecretRotationCount
+        Weight::from_parts(25_000_000, 0)
+            .saturating_add(RocksDbWeight::get().reads(2))
+            .saturating_add(RocksDbWeight::get().writes(3))
+    }
+
+    fn set_member_role() -> Weight {
+        // Reads: Vaults, VaultMembers
+        // Writes: Vaults, VaultMembers
+        Weight::from_parts(20_000_000, 0)
+            .saturating_add(RocksDbWeight::get().reads(2))
+            .saturating_add(RocksDbWeight::get().writes(2))
+    }
+
+    fn replace_member() -> Weight {
+        // Reads: Vaults, VaultMembers (old, new), VaultCountPerActor
+        // Writes: Vaults, VaultMembers (old, new), ActorVaults (old, new),
+        //         VaultCountPerActor (old, new), Shares
+        // The call adds a read per vault the old member may hold shares in.
+        Weight::from_parts(30_000_000, 0)
+            .saturating_add(RocksDbWeight::get().reads(4))
+            .saturating_add(RocksDbWeight::get().writes(8))
+    }
+
+    fn cancel_dissolution() -> Weight {
+        // Reads: Vaults, DissolutionRequests
+        // Writes: DissolutionRequests, DissolutionApprovals (below GuardianApprovalThreshold)
+        Weight::from_parts(25_000_000, 0)
+            .saturating_add(RocksDbWeight::get().reads(2))
+            .saturating_add(RocksDbWeight::get().writes(4))
+    }
 }
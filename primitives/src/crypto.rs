@@ -6,7 +6,7 @@ use scale_info::TypeInfo;
 use sp_core::{blake2_256, H256};
 use sp_runtime::RuntimeDebug;
 
-use crate::traits::{ConstantTimeEq, CryptoHash, DomainSeparatedHash};
+use crate::traits::{ConstantTimeEq, CryptoHash, DomainSeparatedHash, MerkleTree};
 
 // Domain separators for hash functions
 pub const DOMAIN_PRESENCE: &[u8] = b"7ay:presence:v1";
@@ -221,6 +221,89 @@ impl StateRoot {
     }
 }
 
+/// Binary Merkle tree over a fixed set of leaves.
+///
+/// Padded with zero leaves to a power of two, so its root matches
+/// [`StateRoot::from_leaves`] for the same leaves.
+#[derive(Clone, PartialEq, Eq, RuntimeDebug)]
+pub struct BinaryMerkleTree {
+    /// Layers from the padded leaves up to the root.
+    layers: Vec<Vec<H256>>,
+    leaf_count: usize,
+}
+
+impl BinaryMerkleTree {
+    pub fn new(leaves: &[H256]) -> Self {
+        if leaves.is_empty() {
+            return Self {
+                layers: Vec::new(),
+                leaf_count: 0,
+            };
+        }
+
+        let mut layer: Vec<H256> = leaves.to_vec();
+        layer.resize(leaves.len().next_power_of_two(), H256::zero());
+
+        let mut layers = Vec::new();
+        while layer.len() > 1 {
+            let next_layer: Vec<H256> = layer
+                .chunks(2)
+                .map(|chunk| hash_pair(&chunk[0], &chunk[1]))
+                .collect();
+            layers.push(layer);
+            layer = next_layer;
+        }
+        layers.push(layer);
+
+        Self {
+            layers,
+            leaf_count: leaves.len(),
+        }
+    }
+
+    pub fn leaf_count(&self) -> usize {
+        self.leaf_count
+    }
+
+    pub fn state_root(&self) -> StateRoot {
+        StateRoot(self.root())
+    }
+}
+
+impl MerkleTree for BinaryMerkleTree {
+    type Leaf = H256;
+    type Proof = MerkleProof;
+
+    fn root(&self) -> H256 {
+        self.layers
+            .last()
+            .and_then(|top| top.first().copied())
+            .unwrap_or(StateRoot::EMPTY.0)
+    }
+
+    fn prove(&self, index: usize) -> Option<MerkleProof> {
+        if index >= self.leaf_count {
+            return None;
+        }
+
+        let depth = self.layers.len().saturating_sub(1);
+        let siblings = self.layers[..depth]
+            .iter()
+            .enumerate()
+            .map(|(level, layer)| layer[(index >> level) ^ 1])
+            .collect();
+
+        Some(MerkleProof {
+            leaf_index: index as u64,
+            siblings,
+        })
+    }
+
+    fn verify_proof(root: &H256, leaf: &H256, proof: &MerkleProof) -> bool {
+        proof.verify(root, leaf)
+    }
+}
+
 /// Presence proof combining commitment and Merkle proof.
 #[derive(
     Clone,
@@ -809,6 +892,27 @@ mod tests {
         assert_ne!(n1, n4);
     }
 
+    #[test]
+    fn binary_merkle_tree_proofs() {
+        let leaves: Vec<H256> = (1..=5u8).map(H256::repeat_byte).collect();
+        let tree = BinaryMerkleTree::new(&leaves);
+        let root = tree.root();
+
+        assert_eq!(tree.state_root(), StateRoot::from_leaves(&leaves));
+        for (index, leaf) in leaves.iter().enumerate() {
+            let proof = tree.prove(index).expect("leaf should have a proof");
+            assert!(BinaryMerkleTree::verify_proof(&root, leaf, &proof));
+        }
+
+        let proof = tree.prove(2).expect("leaf should have a proof");
+        assert!(!proof.verify(&root, &H256::repeat_byte(0x09)));
+        assert!(tree.prove(5).is_none());
+
+        let empty = BinaryMerkleTree::new(&[]);
+        assert_eq!(empty.state_root(), StateRoot::EMPTY);
+        assert!(empty.prove(0).is_none());
+    }
+
     #[test]
     fn state_root_empty() {
         let root = StateRoot::from_leaves(&[]);
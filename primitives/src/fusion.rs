@@ -6,9 +6,7 @@ use scale_info::TypeInfo;
 use sp_core::H256;
 use sp_runtime::Perbill;
 
-#[cfg(feature = "std")]
 use crate::crypto::{hash_pair, hash_with_domain};
-#[cfg(feature = "std")]
 use crate::traits::ConstantTimeEq;
 
 pub const DOMAIN_DEVICE_COMMITMENT: &[u8] = b"7ay:device:commit:v1";
@@ -81,7 +79,6 @@ impl DeviceCommitment {
         })
     }
 
-    #[cfg(feature = "std")]
     pub fn compute_device_merkle_root(device_mac_hashes: &[H256]) -> H256 {
         if device_mac_hashes.is_empty() {
             return H256::zero();
@@ -104,7 +101,6 @@ impl DeviceCommitment {
         layer[0]
     }
 
-    #[cfg(feature = "std")]
     pub fn compute_commitment(merkle_root: &H256, nonce: &[u8; 32], block_number: u64) -> H256 {
         let data = [
             merkle_root.as_bytes(),
@@ -116,7 +112,9 @@ impl DeviceCommitment {
     }
 }
 
-#[derive(Clone, Debug, PartialEq, Eq, Encode, Decode, TypeInfo)]
+#[derive(
+    Clone, Debug, PartialEq, Eq, Encode, Decode, parity_scale_codec::DecodeWithMemTracking, TypeInfo,
+)]
 pub struct DeviceReveal {
     pub commitment_block: u64,
     pub nonce: [u8; 32],
@@ -126,15 +124,8 @@ pub struct DeviceReveal {
 }
 
 impl DeviceReveal {
-    #[cfg(feature = "std")]
     pub fn verify(&self, commitment: &DeviceCommitment) -> bool {
-        let recomputed = DeviceCommitment::compute_commitment(
-            &self.device_merkle_root,
-            &self.nonce,
-            self.commitment_block,
-        );
-
-        if !recomputed.ct_eq(&commitment.commitment) {
+        if !self.opens(&commitment.commitment) {
             return false;
         }
 
@@ -144,6 +135,17 @@ impl DeviceReveal {
 
         true
     }
+
+    /// Recomputes the domain-separated commitment from the revealed root,
+    /// nonce and block, and compares it to `commitment` in constant time.
+    pub fn opens(&self, commitment: &H256) -> bool {
+        DeviceCommitment::compute_commitment(
+            &self.device_merkle_root,
+            &self.nonce,
+            self.commitment_block,
+        )
+        .ct_eq(commitment)
+    }
 }
 
 #[derive(Clone, Debug, Default, PartialEq, Eq, Encode, Decode, TypeInfo, MaxEncodedLen)]
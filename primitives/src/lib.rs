@@ -20,18 +20,19 @@ pub use types::*;
 
 // Re-export crypto with explicit names to avoid conflicts
 pub use crypto::{
-    derive_actor_id, derive_validator_id, hash_pair, hash_with_domain, MerkleProof, Nullifier,
-    PresenceCommitment, PresenceProof, PresenceStatement, PresenceWitness, Share, ShareIndex,
-    StateRoot, DOMAIN_ACTOR, DOMAIN_COMMITMENT, DOMAIN_EPOCH, DOMAIN_MERKLE, DOMAIN_NULLIFIER,
-    DOMAIN_PRESENCE, DOMAIN_VALIDATOR_ID,
+    derive_actor_id, derive_validator_id, hash_pair, hash_with_domain, BinaryMerkleTree,
+    MerkleProof, Nullifier, PresenceCommitment, PresenceProof, PresenceStatement, PresenceWitness,
+    Share, ShareIndex, StateRoot, DOMAIN_ACTOR, DOMAIN_COMMITMENT, DOMAIN_EPOCH, DOMAIN_MERKLE,
+    DOMAIN_NULLIFIER, DOMAIN_PRESENCE, DOMAIN_VALIDATOR_ID,
 };
 
 // Re-export traits with explicit names
 pub use traits::{
-    AggregateSignature, AlwaysActiveEpoch, AlwaysValidValidator, ChainBound, Commitment,
-    ConstantTimeEq, CryptoHash, DomainSeparatedHash, EpochActiveChecker, EpochBound, EpochProvider,
-    Invariant, MerkleTree, SecretSharing, Signature, StateTransition, ValidatorChecker,
-    ValidatorProvider, ZkProof,
+    AggregateSignature, AggregateSignatureVerifier, AllowAllCapabilities, AlwaysActiveEpoch,
+    AlwaysValidValidator, CapabilityCheck, ChainBound, Commitment, ConstantTimeEq, CryptoHash,
+    DeviceRegistry, DomainSeparatedHash, EpochActiveChecker, EpochBound, EpochProvider, Invariant,
+    MerkleTree, NoDevices, RejectAllAggregates, SecretSharing, Signature, StateTransition,
+    ValidatorAggregateVerifier, ValidatorChecker, ValidatorKeyLookup, ValidatorProvider, ZkProof,
 };
 
 pub use fusion::{
@@ -40,8 +41,10 @@ pub use fusion::{
 };
 
 pub use triangulation::{
-    calculate_weighted_centroid, multilateration, rssi_to_distance_cm, DeviceTrack,
-    SignalObservation, TriangulatedPosition, TriangulationConfig, Velocity,
+    calculate_weighted_centroid, calculate_weighted_centroid_with, distance_squared_cm_to_rssi,
+    multilateration, multilateration_with, nearest_position, rssi_to_distance_cm, DeviceTrack,
+    DistanceMetric, Euclidean, SignalObservation, TriangulatedPosition, TriangulationConfig,
+    Velocity, WeightedAxes,
 };
 
 pub use witness::{
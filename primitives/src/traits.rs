@@ -1,10 +1,11 @@
 //! Protocol trait abstractions for cryptographic operations and state management.
 
 use alloc::vec::Vec;
+use core::marker::PhantomData;
 use parity_scale_codec::{Decode, Encode};
 use sp_core::H256;
 
-use crate::types::{EpochId, ValidatorId};
+use crate::types::{ActorId, EpochId, NamedCapability, ValidatorId};
 
 /// Cryptographic hash computation.
 pub trait CryptoHash {
@@ -97,6 +98,55 @@ pub trait AggregateSignature: Signature {
     fn verify_aggregate(pks: &[Self::PublicKey], msgs: &[&[u8]], agg: &Self::AggregateSig) -> bool;
 }
 
+/// Resolves a validator's public key for a signature scheme.
+pub trait ValidatorKeyLookup<PublicKey> {
+    fn public_key(validator: ValidatorId) -> Option<PublicKey>;
+}
+
+/// Verifies one aggregate signature by a set of validators over a shared message.
+/// Used by pallets that accept batched validator approvals without depending on a scheme.
+pub trait ValidatorAggregateVerifier {
+    type AggregateSig: Encode + Decode;
+
+    fn verify(validators: &[ValidatorId], msg: &[u8], agg: &Self::AggregateSig) -> bool;
+}
+
+/// Never verifies -- use where no aggregate scheme is configured.
+pub struct RejectAllAggregates;
+impl ValidatorAggregateVerifier for RejectAllAggregates {
+    type AggregateSig = ();
+
+    fn verify(_validators: &[ValidatorId], _msg: &[u8], _agg: &Self::AggregateSig) -> bool {
+        false
+    }
+}
+
+/// [`ValidatorAggregateVerifier`] backed by an [`AggregateSignature`] scheme, with
+/// validator keys resolved through `K`. Fails if any key is unknown.
+pub struct AggregateSignatureVerifier<S, K>(PhantomData<(S, K)>);
+impl<S, K> ValidatorAggregateVerifier for AggregateSignatureVerifier<S, K>
+where
+    S: AggregateSignature,
+    K: ValidatorKeyLookup<S::PublicKey>,
+{
+    type AggregateSig = S::AggregateSig;
+
+    fn verify(validators: &[ValidatorId], msg: &[u8], agg: &Self::AggregateSig) -> bool {
+        if validators.is_empty() {
+            return false;
+        }
+        let Some(pks) = validators
+            .iter()
+            .map(|v| K::public_key(*v))
+            .collect::<Option<Vec<_>>>()
+        else {
+            return false;
+        };
+        let msgs: Vec<&[u8]> = validators.iter().map(|_| msg).collect();
+        S::verify_aggregate(&pks, &msgs, agg)
+    }
+}
+
 /// Chain binding for replay protection.
 pub trait ChainBound {
     fn bind(&self, chain_id: u64, block_hash: H256, block_num: u64) -> H256;
@@ -165,6 +215,12 @@ impl ValidatorChecker for AlwaysValidValidator {
 pub trait EpochProvider {
     fn is_epoch_active(epoch_id: EpochId) -> bool;
     fn current_epoch() -> EpochId;
+
+    /// True if the epoch has closed but its grace period has not yet
+    /// elapsed. Providers without a grace concept never report one.
+    fn is_within_grace_period(_epoch_id: EpochId) -> bool {
+        false
+    }
 }
 
 /// Cross-pallet validator set provider.
@@ -173,6 +229,153 @@ pub trait EpochProvider {
 /// pallet without maintaining shadow storage.
 pub trait ValidatorProvider {
     fn is_validator_active(validator_id: ValidatorId) -> bool;
+
+    /// Number of validators currently in the active set.
+    fn active_validator_count() -> u32;
+
+    /// Validators currently in the active set.
+    fn active_validators() -> Vec<ValidatorId>;
+}
+
+/// Checks whether an actor holds a protocol capability.
+/// Used by pallets that gate calls on governance grants without direct dependency.
+pub trait CapabilityCheck {
+    fn has_capability(actor: ActorId, capability: NamedCapability) -> bool;
+}
+
+/// Always returns true -- use in tests or permissionless deployments.
+pub struct AllowAllCapabilities;
+impl CapabilityCheck for AllowAllCapabilities {
+    fn has_capability(_actor: ActorId, _capability: NamedCapability) -> bool {
+        true
+    }
+}
+
+/// Read-only view of registered devices.
+/// Lets pallets account for real devices without depending on the device pallet.
+pub trait DeviceRegistry {
+    type DeviceId;
+
+    /// Owning actor of the device, if it is registered.
+    fn device_owner(device_id: Self::DeviceId) -> Option<ActorId>;
+
+    /// Whether the device is registered and currently active.
+    fn is_device_active(device_id: Self::DeviceId) -> bool;
+
+    /// Whether the actor owns at least one active device.
+    fn has_active_device(actor: ActorId) -> bool;
+}
+
+/// Empty registry -- use where no device pallet is present.
+pub struct NoDevices;
+impl DeviceRegistry for NoDevices {
+    type DeviceId = u64;
+
+    fn device_owner(_device_id: u64) -> Option<ActorId> {
+        None
+    }
+
+    fn is_device_active(_device_id: u64) -> bool {
+        false
+    }
+
+    fn has_active_device(_actor: ActorId) -> bool {
+        false
+    }
+}
+
+/// Read-only view of actor lifecycle status.
+/// Lets pallets gate calls on actor standing without depending on the lifecycle pallet.
+pub trait ActorStatusProvider {
+    /// Whether the actor is registered and in the `Active` lifecycle state.
+    fn is_actor_active(actor: ActorId) -> bool;
+}
+
+/// Treats every actor as active -- use where no lifecycle pallet is present.
+pub struct AllActorsActive;
+impl ActorStatusProvider for AllActorsActive {
+    fn is_actor_active(_actor: ActorId) -> bool {
+        true
+    }
+}
+
+/// Notified when triangulation settles a tracked device's position.
+/// Lets pallets consume triangulated positions without depending on the triangulation pallet.
+pub trait OnPositionConfirmed {
+    /// `device` is the tracked device's MAC hash; coordinates are in centimetres.
+    fn on_position_confirmed(device: H256, x: i64, y: i64, z: i64, confidence: u8);
+}
+
+/// Ignores confirmations -- use where nothing consumes triangulated positions.
+impl OnPositionConfirmed for () {
+    fn on_position_confirmed(_device: H256, _x: i64, _y: i64, _z: i64, _confidence: u8) {}
+}
+
+/// Opens and closes disputes over challenged presences.
+/// Lets the presence pallet raise challenges without depending on the dispute pallet.
+pub trait PresenceDisputes<AccountId> {
+    /// Open a dispute over `actor`'s presence in `epoch` and return its id,
+    /// or `None` if no dispute could be opened.
+    fn open_presence_dispute(
+        challenger: &AccountId,
+        actor: ActorId,
+        epoch: EpochId,
+        evidence: H256,
+    ) -> Option<u64>;
+
+    /// Close a dispute opened by `open_presence_dispute`. `upheld` when the
+    /// presence was slashed.
+    fn close_presence_dispute(dispute_id: u64, upheld: bool);
+}
+
+/// Opens no disputes, so no presence can be challenged -- use where no dispute pallet is present.
+impl<AccountId> PresenceDisputes<AccountId> for () {
+    fn open_presence_dispute(
+        _challenger: &AccountId,
+        _actor: ActorId,
+        _epoch: EpochId,
+        _evidence: H256,
+    ) -> Option<u64> {
+        None
+    }
+
+    fn close_presence_dispute(_dispute_id: u64, _upheld: bool) {}
+}
+
+/// View and completion of in-flight actor key rotations.
+/// Lets pallets that bind state to a key follow a rotation without depending on the lifecycle pallet.
+pub trait KeyRotationProvider {
+    /// Whether `actor` has started, and not yet finished, a rotation to `new_key_hash`.
+    fn is_rotation_pending(actor: ActorId, new_key_hash: H256) -> bool;
+
+    /// Complete `actor`'s pending rotation to `new_key_hash` so it cannot be used
+    /// again. Returns whether such a rotation was pending.
+    fn complete_rotation(actor: ActorId, new_key_hash: H256) -> bool;
+}
+
+/// Treats no rotation as pending -- use where no lifecycle pallet is present.
+impl KeyRotationProvider for () {
+    fn is_rotation_pending(_actor: ActorId, _new_key_hash: H256) -> bool {
+        false
+    }
+
+    fn complete_rotation(_actor: ActorId, _new_key_hash: H256) -> bool {
+        false
+    }
+}
+
+/// Read-only view of presence demand.
+/// Lets capacity planning follow declared presences without depending on the presence pallet.
+pub trait PresenceLoad {
+    /// Presences declared in the current epoch.
+    fn current_presence_count() -> u32;
+}
+
+/// Reports no presence demand -- use where no presence pallet is present.
+impl PresenceLoad for () {
+    fn current_presence_count() -> u32 {
+        0
+    }
 }
 
 /// Constant-time equality to prevent timing attacks.
@@ -218,6 +421,63 @@ mod tests {
         assert!(!a.ct_eq(&c));
     }
 
+    /// Toy scheme: a signature is the key plus the message length, and an
+    /// aggregate is the sum of its signatures.
+    struct SumScheme;
+    impl Signature for SumScheme {
+        type PublicKey = u64;
+        type SecretKey = u64;
+        type Sig = u64;
+
+        fn sign(sk: &u64, msg: &[u8]) -> u64 {
+            sk + msg.len() as u64
+        }
+        fn verify(pk: &u64, msg: &[u8], sig: &u64) -> bool {
+            *sig == pk + msg.len() as u64
+        }
+    }
+    impl AggregateSignature for SumScheme {
+        type AggregateSig = u64;
+
+        fn aggregate(signatures: &[u64]) -> Option<u64> {
+            Some(signatures.iter().sum())
+        }
+        fn verify_aggregate(pks: &[u64], msgs: &[&[u8]], agg: &u64) -> bool {
+            let expected: u64 = pks
+                .iter()
+                .zip(msgs)
+                .map(|(pk, msg)| pk + msg.len() as u64)
+                .sum();
+            expected == *agg
+        }
+    }
+
+    struct FirstByteKeys;
+    impl ValidatorKeyLookup<u64> for FirstByteKeys {
+        fn public_key(validator: ValidatorId) -> Option<u64> {
+            let byte = validator.as_bytes()[0];
+            (byte != 0).then_some(byte as u64)
+        }
+    }
+
+    #[test]
+    fn aggregate_verifier_resolves_keys_and_checks_signature() {
+        type Verifier = AggregateSignatureVerifier<SumScheme, FirstByteKeys>;
+        let v1 = ValidatorId::from_raw([1u8; 32]);
+        let v2 = ValidatorId::from_raw([2u8; 32]);
+        let unknown = ValidatorId::from_raw([0u8; 32]);
+        let msg = b"vote";
+
+        let agg = SumScheme::aggregate(&[SumScheme::sign(&1, msg), SumScheme::sign(&2, msg)]);
+        assert_eq!(agg, Some(1 + 2 + 2 * msg.len() as u64));
+        let agg = agg.unwrap_or_default();
+        assert!(Verifier::verify(&[v1, v2], msg, &agg));
+        assert!(!Verifier::verify(&[v1], msg, &agg));
+        assert!(!Verifier::verify(&[v1, unknown], msg, &agg));
+        assert!(!Verifier::verify(&[], msg, &0));
+        assert!(!RejectAllAggregates::verify(&[v1], msg, &()));
+    }
+
     #[test]
     fn ct_eq_h256() {
         let a = H256::zero();
@@ -70,24 +70,154 @@ pub fn rssi_to_distance_cm(rssi: i8, tx_power: i8, path_loss_x100: u16) -> u32 {
     distance_cm.clamp(10, 10_000_000)
 }
 
+/// Expected RSSI at a squared distance (cm²) under the log-distance path-loss
+/// model, `tx_power - 10 * n * log10(d / 1m)`. Distances within one metre
+/// report `tx_power`.
+pub fn distance_squared_cm_to_rssi(
+    distance_squared_cm: u128,
+    tx_power: i8,
+    path_loss_x100: u16,
+) -> i8 {
+    const ONE_METRE_SQUARED_CM: u128 = 10_000;
+    if distance_squared_cm <= ONE_METRE_SQUARED_CM {
+        return tx_power;
+    }
+
+    // log10(d_m) = log10(d_cm²) / 2 - 2, in thousandths.
+    let log10_metres_milli = log10_milli(distance_squared_cm) / 2 - 2_000;
+    let loss_db = (path_loss_x100 as u64)
+        .saturating_mul(log10_metres_milli)
+        .saturating_add(5_000)
+        / 10_000;
+
+    (tx_power as i64)
+        .saturating_sub(loss_db.min(i64::MAX as u64) as i64)
+        .max(i8::MIN as i64) as i8
+}
+
+/// `1000 * log10(value)` for `value >= 1`, via a 16-bit fixed-point log2.
+fn log10_milli(value: u128) -> u64 {
+    const FRACTION_BITS: u32 = 16;
+    const ONE: u128 = 1 << 62;
+
+    let int_part = 127 - value.max(1).leading_zeros();
+    let mut mantissa = if int_part >= 62 {
+        value >> (int_part - 62)
+    } else {
+        value << (62 - int_part)
+    };
+
+    let mut log2_fixed = (int_part as u64) << FRACTION_BITS;
+    for bit in (0..FRACTION_BITS).rev() {
+        mantissa = (mantissa * mantissa) >> 62;
+        if mantissa >= 2 * ONE {
+            mantissa >>= 1;
+            log2_fixed |= 1 << bit;
+        }
+    }
+
+    // log10(2) ≈ 0.30103
+    log2_fixed.saturating_mul(30_103) / (100 << FRACTION_BITS)
+}
+
+/// Distance between positions used when combining observations.
+pub trait DistanceMetric {
+    /// Squared distance between `a` and `b` under this metric.
+    fn distance_squared(&self, a: &Position, b: &Position) -> u64;
+}
+
+/// Plain Euclidean distance; the default for all helpers.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct Euclidean;
+
+impl DistanceMetric for Euclidean {
+    fn distance_squared(&self, a: &Position, b: &Position) -> u64 {
+        a.distance_squared(b)
+    }
+}
+
+/// Euclidean distance with per-axis multipliers on the squared components, e.g. a
+/// heavy z weight so observers on other floors of a building count as far away.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Encode, Decode, TypeInfo, MaxEncodedLen)]
+pub struct WeightedAxes {
+    pub x_weight: u32,
+    pub y_weight: u32,
+    pub z_weight: u32,
+}
+
+impl WeightedAxes {
+    /// Horizontal axes at unit weight, vertical axis scaled by `z_weight`.
+    pub fn floor_aware(z_weight: u32) -> Self {
+        Self {
+            x_weight: 1,
+            y_weight: 1,
+            z_weight,
+        }
+    }
+}
+
+impl DistanceMetric for WeightedAxes {
+    fn distance_squared(&self, a: &Position, b: &Position) -> u64 {
+        let axis = |from: i32, to: i32, weight: u32| {
+            let d = (from as i64 - to as i64).unsigned_abs();
+            d.saturating_mul(d).saturating_mul(weight as u64)
+        };
+        axis(a.x, b.x, self.x_weight)
+            .saturating_add(axis(a.y, b.y, self.y_weight))
+            .saturating_add(axis(a.z, b.z, self.z_weight))
+    }
+}
+
+/// Index of the candidate closest to `target` under `metric`; ties go to the first.
+pub fn nearest_position<M: DistanceMetric>(
+    metric: &M,
+    target: &Position,
+    candidates: &[Position],
+) -> Option<usize> {
+    candidates
+        .iter()
+        .enumerate()
+        .min_by_key(|(_, p)| metric.distance_squared(target, p))
+        .map(|(i, _)| i)
+}
+
 pub fn calculate_weighted_centroid(
     observations: &[SignalObservation],
     config: &TriangulationConfig,
+) -> Option<TriangulatedPosition> {
+    calculate_weighted_centroid_with(observations, config, &Euclidean)
+}
+
+/// Weighted centroid where observers the metric places farther from the strongest
+/// observer than plain geometry does lose weight proportionally. With
+/// [`Euclidean`] this is identical to [`calculate_weighted_centroid`].
+pub fn calculate_weighted_centroid_with<M: DistanceMetric>(
+    observations: &[SignalObservation],
+    config: &TriangulationConfig,
+    metric: &M,
 ) -> Option<TriangulatedPosition> {
     if observations.len() < config.min_signals as usize {
         return None;
     }
 
+    let anchor = observations
+        .iter()
+        .max_by_key(|obs| obs.rssi)?
+        .observer_position;
+
     let weights: Vec<u64> = observations
         .iter()
         .map(|obs| {
             let dist =
                 rssi_to_distance_cm(obs.rssi, config.tx_power, config.path_loss_exponent_x100);
-            if dist == 0 {
+            let base = if dist == 0 {
                 1
             } else {
                 (1_000_000_u64) / (dist as u64 * dist as u64).max(1)
-            }
+            };
+            let plain = Euclidean.distance_squared(&anchor, &obs.observer_position) as u128;
+            let weighted = metric.distance_squared(&anchor, &obs.observer_position) as u128;
+            ((base as u128).saturating_mul(plain + 1) / weighted.saturating_add(1)) as u64
         })
         .collect();
 
@@ -158,9 +288,20 @@ pub fn calculate_weighted_centroid(
 pub fn multilateration(
     observations: &[SignalObservation],
     config: &TriangulationConfig,
+) -> Option<TriangulatedPosition> {
+    multilateration_with(observations, config, &Euclidean)
+}
+
+/// Multilateration in the horizontal plane. `metric` only shapes the centroid
+/// fallback taken for fewer than three or collinear observers; the lateration
+/// solve itself is plain Euclidean and takes `z` from the first observer.
+pub fn multilateration_with<M: DistanceMetric>(
+    observations: &[SignalObservation],
+    config: &TriangulationConfig,
+    metric: &M,
 ) -> Option<TriangulatedPosition> {
     if observations.len() < 3 {
-        return calculate_weighted_centroid(observations, config);
+        return calculate_weighted_centroid_with(observations, config, metric);
     }
 
     let distances: Vec<(Position, u32)> = observations
@@ -196,7 +337,7 @@ pub fn multilateration(
 
     let denom = a * e - b * d;
     if denom == 0 {
-        return calculate_weighted_centroid(observations, config);
+        return calculate_weighted_centroid_with(observations, config, metric);
     }
 
     let x = (c * e - f * b) / denom;
@@ -312,6 +453,23 @@ mod tests {
         assert!(weak > strong);
     }
 
+    #[test]
+    fn test_distance_to_rssi_matches_path_loss() {
+        let tx = TX_POWER_DEFAULT;
+        let rssi_at = |metres: u128| {
+            let cm = metres * 100;
+            distance_squared_cm_to_rssi(cm * cm, tx, 270)
+        };
+
+        assert_eq!(rssi_at(1), -59);
+        assert_eq!(rssi_at(2), -67);
+        assert_eq!(rssi_at(10), -86);
+        assert_eq!(rssi_at(100), -113);
+        assert_eq!(distance_squared_cm_to_rssi(1_000_000, tx, 200), -79);
+        assert_eq!(distance_squared_cm_to_rssi(0, tx, 270), tx);
+        assert_eq!(distance_squared_cm_to_rssi(u128::MAX, tx, 270), i8::MIN);
+    }
+
     #[test]
     fn test_weighted_centroid() {
         let config = TriangulationConfig::default();
@@ -344,6 +502,137 @@ mod tests {
         assert!(pos.position.x >= 40 && pos.position.x <= 60);
     }
 
+    fn multi_floor_points() -> Vec<Position> {
+        // Same spot two floors up (z in cm) vs. 5 m away on the same floor
+        vec![Position::new(0, 0, 600), Position::new(500, 0, 0)]
+    }
+
+    #[test]
+    fn test_nearest_position_depends_on_metric() {
+        // Device 1 m above its floor: directly below the upper-floor point, 5 m
+        // horizontally from the same-floor point
+        let target = Position::new(0, 0, 100);
+        let points = multi_floor_points();
+
+        assert_eq!(nearest_position(&Euclidean, &target, &points), Some(0));
+        assert_eq!(
+            nearest_position(&WeightedAxes::floor_aware(1), &target, &points),
+            Some(0)
+        );
+        assert_eq!(
+            nearest_position(&WeightedAxes::floor_aware(4), &target, &points),
+            Some(1)
+        );
+        assert_eq!(nearest_position(&Euclidean, &target, &[]), None);
+    }
+
+    #[test]
+    fn test_weighted_axes_distance() {
+        let a = Position::new(0, 0, 0);
+        let b = Position::new(3, 4, 2);
+
+        assert_eq!(Euclidean.distance_squared(&a, &b), 29);
+        assert_eq!(WeightedAxes::floor_aware(1).distance_squared(&a, &b), 29);
+        assert_eq!(WeightedAxes::floor_aware(10).distance_squared(&a, &b), 65);
+    }
+
+    #[test]
+    fn test_centroid_with_metric() {
+        let config = TriangulationConfig::default();
+        let obs = |x: i32, z: i32, rssi: i8| SignalObservation {
+            observer_position: Position::new(x, 0, z),
+            rssi,
+            frequency_mhz: None,
+            timestamp: 1000,
+        };
+        // Two observers on the device's floor, one strong reading from the floor above
+        let observations = vec![obs(0, 0, -45), obs(200, 0, -50), obs(100, 400, -50)];
+
+        let default = calculate_weighted_centroid(&observations, &config).unwrap();
+        let euclidean =
+            calculate_weighted_centroid_with(&observations, &config, &Euclidean).unwrap();
+        assert_eq!(default, euclidean);
+        assert_eq!(
+            multilateration(&observations, &config),
+            multilateration_with(&observations, &config, &Euclidean)
+        );
+
+        let floor_aware = calculate_weighted_centroid_with(
+            &observations,
+            &config,
+            &WeightedAxes::floor_aware(25),
+        )
+        .unwrap();
+        assert!(floor_aware.position.z < default.position.z);
+    }
+
+    #[test]
+    fn test_centroid_metric_scales_weight_by_distance_ratio() {
+        let config = TriangulationConfig {
+            min_signals: 2,
+            ..Default::default()
+        };
+        let obs = |z: i32, rssi: i8| SignalObservation {
+            observer_position: Position::new(0, 0, z),
+            rssi,
+            frequency_mhz: None,
+            timestamp: 1000,
+        };
+        // Strongest observer at the origin, a weaker one 3 m straight above it
+        let observations = vec![obs(0, -45), obs(300, -50)];
+        let base = |rssi: i8| {
+            let d =
+                rssi_to_distance_cm(rssi, config.tx_power, config.path_loss_exponent_x100) as u64;
+            1_000_000 / (d * d).max(1)
+        };
+        let centroid_z = |upper_weight: u64| {
+            let total = base(-45) + upper_weight;
+            (300 * upper_weight / total) as i32
+        };
+
+        let euclidean =
+            calculate_weighted_centroid_with(&observations, &config, &Euclidean).unwrap();
+        assert_eq!(euclidean.position.z, centroid_z(base(-50)));
+
+        // Plain distance² 90_000 against 360_000 under a 4x z weight
+        let floor_aware =
+            calculate_weighted_centroid_with(&observations, &config, &WeightedAxes::floor_aware(4))
+                .unwrap();
+        assert_eq!(
+            floor_aware.position.z,
+            centroid_z(base(-50) * 90_001 / 360_001)
+        );
+        assert!(floor_aware.position.z < euclidean.position.z);
+    }
+
+    #[test]
+    fn test_multilateration_metric_only_affects_fallback() {
+        let config = TriangulationConfig::default();
+        let obs = |x: i32, y: i32, z: i32| SignalObservation {
+            observer_position: Position::new(x, y, z),
+            rssi: -50,
+            frequency_mhz: None,
+            timestamp: 1000,
+        };
+        let floor_aware = WeightedAxes::floor_aware(25);
+
+        let spread = vec![obs(0, 0, 0), obs(1000, 0, 300), obs(0, 1000, 300)];
+        assert_eq!(
+            multilateration_with(&spread, &config, &floor_aware),
+            multilateration(&spread, &config)
+        );
+
+        let collinear = vec![obs(0, 0, 0), obs(500, 0, 300), obs(1000, 0, 300)];
+        assert_eq!(
+            multilateration_with(&collinear, &config, &floor_aware),
+            calculate_weighted_centroid_with(&collinear, &config, &floor_aware)
+        );
+        assert_ne!(
+            multilateration_with(&collinear, &config, &floor_aware),
+            multilateration(&collinear, &config)
+        );
+    }
+
     #[test]
     fn test_integer_sqrt() {
         assert_eq!(integer_sqrt(0), 0);
@@ -2,6 +2,7 @@
 
 use parity_scale_codec::{Decode, Encode, MaxEncodedLen};
 use scale_info::TypeInfo;
+use sp_arithmetic::Perbill;
 use sp_core::H256;
 use sp_runtime::RuntimeDebug;
 
@@ -135,6 +136,9 @@ impl From<u64> for EpochId {
 // =============================================================================
 
 /// State: None -> Declared -> Validated -> Finalized | Slashed
+///
+/// A finalized presence may be `Challenged`, which resolves back to
+/// `Finalized` or to `Slashed`.
 #[derive(
     Clone,
     Copy,
@@ -155,6 +159,7 @@ pub enum PresenceState {
     Validated,
     Finalized,
     Slashed,
+    Challenged,
 }
 
 impl PresenceState {
@@ -170,6 +175,8 @@ impl PresenceState {
                 | (Self::Declared, Self::Validated)
                 | (Self::Validated, Self::Finalized)
                 | (Self::None | Self::Declared | Self::Validated, Self::Slashed)
+                | (Self::Finalized, Self::Challenged)
+                | (Self::Challenged, Self::Finalized | Self::Slashed)
         )
     }
 }
@@ -242,6 +249,8 @@ pub struct PresenceRecord<BlockNumber> {
     pub validated_at: Option<BlockNumber>,
     pub finalized_at: Option<BlockNumber>,
     pub vote_count: u32,
+    /// Declared during the epoch's grace period after it closed.
+    pub late: bool,
 }
 
 impl<BlockNumber: Default> Default for PresenceRecord<BlockNumber> {
@@ -254,6 +263,7 @@ impl<BlockNumber: Default> Default for PresenceRecord<BlockNumber> {
             validated_at: None,
             finalized_at: None,
             vote_count: 0,
+            late: false,
         }
     }
 }
@@ -268,6 +278,7 @@ impl<BlockNumber> PresenceRecord<BlockNumber> {
             validated_at: None,
             finalized_at: None,
             vote_count: 0,
+            late: false,
         }
     }
 }
@@ -386,6 +397,71 @@ impl Default for QuorumConfig {
     }
 }
 
+/// How many approving votes a presence needs.
+#[derive(
+    Clone,
+    Copy,
+    PartialEq,
+    Eq,
+    Encode,
+    Decode,
+    parity_scale_codec::DecodeWithMemTracking,
+    MaxEncodedLen,
+    TypeInfo,
+    RuntimeDebug,
+)]
+pub enum QuorumMode {
+    /// A fixed number of votes regardless of validator set size.
+    Absolute(u32),
+    /// A share of the active validator set, rounded up, at vote time.
+    Fraction(Perbill),
+}
+
+impl QuorumMode {
+    /// Votes required given `validator_count` active validators. Never below one.
+    pub fn required_votes(&self, validator_count: u32) -> u32 {
+        match self {
+            Self::Absolute(threshold) => *threshold,
+            Self::Fraction(share) => share.mul_ceil(validator_count).max(1),
+        }
+    }
+
+    /// INV10: Validated requires votes >= the mode's required votes
+    pub fn is_met(&self, votes: u32, validator_count: u32) -> bool {
+        votes >= self.required_votes(validator_count)
+    }
+
+    pub fn is_valid(&self) -> bool {
+        match self {
+            Self::Absolute(threshold) => *threshold > 0,
+            Self::Fraction(share) => !share.is_zero(),
+        }
+    }
+}
+
+// =============================================================================
+// Named Capabilities
+// =============================================================================
+
+/// Protocol-level capabilities that pallets can require of a caller without
+/// depending on the governance pallet directly.
+#[derive(
+    Clone,
+    Copy,
+    PartialEq,
+    Eq,
+    Encode,
+    Decode,
+    parity_scale_codec::DecodeWithMemTracking,
+    MaxEncodedLen,
+    TypeInfo,
+    RuntimeDebug,
+)]
+pub enum NamedCapability {
+    /// Create a new octopus cluster.
+    CreateCluster,
+}
+
 // =============================================================================
 // Block Reference (INV43: Chain Binding)
 // =============================================================================
@@ -460,6 +536,12 @@ mod tests {
         assert!(!PresenceState::Finalized.can_transition_to(&PresenceState::Slashed));
         assert!(!PresenceState::Slashed.can_transition_to(&PresenceState::Finalized));
         assert!(!PresenceState::Declared.can_transition_to(&PresenceState::None));
+
+        assert!(PresenceState::Finalized.can_transition_to(&PresenceState::Challenged));
+        assert!(PresenceState::Challenged.can_transition_to(&PresenceState::Finalized));
+        assert!(PresenceState::Challenged.can_transition_to(&PresenceState::Slashed));
+        assert!(!PresenceState::Slashed.can_transition_to(&PresenceState::Challenged));
+        assert!(!PresenceState::Validated.can_transition_to(&PresenceState::Challenged));
     }
 
     #[test]
@@ -494,6 +576,23 @@ mod tests {
         assert!(!QuorumConfig::new(6, 5).is_valid());
     }
 
+    #[test]
+    fn quorum_mode() {
+        let absolute = QuorumMode::Absolute(3);
+        assert!(absolute.is_valid());
+        assert!(absolute.is_met(3, 100));
+        assert!(!absolute.is_met(2, 2));
+
+        let two_thirds = QuorumMode::Fraction(Perbill::from_rational(2u32, 3u32));
+        assert!(two_thirds.is_valid());
+        assert_eq!(two_thirds.required_votes(3), 2);
+        assert_eq!(two_thirds.required_votes(10), 7);
+        assert_eq!(two_thirds.required_votes(0), 1);
+
+        assert!(!QuorumMode::Absolute(0).is_valid());
+        assert!(!QuorumMode::Fraction(Perbill::zero()).is_valid());
+    }
+
     #[test]
     fn violation_slash() {
         assert_eq!(ViolationType::Minor.slash_percent(), 5);
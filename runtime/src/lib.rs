@@ -250,9 +250,22 @@ parameter_types! {
     pub const DefaultQuorumTotal: u32 = 3;
     pub const CommitRevealDelay: BlockNumber = 2;
     pub const RevealWindow: BlockNumber = 4;
+    pub const MinEpochRevealRatio: Perbill = Perbill::from_percent(50);
+    pub const RevealFailurePenaltyEnabled: bool = false;
+    pub const MaxRevealFailures: u32 = 3;
     // Position-Based Triangulation
     pub const MinWitnessesForVerification: u32 = 3;
     pub const PositionToleranceMeters: u32 = 100;
+    pub const DeclarationCooldownBlocks: BlockNumber = 10;
+    pub const MaxIdleFinalizations: u32 = 20;
+    pub const RequireActiveDevice: bool = false;
+    pub const RequireActiveActor: bool = false;
+    pub const MinCommitmentsForReveal: u32 = 0;
+    pub const MaxCommitDuration: BlockNumber = 20;
+    pub const ChallengeWindow: BlockNumber = 14_400;
+    pub const MaxFinalizeBatch: u32 = 128;
+    pub const MaxPresencesPerEpoch: u32 = 100_000;
+    pub const MaxOpenChallengesPerValidator: u32 = 4;
 }
 
 impl pallet_presence::Config for Runtime {
@@ -262,10 +275,27 @@ impl pallet_presence::Config for Runtime {
     type DefaultQuorumTotal = DefaultQuorumTotal;
     type CommitRevealDelay = CommitRevealDelay;
     type RevealWindow = RevealWindow;
+    type MinEpochRevealRatio = MinEpochRevealRatio;
+    type RevealFailurePenaltyEnabled = RevealFailurePenaltyEnabled;
+    type MaxRevealFailures = MaxRevealFailures;
     type MinWitnessesForVerification = MinWitnessesForVerification;
     type PositionToleranceMeters = PositionToleranceMeters;
+    type DeclarationCooldownBlocks = DeclarationCooldownBlocks;
+    type MaxIdleFinalizations = MaxIdleFinalizations;
     type EpochProvider = Epoch;
     type ValidatorProvider = Validator;
+    type AggregateVoteVerifier = seveny_primitives::traits::RejectAllAggregates;
+    type DeviceRegistry = Device;
+    type RequireActiveDevice = RequireActiveDevice;
+    type ActorStatusProvider = Lifecycle;
+    type RequireActiveActor = RequireActiveActor;
+    type MinCommitmentsForReveal = MinCommitmentsForReveal;
+    type MaxCommitDuration = MaxCommitDuration;
+    type PresenceDisputes = Dispute;
+    type ChallengeWindow = ChallengeWindow;
+    type MaxFinalizeBatch = MaxFinalizeBatch;
+    type MaxPresencesPerEpoch = MaxPresencesPerEpoch;
+    type MaxOpenChallengesPerValidator = MaxOpenChallengesPerValidator;
 }
 
 parameter_types! {
@@ -289,16 +319,22 @@ parameter_types! {
     pub const MinValidators: u32 = 50;
     pub const BondingDuration: BlockNumber = 345_600;
     pub const SlashDeferDuration: BlockNumber = 86_400;
+    pub const ImmediateCriticalSlash: bool = false;
+    pub const MaxCommission: Perbill = Perbill::from_percent(50);
 }
 
 impl pallet_validator::Config for Runtime {
     type WeightInfo = ();
     type Currency = Balances;
+    type OnSlash = ();
     type MinStake = MinStake;
     type MaxValidators = MaxValidators;
     type MinValidators = MinValidators;
     type BondingDuration = BondingDuration;
     type SlashDeferDuration = SlashDeferDuration;
+    type ImmediateCriticalSlash = ImmediateCriticalSlash;
+    type MaxCommission = MaxCommission;
+    type KeyRotation = Lifecycle;
 }
 
 parameter_types! {
@@ -348,6 +384,17 @@ parameter_types! {
     pub const DiscoveryRateLimitBlocks: BlockNumber = 10;
     pub const RelationshipExpiryBlocks: BlockNumber = 10000;
     pub const MaxTrustLevel: u8 = 100;
+    pub const MaxGraphTraversal: u32 = 256;
+    pub const MaxTrustDeltaPerUpdate: u8 = 10;
+    pub const TrustUpdateCooldownBlocks: BlockNumber = 100;
+    pub const MinDiscoveryResults: u32 = 10;
+    pub const FullDiscoveryTrustScore: u32 = 100;
+    pub const MaxImportBatch: u32 = 256;
+    pub const DiscoveryRequestTtl: BlockNumber = 14_400;
+    pub const InteractionTrustAdjustment: bool = true;
+    pub const InteractionsPerTrustStep: u32 = 10;
+    pub const InteractionTrustCap: u8 = 80;
+    pub const InteractionDecayBlocks: BlockNumber = 14_400;
 }
 
 impl pallet_semantic::Config for Runtime {
@@ -357,6 +404,17 @@ impl pallet_semantic::Config for Runtime {
     type DiscoveryRateLimitBlocks = DiscoveryRateLimitBlocks;
     type RelationshipExpiryBlocks = RelationshipExpiryBlocks;
     type MaxTrustLevel = MaxTrustLevel;
+    type MaxGraphTraversal = MaxGraphTraversal;
+    type MaxTrustDeltaPerUpdate = MaxTrustDeltaPerUpdate;
+    type TrustUpdateCooldownBlocks = TrustUpdateCooldownBlocks;
+    type MinDiscoveryResults = MinDiscoveryResults;
+    type FullDiscoveryTrustScore = FullDiscoveryTrustScore;
+    type MaxImportBatch = MaxImportBatch;
+    type DiscoveryRequestTtl = DiscoveryRequestTtl;
+    type InteractionTrustAdjustment = InteractionTrustAdjustment;
+    type InteractionsPerTrustStep = InteractionsPerTrustStep;
+    type InteractionTrustCap = InteractionTrustCap;
+    type InteractionDecayBlocks = InteractionDecayBlocks;
 }
 
 parameter_types! {
@@ -381,6 +439,8 @@ parameter_types! {
     pub const BehaviorExpiryBlocks: BlockNumber = 10000;
     pub const ScoreIncreasePerMatch: u8 = 5;
     pub const MaxActorsPerPattern: u32 = 500;
+    pub const MaxFlagsBeforeBan: u32 = 5;
+    pub const ScoreSmoothingAlpha: u8 = 30;
 }
 
 impl pallet_autonomous::Config for Runtime {
@@ -391,6 +451,8 @@ impl pallet_autonomous::Config for Runtime {
     type BehaviorExpiryBlocks = BehaviorExpiryBlocks;
     type ScoreIncreasePerMatch = ScoreIncreasePerMatch;
     type MaxActorsPerPattern = MaxActorsPerPattern;
+    type MaxFlagsBeforeBan = MaxFlagsBeforeBan;
+    type ScoreSmoothingAlpha = ScoreSmoothingAlpha;
 }
 
 parameter_types! {
@@ -404,6 +466,26 @@ parameter_types! {
     pub const MaxConsecutiveMisses: u8 = 3;
     pub const HealthScoreDecay: u8 = 10;
     pub const HealthScoreRecovery: u8 = 5;
+    pub const MissRecoveryDecay: u8 = 1;
+    pub const AtRiskMissGrace: u8 = 1;
+    pub const ProbationHealthCeiling: u8 = 60;
+    pub const HealthCeilingRecovery: u8 = 1;
+    pub const MaxSubnodeVelocity: u64 = 1_000;
+    pub const GateClusterCreation: bool = false;
+    pub const PositionVarianceThreshold: u32 = 5000;
+    pub const ThroughputSla: Perbill = Perbill::from_percent(20);
+    pub const SlaBreachBlocks: BlockNumber = 100;
+    pub const MinSamplesForScaling: u32 = 3;
+    pub const MaxDevicesPerSubnode: u32 = 16;
+    pub const MaxSubnodeThroughputSamples: u32 = 64;
+    pub const AutoHealResetScore: u8 = 50;
+    pub const SeverityTrendWindow: u32 = 5;
+    pub const MaxSubnodesHardCeiling: u32 = 32;
+    pub const PositionVarianceDecay: u8 = 1;
+    pub const MaxSubnodesPerOperator: u32 = 16;
+    pub const PresenceAwareScaling: bool = true;
+    pub const PresencesPerSubnode: u32 = 500;
+    pub const ActivationHeartbeatWindow: BlockNumber = 10;
 }
 
 impl pallet_octopus::Config for Runtime {
@@ -418,6 +500,29 @@ impl pallet_octopus::Config for Runtime {
     type MaxConsecutiveMisses = MaxConsecutiveMisses;
     type HealthScoreDecay = HealthScoreDecay;
     type HealthScoreRecovery = HealthScoreRecovery;
+    type MissRecoveryDecay = MissRecoveryDecay;
+    type AtRiskMissGrace = AtRiskMissGrace;
+    type ProbationHealthCeiling = ProbationHealthCeiling;
+    type HealthCeilingRecovery = HealthCeilingRecovery;
+    type MaxSubnodeVelocity = MaxSubnodeVelocity;
+    type GateClusterCreation = GateClusterCreation;
+    type CapabilityCheck = Governance;
+    type PositionVarianceThreshold = PositionVarianceThreshold;
+    type ThroughputSla = ThroughputSla;
+    type SlaBreachBlocks = SlaBreachBlocks;
+    type MinSamplesForScaling = MinSamplesForScaling;
+    type DeviceRegistry = Device;
+    type MaxDevicesPerSubnode = MaxDevicesPerSubnode;
+    type MaxSubnodeThroughputSamples = MaxSubnodeThroughputSamples;
+    type AutoHealResetScore = AutoHealResetScore;
+    type SeverityTrendWindow = SeverityTrendWindow;
+    type MaxSubnodesHardCeiling = MaxSubnodesHardCeiling;
+    type PositionVarianceDecay = PositionVarianceDecay;
+    type MaxSubnodesPerOperator = MaxSubnodesPerOperator;
+    type PresenceLoad = Presence;
+    type PresenceAwareScaling = PresenceAwareScaling;
+    type PresencesPerSubnode = PresencesPerSubnode;
+    type ActivationHeartbeatWindow = ActivationHeartbeatWindow;
 }
 
 parameter_types! {
@@ -428,6 +533,14 @@ parameter_types! {
     pub const DeviceMaxConsecutiveMisses: u32 = 3;
     pub const DeviceHealthScoreDecay: u8 = 10;
     pub const DeviceHealthScoreRecovery: u8 = 5;
+    pub const DeviceHealthRecoveryCurve: pallet_device::HealthRecoveryCurve =
+        pallet_device::HealthRecoveryCurve::Asymptotic;
+    pub const DeviceMinAttesterTrustScore: u8 = 80;
+    pub const DeviceMaxBatchRegister: u32 = 50;
+    pub const DeviceMaxHeartbeatTimeoutBlocks: BlockNumber = 100;
+    pub const DeviceMinHealthScore: u8 = 30;
+    pub const DeviceRecoveryHealthScore: u8 = 50;
+    pub const DeviceAttestationUpgradeTrustBonus: u8 = 10;
 }
 
 impl pallet_device::Config for Runtime {
@@ -439,6 +552,14 @@ impl pallet_device::Config for Runtime {
     type MaxConsecutiveMisses = DeviceMaxConsecutiveMisses;
     type HealthScoreDecay = DeviceHealthScoreDecay;
     type HealthScoreRecovery = DeviceHealthScoreRecovery;
+    type HealthRecoveryCurve = DeviceHealthRecoveryCurve;
+    type MinAttesterTrustScore = DeviceMinAttesterTrustScore;
+    type MaxBatchRegister = DeviceMaxBatchRegister;
+    type MaxHeartbeatTimeoutBlocks = DeviceMaxHeartbeatTimeoutBlocks;
+    type MinHealthScore = DeviceMinHealthScore;
+    type RecoveryHealthScore = DeviceRecoveryHealthScore;
+    type AttestationVerifier = ();
+    type AttestationUpgradeTrustBonus = DeviceAttestationUpgradeTrustBonus;
 }
 
 parameter_types! {
@@ -449,6 +570,9 @@ parameter_types! {
     pub const MaxVaultsPerActor: u32 = 5;
     pub const MaxFilesPerVault: u32 = 64;
     pub const UnlockPeriodBlocks: BlockNumber = 300;
+    pub const GuardianApprovalThreshold: u32 = 2;
+    pub const RecoveryChallenge: bool = false;
+    pub const MaxConcurrentRecoveries: u32 = 2;
 }
 
 impl pallet_vault::Config for Runtime {
@@ -460,6 +584,9 @@ impl pallet_vault::Config for Runtime {
     type MaxVaultsPerActor = MaxVaultsPerActor;
     type MaxFilesPerVault = ConstU32<64>;
     type UnlockPeriodBlocks = ConstU32<300>;
+    type GuardianApprovalThreshold = GuardianApprovalThreshold;
+    type RecoveryChallenge = RecoveryChallenge;
+    type MaxConcurrentRecoveries = MaxConcurrentRecoveries;
 }
 
 parameter_types! {
@@ -522,6 +649,31 @@ parameter_types! {
     pub const LostTimeoutBlocks: BlockNumber = 100;
     pub const MinReadingsForActive: u32 = 3;
     pub const SignalRetentionBlocks: BlockNumber = 1000;
+    pub const MaxReporterMovementCm: u64 = 30_000;
+    pub const StrongSignalRssi: i8 = -60;
+    pub const FraudCaseRetentionBlocks: BlockNumber = 14_400;
+    pub const FraudResolutionTimeout: BlockNumber = 100_800;
+    pub const AutoUpholdStrongEvidence: bool = false;
+    pub const InitialReporterReputation: u8 = 50;
+    pub const DeviceRootInterval: BlockNumber = 100;
+    pub const MaxDeviceSetLeaves: u32 = 1024;
+    pub const ReferenceTxPower: i8 = -59;
+    pub const PathLossExponentX100: u16 = 270;
+    pub const MaxBatchReadings: u32 = 64;
+    pub const GhostRetentionBlocks: BlockNumber = 100_800;
+    pub const MaxHistoryPerReporter: u32 = 256;
+    pub const AnomalyThreshold: u32 = 2000;
+    pub const MinReportersForTrust: u32 = 2;
+    pub const SingleReporterConfidenceCap: u8 = 60;
+    pub const PositionConfirmationThreshold: u8 = 80;
+    pub const GhostWitnessQuorum: u32 = 2;
+    pub const UnwitnessedLostTimeoutBlocks: BlockNumber = 400;
+    pub const MaxReadingsPerReporterPerBlock: u32 = 64;
+    pub const MinAuthoritativeConfidence: u8 = 70;
+    pub const AllowSignalTypeMigration: bool = false;
+    pub const MaxAccusationDistanceCm: u64 = 500_000;
+    pub const ReputationGainWindow: BlockNumber = 14_400;
+    pub const MaxReputationGainPerWindow: u8 = 5;
 }
 
 impl pallet_triangulation::Config for Runtime {
@@ -533,6 +685,32 @@ impl pallet_triangulation::Config for Runtime {
     type LostTimeoutBlocks = LostTimeoutBlocks;
     type MinReadingsForActive = MinReadingsForActive;
     type SignalRetentionBlocks = SignalRetentionBlocks;
+    type MaxReporterMovementCm = MaxReporterMovementCm;
+    type StrongSignalRssi = StrongSignalRssi;
+    type FraudCaseRetentionBlocks = FraudCaseRetentionBlocks;
+    type FraudResolutionTimeout = FraudResolutionTimeout;
+    type AutoUpholdStrongEvidence = AutoUpholdStrongEvidence;
+    type InitialReporterReputation = InitialReporterReputation;
+    type DeviceRootInterval = DeviceRootInterval;
+    type MaxDeviceSetLeaves = MaxDeviceSetLeaves;
+    type ReferenceTxPower = ReferenceTxPower;
+    type PathLossExponentX100 = PathLossExponentX100;
+    type MaxBatchReadings = MaxBatchReadings;
+    type GhostRetentionBlocks = GhostRetentionBlocks;
+    type MaxHistoryPerReporter = MaxHistoryPerReporter;
+    type AnomalyThreshold = AnomalyThreshold;
+    type MinReportersForTrust = MinReportersForTrust;
+    type SingleReporterConfidenceCap = SingleReporterConfidenceCap;
+    type PositionConfirmed = Octopus;
+    type PositionConfirmationThreshold = PositionConfirmationThreshold;
+    type GhostWitnessQuorum = GhostWitnessQuorum;
+    type UnwitnessedLostTimeoutBlocks = UnwitnessedLostTimeoutBlocks;
+    type MaxReadingsPerReporterPerBlock = MaxReadingsPerReporterPerBlock;
+    type MinAuthoritativeConfidence = MinAuthoritativeConfidence;
+    type AllowSignalTypeMigration = AllowSignalTypeMigration;
+    type MaxAccusationDistanceCm = MaxAccusationDistanceCm;
+    type ReputationGainWindow = ReputationGainWindow;
+    type MaxReputationGainPerWindow = MaxReputationGainPerWindow;
 }
 
 parameter_types! {